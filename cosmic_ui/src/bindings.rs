@@ -27,3 +27,9 @@ impl<T> ComponentBinding<T> {
         Self { _phantom: std::marker::PhantomData }
     }
 }
+
+impl<T> Default for ComponentBinding<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
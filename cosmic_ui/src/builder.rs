@@ -5,18 +5,22 @@ use bevy::prelude::*;
 use crate::widgets::*;
 
 /// Fluent builder for creating optimized UI layouts
-pub struct WidgetBuilder<'a> {
-    commands: &'a mut Commands<'a, 'a>,
+pub struct WidgetBuilder<'a, 'w, 's> {
+    commands: &'a mut Commands<'w, 's>,
     font_handle: Handle<Font>,
     current_entity: Option<Entity>,
+    root_entity: Option<Entity>,
+    parent_stack: Vec<Option<Entity>>, // Parents to return to as stacks are closed
 }
 
-impl<'a> WidgetBuilder<'a> {
-    pub fn new(commands: &'a mut Commands<'a, 'a>, font_handle: Handle<Font>) -> Self {
+impl<'a, 'w, 's> WidgetBuilder<'a, 'w, 's> {
+    pub fn new(commands: &'a mut Commands<'w, 's>, font_handle: Handle<Font>) -> Self {
         Self {
             commands,
             font_handle,
             current_entity: None,
+            root_entity: None,
+            parent_stack: Vec::new(),
         }
     }
     
@@ -33,9 +37,60 @@ impl<'a> WidgetBuilder<'a> {
         )).id();
         
         self.current_entity = Some(entity);
+        self.root_entity = Some(entity);
         self
     }
     
+    /// Open a column container, widgets built until `end_stack` are laid out top to bottom
+    pub fn vstack(self, position: UIPosition, spacing: f32) -> Self {
+        self.stack(position, spacing, FlexDirection::Column)
+    }
+    
+    /// Open a row container, widgets built until `end_stack` are laid out left to right
+    pub fn hstack(self, position: UIPosition, spacing: f32) -> Self {
+        self.stack(position, spacing, FlexDirection::Row)
+    }
+    
+    fn stack(mut self, position: UIPosition, spacing: f32, direction: FlexDirection) -> Self {
+        let container = self.commands.spawn(Node {
+            position_type: self.widget_position_type(),
+            left: position.left,
+            top: position.top,
+            right: position.right,
+            bottom: position.bottom,
+            flex_direction: direction,
+            row_gap: Val::Px(spacing),
+            column_gap: Val::Px(spacing),
+            align_items: AlignItems::Start,
+            ..default()
+        }).id();
+        
+        if let Some(parent) = self.current_entity {
+            self.commands.entity(parent).add_child(container);
+        }
+        
+        self.parent_stack.push(self.current_entity);
+        self.current_entity = Some(container);
+        self
+    }
+    
+    /// Close the innermost stack and go back to building into its parent
+    pub fn end_stack(mut self) -> Self {
+        if let Some(parent) = self.parent_stack.pop() {
+            self.current_entity = parent;
+        }
+        self
+    }
+    
+    /// Widgets inside a stack join the flex flow, elsewhere they're placed absolutely
+    fn widget_position_type(&self) -> PositionType {
+        if self.parent_stack.is_empty() {
+            PositionType::Absolute
+        } else {
+            PositionType::Relative
+        }
+    }
+    
     /// Add text display with automatic formatting
     pub fn text_display(self, initial_text: &str, position: UIPosition) -> (Self, TextDisplay) {
         let entity = self.commands.spawn((
@@ -47,7 +102,7 @@ impl<'a> WidgetBuilder<'a> {
             },
             TextColor(position.color),
             Node {
-                position_type: PositionType::Absolute,
+                position_type: self.widget_position_type(),
                 left: position.left,
                 top: position.top,
                 bottom: position.bottom,
@@ -70,7 +125,7 @@ impl<'a> WidgetBuilder<'a> {
         // Background
         let bg_entity = self.commands.spawn((
             Node {
-                position_type: PositionType::Absolute,
+                position_type: self.widget_position_type(),
                 left: position.left,
                 top: position.top,
                 width: Val::Px(config.width),
@@ -82,6 +137,10 @@ impl<'a> WidgetBuilder<'a> {
             BorderColor(config.border_color),
         )).id();
         
+        if config.segments > 0 {
+            return self.spawn_segmented_fill(bg_entity, config);
+        }
+        
         // Fill
         let fill_entity = self.commands.spawn((
             Node {
@@ -107,6 +166,116 @@ impl<'a> WidgetBuilder<'a> {
         (self, widget)
     }
     
+    /// Row of equal cells inside the bar background, all lit to start with
+    fn spawn_segmented_fill(self, bg_entity: Entity, config: ProgressBarConfig) -> (Self, ProgressBar) {
+        let count = config.segments as usize;
+        let inner_width = config.width - config.border_width * 2.0;
+        let cell_width = ((inner_width - config.segment_gap * (count as f32 + 1.0)) / count as f32).max(1.0);
+        
+        let row = self.commands.spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(config.segment_gap),
+            padding: UiRect::all(Val::Px(config.segment_gap)),
+            ..default()
+        }).id();
+        self.commands.entity(bg_entity).add_child(row);
+        
+        let mut widget = ProgressBar::new(bg_entity, row, config.max_value)
+            .with_gradient(config.color_gradient);
+        widget.segment_fill = config.fill_color;
+        widget.segment_empty = config.background_color;
+        widget.segment_colors = config.segment_colors;
+        
+        for index in 0..count {
+            let cell = self.commands.spawn((
+                Node {
+                    width: Val::Px(cell_width),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(widget.segment_color(index)),
+            )).id();
+            self.commands.entity(row).add_child(cell);
+            widget.segments.push(cell);
+        }
+        widget.lit_segments = count;
+        
+        if let Some(parent) = self.current_entity {
+            self.commands.entity(parent).add_child(bg_entity);
+        }
+        
+        (self, widget)
+    }
+    
+    /// Add radial gauge, a ring of segments lit along the arc as the value rises
+    pub fn radial_gauge(self, position: UIPosition, config: RadialGaugeConfig) -> (Self, RadialGauge) {
+        let container = self.commands.spawn((
+            Node {
+                position_type: self.widget_position_type(),
+                left: position.left,
+                top: position.top,
+                right: position.right,
+                bottom: position.bottom,
+                width: Val::Px(config.radius * 2.0),
+                height: Val::Px(config.radius * 2.0),
+                ..default()
+            },
+        )).id();
+
+        let segment_count = config.segments.max(2);
+        let mut gauge = RadialGauge::new(container, Vec::new(), config.min_value, config.max_value);
+        gauge.start_angle = config.start_angle;
+        gauge.end_angle = config.end_angle;
+        gauge.background_color = config.background_color;
+        gauge.color_gradient = config.color_gradient;
+        gauge.current_value = config.initial_value;
+
+        let half = config.segment_size * 0.5;
+        let ring_radius = config.radius - half;
+        for index in 0..segment_count {
+            let t = index as f32 / (segment_count - 1) as f32;
+            let angle = gauge.angle_at(t);
+            // UI space grows downward, so flip the sine
+            let x = config.radius + angle.cos() * ring_radius - half;
+            let y = config.radius - angle.sin() * ring_radius - half;
+
+            let segment = self.commands.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(x),
+                    top: Val::Px(y),
+                    width: Val::Px(config.segment_size),
+                    height: Val::Px(config.segment_size),
+                    ..default()
+                },
+                BorderRadius::all(Val::Px(half)),
+                BackgroundColor(config.background_color),
+            )).id();
+
+            self.commands.entity(container).add_child(segment);
+            gauge.segments.push(segment);
+        }
+
+        // Paint the initial value so the gauge is correct before its first update
+        let lit = gauge.lit_segments();
+        for (index, segment) in gauge.segments.iter().enumerate() {
+            if index < lit {
+                let color = crate::systems::calculate_gradient_color(&gauge.color_gradient, gauge.segment_t(index));
+                self.commands.entity(*segment).insert(BackgroundColor(color));
+            }
+        }
+
+        self.commands.entity(container).insert(gauge.clone());
+
+        if let Some(parent) = self.current_entity {
+            self.commands.entity(parent).add_child(container);
+        }
+
+        (self, gauge)
+    }
+
 /// Add counter with prefix/suffix
     pub fn counter(self, prefix: &str, suffix: &str, position: UIPosition) -> (Self, Counter) {
         let initial_text = format!("{}{}{}", prefix, 0, suffix);
@@ -134,7 +303,7 @@ impl<'a> WidgetBuilder<'a> {
     pub fn notification_queue(self, position: UIPosition, max_visible: usize) -> (Self, NotificationQueue) {
         let container = self.commands.spawn((
             Node {
-                position_type: PositionType::Absolute,
+                position_type: self.widget_position_type(),
                 left: position.left,
                 top: position.top,
                 flex_direction: FlexDirection::Column,
@@ -156,7 +325,7 @@ impl<'a> WidgetBuilder<'a> {
     pub fn info_panel(self, max_lines: usize, position: UIPosition) -> (Self, InfoPanel) {
         let container = self.commands.spawn((
             Node {
-                position_type: PositionType::Absolute,
+                position_type: self.widget_position_type(),
                 left: position.left,
                 top: position.top,
                 flex_direction: FlexDirection::Column,
@@ -174,6 +343,33 @@ impl<'a> WidgetBuilder<'a> {
         (self, widget)
     }
     
+    /// Build a widget that floats over `target` in the world, e.g. a boss health bar.
+    /// The widget's top left corner tracks the target's projected position plus `offset`.
+    /// It's always built absolutely positioned under the UI root, even from inside a stack,
+    /// since flex layout would fight the anchor system for its position
+    pub fn world_anchored<W: AnchorableWidget>(
+        mut self,
+        target: Entity,
+        offset: Vec2,
+        widget: impl FnOnce(Self, UIPosition) -> (Self, W),
+    ) -> (Self, W) {
+        let current_entity = self.current_entity;
+        let parent_stack = std::mem::take(&mut self.parent_stack);
+        self.current_entity = self.root_entity;
+        
+        let (mut builder, widget) = widget(self, UIPosition::default());
+        builder.current_entity = current_entity;
+        builder.parent_stack = parent_stack;
+        
+        // Hidden until the anchor system has placed it for the first time
+        builder.commands.entity(widget.root_entity()).insert((
+            WorldAnchored { target, offset },
+            Visibility::Hidden,
+        ));
+        
+        (builder, widget)
+    }
+    
     /// Get the current root entity
     pub fn entity(&self) -> Option<Entity> {
         self.current_entity
@@ -189,6 +385,27 @@ pub struct UIPosition {
     pub bottom: Val,
     pub font_size: f32,
     pub color: Color,
+    pub anchor: UIAnchor,
+}
+
+/// Corner of the widget that a percentage offset places
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UIAnchor {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl UIAnchor {
+    fn is_right(self) -> bool {
+        matches!(self, UIAnchor::TopRight | UIAnchor::BottomRight)
+    }
+    
+    fn is_bottom(self) -> bool {
+        matches!(self, UIAnchor::BottomLeft | UIAnchor::BottomRight)
+    }
 }
 
 impl Default for UIPosition {
@@ -200,6 +417,7 @@ impl Default for UIPosition {
             bottom: Val::Auto,
             font_size: 18.0,
             color: Color::WHITE,
+            anchor: UIAnchor::TopLeft,
         }
     }
 }
@@ -250,21 +468,55 @@ impl UIPosition {
         }
     }
     
-    /// Add offset to current position
+    /// Add offset to current position, measured inwards from whichever edges are set
     pub fn with_offset(mut self, x: f32, y: f32) -> Self {
-        match self.left {
-            Val::Px(px) => self.left = Val::Px(px + x),
-            Val::Percent(pct) => self.left = Val::Percent(pct + x),
+        let horizontal = if matches!(self.left, Val::Auto) { &mut self.right } else { &mut self.left };
+        match *horizontal {
+            Val::Px(px) => *horizontal = Val::Px(px + x),
+            Val::Percent(pct) => *horizontal = Val::Percent(pct + x),
             _ => {}
         }
-        match self.top {
-            Val::Px(px) => self.top = Val::Px(px + y),
-            Val::Percent(pct) => self.top = Val::Percent(pct + y),
+        let vertical = if matches!(self.top, Val::Auto) { &mut self.bottom } else { &mut self.top };
+        match *vertical {
+            Val::Px(px) => *vertical = Val::Px(px + y),
+            Val::Percent(pct) => *vertical = Val::Percent(pct + y),
             _ => {}
         }
         self
     }
     
+    /// Place the anchor corner at a share of the window (0 to 100, from the top left),
+    /// so the layout follows window resizes
+    pub fn with_percent_offset(mut self, px: f32, py: f32) -> Self {
+        let (left, right) = if self.anchor.is_right() {
+            (Val::Auto, Val::Percent(100.0 - px))
+        } else {
+            (Val::Percent(px), Val::Auto)
+        };
+        let (top, bottom) = if self.anchor.is_bottom() {
+            (Val::Auto, Val::Percent(100.0 - py))
+        } else {
+            (Val::Percent(py), Val::Auto)
+        };
+        self.left = left;
+        self.right = right;
+        self.top = top;
+        self.bottom = bottom;
+        self
+    }
+    
+    /// Choose which widget corner percentage offsets place, existing percentages are kept in place
+    pub fn with_anchor(mut self, anchor: UIAnchor) -> Self {
+        if anchor.is_right() != self.anchor.is_right() {
+            (self.left, self.right) = mirror_percent(self.left, self.right);
+        }
+        if anchor.is_bottom() != self.anchor.is_bottom() {
+            (self.top, self.bottom) = mirror_percent(self.top, self.bottom);
+        }
+        self.anchor = anchor;
+        self
+    }
+    
     /// Set font size
     pub fn with_font_size(mut self, size: f32) -> Self {
         self.font_size = size;
@@ -278,6 +530,15 @@ impl UIPosition {
     }
 }
 
+/// Move a percentage from one edge to the opposite one, pixel offsets can't be mirrored without the window size
+fn mirror_percent(near: Val, far: Val) -> (Val, Val) {
+    match (near, far) {
+        (Val::Percent(pct), Val::Auto) => (Val::Auto, Val::Percent(100.0 - pct)),
+        (Val::Auto, Val::Percent(pct)) => (Val::Percent(100.0 - pct), Val::Auto),
+        other => other,
+    }
+}
+
 /// Configuration for progress bars
 #[derive(Clone, Debug)]
 pub struct ProgressBarConfig {
@@ -289,6 +550,9 @@ pub struct ProgressBarConfig {
     pub fill_color: Color,
     pub max_value: f32,
     pub color_gradient: Vec<(f32, Color)>,
+    pub segments: u32,                    // 0 = smooth fill, otherwise discrete pips
+    pub segment_gap: f32,
+    pub segment_colors: Vec<(usize, Color)>, // Per-segment fill overrides
 }
 
 impl Default for ProgressBarConfig {
@@ -306,11 +570,35 @@ impl Default for ProgressBarConfig {
                 (0.6, Color::srgb(0.8, 0.8, 0.2)), // Yellow at 60%
                 (1.0, Color::srgb(0.2, 0.8, 0.2)), // Green at 100%
             ],
+            segments: 0,
+            segment_gap: 2.0,
+            segment_colors: Vec::new(),
         }
     }
 }
 
 impl ProgressBarConfig {
+    /// Discrete pips for shields, armor or lives, each cell fills or empties as a whole
+    pub fn segmented(count: u32) -> Self {
+        Self {
+            width: 24.0 * count as f32,
+            height: 14.0,
+            border_color: Color::srgb(0.3, 0.8, 0.6),
+            background_color: Color::srgb(0.05, 0.15, 0.1),
+            fill_color: Color::srgb(0.3, 0.9, 1.0),
+            max_value: count as f32,
+            segments: count,
+            ..default()
+        }
+    }
+    
+    /// Fill a single segment with its own color, e.g. the last pip in red
+    pub fn with_segment_color(mut self, index: usize, color: Color) -> Self {
+        self.segment_colors.retain(|(existing, _)| *existing != index);
+        self.segment_colors.push((index, color));
+        self
+    }
+    
     /// Create config for health bar
     pub fn health_bar() -> Self {
         Self {
@@ -363,3 +651,61 @@ impl ProgressBarConfig {
         }
     }
 }
+
+/// Configuration for radial gauges
+#[derive(Clone, Debug)]
+pub struct RadialGaugeConfig {
+    pub radius: f32,
+    pub segments: usize,
+    pub segment_size: f32,
+    pub start_angle: f32, // Radians, 0 = right, counter-clockwise positive
+    pub end_angle: f32,
+    pub min_value: f32,
+    pub max_value: f32,
+    pub initial_value: f32,
+    pub background_color: Color,
+    pub color_gradient: Vec<(f32, Color)>,
+}
+
+impl Default for RadialGaugeConfig {
+    fn default() -> Self {
+        Self {
+            radius: 40.0,
+            segments: 24,
+            segment_size: 8.0,
+            start_angle: std::f32::consts::PI * 1.25, // Bottom left
+            end_angle: -std::f32::consts::PI * 0.25,  // Bottom right, sweeping over the top
+            min_value: 0.0,
+            max_value: 100.0,
+            initial_value: 0.0,
+            background_color: Color::srgba(0.1, 0.1, 0.1, 0.6),
+            color_gradient: vec![
+                (0.0, Color::srgb(0.2, 0.8, 0.2)), // Green at the start of the arc
+                (0.5, Color::srgb(0.8, 0.8, 0.2)), // Yellow halfway
+                (1.0, Color::srgb(0.8, 0.2, 0.2)), // Red at the end
+            ],
+        }
+    }
+}
+
+impl RadialGaugeConfig {
+    /// Create config for an environment danger gauge, calm green to hostile red
+    pub fn danger() -> Self {
+        Self {
+            max_value: 1.0,
+            ..default()
+        }
+    }
+
+    /// Create config for a health gauge, filling red to green as health rises
+    pub fn health() -> Self {
+        Self {
+            color_gradient: vec![
+                (0.0, Color::srgb(0.8, 0.2, 0.2)),   // Red when nearly empty
+                (0.5, Color::srgb(0.8, 0.8, 0.2)),   // Yellow at half
+                (1.0, Color::srgb(0.2, 0.8, 0.4)),   // Green when full
+            ],
+            ..default()
+        }
+    }
+}
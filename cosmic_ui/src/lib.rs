@@ -2,6 +2,9 @@
 //! Cosmic UI Framework - Ultra-performant declarative UI for Bevy games
 //! Zero-cost abstractions with compile-time generation
 
+// Bevy systems take their queries and resources as arguments
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
 use bevy::prelude::*;
 
 pub mod prelude {
@@ -34,15 +37,27 @@ impl Plugin for CosmicUIPlugin {
             .init_resource::<UIPerformanceMetrics>()
             .add_systems(PreUpdate, cosmic_ui_change_detection)
             .add_systems(Update, cosmic_ui_batch_updates)
-            .add_systems(PostUpdate, cosmic_ui_cleanup);
+            .add_systems(PostUpdate, (
+                cosmic_ui_cleanup,
+                update_world_anchored.before(bevy::ui::UiSystem::Layout),
+            ));
     }
 }
 
 /// Resource for batching UI updates for maximum performance
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct UIUpdateScheduler {
     pub pending_updates: Vec<UIUpdateCommand>,
     pub frame_budget_us: u64,
+    /// Called with the number of updates pushed to next frame when the budget runs out
+    pub on_budget_exceeded: Option<Box<dyn Fn(usize) + Send + Sync>>,
+    pub(crate) active_tweens: Vec<ActiveTween>,
+}
+
+impl Default for UIUpdateScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl UIUpdateScheduler {
@@ -50,20 +65,91 @@ impl UIUpdateScheduler {
         Self {
             pending_updates: Vec::new(),
             frame_budget_us: 500, // 0.5ms budget per frame
+            on_budget_exceeded: None,
+            active_tweens: Vec::new(),
         }
     }
     
     pub fn queue_update(&mut self, update: UIUpdateCommand) {
         self.pending_updates.push(update);
     }
+    
+    /// Install the budget overflow hook, e.g. to log UI thrashing during king tides
+    pub fn set_budget_exceeded_hook(&mut self, hook: impl Fn(usize) + Send + Sync + 'static) {
+        self.on_budget_exceeded = Some(Box::new(hook));
+    }
+    
+    /// Number of tweens still animating
+    pub fn active_tween_count(&self) -> usize {
+        self.active_tweens.len()
+    }
 }
 
+// StyleUpdate carries a whole Node, commands only live in the scheduler queue for a frame
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum UIUpdateCommand {
     TextUpdate { entity: Entity, text: String },
     StyleUpdate { entity: Entity, style: Node },
     ColorUpdate { entity: Entity, color: Color },
     VisibilityUpdate { entity: Entity, visible: bool },
+    GaugeUpdate { entity: Entity, value: f32 },
+    AnimatedUpdate { entity: Entity, target: TweenTarget, duration: f32, easing: Easing },
+    /// `entity` is the notification queue's container
+    NotificationPush { entity: Entity, text: String, priority: NotificationPriority },
+}
+
+/// End value of an animated update, the start value is read from the entity when the tween begins
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum TweenTarget {
+    Color(Color),
+    Style(Node),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseOutQuad,
+    EaseInOutCubic,
+}
+
+impl Easing {
+    /// Map linear progress (0.0 to 1.0) onto the easing curve
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseOutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A tween in flight, progress is derived from the start time so tweens skipped
+/// by the frame budget catch up on the next frame
+#[derive(Debug, Clone)]
+pub(crate) struct ActiveTween {
+    pub entity: Entity,
+    pub from: TweenTarget,
+    pub to: TweenTarget,
+    pub started_at: f32,
+    pub duration: f32,
+    pub easing: Easing,
+}
+
+impl ActiveTween {
+    pub fn progress(&self, now: f32) -> f32 {
+        if self.duration <= 0.0 { return 1.0; }
+        ((now - self.started_at) / self.duration).clamp(0.0, 1.0)
+    }
 }
 
 /// Trait for game HUDs with automatic binding generation
@@ -74,8 +160,9 @@ pub trait GameHUD: Component + Sized {
     /// Generated method that creates optimized update systems
     fn register_systems(app: &mut App);
     
-    /// Update method called when bound data changes
-    fn update_bindings(&mut self, world: &World, ui_root: Entity);
+    /// Read every bound source from `world` and return the resulting updates for the caller to queue
+    /// on the `UIUpdateScheduler`. Refreshes unconditionally, for HUDs driven by hand instead of `register_systems`
+    fn update_bindings(&mut self, world: &World, ui_root: Entity) -> Vec<UIUpdateCommand>;
 }
 
 /// Compile-time UI binding with change detection
@@ -87,7 +174,8 @@ pub struct UIBinding<T, W> {
 #[derive(Resource, Default)]
 pub struct UIPerformanceMetrics {
     pub frame_time_us: u64,
-    pub updates_per_frame: usize,
+    pub updates_per_frame: usize, // Superseded updates collapsed away this frame
+    pub updates_deferred: usize, // Left for next frame when the budget ran out
     pub widgets_active: usize,
     pub memory_usage_kb: usize,
 }
@@ -2,6 +2,8 @@
 //! ECS systems for high-performance UI updates
 
 use bevy::prelude::*;
+use std::collections::HashMap;
+use std::mem::Discriminant;
 use std::time::Instant;
 use crate::*;
 
@@ -14,6 +16,9 @@ pub fn cosmic_ui_change_detection(
     // Generated change detection queries go here
 }
 
+/// Seconds a pushed notification stays on screen
+const NOTIFICATION_LIFETIME: f32 = 3.0;
+
 /// Batch update system - processes all UI updates in one frame
 pub fn cosmic_ui_batch_updates(
     mut scheduler: ResMut<UIUpdateScheduler>,
@@ -22,35 +27,106 @@ pub fn cosmic_ui_batch_updates(
     mut node_query: Query<&mut Node>,
     mut background_color_query: Query<&mut BackgroundColor>,
     mut visibility_query: Query<&mut Visibility>,
+    mut gauge_query: Query<&mut RadialGauge>,
+    mut notification_query: Query<&mut NotificationQueue>,
+    mut commands: Commands,
+    time: Res<Time>,
 ) {
     let start = Instant::now();
-    let mut updates_processed = 0;
     
-    // Process updates within frame budget
-    while let Some(update) = scheduler.pending_updates.pop() {
+    // Only the last text/color/visibility update per entity matters
+    let (coalesced, collapsed) = coalesce_updates(std::mem::take(&mut scheduler.pending_updates));
+    
+    // Process updates in queue order within frame budget, whatever is left waits for next frame
+    let mut pending = coalesced.into_iter();
+    for update in pending.by_ref() {
         match update {
             UIUpdateCommand::TextUpdate { entity, text } => {
                 if let Ok(mut text_component) = text_query.get_mut(entity) {
                     **text_component = text;
-                    updates_processed += 1;
                 }
             }
             UIUpdateCommand::StyleUpdate { entity, style } => {
                 if let Ok(mut node_component) = node_query.get_mut(entity) {
                     *node_component = style;
-                    updates_processed += 1;
                 }
             }
             UIUpdateCommand::ColorUpdate { entity, color } => {
                 if let Ok(mut color_component) = background_color_query.get_mut(entity) {
                     *color_component = BackgroundColor(color);
-                    updates_processed += 1;
                 }
             }
             UIUpdateCommand::VisibilityUpdate { entity, visible } => {
                 if let Ok(mut visibility) = visibility_query.get_mut(entity) {
                     *visibility = if visible { Visibility::Visible } else { Visibility::Hidden };
-                    updates_processed += 1;
+                }
+            }
+            UIUpdateCommand::GaugeUpdate { entity, value } => {
+                if let Ok(mut gauge) = gauge_query.get_mut(entity) {
+                    gauge.current_value = value;
+                    let lit = gauge.lit_segments();
+                    for (index, segment) in gauge.segments.iter().enumerate() {
+                        if let Ok(mut color_component) = background_color_query.get_mut(*segment) {
+                            color_component.0 = if index < lit {
+                                calculate_gradient_color(&gauge.color_gradient, gauge.segment_t(index))
+                            } else {
+                                gauge.background_color
+                            };
+                        }
+                    }
+                }
+            }
+            UIUpdateCommand::AnimatedUpdate { entity, target, duration, easing } => {
+                let from = match &target {
+                    TweenTarget::Color(_) => background_color_query.get(entity).ok().map(|color| TweenTarget::Color(color.0)),
+                    TweenTarget::Style(_) => node_query.get(entity).ok().map(|node| TweenTarget::Style(node.clone())),
+                };
+                if let Some(from) = from {
+                    // A new tween on the same entity and property replaces the old one
+                    scheduler.active_tweens.retain(|tween| {
+                        tween.entity != entity || std::mem::discriminant(&tween.to) != std::mem::discriminant(&target)
+                    });
+                    scheduler.active_tweens.push(ActiveTween {
+                        entity,
+                        from,
+                        to: target,
+                        started_at: time.elapsed_secs(),
+                        duration,
+                        easing,
+                    });
+                }
+            }
+            UIUpdateCommand::NotificationPush { entity, text, priority } => {
+                if let Some(mut queue) = notification_query.iter_mut().find(|queue| queue.container == entity) {
+                    let severity = NotificationLevel::for_priority(priority);
+                    // The text entity is only spawned once the queue accepts a new entry
+                    let notification = Notification::new(Entity::PLACEHOLDER, text, severity, NOTIFICATION_LIFETIME).with_priority(priority);
+                    
+                    match queue.push_with_priority(notification) {
+                        NotificationPushOutcome::Inserted { index, evicted } => {
+                            if let Some(evicted) = evicted {
+                                commands.entity(evicted).try_despawn();
+                            }
+                            let notification = &mut queue.notifications[index];
+                            let line = commands.spawn((
+                                Text::new(notification.display_text()),
+                                TextColor(notification.severity.color()),
+                            )).id();
+                            notification.entity = line;
+                            commands.entity(entity).insert_children(index, &[line]);
+                        }
+                        NotificationPushOutcome::Merged { entity: merged, .. } => {
+                            // The count lives on the queue entry. A line spawned earlier this frame has no
+                            // Text yet, so the merged text is queued to land after its spawn bundle
+                            if let Some(existing) = queue.notifications.iter().find(|n| n.entity == merged) {
+                                match text_query.get_mut(merged) {
+                                    Ok(mut text_component) => **text_component = existing.display_text(),
+                                    Err(_) => { commands.entity(merged).try_insert(Text::new(existing.display_text())); }
+                                }
+                            }
+                        }
+                        NotificationPushOutcome::Rejected => {}
+                    }
                 }
             }
         }
@@ -61,9 +137,83 @@ pub fn cosmic_ui_batch_updates(
         }
     }
     
+    let deferred: Vec<UIUpdateCommand> = pending.collect();
+    let deferred_count = deferred.len();
+    scheduler.pending_updates = deferred;
+    if deferred_count > 0 {
+        if let Some(hook) = &scheduler.on_budget_exceeded {
+            hook(deferred_count);
+        }
+    }
+    
+    // Advance active tweens with whatever budget is left
+    let now = time.elapsed_secs();
+    let budget = scheduler.frame_budget_us;
+    let mut finished = Vec::new();
+    for (index, tween) in scheduler.active_tweens.iter().enumerate() {
+        let progress = tween.progress(now);
+        let eased = tween.easing.apply(progress);
+        match (&tween.from, &tween.to) {
+            (TweenTarget::Color(from), TweenTarget::Color(to)) => {
+                if let Ok(mut color_component) = background_color_query.get_mut(tween.entity) {
+                    color_component.0 = lerp_color(*from, *to, eased);
+                }
+            }
+            (TweenTarget::Style(from), TweenTarget::Style(to)) => {
+                if let Ok(mut node_component) = node_query.get_mut(tween.entity) {
+                    *node_component = lerp_node(from, to, eased);
+                }
+            }
+            _ => {}
+        }
+        
+        if progress >= 1.0 {
+            finished.push(index);
+        }
+        
+        // Frame budget check
+        if start.elapsed().as_micros() as u64 > budget {
+            break;
+        }
+    }
+    for index in finished.into_iter().rev() {
+        scheduler.active_tweens.swap_remove(index);
+    }
+    
     // Update performance metrics
     metrics.frame_time_us = start.elapsed().as_micros() as u64;
-    metrics.updates_per_frame = updates_processed;
+    metrics.updates_per_frame = collapsed;
+    metrics.updates_deferred = deferred_count;
+}
+
+/// Drop TextUpdate, ColorUpdate and VisibilityUpdate commands superseded by a later one
+/// of the same kind for the same entity. Order is kept, returns the survivors and the number dropped
+pub fn coalesce_updates(updates: Vec<UIUpdateCommand>) -> (Vec<UIUpdateCommand>, usize) {
+    fn coalesce_key(update: &UIUpdateCommand) -> Option<(Entity, Discriminant<UIUpdateCommand>)> {
+        match update {
+            UIUpdateCommand::TextUpdate { entity, .. }
+            | UIUpdateCommand::ColorUpdate { entity, .. }
+            | UIUpdateCommand::VisibilityUpdate { entity, .. } => Some((*entity, std::mem::discriminant(update))),
+            _ => None,
+        }
+    }
+    
+    let mut last_index: HashMap<(Entity, Discriminant<UIUpdateCommand>), usize> = HashMap::new();
+    for (index, update) in updates.iter().enumerate() {
+        if let Some(key) = coalesce_key(update) {
+            last_index.insert(key, index);
+        }
+    }
+    
+    let total = updates.len();
+    let kept: Vec<UIUpdateCommand> = updates.into_iter()
+        .enumerate()
+        .filter(|(index, update)| coalesce_key(update).is_none_or(|key| last_index[&key] == *index))
+        .map(|(_, update)| update)
+        .collect();
+    let collapsed = total - kept.len();
+    
+    (kept, collapsed)
 }
 
 /// Cleanup system for notification lifetimes and temporary UI
@@ -106,9 +256,10 @@ pub fn cosmic_ui_cleanup(
                     }
                 }
                 StatusAnimation::Fade { duration } => {
-                    if status.animation_timer >= duration {
+                    if status.animation_timer >= duration && status.transition_progress().is_none() {
                         // Move to next state or loop
-                        status.current_state = (status.current_state + 1) % status.states.len();
+                        let next = (status.current_state + 1) % status.states.len();
+                        status.set_state(next);
                         status.animation_timer = 0.0;
                     }
                 }
@@ -127,7 +278,23 @@ pub fn update_progress_bars(
     mut background_color_query: Query<&mut BackgroundColor>,
     time: Res<Time>,
 ) {
-    for progress_bar in progress_bars.iter_mut() {
+    for mut progress_bar in progress_bars.iter_mut() {
+        if !progress_bar.segments.is_empty() {
+            // Only repaint the cells between the old and new fill level
+            let target = progress_bar.target_segments();
+            let current = progress_bar.lit_segments;
+            if target == current { continue; }
+            
+            for index in target.min(current)..target.max(current) {
+                let color = if index < target { progress_bar.segment_color(index) } else { progress_bar.segment_empty };
+                if let Ok(mut segment_color) = background_color_query.get_mut(progress_bar.segments[index]) {
+                    segment_color.0 = color;
+                }
+            }
+            progress_bar.lit_segments = target;
+            continue;
+        }
+        
         // Smooth animation towards target percentage
         if let Ok(mut node) = node_query.get_mut(progress_bar.fill_entity) {
             let style = &mut node;
@@ -161,6 +328,41 @@ pub fn animate_status_indicators(
     for mut indicator in indicators.iter_mut() {
         indicator.animation_timer += time.delta_secs();
         
+        if let Some(progress) = indicator.transition_progress() {
+            indicator.transition_timer += time.delta_secs();
+            
+            if progress >= 1.0 {
+                indicator.current_state = indicator.target_state;
+                indicator.animation_timer = 0.0;
+            } else if let (Some(outgoing), Some(incoming)) = (
+                indicator.states.get(indicator.current_state),
+                indicator.states.get(indicator.target_state),
+            ) {
+                // Outgoing text fades out over the first half, incoming fades in over the second
+                let (text_source, text_alpha) = if progress < 0.5 {
+                    (outgoing, 1.0 - progress * 2.0)
+                } else {
+                    (incoming, progress * 2.0 - 1.0)
+                };
+                
+                if let Ok(mut text) = text_query.get_mut(indicator.entity) {
+                    if **text != text_source.text {
+                        **text = text_source.text.clone();
+                    }
+                }
+                
+                if let Ok(mut text_color) = color_query.get_mut(indicator.entity) {
+                    let blended = lerp_color(
+                        status_color(outgoing, indicator.animation_timer),
+                        status_color(incoming, indicator.transition_timer),
+                        progress,
+                    );
+                    text_color.0 = blended.with_alpha(blended.alpha() * text_alpha);
+                }
+                continue;
+            }
+        }
+        
         if indicator.current_state < indicator.states.len() {
             let state = &indicator.states[indicator.current_state];
             
@@ -173,39 +375,77 @@ pub fn animate_status_indicators(
             
             // Update color with animation
             if let Ok(mut text_color) = color_query.get_mut(indicator.entity) {
-                let base_color = state.color;
-                let animated_color = match state.animation {
-                    StatusAnimation::Pulse { frequency } => {
-                        let pulse = (indicator.animation_timer * frequency * 2.0 * std::f32::consts::PI).sin();
-                        let intensity = 0.7 + pulse * 0.3;
-                        Color::srgba(
-                            base_color.to_srgba().red * intensity,
-                            base_color.to_srgba().green * intensity,
-                            base_color.to_srgba().blue * intensity,
-                            base_color.to_srgba().alpha,
-                        )
-                    }
-                    StatusAnimation::Flash { interval } => {
-                        let flash_cycle = indicator.animation_timer % interval;
-                        if flash_cycle < interval * 0.1 {
-                            Color::WHITE
-                        } else {
-                            base_color
-                        }
-                    }
-                    StatusAnimation::Fade { duration } => {
-                        let fade_progress = (indicator.animation_timer % duration) / duration;
-                        let alpha = 1.0 - fade_progress;
-                        Color::srgba(
-                            base_color.to_srgba().red,
-                            base_color.to_srgba().green,
-                            base_color.to_srgba().blue,
-                            alpha,
-                        )
-                    }
-                    StatusAnimation::Static => base_color,
-                };
-                text_color.0 = animated_color;
+                text_color.0 = status_color(state, indicator.animation_timer);
+            }
+        }
+    }
+}
+
+/// Color of a status state `timer` seconds into its animation
+pub fn status_color(state: &StatusState, timer: f32) -> Color {
+    let base_color = state.color;
+    match state.animation {
+        StatusAnimation::Pulse { frequency } => {
+            let pulse = (timer * frequency * 2.0 * std::f32::consts::PI).sin();
+            let intensity = 0.7 + pulse * 0.3;
+            Color::srgba(
+                base_color.to_srgba().red * intensity,
+                base_color.to_srgba().green * intensity,
+                base_color.to_srgba().blue * intensity,
+                base_color.to_srgba().alpha,
+            )
+        }
+        StatusAnimation::Flash { interval } => {
+            let flash_cycle = timer % interval;
+            if flash_cycle < interval * 0.1 {
+                Color::WHITE
+            } else {
+                base_color
+            }
+        }
+        StatusAnimation::Fade { duration } => {
+            let fade_progress = (timer % duration) / duration;
+            let alpha = 1.0 - fade_progress;
+            Color::srgba(
+                base_color.to_srgba().red,
+                base_color.to_srgba().green,
+                base_color.to_srgba().blue,
+                alpha,
+            )
+        }
+        StatusAnimation::Static => base_color,
+    }
+}
+
+/// Move world-anchored widgets over their targets, hiding them when the target is gone or off-screen
+pub fn update_world_anchored(
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    target_query: Query<&GlobalTransform, Without<WorldAnchored>>,
+    mut anchored_query: Query<(&WorldAnchored, &mut Node, &mut Visibility)>,
+) {
+    let camera = camera_query.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order);
+    
+    for (anchor, mut node, mut visibility) in anchored_query.iter_mut() {
+        let screen_position = camera.zip(target_query.get(anchor.target).ok())
+            .and_then(|((camera, camera_transform), target)| {
+                let world_position = target.translation() + anchor.offset.extend(0.0);
+                let viewport_position = camera.world_to_viewport(camera_transform, world_position).ok()?;
+                let viewport_size = camera.logical_viewport_size()?;
+                let on_screen = viewport_position.cmpge(Vec2::ZERO).all() && viewport_position.cmple(viewport_size).all();
+                on_screen.then_some(viewport_position)
+            });
+        
+        match screen_position {
+            Some(position) => {
+                node.position_type = PositionType::Absolute;
+                node.left = Val::Px(position.x);
+                node.top = Val::Px(position.y);
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
             }
         }
     }
@@ -237,4 +477,38 @@ pub fn calculate_gradient_color(gradient: &[(f32, Color)], value: f32) -> Color
     }
     
     gradient.last().unwrap().1
-}
\ No newline at end of file
+}
+
+/// Linear blend between two colors in sRGB space
+pub fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let (a, b) = (from.to_srgba(), to.to_srgba());
+    Color::srgba(
+        a.red + (b.red - a.red) * t,
+        a.green + (b.green - a.green) * t,
+        a.blue + (b.blue - a.blue) * t,
+        a.alpha + (b.alpha - a.alpha) * t,
+    )
+}
+
+/// Blend two Vals of the same unit, mismatched units snap to the target at the end
+pub fn lerp_val(from: Val, to: Val, t: f32) -> Val {
+    match (from, to) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(a + (b - a) * t),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(a + (b - a) * t),
+        _ if t >= 1.0 => to,
+        _ => from,
+    }
+}
+
+/// Blend size and position of a node, every other layout property comes from the target
+pub fn lerp_node(from: &Node, to: &Node, t: f32) -> Node {
+    Node {
+        width: lerp_val(from.width, to.width, t),
+        height: lerp_val(from.height, to.height, t),
+        left: lerp_val(from.left, to.left, t),
+        top: lerp_val(from.top, to.top, t),
+        right: lerp_val(from.right, to.right, t),
+        bottom: lerp_val(from.bottom, to.bottom, t),
+        ..to.clone()
+    }
+}
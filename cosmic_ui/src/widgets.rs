@@ -4,6 +4,18 @@
 use bevy::prelude::*;
 use std::collections::VecDeque;
 
+/// Widgets with a single root node that can be moved as a whole
+pub trait AnchorableWidget {
+    fn root_entity(&self) -> Entity;
+}
+
+/// Keeps a widget's root node over a world entity, see `WidgetBuilder::world_anchored`
+#[derive(Component, Clone, Copy, Debug)]
+pub struct WorldAnchored {
+    pub target: Entity,
+    pub offset: Vec2, // World units from the target, y up
+}
+
 /// High-performance text display with automatic formatting
 #[derive(Component, Clone, Debug)]
 pub struct TextDisplay {
@@ -30,6 +42,11 @@ pub struct ProgressBar {
     pub max_value: f32,
     pub current_percent: f32,
     pub color_gradient: Vec<(f32, Color)>, // Threshold, Color pairs
+    pub segments: Vec<Entity>, // Empty for a smooth fill
+    pub segment_fill: Color,
+    pub segment_empty: Color,
+    pub segment_colors: Vec<(usize, Color)>,
+    pub lit_segments: usize, // Segments lit on screen right now
 }
 
 impl ProgressBar {
@@ -44,6 +61,11 @@ impl ProgressBar {
                 (0.6, Color::srgb(0.8, 0.8, 0.2)), // Yellow at 60%
                 (1.0, Color::srgb(0.2, 0.8, 0.2)), // Green at 100%
             ],
+            segments: Vec::new(),
+            segment_fill: Color::srgb(0.2, 0.8, 0.2),
+            segment_empty: Color::srgb(0.1, 0.1, 0.1),
+            segment_colors: Vec::new(),
+            lit_segments: 0,
         }
     }
     
@@ -51,6 +73,76 @@ impl ProgressBar {
         self.color_gradient = gradient;
         self
     }
+    
+    /// Segments that should be lit for `current_percent`, rounded to the nearest boundary
+    pub fn target_segments(&self) -> usize {
+        let count = self.segments.len();
+        ((self.current_percent.clamp(0.0, 1.0) * count as f32).round() as usize).min(count)
+    }
+    
+    /// Fill color of a lit segment, honouring per-segment overrides
+    pub fn segment_color(&self, index: usize) -> Color {
+        self.segment_colors.iter()
+            .find(|(segment, _)| *segment == index)
+            .map_or(self.segment_fill, |(_, color)| *color)
+    }
+}
+
+/// Circular gauge drawn as a ring of segments sweeping from start_angle to end_angle
+#[derive(Component, Clone, Debug)]
+pub struct RadialGauge {
+    pub entity: Entity,
+    pub segments: Vec<Entity>,
+    pub min_value: f32,
+    pub max_value: f32,
+    pub current_value: f32,
+    pub start_angle: f32, // Radians, 0 = right, counter-clockwise positive
+    pub end_angle: f32,
+    pub background_color: Color,
+    pub color_gradient: Vec<(f32, Color)>, // Threshold, Color pairs along the arc
+}
+
+impl RadialGauge {
+    pub fn new(entity: Entity, segments: Vec<Entity>, min_value: f32, max_value: f32) -> Self {
+        Self {
+            entity,
+            segments,
+            min_value,
+            max_value,
+            current_value: min_value,
+            start_angle: std::f32::consts::PI * 1.25,
+            end_angle: -std::f32::consts::PI * 0.25,
+            background_color: Color::srgba(0.1, 0.1, 0.1, 0.6),
+            color_gradient: vec![
+                (0.0, Color::srgb(0.2, 0.8, 0.2)), // Green at the start of the arc
+                (0.5, Color::srgb(0.8, 0.8, 0.2)), // Yellow halfway
+                (1.0, Color::srgb(0.8, 0.2, 0.2)), // Red at the end
+            ],
+        }
+    }
+
+    /// Share of the arc that is filled, 0.0 to 1.0
+    pub fn fraction(&self) -> f32 {
+        let range = self.max_value - self.min_value;
+        if range.abs() <= f32::EPSILON { return 0.0; }
+        ((self.current_value - self.min_value) / range).clamp(0.0, 1.0)
+    }
+
+    /// Angle of a point `t` (0.0 to 1.0) along the arc
+    pub fn angle_at(&self, t: f32) -> f32 {
+        self.start_angle + (self.end_angle - self.start_angle) * t
+    }
+
+    /// Position of segment `index` along the arc, 0.0 to 1.0
+    pub fn segment_t(&self, index: usize) -> f32 {
+        if self.segments.len() <= 1 { return 0.0; }
+        index as f32 / (self.segments.len() - 1) as f32
+    }
+
+    /// Number of segments lit for the current value
+    pub fn lit_segments(&self) -> usize {
+        (self.fraction() * self.segments.len() as f32).round() as usize
+    }
 }
 
 /// Counter widget for numeric displays (lives, ammo, etc)
@@ -79,7 +171,10 @@ pub struct StatusIndicator {
     pub entity: Entity,
     pub states: Vec<StatusState>,
     pub current_state: usize,
+    pub target_state: usize, // Differs from current_state while cross-fading
     pub animation_timer: f32,
+    pub transition_duration: f32, // 0.0 snaps between states
+    pub transition_timer: f32,
 }
 
 impl StatusIndicator {
@@ -88,9 +183,39 @@ impl StatusIndicator {
             entity,
             states,
             current_state: 0,
+            target_state: 0,
             animation_timer: 0.0,
+            transition_duration: 0.0,
+            transition_timer: 0.0,
         }
     }
+    
+    pub fn with_transition(mut self, duration: f32) -> Self {
+        self.transition_duration = duration.max(0.0);
+        self
+    }
+    
+    /// Start blending towards `index`. Static target states still snap instantly
+    pub fn set_state(&mut self, index: usize) {
+        if index >= self.states.len() || index == self.target_state { return; }
+        
+        self.target_state = index;
+        self.transition_timer = 0.0;
+        
+        let snaps = self.transition_duration <= 0.0
+            || matches!(self.states[index].animation, StatusAnimation::Static);
+        if snaps {
+            self.current_state = index;
+            self.animation_timer = 0.0;
+        }
+    }
+    
+    /// Blend progress from 0.0 (outgoing) to 1.0 (incoming), None when not transitioning
+    pub fn transition_progress(&self) -> Option<f32> {
+        if self.current_state == self.target_state { return None; }
+        if self.transition_duration <= 0.0 { return Some(1.0); }
+        Some((self.transition_timer / self.transition_duration).clamp(0.0, 1.0))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -139,6 +264,7 @@ pub struct NotificationQueue {
     pub container: Entity,
     pub notifications: VecDeque<Notification>,
     pub max_visible: usize,
+    pub dedupe: bool, // Merge repeated messages into one entry with a count badge
 }
 
 impl NotificationQueue {
@@ -147,15 +273,73 @@ impl NotificationQueue {
             container,
             notifications: VecDeque::new(),
             max_visible,
+            dedupe: false,
         }
     }
     
+    pub fn with_dedupe(mut self, dedupe: bool) -> Self {
+        self.dedupe = dedupe;
+        self
+    }
+    
     pub fn push_notification(&mut self, notification: Notification) {
         if self.notifications.len() >= self.max_visible {
             self.notifications.pop_front();
         }
         self.notifications.push_back(notification);
     }
+    
+    /// Push keeping higher priorities on top. A full queue evicts its oldest lowest-priority
+    /// entry, unless everything shown outranks the new notification
+    pub fn push_with_priority(&mut self, notification: Notification) -> NotificationPushOutcome {
+        if self.dedupe {
+            if let Some(existing) = self.notifications.iter_mut().find(|n| n.message == notification.message) {
+                existing.count += 1;
+                existing.lifetime = existing.max_lifetime;
+                existing.priority = existing.priority.max(notification.priority);
+                return NotificationPushOutcome::Merged { entity: existing.entity, count: existing.count };
+            }
+        }
+        
+        let mut evicted = None;
+        if self.notifications.len() >= self.max_visible {
+            let lowest = self.notifications.iter()
+                .enumerate()
+                .min_by_key(|(index, n)| (n.priority, *index))
+                .map(|(index, n)| (index, n.priority));
+            match lowest {
+                Some((index, priority)) if priority <= notification.priority => {
+                    evicted = self.notifications.remove(index).map(|n| n.entity);
+                }
+                Some(_) => return NotificationPushOutcome::Rejected,
+                None => {}
+            }
+        }
+        
+        let index = self.notifications.iter()
+            .position(|n| n.priority < notification.priority)
+            .unwrap_or(self.notifications.len());
+        self.notifications.insert(index, notification);
+        
+        NotificationPushOutcome::Inserted { index, evicted }
+    }
+}
+
+/// What `push_with_priority` did, so the caller can spawn or despawn the matching entities
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NotificationPushOutcome {
+    Inserted { index: usize, evicted: Option<Entity> },
+    Merged { entity: Entity, count: u32 },
+    Rejected,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NotificationPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Critical, // Player death imminent, king tide incoming
 }
 
 #[derive(Clone, Debug)]
@@ -166,6 +350,8 @@ pub struct Notification {
     pub lifetime: f32,
     pub max_lifetime: f32,
     pub fade_time: f32,
+    pub priority: NotificationPriority,
+    pub count: u32,
 }
 
 impl Notification {
@@ -177,6 +363,22 @@ impl Notification {
             lifetime,
             max_lifetime: lifetime,
             fade_time: 1.0,
+            priority: NotificationPriority::Normal,
+            count: 1,
+        }
+    }
+    
+    pub fn with_priority(mut self, priority: NotificationPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+    
+    /// Message with a count badge once deduplication has merged repeats
+    pub fn display_text(&self) -> String {
+        if self.count > 1 {
+            format!("{} ×{}", self.message, self.count)
+        } else {
+            self.message.clone()
         }
     }
 }
@@ -190,6 +392,15 @@ pub enum NotificationLevel {
 }
 
 impl NotificationLevel {
+    /// Severity used for notifications pushed through `UIUpdateCommand::NotificationPush`
+    pub fn for_priority(priority: NotificationPriority) -> Self {
+        match priority {
+            NotificationPriority::Low | NotificationPriority::Normal => NotificationLevel::Info,
+            NotificationPriority::High => NotificationLevel::Warning,
+            NotificationPriority::Critical => NotificationLevel::Critical,
+        }
+    }
+    
     pub fn color(&self) -> Color {
         match self {
             NotificationLevel::Info => Color::srgb(0.3, 0.8, 1.0),
@@ -208,3 +419,31 @@ impl NotificationLevel {
         }
     }
 }
+
+impl AnchorableWidget for TextDisplay {
+    fn root_entity(&self) -> Entity { self.entity }
+}
+
+impl AnchorableWidget for ProgressBar {
+    fn root_entity(&self) -> Entity { self.entity }
+}
+
+impl AnchorableWidget for RadialGauge {
+    fn root_entity(&self) -> Entity { self.entity }
+}
+
+impl AnchorableWidget for Counter {
+    fn root_entity(&self) -> Entity { self.entity }
+}
+
+impl AnchorableWidget for StatusIndicator {
+    fn root_entity(&self) -> Entity { self.entity }
+}
+
+impl AnchorableWidget for InfoPanel {
+    fn root_entity(&self) -> Entity { self.entity }
+}
+
+impl AnchorableWidget for NotificationQueue {
+    fn root_entity(&self) -> Entity { self.container }
+}
@@ -0,0 +1,126 @@
+// cosmic_ui/tests/game_hud_derive.rs
+//! Derives GameHUD on a real struct so the generated spawn, bind and update code has to type-check
+
+use bevy::prelude::*;
+use cosmic_ui::prelude::*;
+use cosmic_ui::UIUpdateCommand;
+
+#[derive(Resource, Default)]
+struct Score {
+    current: u32,
+}
+
+#[derive(Resource, Default)]
+struct Wave {
+    number: u32,
+}
+
+#[derive(Component)]
+struct Ship;
+
+#[derive(Component)]
+struct Lives(i32);
+
+#[derive(Component, GameHUD)]
+struct TestHud {
+    #[bind(resource = Score, field = current)]
+    #[format = "Score: {}"]
+    #[position(top_left, offset_x = 10, offset_y = 10)]
+    score: TextDisplay,
+
+    #[bind(resource = Score, field = current)]
+    #[bind(resource = Wave, field = number)]
+    #[format = "{} pts on wave {}"]
+    #[position(top_right)]
+    summary: TextDisplay,
+
+    #[bind(component = Lives, field = 0, with = Ship)]
+    #[format = "Lives: {}"]
+    #[position(bottom_left)]
+    lives: Counter,
+}
+
+fn app_with_hud() -> App {
+    let mut app = App::new();
+    app.init_resource::<UIUpdateScheduler>()
+        .init_resource::<Score>()
+        .init_resource::<Wave>();
+    app.world_mut().spawn((Ship, Lives(3)));
+
+    let mut commands = app.world_mut().commands();
+    TestHud::spawn_ui(&mut commands, Handle::default());
+    app.world_mut().flush();
+    app
+}
+
+fn queued_texts(app: &App) -> Vec<String> {
+    app.world().resource::<UIUpdateScheduler>().pending_updates.iter()
+        .filter_map(|update| match update {
+            UIUpdateCommand::TextUpdate { text, .. } => Some(text.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn registered_systems_queue_bound_values() {
+    let mut app = app_with_hud();
+    TestHud::register_systems(&mut app);
+    app.world_mut().resource_mut::<Score>().current = 120;
+    app.world_mut().resource_mut::<Wave>().number = 4;
+    app.update();
+
+    let texts = queued_texts(&app);
+    assert!(texts.contains(&"Score: 120".to_string()), "{:?}", texts);
+    assert!(texts.contains(&"120 pts on wave 4".to_string()), "{:?}", texts);
+    assert!(texts.contains(&"Lives: 3".to_string()), "{:?}", texts);
+}
+
+fn update_texts(updates: &[UIUpdateCommand]) -> Vec<String> {
+    updates.iter()
+        .filter_map(|update| match update {
+            UIUpdateCommand::TextUpdate { text, .. } => Some(text.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn take_hud(app: &mut App) -> (Entity, TestHud) {
+    let mut hud_query = app.world_mut().query_filtered::<Entity, With<TestHud>>();
+    let hud_entity = hud_query.single(app.world()).unwrap();
+    let hud = app.world_mut().entity_mut(hud_entity).take::<TestHud>().unwrap();
+    (hud_entity, hud)
+}
+
+#[test]
+fn update_bindings_reads_sources_from_a_shared_world() {
+    let mut app = app_with_hud();
+    app.world_mut().resource_mut::<Score>().current = 7;
+    let (hud_entity, mut hud) = take_hud(&mut app);
+
+    let updates = hud.update_bindings(app.world(), hud_entity);
+
+    let texts = update_texts(&updates);
+    assert!(texts.contains(&"Score: 7".to_string()), "{:?}", texts);
+    assert!(texts.contains(&"7 pts on wave 0".to_string()), "{:?}", texts);
+    assert!(texts.contains(&"Lives: 3".to_string()), "{:?}", texts);
+    // Queuing is left to the caller
+    assert!(queued_texts(&app).is_empty());
+}
+
+#[test]
+fn update_bindings_skips_sources_the_world_has_never_seen() {
+    let mut app = App::new();
+    app.init_resource::<UIUpdateScheduler>()
+        .init_resource::<Score>();
+    let mut commands = app.world_mut().commands();
+    TestHud::spawn_ui(&mut commands, Handle::default());
+    app.world_mut().flush();
+    let (hud_entity, mut hud) = take_hud(&mut app);
+
+    // No Wave resource and no Lives component was ever registered
+    let updates = hud.update_bindings(app.world(), hud_entity);
+
+    let texts = update_texts(&updates);
+    assert_eq!(texts, vec!["Score: 0".to_string()]);
+}
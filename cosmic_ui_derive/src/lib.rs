@@ -9,20 +9,76 @@ use syn::{parse_macro_input, DeriveInput, Data, Fields, Field, Meta, Lit, Type};
 #[proc_macro_derive(GameHUD, attributes(bind, format, position, style))]
 pub fn derive_game_hud(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    
+    expand_game_hud(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Errors are reported as compile errors spanned on the offending item rather than macro panics
+fn expand_game_hud(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
     
-    match &input.data {
-        Data::Struct(data_struct) => {
-            match &data_struct.fields {
-                Fields::Named(fields) => {
-                    let bindings = extract_bindings(&fields.named);
-                    generate_hud_implementation(name, bindings)
-                }
-                _ => panic!("GameHUD can only be derived for structs with named fields"),
+    let fields = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => &fields.named,
+            other => return Err(syn::Error::new_spanned(other, "GameHUD can only be derived for structs with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(name, "GameHUD can only be derived for structs")),
+    };
+    
+    let bindings = extract_bindings(fields)?;
+    validate_bindings(&bindings)?;
+    Ok(generate_hud_implementation(name, bindings))
+}
+
+// ===== BUILT-IN SOURCES =====
+
+/// Named `#[bind(Source)]` sources and the widgets each one can drive
+const BUILTIN_SOURCES: &[(&str, &[&str])] = &[
+    ("PlayerLives", &["Counter"]),
+    ("PlayerATP", &["TextDisplay"]),
+    ("PlayerHealth", &["ProgressBar"]),
+    ("GameScore", &["TextDisplay"]),
+    ("CellWallTimer", &["TextDisplay"]),
+    ("EnvironmentStatus", &["TextDisplay"]),
+];
+
+/// Reject bind sources that would otherwise generate a system that never updates anything
+fn validate_bindings(bindings: &[FieldBinding]) -> syn::Result<()> {
+    let mut errors: Option<syn::Error> = None;
+    let mut push = |error: syn::Error| match &mut errors {
+        Some(existing) => existing.combine(error),
+        None => errors = Some(error),
+    };
+    
+    for binding in bindings {
+        let Some(source) = &binding.binding_source else { continue };
+        let source_name = quote!(#source).to_string();
+        let widget_type = extract_widget_type(&binding.field_type);
+        
+        match BUILTIN_SOURCES.iter().find(|(name, _)| *name == source_name) {
+            None => {
+                let known: Vec<&str> = BUILTIN_SOURCES.iter().map(|(name, _)| *name).collect();
+                push(syn::Error::new_spanned(source, format!(
+                    "unknown bind source `{}`, expected one of {} or `resource = T, field = f` / `component = T, field = f`",
+                    source_name,
+                    known.join(", "),
+                )));
             }
+            Some((_, widgets)) if !widgets.contains(&widget_type.as_str()) => {
+                push(syn::Error::new_spanned(&binding.field_type, format!(
+                    "bind source `{}` can't drive a {}, expected {}",
+                    source_name,
+                    widget_type,
+                    widgets.join(" or "),
+                )));
+            }
+            Some(_) => {}
         }
-        _ => panic!("GameHUD can only be derived for structs"),
     }
+    
+    errors.map_or(Ok(()), Err)
 }
 
 // ===== BINDING EXTRACTION =====
@@ -31,17 +87,76 @@ struct FieldBinding {
     field_name: syn::Ident,
     field_type: syn::Type,
     binding_source: Option<syn::Path>,
+    generic_bindings: Vec<GenericBinding>, // Repeatable, each source fills the next `{}` of the format string
     format_string: Option<String>,
-    position: Option<String>,
+    position: Option<PositionSpec>,
+    #[allow(dead_code)] // Collected from `#[style(..)]`, not applied by the generated code yet
     style_attrs: Vec<(String, String)>,
 }
 
-fn extract_bindings(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>) -> Vec<FieldBinding> {
+/// `#[position(corner, offset_x = N, offset_y = N)]`, both offsets optional
+#[derive(Debug, PartialEq)]
+struct PositionSpec {
+    corner: String,
+    offset_x: Option<f32>,
+    offset_y: Option<f32>,
+}
+
+/// `#[bind(resource = T, field = f)]` or `#[bind(component = T, field = f, with = Marker)]`
+struct GenericBinding {
+    source: GenericSource,
+    field: proc_macro2::TokenStream, // Field access path, e.g. `current`, `0` or `stats.wave`
+}
+
+enum GenericSource {
+    Resource(syn::Path),
+    Component { component: syn::Path, with: Option<syn::Path> },
+}
+
+fn parse_generic_binding(meta_list: &syn::MetaList) -> syn::Result<GenericBinding> {
+    let pairs = meta_list
+        .parse_args_with(syn::punctuated::Punctuated::<syn::MetaNameValue, syn::Token![,]>::parse_terminated)?;
+
+    let mut resource = None;
+    let mut component = None;
+    let mut with = None;
+    let mut field = None;
+
+    for pair in pairs {
+        let value = &pair.value;
+        let type_path = || syn::parse2::<syn::Path>(quote!(#value))
+            .map_err(|_| syn::Error::new_spanned(value, "expected a type path"));
+        if pair.path.is_ident("resource") {
+            resource = Some(type_path()?);
+        } else if pair.path.is_ident("component") {
+            component = Some(type_path()?);
+        } else if pair.path.is_ident("with") {
+            with = Some(type_path()?);
+        } else if pair.path.is_ident("field") {
+            field = Some(quote!(#value));
+        } else {
+            return Err(syn::Error::new_spanned(&pair.path, "unknown bind key, expected resource, component, with or field"));
+        }
+    }
+
+    let field = field.ok_or_else(|| syn::Error::new_spanned(meta_list, "bind(resource/component = ...) requires a field"))?;
+    let source = match (resource, component) {
+        (Some(resource), None) => GenericSource::Resource(resource),
+        (None, Some(component)) => GenericSource::Component { component, with },
+        _ => return Err(syn::Error::new_spanned(meta_list, "bind needs exactly one of resource or component")),
+    };
+
+    Ok(GenericBinding { source, field })
+}
+
+fn extract_bindings(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>) -> syn::Result<Vec<FieldBinding>> {
     fields.iter().map(|field| {
-        let field_name = field.ident.clone().expect("Named field required");
+        let field_name = field.ident.clone()
+            .ok_or_else(|| syn::Error::new_spanned(field, "GameHUD fields must be named"))?;
         let field_type = field.ty.clone();
         
         let mut binding_source = None;
+        let mut generic_bindings = Vec::new();
         let mut format_string = None;
         let mut position = None;
         let mut style_attrs = Vec::new();
@@ -52,7 +167,12 @@ fn extract_bindings(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>)
                 match &attr.meta {
                     Meta::List(meta_list) if attr.path().is_ident("bind") => {
                         if let Ok(path) = syn::parse2::<syn::Path>(meta_list.tokens.clone()) {
+                            if binding_source.is_some() {
+                                return Err(syn::Error::new_spanned(meta_list, "only one named bind source per field, use `resource = T, field = f` binds to combine sources"));
+                            }
                             binding_source = Some(path);
+                        } else {
+                            generic_bindings.push(parse_generic_binding(meta_list)?);
                         }
                     }
                     Meta::NameValue(meta_name_value) if attr.path().is_ident("format") => {
@@ -61,8 +181,9 @@ fn extract_bindings(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>)
                         }
                     }
                     Meta::List(meta_list) if attr.path().is_ident("position") => {
-                        // Simple position parsing - in a real implementation this would be more robust
-                        position = Some(meta_list.tokens.to_string());
+                        let spec = parse_position(&meta_list.tokens.to_string())
+                            .map_err(|message| syn::Error::new_spanned(meta_list, message))?;
+                        position = Some(spec);
                     }
                     Meta::List(meta_list) if attr.path().is_ident("style") => {
                         // Simple style parsing
@@ -74,23 +195,39 @@ fn extract_bindings(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>)
             //}
         }
         
-        FieldBinding {
+        if binding_source.is_some() && !generic_bindings.is_empty() {
+            return Err(syn::Error::new_spanned(&field_name, "a named bind source can't be combined with `resource = ..` or `component = ..` binds"));
+        }
+        if generic_bindings.len() > 1 {
+            let placeholders = format_string.as_deref().map_or(generic_bindings.len(), count_format_args);
+            if placeholders != generic_bindings.len() {
+                return Err(syn::Error::new_spanned(&field_name, format!(
+                    "{} bind sources but the format string has {} `{{}}` placeholders",
+                    generic_bindings.len(),
+                    placeholders,
+                )));
+            }
+        }
+        
+        Ok(FieldBinding {
             field_name,
             field_type,
             binding_source,
+            generic_bindings,
             format_string,
             position,
             style_attrs,
-        }
+        })
     }).collect()
 }
 
 // ===== CODE GENERATION =====
 
-fn generate_hud_implementation(name: &syn::Ident, bindings: Vec<FieldBinding>) -> TokenStream {
+fn generate_hud_implementation(name: &syn::Ident, bindings: Vec<FieldBinding>) -> proc_macro2::TokenStream {
     let spawn_ui_body = generate_spawn_ui_method(&bindings);
     let register_systems_body = generate_register_systems_method(name, &bindings);
     let update_systems = generate_update_systems(name, &bindings);
+    let update_bindings_body = generate_update_bindings_method(&bindings);
     
     let expanded = quote! {
         impl cosmic_ui::GameHUD for #name {
@@ -102,15 +239,15 @@ fn generate_hud_implementation(name: &syn::Ident, bindings: Vec<FieldBinding>) -
                 #register_systems_body
             }
             
-            fn update_bindings(&mut self, world: &bevy::prelude::World, ui_root: bevy::prelude::Entity) {
-                // Generated binding updates would go here
+            fn update_bindings(&mut self, world: &bevy::prelude::World, _ui_root: bevy::prelude::Entity) -> Vec<cosmic_ui::UIUpdateCommand> {
+                #update_bindings_body
             }
         }
         
         #update_systems
     };
     
-    TokenStream::from(expanded)
+    expanded
 }
 
 fn generate_spawn_ui_method(bindings: &[FieldBinding]) -> proc_macro2::TokenStream {
@@ -139,6 +276,11 @@ fn generate_spawn_ui_method(bindings: &[FieldBinding]) -> proc_macro2::TokenStre
                     let (mut builder, #widget_var) = builder.progress_bar(#position, cosmic_ui::builder::ProgressBarConfig::biological());
                 }
             }
+            "RadialGauge" => {
+                quote! {
+                    let (mut builder, #widget_var) = builder.radial_gauge(#position, cosmic_ui::builder::RadialGaugeConfig::default());
+                }
+            }
             "StatusIndicator" => {
                 quote! {
                     let states = vec![
@@ -171,7 +313,7 @@ fn generate_spawn_ui_method(bindings: &[FieldBinding]) -> proc_macro2::TokenStre
     }).collect();
     
     quote! {
-        let mut builder = cosmic_ui::builder::WidgetBuilder::new(commands, fonts).root();
+        let mut builder = cosmic_ui::builder::WidgetBuilder::new(commands, font_handle).root();
         
         #(#widget_creations)*
         
@@ -185,7 +327,7 @@ fn generate_spawn_ui_method(bindings: &[FieldBinding]) -> proc_macro2::TokenStre
 
 fn generate_register_systems_method(_hud_name: &syn::Ident, bindings: &[FieldBinding]) -> proc_macro2::TokenStream {
     let system_names: Vec<proc_macro2::TokenStream> = bindings.iter()
-        .filter_map(|binding| binding.binding_source.as_ref().map(|_| binding))
+        .filter(|binding| binding.binding_source.is_some() || !binding.generic_bindings.is_empty())
         .map(|binding| {
             let field_name = &binding.field_name;
             let system_name = syn::Ident::new(&format!("update_{}_system", field_name), field_name.span());
@@ -202,14 +344,17 @@ fn generate_register_systems_method(_hud_name: &syn::Ident, bindings: &[FieldBin
 
 fn generate_update_systems(hud_name: &syn::Ident, bindings: &[FieldBinding]) -> proc_macro2::TokenStream {
     let systems: Vec<proc_macro2::TokenStream> = bindings.iter()
-        .filter_map(|binding| binding.binding_source.as_ref().map(|source| (binding, source)))
-        .map(|(binding, source)| {
+        .filter(|binding| binding.binding_source.is_some() || !binding.generic_bindings.is_empty())
+        .map(|binding| {
             let field_name = &binding.field_name;
             let system_name = syn::Ident::new(&format!("update_{}_system", field_name), field_name.span());
             let widget_type = extract_widget_type(&binding.field_type);
             
             // Generate update logic based on binding source and widget type
-            let (query_params, update_logic) = generate_update_logic(binding, source, &widget_type);
+            let (query_params, update_logic) = match &binding.binding_source {
+                Some(source) => generate_update_logic(binding, source, &widget_type),
+                None => generate_generic_update_logic(binding, &binding.generic_bindings, &widget_type),
+            };
             
             quote! {
                 fn #system_name(
@@ -260,7 +405,7 @@ fn generate_update_logic(binding: &FieldBinding, source: &syn::Path, widget_type
     
     let update_logic = match (source_str.as_str(), widget_type) {
         ("PlayerLives", "Counter") => quote! {
-            if let Ok(player) = player_query.get_single() {
+            if let Ok(player) = player_query.single() {
                 for mut hud in hud_query.iter_mut() {
                     if hud.#field_name.last_value != player.lives {
                         let text = format!("{}{}{}",
@@ -278,7 +423,7 @@ fn generate_update_logic(binding: &FieldBinding, source: &syn::Path, widget_type
             }
         },
         ("PlayerATP", "TextDisplay") => quote! {
-            if let Ok(atp) = atp_query.get_single() {
+            if let Ok(atp) = atp_query.single() {
                 for mut hud in hud_query.iter_mut() {
                     let new_hash = atp.amount as u64;
                     if hud.#field_name.last_value_hash != new_hash {
@@ -293,7 +438,7 @@ fn generate_update_logic(binding: &FieldBinding, source: &syn::Path, widget_type
             }
         },
         ("PlayerHealth", "ProgressBar") => quote! {
-            if let Ok(health) = health_query.get_single() {
+            if let Ok(health) = health_query.single() {
                 for mut hud in hud_query.iter_mut() {
                     let percent = health.0 as f32 / 100.0;
                     if (hud.#field_name.current_percent - percent).abs() > 0.01 {
@@ -323,7 +468,7 @@ fn generate_update_logic(binding: &FieldBinding, source: &syn::Path, widget_type
         },
         ("CellWallTimer", "TextDisplay") => quote! {
             for mut hud in hud_query.iter_mut() {
-                if let Ok(cell_wall) = cell_wall_query.get_single() {
+                if let Ok(cell_wall) = cell_wall_query.single() {
                     let remaining = cell_wall.timer.max(0.0);
                     let icon = if remaining < 3.0 { "⚠️" } else { "🛡️" };
                     let text = format!("{} Cell Wall: {:.1}s", icon, remaining);
@@ -364,29 +509,283 @@ fn generate_update_logic(binding: &FieldBinding, source: &syn::Path, widget_type
     (query_params, update_logic)
 }
 
-fn generate_position_code(position: &Option<String>) -> proc_macro2::TokenStream {
-    match position.as_ref().map(|s| s.as_str()) {
-        Some("top_left") => quote! { cosmic_ui::builder::UIPosition::top_left() },
-        Some("top_right") => quote! { cosmic_ui::builder::UIPosition::top_right() },
-        Some("bottom_left") => quote! { cosmic_ui::builder::UIPosition::bottom_left() },
-        Some("bottom_right") => quote! { cosmic_ui::builder::UIPosition::bottom_right() },
-        Some("center") => quote! { cosmic_ui::builder::UIPosition::center() },
-        Some(pos) if pos.contains("offset") => {
-            // Parse offset patterns like "bottom_left, offset_y = 30"
-            if pos.contains("bottom_left") {
-                let offset_y = extract_offset_value(pos).unwrap_or(0.0);
-                quote! { cosmic_ui::builder::UIPosition::bottom_left().with_offset(0.0, #offset_y) }
-            } else if pos.contains("top_left") {
-                let offset_y = extract_offset_value(pos).unwrap_or(0.0);
-                quote! { cosmic_ui::builder::UIPosition::top_left().with_offset(0.0, #offset_y) }
-            } else {
-                quote! { cosmic_ui::builder::UIPosition::default() }
+/// Query/Res parameters and update body for `#[bind(resource = ..)]` and `#[bind(component = ..)]`.
+/// A field with several binds reads every source and redraws when any of them changed
+fn generate_generic_update_logic(binding: &FieldBinding, generics: &[GenericBinding], widget_type: &str) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let field_name = &binding.field_name;
+    let format_string = generic_format_string(binding, generics.len());
+    let sources = source_idents(generics.len());
+    let params = param_idents(generics.len());
+    let fields: Vec<&proc_macro2::TokenStream> = generics.iter().map(|generic| &generic.field).collect();
+
+    let source_params: Vec<proc_macro2::TokenStream> = generics.iter().zip(&params).map(|(generic, param)| {
+        match &generic.source {
+            GenericSource::Resource(resource) => quote! {
+                #param: bevy::prelude::Res<#resource>,
+            },
+            GenericSource::Component { component, with: Some(with) } => quote! {
+                #param: bevy::prelude::Query<bevy::prelude::Ref<#component>, bevy::prelude::With<#with>>,
+            },
+            GenericSource::Component { component, with: None } => quote! {
+                #param: bevy::prelude::Query<bevy::prelude::Ref<#component>>,
+            },
+        }
+    }).collect();
+
+    let query_params = quote! {
+        #(#source_params)*
+        mut last_text: bevy::prelude::Local<Option<String>>,
+    };
+
+    let read_values: Vec<proc_macro2::TokenStream> = generics.iter().zip(sources.iter().zip(&params)).map(|(generic, (source, param))| {
+        match &generic.source {
+            GenericSource::Resource(_) => quote! {
+                let #source = &#param;
+            },
+            GenericSource::Component { .. } => quote! {
+                let Some(#source) = #param.iter().next() else { return };
+            },
+        }
+    }).collect();
+
+    // Resources and `Ref` both track changes, so one guard covers every source
+    let first_source = &sources[0];
+    let first_field = fields[0];
+    let queue_update = match widget_type {
+        "RadialGauge" => quote! {
+            scheduler.queue_update(cosmic_ui::UIUpdateCommand::GaugeUpdate {
+                entity: hud.#field_name.entity,
+                value: #first_source.#first_field as f32,
+            });
+        },
+        "Counter" => quote! {
+            scheduler.queue_update(cosmic_ui::UIUpdateCommand::TextUpdate {
+                entity: hud.#field_name.entity,
+                text: format!("{}{}{}", hud.#field_name.prefix, #first_source.#first_field, hud.#field_name.suffix),
+            });
+        },
+        _ => quote! {
+            scheduler.queue_update(cosmic_ui::UIUpdateCommand::TextUpdate {
+                entity: hud.#field_name.entity,
+                text: text.clone(),
+            });
+        },
+    };
+
+    let update_logic = quote! {
+        #(#read_values)*
+        if !(#(#sources.is_changed())||*) { return; }
+        let text = format!(#format_string, #(#sources.#fields),*);
+
+        // Skip the update when the rendered value hasn't changed
+        if last_text.as_ref() == Some(&text) { return; }
+
+        for hud in hud_query.iter_mut() {
+            #queue_update
+        }
+        *last_text = Some(text);
+    };
+
+    (query_params, update_logic)
+}
+
+/// Explicit `#[format]`, or one `{}` per source separated by spaces
+fn generic_format_string(binding: &FieldBinding, source_count: usize) -> String {
+    binding.format_string.clone().unwrap_or_else(|| vec!["{}"; source_count].join(" "))
+}
+
+fn source_idents(count: usize) -> Vec<syn::Ident> {
+    (0..count).map(|i| quote::format_ident!("source_{}", i)).collect()
+}
+
+fn param_idents(count: usize) -> Vec<syn::Ident> {
+    (0..count).map(|i| quote::format_ident!("bound_source_{}", i)).collect()
+}
+
+/// Number of positional arguments a format string takes, `{{` and `}}` are escapes
+fn count_format_args(format: &str) -> usize {
+    let mut count = 0;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => { chars.next(); }
+            '{' => count += 1,
+            _ => {}
+        }
+    }
+    count
+}
+
+/// Body of `update_bindings`, reads each source straight from the `World` without change detection
+/// Queries go through `try_query` so a source type the world has never seen is skipped rather than registered
+fn generate_update_bindings_method(bindings: &[FieldBinding]) -> proc_macro2::TokenStream {
+    let reads: Vec<proc_macro2::TokenStream> = bindings.iter()
+        .filter_map(|binding| {
+            let widget_type = extract_widget_type(&binding.field_type);
+            match &binding.binding_source {
+                Some(source) => Some(generate_manual_read(binding, source, &widget_type)),
+                None if !binding.generic_bindings.is_empty() => Some(generate_generic_manual_read(binding, &binding.generic_bindings, &widget_type)),
+                None => None,
             }
+        })
+        .collect();
+
+    quote! {
+        #[allow(unused_mut)] // A HUD with no readable bindings never pushes
+        let mut updates: Vec<cosmic_ui::UIUpdateCommand> = Vec::new();
+
+        #(#reads)*
+
+        updates
+    }
+}
+
+fn generate_generic_manual_read(binding: &FieldBinding, generics: &[GenericBinding], widget_type: &str) -> proc_macro2::TokenStream {
+    let field_name = &binding.field_name;
+    let format_string = generic_format_string(binding, generics.len());
+    let sources = source_idents(generics.len());
+    let queries: Vec<syn::Ident> = (0..generics.len()).map(|i| quote::format_ident!("bound_query_{}", i)).collect();
+    let fields: Vec<&proc_macro2::TokenStream> = generics.iter().map(|generic| &generic.field).collect();
+    let first_source = &sources[0];
+    let first_field = fields[0];
+
+    let command = match widget_type {
+        "RadialGauge" => quote! {
+            cosmic_ui::UIUpdateCommand::GaugeUpdate {
+                entity: self.#field_name.entity,
+                value: #first_source.#first_field as f32,
+            }
+        },
+        "Counter" => quote! {
+            cosmic_ui::UIUpdateCommand::TextUpdate {
+                entity: self.#field_name.entity,
+                text: format!("{}{}{}", self.#field_name.prefix, #first_source.#first_field, self.#field_name.suffix),
+            }
+        },
+        _ => quote! {
+            cosmic_ui::UIUpdateCommand::TextUpdate {
+                entity: self.#field_name.entity,
+                text: format!(#format_string, #(#sources.#fields),*),
+            }
+        },
+    };
+
+    let reads: Vec<proc_macro2::TokenStream> = generics.iter().zip(sources.iter().zip(&queries)).map(|(generic, (source, query))| {
+        match &generic.source {
+            GenericSource::Resource(resource) => quote! {
+                let Some(#source) = world.get_resource::<#resource>() else { break 'read; };
+            },
+            GenericSource::Component { component, with: Some(with) } => quote! {
+                let Some(mut #query) = world.try_query_filtered::<&#component, bevy::prelude::With<#with>>() else { break 'read; };
+                let Some(#source) = #query.iter(world).next() else { break 'read; };
+            },
+            GenericSource::Component { component, with: None } => quote! {
+                let Some(mut #query) = world.try_query::<&#component>() else { break 'read; };
+                let Some(#source) = #query.iter(world).next() else { break 'read; };
+            },
+        }
+    }).collect();
+
+    quote! {
+        'read: {
+            #(#reads)*
+            updates.push(#command);
+        }
+    }
+}
+
+/// Manual counterpart of `generate_update_logic` for the built-in binding sources
+fn generate_manual_read(binding: &FieldBinding, source: &syn::Path, widget_type: &str) -> proc_macro2::TokenStream {
+    let field_name = &binding.field_name;
+    let source_str = quote!(#source).to_string();
+
+    match (source_str.as_str(), widget_type) {
+        ("PlayerLives", "Counter") => quote! {
+            if let Some(player) = world.try_query::<&crate::components::Player>().and_then(|mut player_query| player_query.iter(world).next()) {
+                updates.push(cosmic_ui::UIUpdateCommand::TextUpdate {
+                    entity: self.#field_name.entity,
+                    text: format!("{}{}{}", self.#field_name.prefix, player.lives, self.#field_name.suffix),
+                });
+                self.#field_name.last_value = player.lives;
+            }
+        },
+        ("PlayerATP", "TextDisplay") => quote! {
+            if let Some(atp) = world.try_query_filtered::<&crate::components::ATP, bevy::prelude::With<crate::components::Player>>().and_then(|mut atp_query| atp_query.iter(world).next()) {
+                updates.push(cosmic_ui::UIUpdateCommand::TextUpdate {
+                    entity: self.#field_name.entity,
+                    text: format!("ATP: {}⚡", atp.amount),
+                });
+                self.#field_name.last_value_hash = atp.amount as u64;
+            }
+        },
+        ("PlayerHealth", "ProgressBar") => quote! {
+            if let Some(health) = world.try_query_filtered::<&crate::components::Health, bevy::prelude::With<crate::components::Player>>().and_then(|mut health_query| health_query.iter(world).next()) {
+                // The progress bar animation system will handle the visual update
+                self.#field_name.current_percent = health.0 as f32 / 100.0;
+            }
+        },
+        ("GameScore", "TextDisplay") => quote! {
+            if let Some(score) = world.get_resource::<crate::resources::GameScore>() {
+                let text = if score.score_multiplier > 1.0 {
+                    format!("Score: {} ({}x)", score.current, score.score_multiplier)
+                } else {
+                    format!("Score: {}", score.current)
+                };
+                updates.push(cosmic_ui::UIUpdateCommand::TextUpdate {
+                    entity: self.#field_name.entity,
+                    text,
+                });
+            }
+        },
+        ("CellWallTimer", "TextDisplay") => quote! {
+            let text = match world.try_query::<&crate::components::CellWallReinforcement>().and_then(|mut cell_wall_query| cell_wall_query.iter(world).next()) {
+                Some(cell_wall) => {
+                    let remaining = cell_wall.timer.max(0.0);
+                    let icon = if remaining < 3.0 { "⚠️" } else { "🛡️" };
+                    format!("{} Cell Wall: {:.1}s", icon, remaining)
+                }
+                None => String::new(),
+            };
+            updates.push(cosmic_ui::UIUpdateCommand::TextUpdate {
+                entity: self.#field_name.entity,
+                text,
+            });
+        },
+        ("EnvironmentStatus", "TextDisplay") => quote! {
+            if let Some(environment) = world.get_resource::<crate::resources::ChemicalEnvironment>() {
+                updates.push(cosmic_ui::UIUpdateCommand::TextUpdate {
+                    entity: self.#field_name.entity,
+                    text: format!(
+                        "pH: {:.1} | O2: {:.0}%",
+                        environment.base_ph,
+                        environment.base_oxygen * 100.0
+                    ),
+                });
+            }
+        },
+        _ => quote! {
+            // Default update logic - no-op
         },
-        _ => quote! { cosmic_ui::builder::UIPosition::default() },
     }
 }
 
+const POSITION_CORNERS: [&str; 5] = ["top_left", "top_right", "bottom_left", "bottom_right", "center"];
+
+fn generate_position_code(position: &Option<PositionSpec>) -> proc_macro2::TokenStream {
+    let Some(spec) = position else {
+        return quote! { cosmic_ui::builder::UIPosition::default() };
+    };
+    
+    let corner = syn::Ident::new(&spec.corner, proc_macro2::Span::call_site());
+    let base = quote! { cosmic_ui::builder::UIPosition::#corner() };
+    if spec.offset_x.is_none() && spec.offset_y.is_none() {
+        return base;
+    }
+    
+    let (x, y) = (spec.offset_x.unwrap_or(0.0), spec.offset_y.unwrap_or(0.0));
+    quote! { #base.with_offset(#x, #y) }
+}
+
 fn extract_widget_type(ty: &Type) -> String {
     if let Type::Path(type_path) = ty {
         if let Some(segment) = type_path.path.segments.last() {
@@ -408,16 +807,66 @@ fn parse_counter_format(format: &Option<String>) -> Option<(String, String)> {
     })
 }
 
-fn extract_offset_value(pos: &str) -> Option<f32> {
-    // Simple parsing for offset_y = 30 patterns
-    if let Some(start) = pos.find("offset_y = ") {
-        let value_start = start + 11; // Length of "offset_y = "
-        let value_str: String = pos.chars()
-            .skip(value_start)
-            .take_while(|c| c.is_ascii_digit() || *c == '.')
-            .collect();
-        value_str.parse().ok()
-    } else {
-        None
+/// Parse a stringified position attribute, e.g. "bottom_left, offset_x = 20, offset_y = 30".
+/// The corner comes first. Attribute tokens are stringified, so the spacing around `=` and a `-` sign varies
+fn parse_position(pos: &str) -> Result<PositionSpec, String> {
+    let mut parts = pos.split(',').map(str::trim);
+    let corner = parts.next().unwrap_or_default();
+    if !POSITION_CORNERS.contains(&corner) {
+        return Err(format!("unknown position `{}`, expected one of {}", corner, POSITION_CORNERS.join(", ")));
+    }
+    
+    let mut spec = PositionSpec { corner: corner.to_string(), offset_x: None, offset_y: None };
+    for part in parts.filter(|part| !part.is_empty()) {
+        let (key, value) = part.split_once('=')
+            .ok_or_else(|| format!("expected `offset_x = N` or `offset_y = N`, found `{}`", part))?;
+        let slot = match key.trim() {
+            "offset_x" => &mut spec.offset_x,
+            "offset_y" => &mut spec.offset_y,
+            other => return Err(format!("unknown position key `{}`, expected offset_x or offset_y", other)),
+        };
+        let value = value.replace(' ', "");
+        *slot = Some(value.parse().map_err(|_| format!("{} must be a number, found `{}`", key.trim(), value))?);
     }
-}
\ No newline at end of file
+    Ok(spec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corner_and_both_offsets() {
+        let spec = parse_position("bottom_left, offset_x = 20, offset_y = 30").unwrap();
+        assert_eq!(spec, PositionSpec { corner: "bottom_left".to_string(), offset_x: Some(20.0), offset_y: Some(30.0) });
+
+        let code = generate_position_code(&Some(spec)).to_string();
+        assert!(code.contains("UIPosition :: bottom_left ()"), "{}", code);
+        assert!(code.contains("with_offset (20f32 , 30f32)"), "{}", code);
+    }
+
+    #[test]
+    fn stringified_negative_offsets_parse() {
+        let spec = parse_position("top_right , offset_x = - 12.5").unwrap();
+        assert_eq!(spec.offset_x, Some(-12.5));
+    }
+
+    #[test]
+    fn missing_offset_key_is_left_unset() {
+        let spec = parse_position("top_left, offset_y = 30").unwrap();
+        assert_eq!(spec.offset_x, None);
+        assert_eq!(spec.offset_y, Some(30.0));
+
+        let code = generate_position_code(&Some(spec)).to_string();
+        assert!(code.contains("with_offset (0f32 , 30f32)"), "{}", code);
+        assert!(parse_position("center").unwrap().offset_x.is_none());
+    }
+
+    #[test]
+    fn malformed_entries_are_errors() {
+        assert!(parse_position("bottom_left, offset_x = 2o").unwrap_err().contains("must be a number"));
+        assert!(parse_position("bottom_left, offset_x").unwrap_err().contains("expected `offset_x = N`"));
+        assert!(parse_position("bottom_left, offset_z = 4").unwrap_err().contains("unknown position key"));
+        assert!(parse_position("middle").unwrap_err().contains("unknown position"));
+    }
+}
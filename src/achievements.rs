@@ -7,6 +7,8 @@ use std::collections::HashMap;
 use crate::components::*;
 use crate::resources::*;
 use crate::despawn::*;
+use crate::save_versioning::*;
+use crate::enemy_types::EnemyType;
 
 // Achievement System Components and Resources
 #[derive(Resource, Default)]
@@ -64,6 +66,7 @@ pub enum UnlockCondition {
     
     // Discovery Achievements
     EncounterAllEnemyTypes,
+    SurviveAllRunModifiers, // clear a wave under each run modifier, across any number of runs
     UseAllPowerUps,
     VisitAllBiomes, // different environmental zones
     CollectAllStoryFragments,
@@ -117,6 +120,8 @@ pub struct SessionStats {
     pub shots_fired: u32,
     pub shots_hit: u32,
     pub evolutions_used: Vec<String>,
+    #[serde(default)]
+    pub enemy_types_killed: Vec<String>, // Biological descriptions, as carried by EnemyKilled
     pub powerups_used: Vec<String>,
     pub story_fragments_found: u32,
     pub king_tides_survived: u32,
@@ -139,6 +144,8 @@ pub struct LifetimeStats {
     pub high_score_streak: u32,
     pub perfect_games: u32, // no damage taken
     pub story_completion: f32, // % of lore discovered
+    #[serde(default)]
+    pub modifiers_survived: Vec<String>, // RunModifier display names a wave was cleared under
 }
 
 // Steam Integration (optional)
@@ -260,6 +267,52 @@ pub fn initialize_achievements() -> AchievementManager {
         reward: Some(AchievementReward::UnlockTitle("Deep Sea Scholar".to_string())),
     });
     
+    // Variety Achievements
+    achievements.insert("bestiary_complete".to_string(), Achievement {
+        id: "bestiary_complete".to_string(),
+        name: "Bestiary Complete".to_string(),
+        description: "Defeat one of every microorganism type in a single run".to_string(),
+        unlock_condition: UnlockCondition::EncounterAllEnemyTypes,
+        category: AchievementCategory::Discovery,
+        rarity: AchievementRarity::Rare,
+        steam_id: Some("BESTIARY_COMPLETE".to_string()),
+        unlock_date: None,
+        progress_current: 0.0,
+        progress_required: EnemyType::ALL.len() as f32,
+        icon_path: "achievements/bestiary_complete.png".to_string(),
+        reward: Some(AchievementReward::UnlockTitle("Microbiologist".to_string())),
+    });
+    
+    achievements.insert("full_spectrum".to_string(), Achievement {
+        id: "full_spectrum".to_string(),
+        name: "Full Spectrum".to_string(),
+        description: "Fight with every evolution in a single run".to_string(),
+        unlock_condition: UnlockCondition::UseAllEvolutions,
+        category: AchievementCategory::Evolution,
+        rarity: AchievementRarity::Epic,
+        steam_id: Some("FULL_SPECTRUM".to_string()),
+        unlock_date: None,
+        progress_current: 0.0,
+        progress_required: EvolutionType::DISPLAY_NAMES.len() as f32,
+        icon_path: "achievements/full_spectrum.png".to_string(),
+        reward: Some(AchievementReward::UnlockCosmetic("Prismatic Membrane".to_string())),
+    });
+    
+    achievements.insert("strain_survivor".to_string(), Achievement {
+        id: "strain_survivor".to_string(),
+        name: "Strain Survivor".to_string(),
+        description: "Clear a wave under every run modifier".to_string(),
+        unlock_condition: UnlockCondition::SurviveAllRunModifiers,
+        category: AchievementCategory::Challenge,
+        rarity: AchievementRarity::Epic,
+        steam_id: Some("STRAIN_SURVIVOR".to_string()),
+        unlock_date: None,
+        progress_current: 0.0,
+        progress_required: RunModifier::ALL.len() as f32,
+        icon_path: "achievements/strain_survivor.png".to_string(),
+        reward: Some(AchievementReward::UnlockTitle("Hardened Strain".to_string())),
+    });
+    
     AchievementManager {
         achievements,
         unlocked_achievements: Vec::new(),
@@ -277,6 +330,7 @@ pub fn track_achievements_system(
     player_query: Query<(&Player, &Health, &EvolutionSystem, &ATP)>,
     ecosystem: Res<EcosystemState>,
     game_score: Res<GameScore>,
+    run_modifiers: Res<RunModifiers>,
     time: Res<Time>,
     mut commands: Commands,
 ) {
@@ -284,6 +338,12 @@ pub fn track_achievements_system(
     if let Ok((player, health, evolution, atp)) = player_query.single() {
         achievement_manager.session_stats.time_survived = time.elapsed_secs();
         achievement_manager.session_stats.score_achieved = game_score.current;
+        
+        // Chambers, swaps and loadouts all change the evolution, so watch the player directly
+        let evolution_name = evolution.primary_evolution.get_display_name();
+        if !achievement_manager.session_stats.evolutions_used.iter().any(|used| used == evolution_name) {
+            achievement_manager.session_stats.evolutions_used.push(evolution_name.to_string());
+        }
     }
     
     // Process achievement events
@@ -292,12 +352,14 @@ pub fn track_achievements_system(
             AchievementEvent::EnemyKilled(enemy_type) => {
                 achievement_manager.session_stats.enemies_killed += 1;
                 achievement_manager.lifetime_stats.total_enemies_killed += 1;
+                if !achievement_manager.session_stats.enemy_types_killed.contains(enemy_type) {
+                    achievement_manager.session_stats.enemy_types_killed.push(enemy_type.clone());
+                }
             }
-            AchievementEvent::EvolutionReached(evolution_name) => {
-                if !achievement_manager.session_stats.evolutions_used.contains(evolution_name) {
+            AchievementEvent::EvolutionReached(evolution_name)
+                if !achievement_manager.session_stats.evolutions_used.contains(evolution_name) => {
                     achievement_manager.session_stats.evolutions_used.push(evolution_name.clone());
                 }
-            }
             AchievementEvent::ATPCollected(amount) => {
                 achievement_manager.session_stats.atp_collected += amount;
                 achievement_manager.lifetime_stats.total_atp_collected += amount;
@@ -317,6 +379,14 @@ pub fn track_achievements_system(
             AchievementEvent::CriticalHit => {
                 achievement_manager.session_stats.critical_hits += 1;
             }
+            AchievementEvent::WaveCompleted { .. } => {
+                for modifier in &run_modifiers.active {
+                    let name = modifier.display_name();
+                    if !achievement_manager.lifetime_stats.modifiers_survived.iter().any(|survived| survived == name) {
+                        achievement_manager.lifetime_stats.modifiers_survived.push(name.to_string());
+                    }
+                }
+            }
             _ => {
                 // PLACEHOLDER
             }
@@ -370,7 +440,7 @@ fn check_achievement_progress(
             continue; // Already unlocked
         }
         
-        let progress = calculate_progress(achievement, &achievement_manager.session_stats, ecosystem);
+        let progress = calculate_progress(achievement, &achievement_manager.session_stats, &achievement_manager.lifetime_stats, ecosystem);
         achievement.progress_current = progress;
         
         if progress >= achievement.progress_required {
@@ -380,13 +450,14 @@ fn check_achievement_progress(
     
     // Unlock achievements and spawn notifications
     for (id, achievement) in newly_unlocked {
-        unlock_achievement(&mut achievement_manager, id, achievement, commands);
+        unlock_achievement(achievement_manager, id, achievement, commands);
     }
 }
 
 fn calculate_progress(
     achievement: &Achievement, 
     session_stats: &SessionStats,
+    lifetime_stats: &LifetimeStats,
     ecosystem: &EcosystemState
 ) -> f32 {
     match &achievement.unlock_condition {
@@ -397,23 +468,47 @@ fn calculate_progress(
         UnlockCondition::CriticalHits(target) => session_stats.critical_hits as f32,
         UnlockCondition::SurviveKingTide(target) => session_stats.king_tides_survived as f32,
         UnlockCondition::ExploreDebris(target) => session_stats.story_fragments_found as f32,
-        UnlockCondition::PerfectAccuracy(target) => {
-            if session_stats.shots_fired > 0 {
+        UnlockCondition::PerfectAccuracy(target)
+            if session_stats.shots_fired > 0 => {
                 session_stats.shots_hit as f32 / session_stats.shots_fired as f32
-            } else {
-                0.0
-            }
-        },
-        UnlockCondition::EcosystemHealth(target) => {
-            if ecosystem.health >= *target { session_stats.time_survived } else { 0.0 }
-        },
-        UnlockCondition::ReachEvolutionLevel(evolution) => {
-            if session_stats.evolutions_used.contains(evolution) { 1.0 } else { 0.0 }
-        },
+            },
+        UnlockCondition::EcosystemHealth(target)
+            if ecosystem.health >= *target => { session_stats.time_survived },
+        UnlockCondition::ReachEvolutionLevel(evolution)
+            if session_stats.evolutions_used.contains(evolution) => { 1.0 },
+        UnlockCondition::EncounterAllEnemyTypes => bestiary_progress(session_stats) as f32,
+        UnlockCondition::UseAllEvolutions => evolution_variety_progress(session_stats) as f32,
+        UnlockCondition::SurviveAllRunModifiers => modifier_survival_progress(lifetime_stats) as f32,
         _ => 0.0, // Handle other conditions as needed
     }
 }
 
+/// Distinct enemy types defeated this run
+pub fn bestiary_progress(session_stats: &SessionStats) -> usize {
+    EnemyType::ALL.iter()
+        .filter(|enemy_type| session_stats.enemy_types_killed.iter().any(|killed| killed == enemy_type.get_biological_description()))
+        .count()
+}
+
+/// Distinct evolutions fought with this run
+pub fn evolution_variety_progress(session_stats: &SessionStats) -> usize {
+    EvolutionType::DISPLAY_NAMES.iter()
+        .filter(|name| session_stats.evolutions_used.iter().any(|used| used == *name))
+        .count()
+}
+
+/// Distinct run modifiers a wave has been cleared under, across every run
+pub fn modifier_survival_progress(lifetime_stats: &LifetimeStats) -> usize {
+    RunModifier::ALL.iter()
+        .filter(|modifier| lifetime_stats.modifiers_survived.iter().any(|survived| survived == modifier.display_name()))
+        .count()
+}
+
+/// Per-run stats start fresh with each new run so single-run achievements mean what they say
+pub fn reset_achievement_session_stats(mut achievement_manager: ResMut<AchievementManager>) {
+    achievement_manager.session_stats = SessionStats::default();
+}
+
 fn unlock_achievement(
     achievement_manager: &mut AchievementManager,
     id: String,
@@ -430,10 +525,8 @@ fn unlock_achievement(
     }
     
     // Steam integration
-    if let Some(steam) = &mut achievement_manager.steam_integration {
-        if let Some(steam_id) = &achievement.steam_id {
-            steam.pending_unlocks.push(steam_id.clone());
-        }
+    if let Some(steam) = &mut achievement_manager.steam_integration && let Some(steam_id) = &achievement.steam_id {
+        steam.pending_unlocks.push(steam_id.clone());
     }
     
     // Spawn achievement notification
@@ -526,22 +619,19 @@ pub fn update_achievement_notifications(
 // Save/Load achievements
 pub fn save_achievements(achievement_manager: &AchievementManager) {
     let save_data = AchievementSaveData {
+        version: AchievementSaveData::CURRENT_VERSION,
         unlocked_achievements: achievement_manager.unlocked_achievements.clone(),
         lifetime_stats: achievement_manager.lifetime_stats.clone(),
     };
     
-    if let Ok(json) = serde_json::to_string_pretty(&save_data) {
-        // Save to file system (implementation depends on platform)
-        std::fs::write("achievements.json", json).ok();
-    }
+    // Save to file system (implementation depends on platform)
+    save_versioned("achievements.json", &save_data);
 }
 
 pub fn load_achievements(achievement_manager: &mut AchievementManager) {
-    if let Ok(json) = std::fs::read_to_string("achievements.json") {
-        if let Ok(save_data) = serde_json::from_str::<AchievementSaveData>(&json) {
-            achievement_manager.unlocked_achievements = save_data.unlocked_achievements;
-            achievement_manager.lifetime_stats = save_data.lifetime_stats;
-        }
+    if let Some(save_data) = load_versioned::<AchievementSaveData>("achievements.json") {
+        achievement_manager.unlocked_achievements = save_data.unlocked_achievements;
+        achievement_manager.lifetime_stats = save_data.lifetime_stats;
     }
 }
 
@@ -556,10 +646,17 @@ pub fn load_achievements_from_file() -> PersistentAchievements {
 
 #[derive(Serialize, Deserialize)]
 pub struct AchievementSaveData {
+    #[serde(default)]
+    pub version: u32,
     pub unlocked_achievements: Vec<String>,
     pub lifetime_stats: LifetimeStats,
 }
 
+impl VersionedSave for AchievementSaveData {
+    const CURRENT_VERSION: u32 = 1;
+    const LABEL: &'static str = "achievements";
+}
+
 pub fn save_achievements_on_exit(achievement_manager: Res<AchievementManager>) {
     save_achievements(&achievement_manager);
 }
@@ -578,4 +675,66 @@ pub struct PersistentAchievements {
     pub last_updated: String,
     pub total_games: u32,
     pub total_playtime: f32,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn achievement_app(active: Vec<RunModifier>) -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<EcosystemState>()
+            .init_resource::<GameScore>()
+            .insert_resource(RunModifiers { active })
+            .insert_resource(initialize_achievements())
+            .add_event::<AchievementEvent>()
+            .add_systems(Update, track_achievements_system);
+        app
+    }
+
+    fn clear_wave(app: &mut App) {
+        app.world_mut().send_event(AchievementEvent::WaveCompleted { wave_number: 1, completion_time: 30.0 });
+        app.update();
+    }
+
+    fn strain_survivor_unlocked(app: &App) -> bool {
+        app.world().resource::<AchievementManager>().unlocked_achievements.iter().any(|id| id == "strain_survivor")
+    }
+
+    #[test]
+    fn clearing_a_wave_under_each_modifier_across_runs_unlocks_strain_survivor() {
+        let mut app = achievement_app(vec![RunModifier::Frenzy]);
+        clear_wave(&mut app);
+        assert!(!strain_survivor_unlocked(&app));
+
+        // A later run with the other modifier completes the set
+        app.world_mut().resource_mut::<RunModifiers>().active = vec![RunModifier::IronCell];
+        clear_wave(&mut app);
+        assert!(strain_survivor_unlocked(&app));
+    }
+
+    #[test]
+    fn killing_one_of_each_enemy_type_completes_the_bestiary() {
+        let mut app = achievement_app(Vec::new());
+        let (last, rest) = EnemyType::ALL.split_last().unwrap();
+        for enemy_type in rest {
+            app.world_mut().send_event(AchievementEvent::EnemyKilled(enemy_type.get_biological_description().to_string()));
+        }
+        app.update();
+        assert!(!app.world().resource::<AchievementManager>().unlocked_achievements.iter().any(|id| id == "bestiary_complete"));
+
+        app.world_mut().send_event(AchievementEvent::EnemyKilled(last.get_biological_description().to_string()));
+        app.update();
+        assert!(app.world().resource::<AchievementManager>().unlocked_achievements.iter().any(|id| id == "bestiary_complete"));
+    }
+
+    #[test]
+    fn waves_cleared_without_modifiers_do_not_count() {
+        let mut app = achievement_app(Vec::new());
+        clear_wave(&mut app);
+        clear_wave(&mut app);
+        assert!(app.world().resource::<AchievementManager>().lifetime_stats.modifiers_survived.is_empty());
+        assert!(!strain_survivor_unlocked(&app));
+    }
+}
@@ -9,10 +9,12 @@ use crate::achievements::{AchievementEvent};
 use crate::despawn::*;
 // ===== CONSTANTS =====
 const MAX_CONCURRENT_SFX: usize = 20;
-const MAX_EXPLOSION_SFX_PER_FRAME: u8 = 2;
+const MAX_EXPLOSION_SFX_PER_FRAME: usize = 2;
 const SHOOT_SFX_THROTTLE: f32 = 0.1;
 const MUSIC_FADE_DURATION: f32 = 2.0;
 const AUDIO_CLEANUP_THRESHOLD: usize = 30;
+const SPATIAL_SFX_SCALE: f32 = 1.0 / 200.0;  // World pixels to audio units for positional cues
+pub const SPATIAL_LISTENER_EAR_GAP: f32 = 4.0;
 
 // ===== AUDIO CONFIGURATION =====
 #[derive(Resource)]
@@ -35,6 +37,7 @@ pub enum SfxType {
     EnemyDeath(EnemyType),
     EnemyMovement(EnemyType),
     EnemyAttack(EnemyType),
+    EnemySpawn(EnemyType),
     
     // UI sounds
     ButtonClick,
@@ -49,6 +52,11 @@ pub enum SfxType {
     ExplosionBiological,
     ExplosionChemical,
     AtpCollect,
+    
+    // Telegraph cues
+    TurretCharge,
+    BossPhaseRumble,
+    BossRoar,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -94,6 +102,63 @@ pub struct VolumeSettings {
     pub ui: f32,
 }
 
+#[derive(Resource)]
+pub struct TelegraphAudioConfig {
+    pub enabled: bool,
+    pub spatial: bool, // Pan cues towards the enemy that raised them
+}
+
+impl Default for TelegraphAudioConfig {
+    fn default() -> Self {
+        Self { enabled: true, spatial: true }
+    }
+}
+
+impl TelegraphCueKind {
+    pub fn sfx(&self) -> SfxType {
+        match self {
+            TelegraphCueKind::TurretCharge => SfxType::TurretCharge,
+            TelegraphCueKind::BossPhaseShift => SfxType::BossPhaseRumble,
+            TelegraphCueKind::BossRoar => SfxType::BossRoar,
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct EnemySpawnAudioConfig {
+    pub enabled: bool,
+    pub spatial: bool, // Pan entry cues towards where the enemy arrives
+}
+
+impl Default for EnemySpawnAudioConfig {
+    fn default() -> Self {
+        Self { enabled: true, spatial: true }
+    }
+}
+
+impl EnemySpawnAudioConfig {
+    /// Type-keyed entry cue for a resolved `SpawnEnemy`, None when spawn sounds are off
+    pub fn cue(&self, enemy_type: EnemyType) -> Option<SfxType> {
+        self.enabled.then_some(SfxType::EnemySpawn(enemy_type))
+    }
+}
+
+/// Entry sound per enemy type. These are spawn-only files, death and pickup sounds are
+/// never reused so an arriving enemy can't be mistaken for a kill or an evolution
+pub fn enemy_spawn_cue_file(enemy_type: EnemyType) -> &'static str {
+    match enemy_type {
+        EnemyType::ViralParticle => "audio/spawn/viral_particle.ogg",
+        EnemyType::AggressiveBacteria => "audio/spawn/aggressive_bacteria.ogg",
+        EnemyType::ParasiticProtozoa => "audio/spawn/parasitic_protozoa.ogg",
+        EnemyType::InfectedMacrophage => "audio/spawn/infected_macrophage.ogg",
+        EnemyType::SuicidalSpore => "audio/spawn/suicidal_spore.ogg",
+        EnemyType::BiofilmColony => "audio/spawn/biofilm_colony.ogg",
+        EnemyType::SwarmCell => "audio/spawn/swarm_cell.ogg",
+        EnemyType::ReproductiveVesicle => "audio/spawn/reproductive_vesicle.ogg",
+        EnemyType::Offspring => "audio/spawn/offspring.ogg",
+    }
+}
+
 // ===== AUDIO MANAGER =====
 #[derive(Resource)]
 pub struct AudioManager {
@@ -204,6 +269,19 @@ impl AudioConfig {
             });
         }
 
+        // Enemy entry sounds, kept quiet so a full wave doesn't drown the mix
+        for enemy_type in [EnemyType::ViralParticle, EnemyType::AggressiveBacteria, EnemyType::ParasiticProtozoa,
+                          EnemyType::InfectedMacrophage, EnemyType::SuicidalSpore, EnemyType::BiofilmColony,
+                          EnemyType::SwarmCell, EnemyType::ReproductiveVesicle, EnemyType::Offspring] {
+            sfx_library.insert(SfxType::EnemySpawn(enemy_type), SfxData {
+                handle: asset_server.load(enemy_spawn_cue_file(enemy_type)),
+                base_volume: 0.15,
+                priority: 40,
+                max_concurrent: 2,
+                throttle_time: 0.15,
+            });
+        }
+
         // UI sounds
         sfx_library.insert(SfxType::ButtonClick, SfxData {
             handle: asset_server.load("audio/ui_click.ogg"),
@@ -279,6 +357,31 @@ impl AudioConfig {
             throttle_time: 0.05,
         });
 
+        // Telegraph cues, reusing existing sounds until dedicated ones land
+        sfx_library.insert(SfxType::TurretCharge, SfxData {
+            handle: asset_server.load("audio/organic_pulse.ogg"),
+            base_volume: 0.35,
+            priority: 170,
+            max_concurrent: 2,
+            throttle_time: 0.15,
+        });
+
+        sfx_library.insert(SfxType::BossPhaseRumble, SfxData {
+            handle: asset_server.load("audio/tidal_wave.ogg"),
+            base_volume: 0.7,
+            priority: 230,
+            max_concurrent: 1,
+            throttle_time: 1.0,
+        });
+
+        sfx_library.insert(SfxType::BossRoar, SfxData {
+            handle: asset_server.load("audio/bio_explosion.ogg"),
+            base_volume: 0.9,
+            priority: 240,
+            max_concurrent: 1,
+            throttle_time: 1.0,
+        });
+
         // ===== MUSIC TRACKS =====
         music_tracks.insert(MusicTrack::MenuAmbient, MusicData {
             handle: asset_server.load("audio/menu_ambient.ogg"),
@@ -361,6 +464,8 @@ pub fn audio_system(
     mut powerup_events: EventReader<SpawnPowerUp>,
     mut player_hit_events: EventReader<PlayerHit>,
     mut achievement_events: EventReader<AchievementEvent>,
+    (mut cue_events, telegraph_config): (EventReader<TelegraphCue>, Res<TelegraphAudioConfig>),
+    (mut spawn_events, spawn_audio_config): (EventReader<SpawnEnemy>, Res<EnemySpawnAudioConfig>),
     input_manager: Res<InputManager>,
     audio_config: Res<AudioConfig>,
     audio_settings: Res<AudioMenuSettings>,
@@ -396,33 +501,43 @@ pub fn audio_system(
     }
 
     // ===== EXPLOSIONS =====
-    let mut explosion_count = 0;
-    for event in explosion_events.read() {
-        if explosion_count >= MAX_EXPLOSION_SFX_PER_FRAME { break; }
-        
+    for event in explosion_events.read().take(MAX_EXPLOSION_SFX_PER_FRAME) {
         let sfx_type = if let Some(enemy_type) = &event.enemy_type {
-            SfxType::EnemyDeath(enemy_type.clone())
+            SfxType::EnemyDeath(*enemy_type)
         } else {
             SfxType::ExplosionStandard
         };
         
         play_sfx(&mut commands, &audio_config, &audio_settings, &mut audio_manager,
                 sfx_type, time.elapsed_secs());
-        explosion_count += 1;
     }
 
     // ===== POWERUPS =====
-    for _ in powerup_events.read() {
+    if powerup_events.read().next().is_some() { // Only one per frame
         play_sfx(&mut commands, &audio_config, &audio_settings, &mut audio_manager,
                 SfxType::PowerupCollect, time.elapsed_secs());
-        break; // Only one per frame
+    }
+
+    // ===== TELEGRAPH CUES =====
+    for cue in cue_events.read() {
+        if !telegraph_config.enabled { continue; }
+        let emitter = telegraph_config.spatial.then_some(cue.position);
+        play_sfx_at(&mut commands, &audio_config, &audio_settings, &mut audio_manager,
+                cue.kind.sfx(), emitter, time.elapsed_secs());
+    }
+
+    // ===== ENEMY ENTRIES =====
+    for event in spawn_events.read() {
+        let Some(sfx_type) = spawn_audio_config.cue(event.enemy_type) else { continue };
+        let emitter = spawn_audio_config.spatial.then_some(event.position);
+        play_sfx_at(&mut commands, &audio_config, &audio_settings, &mut audio_manager,
+                sfx_type, emitter, time.elapsed_secs());
     }
 
     // ===== ACHIEVEMENTS =====
-    for _ in achievement_events.read() {
+    if achievement_events.read().next().is_some() { // Only one per frame
         play_sfx(&mut commands, &audio_config, &audio_settings, &mut audio_manager,
                 SfxType::AchievementUnlock, time.elapsed_secs());
-        break; // Only one per frame
     }
 }
 
@@ -432,6 +547,7 @@ pub fn music_system(
     audio_settings: Res<AudioMenuSettings>,
     mut audio_manager: ResMut<AudioManager>,
     game_state: Res<State<GameState>>,
+    collapse: Res<EcosystemCollapse>,
     time: Res<Time>,
     mut music_query: Query<(Entity, &mut MusicPlayer, &mut PlaybackSettings)>,
 ) {
@@ -440,17 +556,16 @@ pub fn music_system(
 
     // Determine target playlist based on game state
     let target_playlist = match game_state.get() {
-        GameState::TitleScreen | GameState::Settings | GameState::HighScores => Some(PlaylistType::MainMenu),
+        GameState::TitleScreen | GameState::Settings | GameState::HighScores | GameState::LoreArchive | GameState::Bestiary => Some(PlaylistType::MainMenu),
+        GameState::Playing if collapse.active => Some(PlaylistType::Boss),
         GameState::Playing => Some(PlaylistType::Gameplay),
         GameState::GameOver => None, // Let current track finish
         _ => audio_manager.current_playlist,
     };
 
     // Switch playlist if needed
-    if target_playlist != audio_manager.current_playlist {
-        if let Some(playlist) = target_playlist {
-            start_playlist(&mut commands, &audio_config, &audio_settings, &mut audio_manager, playlist);
-        }
+    if target_playlist != audio_manager.current_playlist && let Some(playlist) = target_playlist {
+        start_playlist(&mut commands, &audio_config, &audio_settings, &mut audio_manager, playlist);
     }
 
     // Handle track progression and fading
@@ -497,13 +612,24 @@ fn play_sfx(
     audio_manager: &mut AudioManager,
     sfx_type: SfxType,
     current_time: f32,
+) {
+    play_sfx_at(commands, audio_config, audio_settings, audio_manager, sfx_type, None, current_time);
+}
+
+/// Route a sound through the mixer, panned from `emitter` when given
+fn play_sfx_at(
+    commands: &mut Commands,
+    audio_config: &AudioConfig,
+    audio_settings: &AudioMenuSettings,
+    audio_manager: &mut AudioManager,
+    sfx_type: SfxType,
+    emitter: Option<Vec3>,
+    current_time: f32,
 ) {
     let Some(sfx_data) = audio_config.sfx_library.get(&sfx_type) else { return };
     
     // Check throttling
-    if let Some(throttle) = audio_manager.sfx_throttles.get(&sfx_type) {
-        if *throttle > 0.0 { return; }
-    }
+    if let Some(throttle) = audio_manager.sfx_throttles.get(&sfx_type) && *throttle > 0.0 { return; }
 
     // Check concurrent limit
     let current_count = audio_manager.sfx_counts.get(&sfx_type).unwrap_or(&0);
@@ -519,16 +645,25 @@ fn play_sfx(
 
     let sfx_type_clone = sfx_type.clone();
 
+    let mut playback = PlaybackSettings::DESPAWN.with_volume(Volume::Linear(final_volume));
+    if emitter.is_some() {
+        playback.spatial = true;
+        playback.spatial_scale = Some(SpatialScale::new_2d(SPATIAL_SFX_SCALE));
+    }
+
     // Spawn audio entity
-    commands.spawn((
+    let mut audio_entity = commands.spawn((
         AudioPlayer::new(sfx_data.handle.clone()),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(final_volume)),
+        playback,
         ManagedAudioSource {
             sfx_type: Some(sfx_type),
             priority: sfx_data.priority,
             spawn_time: current_time,
         },
     ));
+    if let Some(position) = emitter {
+        audio_entity.insert(Transform::from_translation(position));
+    }
 
     // Update throttle and count
     audio_manager.sfx_throttles.insert(sfx_type_clone.clone(), sfx_data.throttle_time);
@@ -546,10 +681,8 @@ fn start_playlist(
     audio_manager.track_index = 0;
     audio_manager.track_timer = 0.0;
 
-    if let Some(tracks) = audio_config.playlists.get(&playlist) {
-        if let Some(&first_track) = tracks.first() {
-            start_music_track(commands, audio_config, audio_settings, audio_manager, first_track);
-        }
+    if let Some(tracks) = audio_config.playlists.get(&playlist) && let Some(&first_track) = tracks.first() {
+        start_music_track(commands, audio_config, audio_settings, audio_manager, first_track);
     }
 }
 
@@ -605,12 +738,11 @@ fn handle_music_transitions(
         }
         FadeState::None => {
             // Check if current track should advance (for playlists)
-            if let (Some(current_track), Some(playlist)) = (audio_manager.current_track, audio_manager.current_playlist) {
-                if let Some(music_data) = audio_config.music_tracks.get(&current_track) {
-                    if !music_data.loop_track && audio_manager.track_timer >= music_data.duration {
-                        advance_playlist(commands, audio_config, audio_settings, audio_manager);
-                    }
-                }
+            if let (Some(current_track), Some(playlist)) = (audio_manager.current_track, audio_manager.current_playlist)
+                && let Some(music_data) = audio_config.music_tracks.get(&current_track)
+                && !music_data.loop_track && audio_manager.track_timer >= music_data.duration
+            {
+                advance_playlist(commands, audio_config, audio_settings, audio_manager);
             }
         }
     }
@@ -622,12 +754,10 @@ fn advance_playlist(
     audio_settings: &AudioMenuSettings,
     audio_manager: &mut AudioManager,
 ) {
-    if let Some(playlist) = audio_manager.current_playlist {
-        if let Some(tracks) = audio_config.playlists.get(&playlist) {
-            audio_manager.track_index = (audio_manager.track_index + 1) % tracks.len();
-            if let Some(&next_track) = tracks.get(audio_manager.track_index) {
-                start_music_track(commands, audio_config, audio_settings, audio_manager, next_track);
-            }
+    if let Some(playlist) = audio_manager.current_playlist && let Some(tracks) = audio_config.playlists.get(&playlist) {
+        audio_manager.track_index = (audio_manager.track_index + 1) % tracks.len();
+        if let Some(&next_track) = tracks.get(audio_manager.track_index) {
+            start_music_track(commands, audio_config, audio_settings, audio_manager, next_track);
         }
     }
 }
@@ -658,4 +788,58 @@ pub fn setup_audio_system(mut commands: Commands, asset_server: Res<AssetServer>
     let audio_config = AudioConfig::new(&asset_server);
     commands.insert_resource(audio_config);
     commands.insert_resource(AudioManager::default());
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_cue_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<AudioSource>();
+        let audio_config = AudioConfig::new(app.world().resource::<AssetServer>());
+        app.insert_resource(audio_config)
+            .init_resource::<Time>()
+            .init_resource::<InputManager>()
+            .init_resource::<AudioMenuSettings>()
+            .init_resource::<AudioManager>()
+            .init_resource::<TelegraphAudioConfig>()
+            .init_resource::<EnemySpawnAudioConfig>()
+            .add_event::<SpawnExplosion>()
+            .add_event::<SpawnPowerUp>()
+            .add_event::<PlayerHit>()
+            .add_event::<AchievementEvent>()
+            .add_event::<TelegraphCue>()
+            .add_event::<SpawnEnemy>()
+            .add_systems(Update, audio_system);
+        app
+    }
+
+    /// Handle of the entry cue played for a freshly spawned enemy of `enemy_type`
+    fn entry_cue(app: &mut App, enemy_type: EnemyType) -> Handle<AudioSource> {
+        app.world_mut().send_event(SpawnEnemy {
+            position: Vec3::new(120.0, 300.0, 0.0),
+            ai_type: EnemyAI::Linear { direction: Vec2::NEG_Y },
+            enemy_type,
+        });
+        app.update();
+
+        let mut cues = app.world_mut().query::<(&AudioPlayer, &ManagedAudioSource)>();
+        cues.iter(app.world())
+            .find(|(_, source)| source.sfx_type == Some(SfxType::EnemySpawn(enemy_type)))
+            .map(|(player, _)| player.0.clone())
+            .expect("spawn should queue its entry cue")
+    }
+
+    #[test]
+    fn each_enemy_type_announces_itself_with_its_own_entry_cue() {
+        let mut app = spawn_cue_app();
+        let viral = entry_cue(&mut app, EnemyType::ViralParticle);
+        let bacteria = entry_cue(&mut app, EnemyType::AggressiveBacteria);
+
+        let library = &app.world().resource::<AudioConfig>().sfx_library;
+        assert_eq!(viral, library[&SfxType::EnemySpawn(EnemyType::ViralParticle)].handle);
+        assert_eq!(bacteria, library[&SfxType::EnemySpawn(EnemyType::AggressiveBacteria)].handle);
+        assert_ne!(viral, bacteria);
+    }
+}
@@ -212,59 +212,62 @@ pub fn procedural_background_generation(
     tidal_physics: Res<TidalPoolPhysics>,
     chemical_environment: Res<ChemicalEnvironment>,
     assets: Option<Res<GameAssets>>,
+    graphics: Res<GraphicsSettings>,
     time: Res<Time>,
     mut initial_generation: Local<bool>,
 ) {
-    if let Ok(camera_transform) = camera_query.single() {
-        if let Some(assets) = assets {
-            // Update environmental conditions
-            bg_manager.environmental_state.ecosystem_health = ecosystem.health;
-            bg_manager.environmental_state.contamination_level = 1.0 - ecosystem.health;
-            bg_manager.environmental_state.current_strength = tidal_physics.current_strength;
-            bg_manager.environmental_state.water_clarity = 0.9 - ecosystem.infection_level * 0.4;
+    let detail = graphics.background_detail();
+
+    if let Ok(camera_transform) = camera_query.single() && let Some(assets) = assets {
+        // Update environmental conditions
+        bg_manager.environmental_state.ecosystem_health = ecosystem.health;
+        bg_manager.environmental_state.contamination_level = 1.0 - ecosystem.health;
+        bg_manager.environmental_state.current_strength = tidal_physics.current_strength;
+        bg_manager.environmental_state.water_clarity = 0.9 - ecosystem.infection_level * 0.4;
             
-            let camera_y = camera_transform.translation.y;
+        let camera_y = camera_transform.translation.y;
             
-            // Initial generation - populate entire view on first frame
-            if !*initial_generation {
-                *initial_generation = true;
+        // Initial generation - populate entire view on first frame
+        if !*initial_generation {
+            *initial_generation = true;
                 
-                // Generate tiles across the entire visible area plus buffer
-                for y in -2..=3 {
-                    let tile_y = camera_y + (y as f32 * 200.0);
-                    for depth_layer in [0.1, 0.3, 0.5, 0.7, 0.8] { // More layers for richness
-                        generate_background_tile(
-                            &mut commands,
-                            &mut bg_manager,
-                            &assets,
-                            depth_layer,
-                            tile_y,
-                            time.elapsed_secs() + y as f32,
-                        );
-                    }
-                }
-            }
-            
-            // Normal generation - only ahead of camera
-            let generation_threshold = camera_y + bg_manager.generation_distance;
-            
-            // Check each depth layer for tile generation needs
-            for depth_layer in [0.1, 0.5, 0.8] { // Deep, Mid, Surface
-                if should_generate_tile_at_depth(depth_layer, generation_threshold, &tile_query) {
+            // Generate tiles across the entire visible area plus buffer
+            for y in -2..=3 {
+                let tile_y = camera_y + (y as f32 * 200.0);
+                for depth_layer in [0.1, 0.3, 0.5, 0.7, 0.8] { // More layers for richness
                     generate_background_tile(
                         &mut commands,
                         &mut bg_manager,
                         &assets,
                         depth_layer,
-                        generation_threshold,
-                        time.elapsed_secs(),
+                        tile_y,
+                        time.elapsed_secs() + y as f32,
+                        detail,
                     );
                 }
             }
+        }
             
-            // Cleanup distant tiles
-            cleanup_distant_tiles(&mut commands, &mut bg_manager, camera_y);
+        // Normal generation - only ahead of camera
+        let generation_threshold = camera_y + bg_manager.generation_distance;
+            
+        // Check each depth layer for tile generation needs
+        for depth_layer in [0.1, 0.5, 0.8] { // Deep, Mid, Surface
+            if should_generate_tile_at_depth(depth_layer, generation_threshold, &tile_query) {
+                generate_background_tile(
+                    &mut commands,
+                    &mut bg_manager,
+                    &assets,
+                    depth_layer,
+                    generation_threshold,
+                    time.elapsed_secs(),
+                    detail,
+                );
+            }
         }
+            
+        // Cleanup distant tiles
+        cleanup_distant_tiles(&mut commands, &mut bg_manager, camera_y);
     }
 }
 
@@ -292,6 +295,7 @@ fn generate_background_tile(
     depth_layer: f32,
     y_position: f32,
     time_seed: f32,
+    detail: f32,
 ) {
     let seed = (bg_manager.tile_generation_seed as f32 + time_seed * 1000.0) as u32;
     bg_manager.tile_generation_seed = bg_manager.tile_generation_seed.wrapping_add(1);
@@ -303,7 +307,7 @@ fn generate_background_tile(
     bg_manager.active_tiles.push(tile_entity);
     
     // Generate associated particles
-    spawn_tile_particles(commands, assets, &tile_type, Vec2::new(0.0, y_position), depth_layer);
+    spawn_tile_particles(commands, assets, &tile_type, Vec2::new(0.0, y_position), depth_layer, detail);
 }
 
 fn generate_tile_type_for_depth(
@@ -446,16 +450,17 @@ fn spawn_tile_particles(
     tile_type: &BackgroundTileType,
     position: Vec2,
     depth: f32,
+    detail: f32,
 ) {
     match tile_type {
         BackgroundTileType::OpenWater { plankton_density, .. } => {
-            spawn_plankton_particles(commands, assets, position, *plankton_density, depth);
+            spawn_plankton_particles(commands, assets, position, *plankton_density * detail, depth);
         }
         BackgroundTileType::SurfaceWater { bubble_streams, .. } => {
             spawn_bubble_particles(commands, assets, position, bubble_streams, depth);
         }
         BackgroundTileType::ContaminatedZone { toxic_particles, .. } => {
-            spawn_contamination_particles(commands, assets, position, *toxic_particles, depth);
+            spawn_contamination_particles(commands, assets, position, (*toxic_particles as f32 * detail) as u32, depth);
         }
         _ => {} // Other tile types don't need immediate particle spawning
     }
@@ -921,7 +926,7 @@ pub fn biological_feedback_system(
                 .map(|z| z.ph_level)
                 .sum::<f32>() / chemical_environment.ph_zones.len().max(1) as f32;
                 
-            if avg_ph < 5.5 || avg_ph > 8.5 {
+            if !(5.5..=8.5).contains(&avg_ph) {
                 spawn_chemical_indicator(&mut commands, &assets,
                     player_transform.translation + Vec3::new(50.0, 30.0, 1.0),
                     &format!("pH: {:.1}", avg_ph),
@@ -1021,4 +1026,151 @@ fn spawn_chemical_indicator(
 
 pub fn init_procedural_background(mut commands: Commands) {
     commands.insert_resource(ProceduralBackgroundManager::default());
-}
\ No newline at end of file
+}
+
+// ===== PROJECTILE CONTRAST =====
+const OUTLINE_SCALE: f32 = 1.6;
+const OUTLINE_ALPHA: f32 = 0.75;
+
+/// Keeps shots readable with a halo behind them that contrasts with whatever they cross
+#[derive(Resource, Clone)]
+pub struct ProjectileContrastConfig {
+    pub enabled: bool,
+    pub min_contrast: f32, // Luminance gap below which a projectile gets a visible halo
+}
+
+impl Default for ProjectileContrastConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_contrast: 0.35,
+        }
+    }
+}
+
+/// Halo sprite drawn behind a projectile, so the shot itself keeps its own colour
+#[derive(Component)]
+pub struct ProjectileOutline;
+
+/// Marks a projectile whose outline child has already been spawned
+#[derive(Component)]
+pub struct HasProjectileOutline;
+
+/// Luminance behind `position`, background sprites blended over the clear colour by their alpha
+pub fn sample_background_luminance(position: Vec2, clear_color: Color, tiles: &[(Rect, Color)]) -> f32 {
+    tiles.iter()
+        .filter(|(bounds, _)| bounds.contains(position))
+        .fold(clear_color.luminance(), |luminance, (_, color)| {
+            let alpha = color.alpha();
+            luminance * (1.0 - alpha) + color.luminance() * alpha
+        })
+}
+
+/// Dark halo over bright water, light halo over dark water, none while the shot already stands out
+pub fn outline_color(base: Color, background_luminance: f32, min_contrast: f32) -> Color {
+    if (base.luminance() - background_luminance).abs() >= min_contrast {
+        return Color::NONE;
+    }
+    let halo = if background_luminance > 0.5 { Color::BLACK } else { Color::WHITE };
+    halo.with_alpha(OUTLINE_ALPHA)
+}
+
+pub fn projectile_contrast_system(
+    mut commands: Commands,
+    config: Res<ProjectileContrastConfig>,
+    clear_color: Res<ClearColor>,
+    projectile_query: Query<(Entity, &Transform, &Sprite, Has<HasProjectileOutline>), (With<Projectile>, Without<PendingDespawn>)>,
+    mut outline_query: Query<(&ChildOf, &mut Sprite), (With<ProjectileOutline>, Without<Projectile>)>,
+    layer_query: Query<(&Transform, &Sprite), (With<ParallaxLayer>, Without<Projectile>, Without<ProjectileOutline>)>,
+) {
+    // Parallax layers scroll in every run, the procedural tiles are only spawned while their systems are enabled
+    let tiles: Vec<(Rect, Color)> = layer_query.iter()
+        .filter_map(|(transform, sprite)| {
+            let size = sprite.custom_size? * transform.scale.truncate();
+            Some((Rect::from_center_size(transform.translation.truncate(), size), sprite.color))
+        })
+        .collect();
+
+    for (child_of, mut outline_sprite) in outline_query.iter_mut() {
+        let Ok((_, transform, sprite, _)) = projectile_query.get(child_of.parent()) else { continue };
+        outline_sprite.color = if config.enabled {
+            let background = sample_background_luminance(transform.translation.truncate(), clear_color.0, &tiles);
+            outline_color(sprite.color, background, config.min_contrast)
+        } else {
+            Color::NONE
+        };
+    }
+
+    if !config.enabled { return; }
+
+    // New shots get an invisible halo, coloured from the next frame on
+    for (entity, _, sprite, has_outline) in projectile_query.iter() {
+        if has_outline { continue; }
+        commands.spawn((
+            Sprite {
+                image: sprite.image.clone(),
+                color: Color::NONE,
+                custom_size: sprite.custom_size,
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, -0.01).with_scale(Vec3::splat(OUTLINE_SCALE)),
+            ProjectileOutline,
+            ChildOf(entity),
+        ));
+        commands.entity(entity).try_insert(HasProjectileOutline);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contrast_app() -> App {
+        let mut app = App::new();
+        app.insert_resource(ClearColor(Color::srgb(0.02, 0.05, 0.1)))
+            .init_resource::<ProjectileContrastConfig>()
+            .add_systems(Update, projectile_contrast_system);
+        // Bright water on the right half of the screen
+        app.world_mut().spawn((
+            Sprite { color: Color::srgb(0.75, 0.78, 0.8), custom_size: Some(Vec2::new(400.0, 800.0)), ..default() },
+            Transform::from_xyz(200.0, 0.0, -10.0),
+            ParallaxLayer { speed: 0.0, depth: 1.0 },
+        ));
+        app
+    }
+
+    fn spawn_shot(app: &mut App, x: f32) -> Entity {
+        app.world_mut().spawn((
+            Sprite { color: Color::srgb(0.6, 0.6, 0.6), custom_size: Some(Vec2::splat(8.0)), ..default() },
+            Transform::from_xyz(x, 0.0, 0.0),
+            Projectile { velocity: Vec2::Y * 100.0, damage: 1, friendly: true, organic_trail: false },
+        )).id()
+    }
+
+    fn outline_of(app: &mut App, shot: Entity) -> Color {
+        let mut outlines = app.world_mut().query_filtered::<(&ChildOf, &Sprite), With<ProjectileOutline>>();
+        outlines.iter(app.world()).find(|(child_of, _)| child_of.parent() == shot).map(|(_, sprite)| sprite.color).unwrap()
+    }
+
+    #[test]
+    fn shots_over_bright_water_get_a_darker_halo_than_over_dark_water() {
+        let mut app = contrast_app();
+        let over_bright = spawn_shot(&mut app, 200.0);
+        let over_dark = spawn_shot(&mut app, -200.0);
+        app.update();
+        app.update();
+
+        let bright_halo = outline_of(&mut app, over_bright);
+        let dark_halo = outline_of(&mut app, over_dark);
+        assert!(bright_halo.alpha() > 0.0 && dark_halo.alpha() > 0.0);
+        assert!(bright_halo.luminance() < dark_halo.luminance());
+
+        // The shot keeps the colour it was fired with
+        assert_eq!(app.world().get::<Sprite>(over_bright).unwrap().color, Color::srgb(0.6, 0.6, 0.6));
+    }
+
+    #[test]
+    fn shots_that_already_stand_out_get_no_halo() {
+        assert_eq!(outline_color(Color::WHITE, 0.02, 0.35), Color::NONE);
+    }
+}
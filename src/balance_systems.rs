@@ -1,13 +1,14 @@
 // src/balance_systems.rs - Evolution System Balance Analysis & Tuning
 use bevy::prelude::*;
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use crate::components::*;
 use crate::resources::*;
 use crate::enemy_types::*;
 use crate::achievements::*;
-use crate::wave_systems::{WaveManager};
+use crate::wave_systems::{WaveManager, WaveSpawnQueue};
 use crate::despawn::*;
+use crate::save_versioning::*;
 
 // ===== BALANCE CONSTANTS =====
 pub const ATP_GENERATION_RATES: [(EnemyType, u32, f32); 9] = [
@@ -22,16 +23,6 @@ pub const ATP_GENERATION_RATES: [(EnemyType, u32, f32); 9] = [
     (EnemyType::Offspring, 1, 0.5),
 ];
 
-const EVOLUTION_COSTS: [(EvolutionType, u32); 7] = [
-    (EvolutionType::CytoplasmicSpray { damage: 10, fire_rate: 0.1 }, 0),
-    (EvolutionType::PseudopodNetwork { damage: 8, fire_rate: 0.15, tendril_count: 5, spread_angle: 0.6 }, 50),
-    (EvolutionType::BioluminescentBeam { damage: 15, charge_time: 1.0, duration: 2.0, width: 20.0 }, 100),
-    (EvolutionType::SymbioticHunters { damage: 25, fire_rate: 0.8, homing_strength: 2.0, blast_radius: 50.0 }, 75),
-    (EvolutionType::EnzymeBurst { damage: 6, fire_rate: 0.05, acid_damage: 3.0 }, 60),
-    (EvolutionType::ToxinCloud { damage_per_second: 12, cloud_radius: 80.0, duration: 8.0 }, 90),
-    (EvolutionType::ElectricDischarge { damage: 30, chain_count: 4, range: 150.0 }, 150),
-];
-
 const UPGRADE_COSTS: [(&str, u32, f32); 6] = [
     ("damage", 10, 1.2),
     ("metabolic", 15, 1.3),
@@ -42,8 +33,8 @@ const UPGRADE_COSTS: [(&str, u32, f32); 6] = [
 ];
 
 const INVINCIBILITY_FRAMES: f32 = 1.0; // Base invincibility duration
-const HEALTH_UPGRADE_BASE: i32 = 25;
 const MOVEMENT_SPEED_BASE: f32 = 400.0;
+const DAMAGE_LOG_CAPACITY: usize = 2000;
 
 // ===== BALANCE ANALYSIS RESOURCES =====
 
@@ -164,6 +155,72 @@ pub enum AdjustmentType {
     InvincibilityDuration,
 }
 
+// ===== DAMAGE LOG =====
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DamageDirection {
+    Dealt,
+    Taken,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DamageLogEntry {
+    pub time: f32,
+    pub direction: DamageDirection,
+    pub source: String,
+    pub target: String,
+    pub amount: i32,
+}
+
+/// Per-run ring buffer of every hit dealt and taken, exported with the balance data
+#[derive(Resource, Clone)]
+pub struct DamageLog {
+    pub enabled: bool,
+    pub capacity: usize,
+    pub entries: VecDeque<DamageLogEntry>,
+}
+
+impl Default for DamageLog {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            capacity: DAMAGE_LOG_CAPACITY,
+            entries: VecDeque::new(),
+        }
+    }
+}
+
+impl DamageLog {
+    /// Append a hit, dropping the oldest once the buffer is full
+    pub fn record(&mut self, time: f32, direction: DamageDirection, source: &str, target: &str, amount: i32) {
+        if !self.enabled || self.capacity == 0 || amount <= 0 { return; }
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(DamageLogEntry {
+            time,
+            direction,
+            source: source.to_string(),
+            target: target.to_string(),
+            amount,
+        });
+    }
+
+    pub fn record_dealt(&mut self, time: f32, weapon: &str, target: &str, amount: i32) {
+        self.record(time, DamageDirection::Dealt, weapon, target, amount);
+    }
+
+    pub fn record_taken(&mut self, time: f32, source: &str, amount: i32) {
+        self.record(time, DamageDirection::Taken, source, "Player", amount);
+    }
+
+    pub fn total(&self, direction: DamageDirection) -> i32 {
+        self.entries.iter()
+            .filter(|entry| entry.direction == direction)
+            .map(|entry| entry.amount)
+            .sum()
+    }
+}
+
 // ===== BALANCE ANALYSIS SYSTEMS =====
 
 pub fn initialize_balance_analyzer(mut commands: Commands) {
@@ -342,11 +399,8 @@ pub fn atp_economy_analysis(
     
     // Track ATP transactions
     for event in atp_events.read() {
-        match event {
-            AchievementEvent::ATPCollected(amount) => {
-                atp_gained += amount;
-            }
-            _ => {}
+        if let AchievementEvent::ATPCollected(amount) = event {
+            atp_gained += amount;
         }
     }
     
@@ -440,11 +494,13 @@ pub fn balance_debug_ui(
     input: Res<ButtonInput<KeyCode>>,
     fonts: Res<GameFonts>,
     debug_ui_query: Query<Entity, With<BalanceDebugUI>>,
+    spawn_queue: Res<WaveSpawnQueue>,
+    mut budget_text_query: Query<&mut Text, With<SpawnBudgetDebugText>>,
 ) {
     // Toggle debug mode with F12
     if input.just_pressed(KeyCode::F12) {
         if debug_ui_query.is_empty() {
-            spawn_balance_debug_ui(&mut commands, &balance_analyzer, &fonts);
+            spawn_balance_debug_ui(&mut commands, &balance_analyzer, &fonts, &spawn_queue);
         } else {
             // Remove debug UI
             for entity in debug_ui_query.iter() {
@@ -452,6 +508,13 @@ pub fn balance_debug_ui(
             }
         }
     }
+
+    // Spawn budget is the one live line, the rest is a snapshot taken when the panel opens
+    if spawn_queue.is_changed() {
+        for mut text in budget_text_query.iter_mut() {
+            **text = spawn_queue.budget_label();
+        }
+    }
 }
 
 // ===== HELPER FUNCTIONS =====
@@ -583,7 +646,7 @@ fn calculate_player_power_level(health: i32, upgrades: &CellularUpgrades, wave_m
     (health_factor + upgrade_factor + wave_factor) / 3.0
 }
 
-fn spawn_balance_debug_ui(commands: &mut Commands, balance_analyzer: &BalanceAnalyzer, fonts: &GameFonts) {
+fn spawn_balance_debug_ui(commands: &mut Commands, balance_analyzer: &BalanceAnalyzer, fonts: &GameFonts, spawn_queue: &WaveSpawnQueue) {
     commands.spawn((
         Node {
             position_type: PositionType::Absolute,
@@ -618,6 +681,14 @@ fn spawn_balance_debug_ui(commands: &mut Commands, balance_analyzer: &BalanceAna
             TextColor(Color::WHITE),
         ));
         
+        // Wave spawn budget
+        parent.spawn((
+            Text::new(spawn_queue.budget_label()),
+            TextFont { font: fonts.default_font.clone(), font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.6, 0.9, 1.0)),
+            SpawnBudgetDebugText,
+        ));
+        
         // Weapon Performance
         for (name, stats) in &balance_analyzer.weapon_stats {
             let color = if stats.cost_efficiency > 2.0 { 
@@ -932,7 +1003,7 @@ fn test_late_game_viability(weapon_stats: &HashMap<String, WeaponPerformance>) -
         }
     }
     
-    test.late_game_score = if weapon_stats.len() > 0 {
+    test.late_game_score = if !weapon_stats.is_empty() {
         test.viable_weapons.len() as f32 / weapon_stats.len() as f32
     } else {
         0.0
@@ -1116,35 +1187,43 @@ pub struct BalanceDebugUI;
 #[derive(Component)]
 pub struct BalanceTestRunner;
 
+#[derive(Component)]
+pub struct SpawnBudgetDebugText;
+
 // ===== SAVE/LOAD BALANCE DATA =====
 
 #[derive(Serialize, Deserialize)]
 pub struct BalanceDataSave {
+    #[serde(default)]
+    pub version: u32,
     pub weapon_performance_history: Vec<HashMap<String, WeaponPerformance>>,
     pub balance_sessions: Vec<BalanceSession>,
     pub optimization_recommendations: Vec<String>,
+    #[serde(default)]
+    pub damage_log: Vec<DamageLogEntry>,
 }
 
-pub fn save_balance_data(balance_analyzer: &BalanceAnalyzer) {
+impl VersionedSave for BalanceDataSave {
+    const CURRENT_VERSION: u32 = 1;
+    const LABEL: &'static str = "balance data";
+}
+
+pub fn save_balance_data(balance_analyzer: &BalanceAnalyzer, damage_log: &DamageLog) {
     let save_data = BalanceDataSave {
+        version: BalanceDataSave::CURRENT_VERSION,
         weapon_performance_history: vec![balance_analyzer.weapon_stats.clone()],
         balance_sessions: balance_analyzer.real_time_balance.historical_data.clone(),
         optimization_recommendations: generate_optimization_recommendations(balance_analyzer),
+        damage_log: damage_log.entries.iter().cloned().collect(),
     };
     
-    if let Ok(json) = serde_json::to_string_pretty(&save_data) {
-        if let Err(e) = std::fs::write("balance_data.json", json) {
-            eprintln!("Failed to save balance data: {}", e);
-        }
-    }
+    save_versioned("balance_data.json", &save_data);
 }
 
 pub fn load_balance_data(balance_analyzer: &mut BalanceAnalyzer) {
-    if let Ok(json) = std::fs::read_to_string("balance_data.json") {
-        if let Ok(save_data) = serde_json::from_str::<BalanceDataSave>(&json) {
-            balance_analyzer.real_time_balance.historical_data = save_data.balance_sessions;
-            println!("Loaded {} historical balance sessions", balance_analyzer.real_time_balance.historical_data.len());
-        }
+    if let Some(save_data) = load_versioned::<BalanceDataSave>("balance_data.json") {
+        balance_analyzer.real_time_balance.historical_data = save_data.balance_sessions;
+        println!("Loaded {} historical balance sessions", balance_analyzer.real_time_balance.historical_data.len());
     }
 }
 
@@ -1178,4 +1257,54 @@ fn generate_optimization_recommendations(balance_analyzer: &BalanceAnalyzer) ->
     }
     
     recommendations
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::SpawnEnemy;
+    use crate::wave_systems::*;
+
+    fn queued(enemy_type: EnemyType, spawn_time: f32) -> QueuedSpawn {
+        QueuedSpawn {
+            position: Vec3::new(0.0, 400.0, 0.0),
+            ai_type: EnemyAI::Linear { direction: Vec2::NEG_Y },
+            enemy_type,
+            spawn_time,
+            via_portal: false,
+        }
+    }
+
+    #[test]
+    fn budget_readout_tracks_the_queued_budget_and_spent_total_live() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<BalanceAnalyzer>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(GameFonts { default_font: Handle::default() })
+            .insert_resource(WaveManager { wave_active: true, ..default() })
+            .init_resource::<WaveSpawnQueue>()
+            .init_resource::<ThreatMeter>()
+            .init_resource::<SpawnPortalConfig>()
+            .add_event::<SpawnEnemy>()
+            .add_systems(Update, (wave_spawning_system, balance_debug_ui).chain());
+        {
+            let mut spawn_queue = app.world_mut().resource_mut::<WaveSpawnQueue>();
+            spawn_queue.push(queued(EnemyType::SwarmCell, 0.5));
+            spawn_queue.push(queued(EnemyType::InfectedMacrophage, 10.0));
+        }
+        let readout = app.world_mut().spawn((Text::default(), SpawnBudgetDebugText)).id();
+        let step = |app: &mut App, secs: f32| {
+            app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_secs_f32(secs));
+            app.update();
+            app.world().get::<Text>(readout).unwrap().0.clone()
+        };
+
+        let budget = enemy_threat_weight(&EnemyType::SwarmCell) + enemy_threat_weight(&EnemyType::InfectedMacrophage);
+        let text = step(&mut app, 0.1);
+        assert!(text.starts_with(&format!("Spawn Budget: 0.0 / {:.1} spent", budget)), "{text}");
+
+        let text = step(&mut app, 1.0);
+        assert!(text.starts_with(&format!("Spawn Budget: {:.1} / {:.1} spent", enemy_threat_weight(&EnemyType::SwarmCell), budget)), "{text}");
+        assert!(text.contains(EnemyType::InfectedMacrophage.display_name()) && !text.contains(EnemyType::SwarmCell.display_name()), "{text}");
+    }
+}
@@ -0,0 +1,129 @@
+// src/bestiary.rs - Enemy field guide, each entry unlocked by the first kill of that type
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::resources::*;
+use crate::enemy_types::*;
+use crate::achievements::*;
+use crate::save_versioning::*;
+
+// ===== CONSTANTS =====
+const BESTIARY_SAVE_PATH: &str = "bestiary.json";
+
+// ===== BESTIARY STATE =====
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BestiaryEntry {
+    pub enemy_type: EnemyType,
+    pub kills: u32,
+}
+
+/// Persistent record of every enemy type the player has defeated
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+pub struct Bestiary {
+    #[serde(default)]
+    pub version: u32,
+    pub entries: Vec<BestiaryEntry>,
+    #[serde(skip)]
+    pub dirty: bool, // Kill counts changed since the last save
+}
+
+impl VersionedSave for Bestiary {
+    const CURRENT_VERSION: u32 = 1;
+    const LABEL: &'static str = "bestiary";
+}
+
+impl Bestiary {
+    pub fn entry(&self, enemy_type: EnemyType) -> Option<&BestiaryEntry> {
+        self.entries.iter().find(|entry| entry.enemy_type == enemy_type)
+    }
+
+    pub fn is_unlocked(&self, enemy_type: EnemyType) -> bool {
+        self.entry(enemy_type).is_some()
+    }
+
+    pub fn discovered_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Count a kill, returns true if it unlocked a new entry
+    pub fn record_kill(&mut self, enemy_type: EnemyType) -> bool {
+        self.dirty = true;
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.enemy_type == enemy_type) {
+            entry.kills += 1;
+            return false;
+        }
+        self.entries.push(BestiaryEntry { enemy_type, kills: 1 });
+        true
+    }
+
+    pub fn save(&mut self) {
+        self.version = Self::CURRENT_VERSION;
+        self.dirty = false;
+        save_versioned(BESTIARY_SAVE_PATH, &*self);
+    }
+}
+
+pub fn load_bestiary(mut commands: Commands) {
+    let bestiary = load_versioned::<Bestiary>(BESTIARY_SAVE_PATH).unwrap_or_default();
+    commands.insert_resource(bestiary);
+}
+
+// ===== SYSTEMS =====
+pub fn bestiary_tracking_system(
+    mut bestiary: ResMut<Bestiary>,
+    mut achievement_events: EventReader<AchievementEvent>,
+) {
+    for event in achievement_events.read() {
+        let AchievementEvent::EnemyKilled(description) = event else { continue };
+        let Some(enemy_type) = EnemyType::from_description(description) else { continue };
+
+        // New entries are written straight away, repeat kills wait for the end of the run
+        if bestiary.record_kill(enemy_type) {
+            info!("Bestiary entry unlocked: {}", enemy_type.display_name());
+            bestiary.save();
+        }
+    }
+}
+
+pub fn save_bestiary_on_game_over(mut bestiary: ResMut<Bestiary>) {
+    if bestiary.dirty {
+        bestiary.save();
+    }
+}
+
+// ===== PLUGIN =====
+pub struct BestiaryPlugin;
+
+impl Plugin for BestiaryPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Bestiary>()
+            .add_systems(Startup, load_bestiary)
+            .add_systems(OnEnter(GameState::GameOver), save_bestiary_on_game_over)
+            .add_systems(Update, bestiary_tracking_system.run_if(in_state(IsPaused::Running)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_kill_unlocks_an_entry_and_repeat_kills_only_count() {
+        let mut bestiary = Bestiary::default();
+        let description = EnemyType::AggressiveBacteria.get_biological_description();
+        let killed = EnemyType::from_description(description).unwrap();
+
+        assert!(bestiary.record_kill(killed));
+        assert!(!bestiary.record_kill(killed));
+        assert_eq!(bestiary.discovered_count(), 1);
+        assert_eq!(bestiary.entry(EnemyType::AggressiveBacteria).map(|entry| entry.kills), Some(2));
+        assert!(!bestiary.is_unlocked(EnemyType::ViralParticle));
+    }
+
+    #[test]
+    fn every_enemy_type_is_recognised_from_its_kill_event() {
+        for enemy_type in EnemyType::ALL {
+            assert_eq!(EnemyType::from_description(enemy_type.get_biological_description()), Some(enemy_type));
+        }
+    }
+}
@@ -4,16 +4,19 @@ use crate::resources::*;
 use crate::events::*;
 use crate::enemy_types::*;
 use crate::despawn::{SafeDespawn};
+pub use crate::physics::{sample_ph, sample_oxygen};
+use crate::systems::{EnemyDamage, EnemyDeaths};
 use std::f32::consts::{TAU};
 
 // Constants to replace magic numbers
 const FLUID_UPDATE_INTERVAL: f32 = 0.5;
-const CURRENT_CELL_SIZE: f32 = 20.0;
 const THERMAL_VENT_RANGE: f32 = 250.0;
+const THERMAL_VENT_RADIUS: f32 = 120.0;    // Player heat damage/healing range around a vent
 const CHEMICAL_ZONE_SPAWN_INTERVAL: f32 = 8.0;
-const PH_DAMAGE_THRESHOLD: f32 = 1.5;
 const CORAL_SPAWN_INTERVAL: f32 = 25.0;
 const DEBRIS_SPAWN_INTERVAL: f32 = 8.0;
+const DEPLOYED_ZONE_TICK: f32 = 0.5;
+const DEPLOYED_ZONE_DAMAGE: i32 = 6;      // Per tick, to enemies the zone's chemistry is hostile to
 
 // Fluid Dynamics System - Consolidated current generation
 pub fn fluid_dynamics_system(
@@ -41,6 +44,50 @@ pub fn chemical_environment_system(
     update_oxygen_depletion(&mut chemical_env, enemy_query, &time);
 }
 
+// Deployed Zone System - Player-made pH/oxygen zones burn enemies that can't tolerate them
+pub fn deployed_zone_system(
+    mut commands: Commands,
+    mut chemical_env: ResMut<ChemicalEnvironment>,
+    mut enemy_query: Query<(Entity, &Transform, &mut Health, &ChemicalSensitivity, &Enemy, Has<DamageCap>), (Without<BossIntro>, Without<PendingDespawn>)>,
+    mut visual_query: Query<(Entity, &mut Sprite, &mut DeployedZoneVisual)>,
+    mut deaths: EnemyDeaths,
+    mut enemy_damage: EnemyDamage,
+    mut damage_timer: Local<f32>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    chemical_env.tick_deployed_zones(dt);
+
+    for (entity, mut sprite, mut visual) in visual_query.iter_mut() {
+        visual.timer -= dt;
+        if visual.timer <= 0.0 {
+            commands.entity(entity).safe_despawn();
+            continue;
+        }
+        let fade = (visual.timer / visual.duration).clamp(0.0, 1.0);
+        sprite.color = sprite.color.with_alpha(0.25 * fade.sqrt());
+    }
+
+    *damage_timer -= dt;
+    if *damage_timer > 0.0 { return; }
+    *damage_timer = DEPLOYED_ZONE_TICK;
+
+    for (entity, transform, mut health, sensitivity, enemy, is_capped) in enemy_query.iter_mut() {
+        if health.0 <= 0 { continue; }
+        let position = transform.translation.truncate();
+        if !chemical_env.in_deployed_zone(position) { continue; }
+
+        let ph = sample_ph(&chemical_env, position);
+        let oxygen = sample_oxygen(&chemical_env, position);
+        if !sensitivity.is_stressed_by(ph, oxygen) { continue; }
+
+        enemy_damage.deal(&mut health, DEPLOYED_ZONE_DAMAGE, enemy, is_capped, "Deployed Zone");
+        if health.0 <= 0 {
+            deaths.kill(entity, transform.translation, enemy);
+        }
+    }
+}
+
 // Organic AI System - Streamlined biological behaviors
 pub fn organic_ai_system(
     mut enemy_query: Query<(&mut Transform, &mut Enemy)>,
@@ -286,7 +333,7 @@ pub fn signal_particle_spawning(
     }
 }
 
-// Ecosystem Monitoring - Streamlined state tracking
+// Ecosystem Monitoring - Live pool health that collapse and the ATP trickle key off
 pub fn ecosystem_monitoring_system(
     mut ecosystem: ResMut<EcosystemState>,
     enemy_query: Query<&Enemy>,
@@ -297,6 +344,38 @@ pub fn ecosystem_monitoring_system(
     update_ecosystem_health(&mut ecosystem, &chemical_environment, player_query);
 }
 
+// Ecosystem Collapse - escalating climax while the pool stays critically unhealthy
+pub fn ecosystem_collapse_system(
+    ecosystem: Res<EcosystemState>,
+    mut collapse: ResMut<EcosystemCollapse>,
+    mut shake_events: EventWriter<AddScreenShake>,
+    time: Res<Time>,
+) {
+    match collapse.update(ecosystem.health, time.delta_secs()) {
+        Some(CollapseTransition::Triggered) => {
+            info!("Ecosystem collapse! Health {:.2}", ecosystem.health);
+            shake_events.write(AddScreenShake { amount: 1.0 });
+        }
+        Some(CollapseTransition::Recovered) => info!("Ecosystem recovered from collapse"),
+        None => {}
+    }
+}
+
+// Ecosystem ATP Trickle - passive income while the pool stays healthy
+pub fn ecosystem_atp_trickle_system(
+    ecosystem: Res<EcosystemState>,
+    mut trickle: ResMut<EcosystemAtpTrickle>,
+    mut player_query: Query<&mut ATP, With<Player>>,
+    mut game_score: ResMut<GameScore>,
+    time: Res<Time>,
+) {
+    let Some(amount) = trickle.tick(ecosystem.health, time.delta_secs()) else { return };
+    let Ok(mut atp) = player_query.single_mut() else { return };
+
+    atp.amount += amount;
+    game_score.total_atp_collected += amount as u64;
+}
+
 // Environmental Systems - Consolidated contamination and debris
 pub fn environmental_storytelling_system(
     mut commands: Commands,
@@ -310,7 +389,7 @@ pub fn environmental_storytelling_system(
 ) {
     update_contamination_clouds(contamination_query, &ecosystem, &time);
     update_microscopic_debris(&mut commands, debris_query, player_query, &time);
-    let mut timers_clone = timers.clone();
+    let mut timers_clone = *timers;
     spawn_environmental_elements(&mut commands, &assets, &mut timers.0, &mut timers_clone.1, &time);
 }
 
@@ -374,7 +453,8 @@ fn calculate_vent_influence(world_pos: Vec2, vent: &ThermalVent, time: &Res<Time
 }
 
 fn update_chemical_zones(chemical_env: &mut ChemicalEnvironment, time: &Res<Time>) {
-    for zone in &mut chemical_env.ph_zones {
+    // Deployed zones hold still at full strength so they stay under their marker
+    for zone in chemical_env.ph_zones.iter_mut().filter(|zone| zone.lifetime.is_none()) {
         zone.intensity += (time.elapsed_secs() * 0.5 + zone.position.x * 0.001).sin() * 0.01;
         zone.intensity = zone.intensity.clamp(0.3, 1.0);
         
@@ -434,12 +514,15 @@ fn update_enemy_ai(
     time: &Res<Time>,
 ) {
     match &mut enemy.ai_type {
-        EnemyAI::Chemotaxis { sensitivity, current_direction, .. } => {
+        EnemyAI::Chemotaxis { target_chemical, sensitivity, current_direction } => {
             let player_distance = transform.translation.distance(player_transform.translation);
             if player_distance < 300.0 {
-                let direction = (player_transform.translation.truncate() - transform.translation.truncate()).normalize_or_zero();
-                let influence = (1.0 / (player_distance * 0.01 + 1.0)) * *sensitivity;
-                *current_direction = current_direction.lerp(direction, influence * time.delta_secs());
+                let direction = match target_chemical {
+                    ChemicalType::OxygenAvoider => crate::physics::oxygen_gradient(chemical_env, transform.translation.truncate()),
+                    _ => (player_transform.translation.truncate() - transform.translation.truncate()).normalize_or_zero(),
+                };
+                let influence = (1.0 / (player_distance * 0.01 + 1.0)) * *sensitivity * target_chemical.steering_sign();
+                *current_direction = chemotaxis_steer(*current_direction, direction, influence, time.delta_secs());
             }
             transform.translation += current_direction.extend(0.0) * enemy.speed * time.delta_secs();
         }
@@ -531,9 +614,8 @@ fn apply_thermal_effects_to_entities(
 ) {
     // Player effects
     if let Ok((player_transform, mut player_health)) = queries.p0().single_mut() {
-        let distance = player_transform.translation.distance(vent.position.extend(0.0));
-        if distance < 120.0 {
-            let heat_intensity = (120.0 - distance) / 120.0;
+        let heat_intensity = vent_heat_intensity(vent, player_transform.translation.truncate());
+        if heat_intensity > 0.0 {
             let health_change = if heat_intensity > 0.7 {
                 -(heat_intensity * 15.0 * time.delta_secs()) as i32
             } else if heat_intensity > 0.3 {
@@ -681,14 +763,12 @@ fn apply_harmful_coral_effects(
 ) {
     let harm_effectiveness = coral.corruption_level;
     
-    if damage_rate > 0.0 && harm_effectiveness > 0.3 {
-        if let Ok((player_transform, mut health, _)) = player_query.single_mut() {
-            let distance = player_transform.translation.distance(coral_transform.translation);
-            if distance < coral.influence_radius {
-                let proximity_factor = (coral.influence_radius - distance) / coral.influence_radius;
-                let damage = (damage_rate * harm_effectiveness * proximity_factor * time.delta_secs()) as i32;
-                health.0 -= damage;
-            }
+    if damage_rate > 0.0 && harm_effectiveness > 0.3 && let Ok((player_transform, mut health, _)) = player_query.single_mut() {
+        let distance = player_transform.translation.distance(coral_transform.translation);
+        if distance < coral.influence_radius {
+            let proximity_factor = (coral.influence_radius - distance) / coral.influence_radius;
+            let damage = (damage_rate * harm_effectiveness * proximity_factor * time.delta_secs()) as i32;
+            health.0 -= damage;
         }
     }
     
@@ -987,28 +1067,10 @@ fn sample_current(fluid_env: &FluidEnvironment, grid_pos: (usize, usize)) -> Vec
     fluid_env.current_field.get(index).copied().unwrap_or(Vec2::ZERO)
 }
 
-pub fn sample_ph(chemical_env: &ChemicalEnvironment, position: Vec2) -> f32 {
-    let mut ph = chemical_env.base_ph;
-    for zone in &chemical_env.ph_zones {
-        let distance = position.distance(zone.position);
-        if distance < zone.radius {
-            let influence = (1.0 - distance / zone.radius) * zone.intensity;
-            ph = ph * (1.0 - influence) + zone.ph_level * influence;
-        }
-    }
-    ph.clamp(0.0, 14.0)
-}
-
-pub fn sample_oxygen(chemical_env: &ChemicalEnvironment, position: Vec2) -> f32 {
-    let mut oxygen = chemical_env.base_oxygen;
-    for zone in &chemical_env.oxygen_zones {
-        let distance = position.distance(zone.position);
-        if distance < zone.radius {
-            let influence = 1.0 - distance / zone.radius;
-            oxygen = oxygen * (1.0 - influence) + zone.oxygen_level * influence;
-        }
-    }
-    oxygen.clamp(0.0, 1.0)
+/// Heat felt at `position` from one vent, 1.0 at the vent falling to 0.0 at its edge
+pub fn vent_heat_intensity(vent: &ThermalVent, position: Vec2) -> f32 {
+    let distance = position.distance(vent.position);
+    ((THERMAL_VENT_RADIUS - distance) / THERMAL_VENT_RADIUS).max(0.0)
 }
 
 fn apply_brightness_to_color(color: Color, brightness: f32) -> Color {
@@ -1036,15 +1098,6 @@ fn apply_health_tint(color: Color, health_factor: f32) -> Color {
     )
 }
 
-fn apply_corruption_color_shift(color: Color, factor: f32) -> Color {
-    Color::srgba(
-        color.to_srgba().red * (1.0 - factor * 0.3),
-        color.to_srgba().green * (1.0 + factor * 0.2),
-        color.to_srgba().blue * (1.0 - factor * 0.5),
-        color.to_srgba().alpha,
-    )
-}
-
 fn get_contamination_color(source_type: &ContaminationType, toxicity_level: f32, time: &Res<Time>) -> Color {
     match source_type {
         ContaminationType::IndustrialWaste => {
@@ -1071,4 +1124,139 @@ fn get_contamination_color(source_type: &ContaminationType, toxicity_level: f32,
             Color::srgba(0.7, 0.7, 0.7, 0.5 + toxicity_level * 0.3)
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn ecosystem_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<EcosystemState>()
+            .init_resource::<ChemicalEnvironment>()
+            .init_resource::<EcosystemCollapse>()
+            .init_resource::<EcosystemAtpTrickle>()
+            .init_resource::<GameScore>()
+            .add_event::<AddScreenShake>()
+            .add_systems(Update, (
+                ecosystem_monitoring_system,
+                ecosystem_collapse_system,
+                ecosystem_atp_trickle_system,
+            ).chain());
+        app
+    }
+
+    fn spawn_population(app: &mut App, pathogens: usize, beneficial: usize) -> Vec<Entity> {
+        let pathogen = (0..pathogens).map(|_| EnemyType::AggressiveBacteria);
+        let microbes = (0..beneficial).map(|_| EnemyType::SwarmCell);
+        pathogen.chain(microbes)
+            .map(|enemy_type| app.world_mut().spawn(Enemy { enemy_type, ..default() }).id())
+            .collect()
+    }
+
+    fn spawn_player(app: &mut App) -> Entity {
+        app.world_mut().spawn((
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+            Health(100),
+            ATP { amount: 0 },
+        )).id()
+    }
+
+    fn run_for(app: &mut App, seconds: f32) {
+        for _ in 0..(seconds / 0.5) as usize {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(500));
+            app.update();
+        }
+    }
+
+    #[test]
+    fn sustained_low_health_collapses_and_recovery_clears_it() {
+        let mut app = ecosystem_app();
+        let pathogens = spawn_population(&mut app, 9, 0);
+
+        run_for(&mut app, 7.0);
+        assert!(app.world().resource::<EcosystemState>().health < EcosystemCollapse::default().critical_health);
+        assert!(app.world().resource::<EcosystemCollapse>().active);
+        assert!(app.world().resource::<EcosystemCollapse>().turbulence_multiplier() > 1.0);
+
+        for entity in pathogens {
+            app.world_mut().despawn(entity);
+        }
+        run_for(&mut app, 4.0);
+        assert!(!app.world().resource::<EcosystemCollapse>().active);
+        assert_eq!(app.world().resource::<EcosystemCollapse>().turbulence_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn atp_trickle_scales_with_live_ecosystem_health() {
+        let trickled = |pathogens: usize, beneficial: usize| {
+            let mut app = ecosystem_app();
+            spawn_population(&mut app, pathogens, beneficial);
+            let player = spawn_player(&mut app);
+            run_for(&mut app, 5.0);
+            app.world().get::<ATP>(player).unwrap().amount
+        };
+
+        let pristine = trickled(0, 0);
+        let contested = trickled(1, 1);
+        let overrun = trickled(9, 0);
+        assert_eq!(pristine, EcosystemAtpTrickle::default().max_atp_per_interval);
+        assert!(contested > 0 && contested < pristine);
+        assert_eq!(overrun, 0);
+    }
+
+    fn spawn_sensitive_enemy(app: &mut App, position: Vec2, ph_range: (f32, f32)) -> Entity {
+        app.world_mut().spawn((
+            Enemy::default(),
+            Transform::from_translation(position.extend(0.0)),
+            Health(100),
+            ChemicalSensitivity {
+                ph_tolerance_min: ph_range.0,
+                ph_tolerance_max: ph_range.1,
+                oxygen_requirement: 0.0,
+                damage_per_second_outside_range: 0,
+            },
+        )).id()
+    }
+
+    #[test]
+    fn acidic_zone_burns_ph_sensitive_enemies_until_it_expires() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ChemicalEnvironment>()
+            .init_resource::<GameScore>()
+            .init_resource::<ComboState>()
+            .init_resource::<RunModifiers>()
+            .init_resource::<ScorePopupConfig>()
+            .init_resource::<DamageCapConfig>()
+            .init_resource::<crate::balance_systems::DamageLog>()
+            .insert_resource(GameFonts { default_font: Handle::default() })
+            .add_event::<AddScreenShake>()
+            .add_event::<SpawnExplosion>()
+            .add_event::<EnemyDied>()
+            .add_event::<FrozenShatter>()
+            .add_event::<crate::achievements::AchievementEvent>()
+            .add_systems(Update, deployed_zone_system);
+        let zone_center = Vec2::new(0.0, 100.0);
+        let sensitive = spawn_sensitive_enemy(&mut app, zone_center, (6.5, 7.5));
+        let hardy = spawn_sensitive_enemy(&mut app, zone_center, (0.0, 14.0));
+        app.world_mut().resource_mut::<ChemicalEnvironment>().deploy_zone(DeployedZoneKind::Acidic, zone_center, 120.0, 1.0);
+        let health = |app: &App, entity| app.world().get::<Health>(entity).unwrap().0;
+
+        app.update();
+        assert_eq!(health(&app, sensitive), 100 - DEPLOYED_ZONE_DAMAGE);
+        assert_eq!(health(&app, hardy), 100);
+
+        app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.1));
+        app.update();
+        assert!(!app.world().resource::<ChemicalEnvironment>().in_deployed_zone(zone_center));
+
+        // Once the zone is gone the water stops hurting
+        let after_expiry = health(&app, sensitive);
+        app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(DEPLOYED_ZONE_TICK));
+        app.update();
+        assert_eq!(health(&app, sensitive), after_expiry);
+    }
+}
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 use crate::components::*;
 use crate::resources::*;
 use crate::events::*;
+use crate::target_dummy::TargetDummy;
 use crate::wave_systems::*;
 use crate::enemy_types::*;
 use crate::despawn::*;
@@ -180,13 +181,11 @@ pub fn stage_completion_system(
     wave_manager: Res<WaveManager>,
     mut spawn_card_events: EventWriter<SpawnCardEvent>,
     mut spawn_box_events: EventWriter<SpawnGreenBoxEvent>, // Add this
-    enemy_query: Query<&Enemy>,
+    enemy_query: Query<&Enemy, Without<TargetDummy>>,
 ) {
     // Check if stage is complete (5 waves completed)
-    if wave_manager.current_wave > 0 && wave_manager.current_wave % STAGE_WAVE_COUNT == 0 {
-        if !wave_manager.wave_active && enemy_query.iter().count() == 0 {
-            complete_stage(&mut stage_progress, &mut card_collection, &mut spawn_card_events, &mut spawn_box_events);
-        }
+    if wave_manager.current_wave > 0 && wave_manager.current_wave.is_multiple_of(STAGE_WAVE_COUNT) && !wave_manager.wave_active && enemy_query.iter().count() == 0 {
+        complete_stage(&mut stage_progress, &mut card_collection, &mut spawn_card_events, &mut spawn_box_events);
     }
 }
 
@@ -274,13 +273,11 @@ fn generate_random_permanent_card(collection: &CardCollection, stage: u32) -> Ca
 }
 
 fn generate_random_temporal_card(stage: u32) -> TemporalCard {
-    let cards = vec![
-        TemporalCard::CardDropRateIncrease { duration: 60.0 },
+    let cards = [TemporalCard::CardDropRateIncrease { duration: 60.0 },
         TemporalCard::IncreasedMagnetPower { duration: 45.0 },
         TemporalCard::ExtraATP { duration: 30.0 },
         TemporalCard::MaximumFireRate { duration: 20.0 },
-        TemporalCard::RandomPowerUp,
-    ];
+        TemporalCard::RandomPowerUp];
 
     let index = ((stage as f32 * 567.890).sin().abs() * cards.len() as f32) as usize;
     cards[index.min(cards.len() - 1)].clone()
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 use crate::enemy_types::*;
+use crate::resources::HudElement;
+use crate::input::InputAction;
 
 #[derive(Component)]
 pub struct AlreadyDespawned;
@@ -21,6 +23,12 @@ pub struct Projectile {
     pub organic_trail: bool, // New: leaves bioluminescent trail
 }
 
+/// Per-projectile override for how hard a hit shoves the enemy back, 0.0 disables it
+#[derive(Component, Clone, Copy)]
+pub struct Knockback {
+    pub strength: f32,
+}
+
 #[derive(Component)]
 pub struct Collider {
     pub radius: f32,
@@ -93,6 +101,13 @@ pub struct ChemicalSensitivity {
     pub damage_per_second_outside_range: i32,
 }
 
+impl ChemicalSensitivity {
+    /// Whether the local water chemistry is outside what this organism tolerates
+    pub fn is_stressed_by(&self, ph: f32, oxygen: f32) -> bool {
+        ph < self.ph_tolerance_min || ph > self.ph_tolerance_max || oxygen < self.oxygen_requirement
+    }
+}
+
 // New: Environmental Zones
 #[derive(Component)]
 pub struct EnvironmentalZone {
@@ -168,6 +183,63 @@ pub enum PowerUpType {
 
     // ATP Magnetic Field
     MagneticField { radius_boost: f32, strength_boost: f32, duration: f32 },
+
+    // Crowd control - freezes nearby enemies in place
+    CryoPulse { radius: f32, freeze_duration: f32 },
+
+    // Environmental weapon - drops a short-lived pH or oxygen zone at the player
+    DeployZone { kind: DeployedZoneKind, radius: f32, duration: f32 },
+
+    // Temporary wall ahead of the player that soaks up enemy shots
+    ShieldBarrier { width: f32, duration: f32 },
+}
+
+/// Chemistry a deployed zone forces on the surrounding water
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeployedZoneKind {
+    Acidic,
+    Alkaline,
+    Hypoxic,
+}
+
+impl DeployedZoneKind {
+    pub fn color(&self) -> Color {
+        match self {
+            DeployedZoneKind::Acidic => Color::srgba(0.9, 1.0, 0.3, 0.25),
+            DeployedZoneKind::Alkaline => Color::srgba(0.5, 0.4, 1.0, 0.25),
+            DeployedZoneKind::Hypoxic => Color::srgba(0.4, 0.5, 0.6, 0.25),
+        }
+    }
+}
+
+/// Deployed wall that absorbs enemy projectiles until its timer runs out
+#[derive(Component)]
+pub struct ShieldBarrier {
+    pub timer: f32,
+    pub half_extents: Vec2,
+}
+
+impl ShieldBarrier {
+    /// Circle-vs-box overlap between a projectile and a barrier centred at `barrier_pos`
+    pub fn blocks(&self, barrier_pos: Vec2, projectile_pos: Vec2, projectile_radius: f32) -> bool {
+        if self.timer <= 0.0 { return false; }
+        let offset = projectile_pos - barrier_pos;
+        let closest = offset.clamp(-self.half_extents, self.half_extents);
+        offset.distance_squared(closest) <= projectile_radius * projectile_radius
+    }
+
+    /// Count down, returns true once the barrier has expired
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.timer -= dt;
+        self.timer <= 0.0
+    }
+}
+
+/// Fading ring drawn over a deployed chemical zone, gone when the zone expires
+#[derive(Component)]
+pub struct DeployedZoneVisual {
+    pub timer: f32,
+    pub duration: f32,
 }
 
 // Active Power-up Components (updated names)
@@ -258,7 +330,7 @@ pub struct EvolutionSystem {
     pub emergency_spores: u32,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum EvolutionType {
     CytoplasmicSpray { damage: i32, fire_rate: f32 },
     PseudopodNetwork { damage: i32, fire_rate: f32, tendril_count: u32, spread_angle: f32 },
@@ -286,6 +358,14 @@ pub struct LaserBeam {
     pub width: f32,
     pub length: f32,
     pub bioluminescent: bool, // New: organic beam effects
+    pub origin: Vec3,                   // Beam base, the segment extends `length` from here
+    pub locked_target: Option<Entity>,  // Enemy the beam tracks while it lasts
+}
+
+/// Optional beam target lock held by the player, sweeps beams toward the target
+#[derive(Component)]
+pub struct BeamLock {
+    pub target: Option<Entity>,
 }
 
 #[derive(Component)]
@@ -330,6 +410,7 @@ pub struct CellularUpgrades {
     pub spore_capacity: u32,
     pub magnet_radius: f32,
     pub magnet_strength: f32,    
+    pub accuracy: f32,           // 1.0 = perfect grouping, 0.0 = widest spread
 }
 
 // Emergency Spore System (renamed from Smart Bomb)
@@ -350,6 +431,93 @@ pub struct SporeWave {
     pub damage: i32,
 }
 
+// Boss entrance in progress: no attacks, no damage taken, until the timer runs out
+#[derive(Component)]
+pub struct BossIntro {
+    pub timer: f32,
+    pub duration: f32,
+}
+
+impl BossIntro {
+    pub fn new(duration: f32) -> Self {
+        Self { timer: duration, duration }
+    }
+
+    /// 0 as the boss appears, 1 as combat begins
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 { return 1.0; }
+        (1.0 - self.timer / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Returns true on the frame the intro completes
+    pub fn tick(&mut self, dt: f32) -> bool {
+        let was_running = self.timer > 0.0;
+        self.timer -= dt;
+        was_running && self.timer <= 0.0
+    }
+}
+
+#[derive(Component)]
+pub struct BossIntroBanner;
+
+// Brief impact freeze on a heavy hit, counted in frames rather than seconds
+#[derive(Component)]
+pub struct HitStop {
+    pub frames: u32,
+}
+
+impl HitStop {
+    /// Returns true once the freeze has run out
+    pub fn tick(&mut self) -> bool {
+        self.frames = self.frames.saturating_sub(1);
+        self.frames == 0
+    }
+}
+
+/// Grief/rage buff after a nearby ally died
+#[derive(Component, Clone, Copy)]
+pub struct Berserk {
+    pub remaining: f32,
+    pub speed_multiplier: f32,
+    pub damage_multiplier: f32,
+}
+
+impl Berserk {
+    /// Returns true once the rage has worn off
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.remaining -= dt;
+        self.remaining <= 0.0
+    }
+}
+
+/// Player cloak, chemotaxis enemies lose the pheromone trail while it lasts
+#[derive(Component)]
+pub struct Stealthed {
+    pub remaining: f32,
+}
+
+// Flags an enemy (bosses) whose incoming hits are limited by DamageCapConfig
+#[derive(Component)]
+pub struct DamageCap;
+
+// Colony leader/commander that already carries its priority-target glow
+#[derive(Component)]
+pub struct LeaderHighlight;
+
+#[derive(Component)]
+pub struct LeaderHighlightGlow;
+
+/// Aim line that grows out of an enemy while it winds up a shot, gone by the time the shot fires
+/// Parented to the shooter so it moves with it
+#[derive(Component)]
+pub struct AttackTelegraph {
+    pub elapsed: f32,
+    pub duration: f32,
+    pub length: f32,
+    pub direction: Vec2,
+    pub tracks_player: bool, // Shots aimed at the player are re-aimed when they fire, so the line follows too
+}
+
 // Formation AI Enhancement (with biological terminology)
 #[derive(Component)]
 pub struct ColonyCommander {
@@ -357,6 +525,7 @@ pub struct ColonyCommander {
     pub members: Vec<Entity>,
     pub coordination_pattern: CoordinationPattern,
     pub chemical_timer: f32, // Changed from coordination_timer
+    pub windup_timer: f32,   // Counts down to the volley once a coordinated attack is telegraphed
 }
 
 #[derive(Clone)]
@@ -411,6 +580,21 @@ pub struct TemporaryMagnetBoost {
     pub strength_boost: f32,
 }
 
+// Crowd-control status: frozen enemies neither move nor shoot until they thaw
+#[derive(Component)]
+pub struct Frozen {
+    pub timer: f32,
+    pub original_color: Color,
+}
+
+impl Frozen {
+    /// Count down the freeze, returns true on the tick it thaws
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.timer -= dt;
+        self.timer <= 0.0
+    }
+}
+
 impl Default for EvolutionSystem {
     fn default() -> Self {
         Self {
@@ -444,11 +628,22 @@ impl Default for CellularUpgrades {
             spore_capacity: 3,
             magnet_radius: 0.0,
             magnet_strength: 0.0,
+            accuracy: crate::constants::BASE_ACCURACY,
         }
     }
 }
 
 impl EvolutionType {
+    pub const DISPLAY_NAMES: [&'static str; 7] = [
+        "Cytoplasmic Spray",
+        "Pseudopod Network",
+        "Bioluminescent Beam",
+        "Symbiotic Hunters",
+        "Enzyme Burst",
+        "Toxin Cloud",
+        "Electric Discharge",
+    ];
+
     pub fn get_base_damage(&self) -> i32 {
         match self {
             EvolutionType::CytoplasmicSpray { damage, .. } => *damage,
@@ -571,15 +766,6 @@ pub struct OrganicMotion {
 }
 
 
-// New system: Chemical environment effects
-#[derive(Component)]
-pub struct ChemicalZone {
-    pub ph_level: f32,
-    pub oxygen_level: f32,
-    pub toxicity: f32,
-    pub temperature: f32,
-}
-
 // New: Current field for fluid dynamics
 #[derive(Component)]
 pub struct CurrentField {
@@ -1065,9 +1251,6 @@ pub struct TitleScreen;
 #[derive(Component)]
 pub struct MenuButton { pub action: MenuAction }
 
-#[derive(Component)]
-pub struct BackgroundParticle { pub velocity: Vec2 }
-
 #[derive(Component)]
 pub struct ControlsScreen;
 
@@ -1084,14 +1267,49 @@ pub enum MenuAction {
     Quit,
     Settings,
     HighScores,
+    LoreArchive,
+    Bestiary,
     Back,
     ToggleFullscreen,
     ResetControls,
+    TogglePhotosensitiveMode,
+    CycleRunModifier,
+    CycleLoadout,
+    SaveLoadout,
+    DeleteLoadout,
+    CycleGraphicsQuality,
+    ToggleSporeAiming,
+    ToggleTimeAttack,
+    ToggleHudElement(HudElement),
+    ToggleInputDisplay,
+    ToggleRestartConfirmation,
 }
 
 // ===== MENU SYSTEM COMPONENTS =====
 #[derive(Component)]
-pub struct PulsingText;
+pub struct RunModifierText;
+
+#[derive(Component)]
+pub struct LoadoutText;
+
+#[derive(Component)]
+pub struct GraphicsQualityText;
+
+#[derive(Component)]
+pub struct HudVisibilityText;
+
+#[derive(Component)]
+pub struct InputDisplayOverlay;
+
+#[derive(Component)]
+pub struct RestartConfirmDialog;
+
+#[derive(Component)]
+pub struct RestartConfirmText;
+
+// On-screen key cap that lights while its action is held
+#[derive(Component)]
+pub struct InputDisplayIndicator(pub InputAction);
 
 #[derive(Component)]
 pub struct AnimatedParticle {
@@ -1130,13 +1348,25 @@ pub struct SettingsMenu;
 #[derive(Component)]
 pub struct HighScoreMenu;
 
+#[derive(Component)]
+pub struct LoreMenu;
+
+#[derive(Component)]
+pub struct BestiaryMenu;
+
 // wave systems
 #[derive(Component)]
 pub struct WaveInfoText;
 
+#[derive(Component)]
+pub struct HazardWarningText;
+
 #[derive(Component)] 
 pub struct WaveProgressBar;
 
+#[derive(Component)]
+pub struct WavePreviewPanel;
+
 // ATP Magnet System
 #[derive(Component)]
 pub struct ATPMagnet {
@@ -1151,6 +1381,16 @@ pub struct MagnetizedATP {
     pub pull_force: f32,
 }
 
+/// Seconds a dropped ATP or power-up has been loose, drives DropHomingConfig's delay
+#[derive(Component, Default)]
+pub struct DropAge(pub f32);
+
+/// ATP an enemy has eaten from corpses, capped by CorpseAbsorption::max_absorbed
+#[derive(Component, Default)]
+pub struct AbsorbedATP {
+    pub total: u32,
+}
+
 // Better Despawn
 #[derive(Component)]
 pub struct PendingDespawn {
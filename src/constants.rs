@@ -3,6 +3,10 @@ pub const MENU_ITEM_HEIGHT: f32 = 40.0;
 pub const MENU_PADDING: f32 = 20.0;
 pub const MENU_WIDTH: f32 = 600.0;
 
+// Effect budgets at full particle density, graphics quality scales them down
+pub const MAX_PARTICLES: usize = 200;
+pub const PLAYER_TRAIL_SEGMENTS: usize = 15;
+
 // Missile trail constants
 pub const MAX_TRAIL_SEGMENTS: usize = 8;
 pub const TRAIL_SEGMENT_DISTANCE: f32 = 15.0;
@@ -25,6 +29,24 @@ pub const WING_CANNON_OFFSET: f32 = 25.0; // Distance from center
 pub const WING_CANNON_Y_OFFSET: f32 = 10.0;
 pub const MISSILE_LAUNCH_OFFSET: f32 = 20.0;
 pub const MISSILE_Y_OFFSET: f32 = -15.0;
+pub const MAIN_CANNON_SPREAD: f32 = 12.0;
+pub const COMBO_BUFF_SPREAD: f32 = 18.0;
+
+// Lives a fresh cell starts with before run modifiers
+pub const PLAYER_STARTING_LIVES: i32 = 3;
+
+// Accuracy spread and cannon heat
+pub const BASE_ACCURACY: f32 = 0.85;
+pub const ACCURACY_PER_DAMAGE_LEVEL: f32 = 0.03;
+pub const MAX_SPREAD_ANGLE: f32 = 0.35;        // Radians either side at zero accuracy
+pub const HEAT_PER_VOLLEY: f32 = 0.06;
+pub const HEAT_COOLDOWN_RATE: f32 = 0.5;       // Heat shed per second
+pub const HEAT_ACCURACY_PENALTY: f32 = 0.4;    // Fraction of accuracy lost at full heat
+pub const SPREAD_RNG_SEED: u64 = 0x5eed_c311;
+pub const ENVIRONMENT_SEED: u64 = 0x71de_9001;
+
+// Bioluminescent beam segment length
+pub const BEAM_LENGTH: f32 = 900.0;
 
 // Wing Cannon stats per level
 pub const WING_CANNON_STATS: [(f32, i32, f32, u32); 5] = [
@@ -42,4 +64,7 @@ pub const MISSILE_STATS: [(f32, i32, f32, f32, bool); 5] = [
     (1.8, 150, 500.0, 300.0, false),
     (1.5, 200, 550.0, 350.0, true),
     (1.2, 260, 600.0, 400.0, true),
-];
\ No newline at end of file
+];
+// Shield barrier power-up
+pub const SHIELD_BARRIER_THICKNESS: f32 = 16.0;
+pub const SHIELD_BARRIER_OFFSET: f32 = 90.0;    // Distance ahead of the player the wall is raised
@@ -52,10 +52,26 @@ pub struct BiologicalGameHUD {
     #[position(top_left, offset_y = 30)]
     environment: TextDisplay,
     
-    #[bind(EcosystemHealth)]
     #[position(bottom_right)]
     ecosystem_status: StatusIndicator,
     
+    // Generic bindings read any resource or component field, no macro changes needed
+    // Repeat `bind` to fill several placeholders, the text redraws when any source changes
+    #[bind(resource = WaveManager, field = current_wave)]
+    #[bind(resource = WaveManager, field = enemies_remaining)]
+    #[format("Wave {} ({} left)")]
+    #[position(top_right, offset_y = 30)]
+    wave: TextDisplay,
+    
+    #[bind(resource = FluidEnvironment, field = tidal_phase)]
+    #[format("Tide: {:.2}")]
+    #[position(top_right, offset_y = 60)]
+    tide: TextDisplay,
+    
+    #[bind(component = Health, field = 0, with = Player)]
+    #[position(bottom_right, offset_y = 40)]
+    health_gauge: RadialGauge,
+    
     #[position(top_right, offset_y = 100)]
     notifications: NotificationQueue,
 }
@@ -93,6 +109,40 @@ fn spawn_game_hud(
     BiologicalGameHUD::spawn_ui(&mut commands, fonts.default_font.clone());
 }
 
+// ===== STACKED LAYOUT =====
+// Group readouts into one flex column instead of stacking offset_y values by hand
+
+fn spawn_player_readouts(commands: &mut Commands, font: Handle<Font>) -> (TextDisplay, TextDisplay, Counter) {
+    let builder = WidgetBuilder::new(commands, font).root()
+        .vstack(UIPosition::top_left(), 8.0);
+    let (builder, atp) = builder.text_display("ATP: 0⚡", UIPosition::default());
+    let (builder, score) = builder.text_display("Score: 0", UIPosition::default());
+    let (builder, lives) = builder.counter("Lives: ", "", UIPosition::default());
+    builder.end_stack();
+    
+    (atp, score, lives)
+}
+
+// ===== MANUAL REFRESH =====
+// HUDs that skip register_hud can be driven by hand, e.g. only while the pause menu is open:
+// .add_systems(Update, refresh_hud_while_paused.run_if(in_state(GameState::Paused)))
+
+fn refresh_hud_while_paused(world: &mut World) {
+    let huds: Vec<Entity> = world.query_filtered::<Entity, With<BiologicalGameHUD>>().iter(world).collect();
+
+    for hud_entity in huds {
+        // Take the HUD out so it can update its own bookkeeping while reading the world
+        let Some(mut hud) = world.entity_mut(hud_entity).take::<BiologicalGameHUD>() else { continue };
+        let updates = hud.update_bindings(world, hud_entity);
+        world.entity_mut(hud_entity).insert(hud);
+
+        let mut scheduler = world.resource_mut::<UIUpdateScheduler>();
+        for update in updates {
+            scheduler.queue_update(update);
+        }
+    }
+}
+
 // ===== EXTERNAL TOOLING SUPPORT =====
 
 // Hot-reload configuration file
@@ -345,7 +395,7 @@ pub const UI_SCHEMA: &str = r#"
           "type": "object",
           "properties": {
             "id": {"type": "string"},
-            "type": {"enum": ["TextDisplay", "Counter", "ProgressBar", "StatusIndicator"]},
+            "type": {"enum": ["TextDisplay", "Counter", "ProgressBar", "RadialGauge", "StatusIndicator"]},
             "position": {
               "type": "object",
               "properties": {
@@ -7,7 +7,9 @@ use crate::achievements::*;
 use crate::input::*;
 use crate::physics::*;
 use crate::wave_systems::*;
+use crate::constants::*;
 use crate::despawn::*;
+use crate::target_dummy::TargetDummy;
 
 // FIXED: ATP pickup system - resolved query conflicts
 pub fn atp_pickup_system(
@@ -38,10 +40,49 @@ pub fn atp_pickup_system(
     }
 }
 
+/// Golden ATP pickup the player collects by touching it
+fn spawn_atp_drop(commands: &mut Commands, assets: &GameAssets, position: Vec3, amount: u32) {
+    commands.spawn((
+        Sprite {
+            image: assets.multiplier_powerup_texture.clone(),
+            color: Color::srgb(1.0, 1.0, 0.3), // Golden energy color
+            custom_size: Some(Vec2::splat(18.0)),
+            ..default()
+        },
+        Transform::from_translation(position),
+        ATP { amount },
+        Collider { radius: 9.0 },
+        BioluminescentParticle {
+            base_color: Color::srgb(1.0, 1.0, 0.3),
+            pulse_frequency: 3.0,
+            pulse_intensity: 0.6,
+            organic_motion: OrganicMotion {
+                undulation_speed: 2.0,
+                response_to_current: 0.4,
+            },
+        },
+    ));
+}
+
+/// Egg sacs popped before hatching always pay out
+pub fn egg_sac_reward_system(
+    mut commands: Commands,
+    mut popped_events: EventReader<EggSacPopped>,
+    run_modifiers: Res<RunModifiers>,
+    assets: Option<Res<GameAssets>>,
+) {
+    let Some(assets) = assets else { return };
+    for event in popped_events.read() {
+        let amount = (event.atp_reward as f32 * run_modifiers.atp_multiplier()) as u32;
+        spawn_atp_drop(&mut commands, &assets, event.position, amount);
+    }
+}
+
 // Spawn ATP on enemy death with biological considerations
 pub fn spawn_atp_on_death(
     mut commands: Commands,
     mut explosion_events: EventReader<SpawnExplosion>,
+    run_modifiers: Res<RunModifiers>,
     assets: Option<Res<GameAssets>>,
 ) {
     if let Some(assets) = assets {
@@ -58,30 +99,12 @@ pub fn spawn_atp_on_death(
                     EnemyType::ReproductiveVesicle => (15, 0.9, 12),
                     EnemyType::Offspring => (1, 0.5, 2),
                 };
+                let atp_amount = (atp_amount as f32 * run_modifiers.atp_multiplier()) as u32;
 
                 // Random chance to drop ATP based on organism energy content
                 if (event.position.x * 123.456).sin().abs() < spawn_chance {
                     // Main ATP drop
-                    commands.spawn((
-                        Sprite {
-                            image: assets.multiplier_powerup_texture.clone(),
-                            color: Color::srgb(1.0, 1.0, 0.3), // Golden energy color
-                            custom_size: Some(Vec2::splat(18.0)),
-                            ..default()
-                        },
-                        Transform::from_translation(event.position),
-                        ATP { amount: atp_amount },
-                        Collider { radius: 9.0 },
-                        BioluminescentParticle {
-                            base_color: Color::srgb(1.0, 1.0, 0.3),
-                            pulse_frequency: 3.0,
-                            pulse_intensity: 0.6,
-                            organic_motion: OrganicMotion {
-                                undulation_speed: 2.0,
-                                response_to_current: 0.4,
-                            },
-                        },
-                    ));
+                    spawn_atp_drop(&mut commands, &assets, event.position, atp_amount);
 
                     // Spawn smaller ATP particles for organic feel
                     for i in 0..particle_count {
@@ -124,11 +147,29 @@ pub fn spawn_atp_on_death(
 
 // Move ATP with organic floating motion
 pub fn move_atp(
-    mut atp_query: Query<(&mut Transform, &mut BioluminescentParticle), With<ATP>>,
+    mut commands: Commands,
+    mut atp_query: Query<(Entity, &mut Transform, &mut BioluminescentParticle, Option<&mut DropAge>), (With<ATP>, Without<Player>)>,
+    player_query: Query<&Transform, With<Player>>,
     fluid_environment: Res<FluidEnvironment>,
+    homing_config: Res<DropHomingConfig>,
     time: Res<Time>,
 ) {
-    for (mut transform, bio_particle) in atp_query.iter_mut() {
+    let player_pos = player_query.single().ok().map(|t| t.translation.truncate());
+
+    for (entity, mut transform, bio_particle, age) in atp_query.iter_mut() {
+        // Slow homing toward the player once the drop has been loose a while
+        match age {
+            Some(mut age) => {
+                age.0 += time.delta_secs();
+                if let Some(player_pos) = player_pos {
+                    let pull = homing_config.pull(transform.translation.truncate(), player_pos, age.0, time.delta_secs());
+                    transform.translation += pull.extend(0.0);
+                }
+            }
+            None => { commands.entity(entity).try_insert(DropAge::default()); }
+        }
+
+
         // Gentle downward drift
         transform.translation.y -= 80.0 * time.delta_secs();
 
@@ -151,6 +192,80 @@ pub fn move_atp(
     }
 }
 
+// Guaranteed boss rewards, dropped beside the normal ATP loot
+pub fn boss_loot_system(
+    mut commands: Commands,
+    mut explosion_events: EventReader<SpawnExplosion>,
+    loot_config: Res<BossLootConfig>,
+    run_modifiers: Res<RunModifiers>,
+    assets: Option<Res<GameAssets>>,
+) {
+    let Some(assets) = assets else { return };
+
+    for event in explosion_events.read() {
+        let Some(enemy_type) = &event.enemy_type else { continue };
+
+        for (index, reward) in loot_config.rewards_for(enemy_type).iter().enumerate() {
+            // Fan rewards out so they don't stack on one spot
+            let position = event.position + Vec3::new((index as f32 - 0.5) * 40.0, 20.0, 0.0);
+
+            match reward {
+                BossReward::FreeEvolution(evolution) => {
+                    let color = Color::srgb(0.6, 1.0, 0.9);
+                    commands.spawn((
+                        Sprite {
+                            image: assets.speed_powerup_texture.clone(),
+                            color,
+                            custom_size: Some(Vec2::splat(28.0)),
+                            ..default()
+                        },
+                        Transform::from_translation(position),
+                        EvolutionPowerUp {
+                            evolution_type: evolution.clone(),
+                            adaptation_type: AdaptationType::EvolutionSwap(evolution.clone()),
+                            temporary: false,
+                            duration: None,
+                        },
+                        Collider { radius: 16.0 },
+                        BioluminescentParticle {
+                            base_color: color,
+                            pulse_frequency: 2.5,
+                            pulse_intensity: 0.8,
+                            organic_motion: OrganicMotion {
+                                undulation_speed: 1.5,
+                                response_to_current: 0.3,
+                            },
+                        },
+                    ));
+                }
+                BossReward::AtpPayout(amount) => {
+                    let amount = (*amount as f32 * run_modifiers.atp_multiplier()) as u32;
+                    commands.spawn((
+                        Sprite {
+                            image: assets.multiplier_powerup_texture.clone(),
+                            color: Color::srgb(1.0, 0.85, 0.2),
+                            custom_size: Some(Vec2::splat(30.0)),
+                            ..default()
+                        },
+                        Transform::from_translation(position),
+                        ATP { amount },
+                        Collider { radius: 15.0 },
+                        BioluminescentParticle {
+                            base_color: Color::srgb(1.0, 0.85, 0.2),
+                            pulse_frequency: 3.0,
+                            pulse_intensity: 0.8,
+                            organic_motion: OrganicMotion {
+                                undulation_speed: 2.0,
+                                response_to_current: 0.2,
+                            },
+                        },
+                    ));
+                }
+            }
+        }
+    }
+}
+
 // Evolution Chamber interaction (renamed from upgrade_station_interaction)
 pub fn evolution_chamber_interaction(
     mut commands: Commands,
@@ -163,6 +278,7 @@ pub fn evolution_chamber_interaction(
         &mut CellularUpgrades,
         &mut UpgradeLimits
     ), With<Player>>,
+    cost_config: Res<CostConfig>,
 ) {
     if let Ok((player_transform, mut atp, mut evolution_system, mut upgrades, mut limits)) = player_query.single_mut() {
         for chamber_transform in chamber_query.iter() {
@@ -171,18 +287,21 @@ pub fn evolution_chamber_interaction(
                 
                 // Damage upgrade with limit check
                 if input_manager.just_pressed(InputAction::UpgradeDamage) && 
-                   limits.damage_level < limits.damage_max && atp.amount >= 10 {
-                    atp.amount -= 10;
+                   limits.damage_level < limits.damage_max &&
+                   atp.amount >= cost_config.scaled_cost(10, limits.damage_level) {
+                    atp.amount -= cost_config.scaled_cost(10, limits.damage_level);
                     limits.damage_level += 1;
                     upgrades.damage_amplification *= 1.15; // 15% per level
+                    upgrades.accuracy = (upgrades.accuracy + ACCURACY_PER_DAMAGE_LEVEL).min(1.0);
                     evolution_system.cellular_adaptations.membrane_permeability *= 1.15;
                     println!("Upgraded damage! Level {}/{}", limits.damage_level, limits.damage_max);
                 }
 
                 // Shield upgrade with limit check  
                 if input_manager.just_pressed(InputAction::UpgradeMetabolic) && 
-                   limits.shield_level < limits.shield_max && atp.amount >= 15 {
-                    atp.amount -= 15;
+                   limits.shield_level < limits.shield_max &&
+                   atp.amount >= cost_config.scaled_cost(15, limits.shield_level) {
+                    atp.amount -= cost_config.scaled_cost(15, limits.shield_level);
                     limits.shield_level += 1;
                     // Shield effectiveness would be handled in combat system
                     println!("Upgraded shield! Level {}/{}", limits.shield_level, limits.shield_max);
@@ -190,8 +309,9 @@ pub fn evolution_chamber_interaction(
 
                 // Metabolic upgrade with limit check
                 if input_manager.just_pressed(InputAction::UpgradeMetabolic) && 
-                   limits.metabolic_level < limits.metabolic_max && atp.amount >= 15 {
-                    atp.amount -= 15;
+                   limits.metabolic_level < limits.metabolic_max &&
+                   atp.amount >= cost_config.scaled_cost(15, limits.metabolic_level) {
+                    atp.amount -= cost_config.scaled_cost(15, limits.metabolic_level);
                     limits.metabolic_level += 1;
                     upgrades.metabolic_rate *= 1.2;
                     evolution_system.cellular_adaptations.metabolic_efficiency *= 1.2;
@@ -199,24 +319,27 @@ pub fn evolution_chamber_interaction(
 
                 // Cellular upgrade with limit check
                 if input_manager.just_pressed(InputAction::UpgradeCellular) && 
-                   limits.cellular_level < limits.cellular_max && atp.amount >= 20 {
-                    atp.amount -= 20;
+                   limits.cellular_level < limits.cellular_max &&
+                   atp.amount >= cost_config.scaled_cost(20, limits.cellular_level) {
+                    atp.amount -= cost_config.scaled_cost(20, limits.cellular_level);
                     limits.cellular_level += 1;
                     upgrades.max_health += 25;
                 }
 
                 // Enzyme upgrade with limit check
                 if input_manager.just_pressed(InputAction::UpgradeEnzyme) && 
-                   limits.enzyme_level < limits.enzyme_max && atp.amount >= 25 {
-                    atp.amount -= 25;
+                   limits.enzyme_level < limits.enzyme_max &&
+                   atp.amount >= cost_config.scaled_cost(25, limits.enzyme_level) {
+                    atp.amount -= cost_config.scaled_cost(25, limits.enzyme_level);
                     limits.enzyme_level += 1;
                     evolution_system.cellular_adaptations.extremophile_traits = true;
                 }
 
                 // Bioluminescence upgrade with limit check
                 if input_manager.just_pressed(InputAction::UpgradeBioluminescence) && 
-                   limits.bioluminescence_level < limits.bioluminescence_max && atp.amount >= 30 {
-                    atp.amount -= 30;
+                   limits.bioluminescence_level < limits.bioluminescence_max &&
+                   atp.amount >= cost_config.scaled_cost(30, limits.bioluminescence_level) {
+                    atp.amount -= cost_config.scaled_cost(30, limits.bioluminescence_level);
                     limits.bioluminescence_level += 1;
                     evolution_system.cellular_adaptations.biofilm_formation = true;
                 }
@@ -230,15 +353,17 @@ pub fn evolution_chamber_interaction(
 
                 // Magnet upgrades with limits
                 if input_manager.just_pressed(InputAction::UpgradeMagnetRadius) && 
-                   limits.magnet_radius_level < limits.magnet_radius_max && atp.amount >= 25 {
-                    atp.amount -= 25;
+                   limits.magnet_radius_level < limits.magnet_radius_max &&
+                   atp.amount >= cost_config.scaled_cost(25, limits.magnet_radius_level) {
+                    atp.amount -= cost_config.scaled_cost(25, limits.magnet_radius_level);
                     limits.magnet_radius_level += 1;
                     upgrades.magnet_radius += 20.0;
                 }
 
                 if input_manager.just_pressed(InputAction::UpgradeMagnetStrength) && 
-                   limits.magnet_strength_level < limits.magnet_strength_max && atp.amount >= 30 {
-                    atp.amount -= 30;
+                   limits.magnet_strength_level < limits.magnet_strength_max &&
+                   atp.amount >= cost_config.scaled_cost(30, limits.magnet_strength_level) {
+                    atp.amount -= cost_config.scaled_cost(30, limits.magnet_strength_level);
                     limits.magnet_strength_level += 1;
                     upgrades.magnet_strength += 0.3;
                 }
@@ -493,7 +618,7 @@ pub fn spawn_biological_powerups(
         let power_type = if (time.elapsed_secs() * 987.654).sin().abs() < 0.05 {
             PowerUpType::CellularRegeneration { amount: 1 } // Use as extra life marker
         } else {
-            match (time.elapsed_secs() as u32 / 18) % 10 {
+            match (time.elapsed_secs() as u32 / 18) % 12 {
                 0 => PowerUpType::CellularRegeneration { amount: 30 },
                 1 => PowerUpType::CellWall { duration: 12.0 },
                 2 => PowerUpType::Flagella { multiplier: 1.6, duration: 10.0 },
@@ -502,7 +627,16 @@ pub fn spawn_biological_powerups(
                 5 => PowerUpType::Photosynthesis { energy_regen: 8.0, duration: 15.0 },
                 6 => PowerUpType::Chemotaxis { homing_strength: 2.5, duration: 12.0 },
                 7 => PowerUpType::Osmoregulation { immunity_duration: 10.0 },
-                8 => PowerUpType::Osmoregulation { immunity_duration: 10.0 },
+                8 => PowerUpType::CryoPulse { radius: 260.0, freeze_duration: 3.0 },
+                9 => {
+                    let kind = match (time.elapsed_secs() as u32 / 7) % 3 {
+                        0 => DeployedZoneKind::Acidic,
+                        1 => DeployedZoneKind::Alkaline,
+                        _ => DeployedZoneKind::Hypoxic,
+                    };
+                    PowerUpType::DeployZone { kind, radius: 180.0, duration: 8.0 }
+                }
+                10 => PowerUpType::ShieldBarrier { width: 160.0, duration: 8.0 },
                 _ => PowerUpType::MagneticField { radius_boost: 40.0, strength_boost: 0.5, duration: 20.0 },
             }
         };
@@ -522,6 +656,9 @@ pub fn handle_biological_powerup_collection(
     powerup_query: Query<(Entity, &Transform, &Collider, &PowerUp)>,
     extra_life_query: Query<(Entity, &Transform, &Collider), (With<ExtraLifePowerUp>,Without<PendingDespawn>)>,
     mut player_query: Query<(Entity, &Transform, &Collider, &mut Health, &mut Player), (With<Player>,Without<PendingDespawn>)>,
+    enemy_query: Query<(Entity, &Transform, &Sprite, &Enemy, Has<Frozen>), Without<PendingDespawn>>,
+    status_resistance: Res<StatusResistanceConfig>,
+    mut chemical_environment: ResMut<ChemicalEnvironment>,
     mut particle_events: EventWriter<SpawnParticles>,
     assets: Option<Res<GameAssets>>,
 ) {
@@ -672,6 +809,54 @@ pub fn handle_biological_powerup_collection(
                             strength_boost: 0.5,
                         });
                     }                    
+
+                    PowerUpType::CryoPulse { radius, freeze_duration } => {
+                        for (enemy_entity, enemy_transform, sprite, enemy, already_frozen) in enemy_query.iter() {
+                            if already_frozen || enemy_transform.translation.distance(player_transform.translation) > *radius {
+                                continue;
+                            }
+                            let role = enemy.enemy_type.get_ecosystem_role().role;
+                            commands.entity(enemy_entity).try_insert(Frozen {
+                                timer: status_resistance.scale(&role, *freeze_duration),
+                                original_color: sprite.color,
+                            });
+                        }
+                    }
+
+                    PowerUpType::DeployZone { kind, radius, duration } => {
+                        chemical_environment.deploy_zone(*kind, player_transform.translation.truncate(), *radius, *duration);
+
+                        if let Some(assets) = &assets {
+                            commands.spawn((
+                                Sprite {
+                                    image: assets.barrier_texture.clone(),
+                                    color: kind.color(),
+                                    custom_size: Some(Vec2::splat(*radius * 2.0)),
+                                    ..default()
+                                },
+                                Transform::from_translation(player_transform.translation.with_z(-0.5)),
+                                DeployedZoneVisual { timer: *duration, duration: *duration },
+                            ));
+                        }
+                    }
+
+                    PowerUpType::ShieldBarrier { width, duration } => {
+                        if let Some(assets) = &assets {
+                            commands.spawn((
+                                Sprite {
+                                    image: assets.barrier_texture.clone(),
+                                    color: Color::srgba(0.5, 0.9, 1.0, 0.7),
+                                    custom_size: Some(Vec2::new(*width, SHIELD_BARRIER_THICKNESS)),
+                                    ..default()
+                                },
+                                Transform::from_translation(player_transform.translation + Vec3::new(0.0, SHIELD_BARRIER_OFFSET, 0.2)),
+                                ShieldBarrier {
+                                    timer: *duration,
+                                    half_extents: Vec2::new(*width, SHIELD_BARRIER_THICKNESS) * 0.5,
+                                },
+                            ));
+                        }
+                    }
                 }
 
                 commands.entity(powerup_entity)
@@ -693,11 +878,29 @@ pub fn handle_biological_powerup_collection(
 
 // Enhanced organic power-up movement
 pub fn move_biological_powerups(
-    mut powerup_query: Query<(&mut Transform, &mut PowerUp, Option<&BioluminescentParticle>)>,
+    mut commands: Commands,
+    mut powerup_query: Query<(Entity, &mut Transform, &mut PowerUp, Option<&BioluminescentParticle>, Option<&mut DropAge>), Without<Player>>,
+    player_query: Query<&Transform, With<Player>>,
     fluid_environment: Res<FluidEnvironment>,
+    homing_config: Res<DropHomingConfig>,
     time: Res<Time>,
 ) {
-    for (mut transform, mut powerup, bio_particle) in powerup_query.iter_mut() {
+    let player_pos = player_query.single().ok().map(|t| t.translation.truncate());
+
+    for (entity, mut transform, mut powerup, bio_particle, age) in powerup_query.iter_mut() {
+        // Slow homing toward the player once the drop has been loose a while
+        match age {
+            Some(mut age) => {
+                age.0 += time.delta_secs();
+                if let Some(player_pos) = player_pos {
+                    let pull = homing_config.pull(transform.translation.truncate(), player_pos, age.0, time.delta_secs());
+                    transform.translation += pull.extend(0.0);
+                }
+            }
+            None => { commands.entity(entity).try_insert(DropAge::default()); }
+        }
+
+
         // Slow downward movement
         transform.translation.y -= 120.0 * time.delta_secs();
 
@@ -929,7 +1132,8 @@ pub fn wave_completion_system(
     mut wave_manager: ResMut<WaveManager>,
     mut game_score: ResMut<GameScore>,
     mut spawn_powerup_events: EventWriter<SpawnPowerUp>,
-    enemy_query: Query<&Enemy>,
+    enemy_query: Query<&Enemy, Without<TargetDummy>>,
+    (spawn_queue, portal_query): (Res<WaveSpawnQueue>, Query<(), With<SpawnPortal>>),
     player_query: Query<&Transform, With<Player>>,
     mut achievement_events: EventWriter<AchievementEvent>,
     time: Res<Time>,
@@ -938,8 +1142,7 @@ pub fn wave_completion_system(
         return;
     }
 
-    let living_enemies = enemy_query.iter().count();
-    if living_enemies > 0 || wave_manager.enemies_remaining > 0 {
+    if !wave_cleared(enemy_query.iter().count(), &spawn_queue, !portal_query.is_empty()) {
         return;
     }
 
@@ -981,6 +1184,74 @@ pub fn wave_completion_system(
     complete_current_wave(&mut wave_manager, time.elapsed_secs());
 }
 
+/// Sweep leftover ATP and power-ups into the player once a wave is cleared
+pub fn wave_clear_vacuum_system(
+    mut vacuum: ResMut<WaveClearVacuum>,
+    wave_manager: Res<WaveManager>,
+    player_query: Query<&Transform, With<Player>>,
+    mut pickup_query: Query<&mut Transform, (Or<(With<ATP>, With<PowerUp>)>, Without<Player>, Without<PendingDespawn>)>,
+    mut was_active: Local<bool>,
+    time: Res<Time>,
+) {
+    // Either wave system may close the wave, so watch for the transition rather than hooking one of them
+    if *was_active && !wave_manager.wave_active {
+        vacuum.trigger();
+    }
+    *was_active = wave_manager.wave_active;
+
+    if !vacuum.is_active() { return; }
+    let dt = time.delta_secs();
+    let Some(speed) = vacuum.tick(dt) else { return };
+    let Ok(player_transform) = player_query.single() else { return };
+
+    for mut transform in pickup_query.iter_mut() {
+        transform.translation = WaveClearVacuum::pull(transform.translation, player_transform.translation, speed, dt);
+    }
+}
+
+// Enemies feed on loose ATP the player hasn't reached, growing tougher and faster
+pub fn corpse_atp_absorption_system(
+    mut commands: Commands,
+    absorption: Res<CorpseAbsorption>,
+    player_query: Query<&Transform, With<Player>>,
+    mut atp_query: Query<(Entity, &mut Transform, &Collider, &ATP), (Without<Player>, Without<Enemy>, Without<MagnetizedATP>, Without<PendingDespawn>)>,
+    mut enemy_query: Query<(Entity, &Transform, &Collider, &mut Enemy, &mut Health, Option<&mut AbsorbedATP>), (Without<Player>, Without<ATP>, Without<TargetDummy>, Without<PendingDespawn>)>,
+    time: Res<Time>,
+) {
+    if !absorption.enabled { return; }
+    let player_pos = player_query.single().ok().map(|transform| transform.translation.truncate());
+
+    for (atp_entity, mut atp_transform, atp_collider, atp) in atp_query.iter_mut() {
+        let atp_pos = atp_transform.translation.truncate();
+
+        // Nearest hungry enemy that beats the player to this pickup
+        let Some((enemy_entity, enemy_pos, reach)) = enemy_query.iter()
+            .filter(|(.., absorbed)| absorbed.as_ref().is_none_or(|absorbed| absorbed.total < absorption.max_absorbed))
+            .map(|(entity, transform, collider, ..)| (entity, transform.translation.truncate(), collider.radius + atp_collider.radius))
+            .filter(|(_, enemy_pos, _)| absorption.claims(atp_pos, *enemy_pos, player_pos))
+            .min_by(|a, b| atp_pos.distance_squared(a.1).total_cmp(&atp_pos.distance_squared(b.1)))
+        else { continue };
+
+        if atp_pos.distance(enemy_pos) > reach {
+            let step = (enemy_pos - atp_pos).normalize_or_zero() * absorption.pull_speed * time.delta_secs();
+            atp_transform.translation += step.extend(0.0);
+            continue;
+        }
+
+        let Ok((_, _, _, mut enemy, mut health, absorbed)) = enemy_query.get_mut(enemy_entity) else { continue };
+        let already_absorbed = absorbed.as_ref().map_or(0, |absorbed| absorbed.total);
+        let (health_gain, speed_multiplier, eaten) = absorption.buff(atp.amount, already_absorbed);
+
+        health.0 += health_gain;
+        enemy.speed *= speed_multiplier;
+        match absorbed {
+            Some(mut absorbed) => absorbed.total += eaten,
+            None => { commands.entity(enemy_entity).try_insert(AbsorbedATP { total: eaten }); }
+        }
+        commands.entity(atp_entity).safe_despawn();
+    }
+}
+
 pub fn atp_magnet_system(
     mut commands: Commands,
     player_query: Query<(&Transform, &CellularUpgrades), With<Player>>,
@@ -1030,4 +1301,219 @@ pub fn atp_magnet_system(
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn completion_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<WaveManager>()
+            .init_resource::<GameScore>()
+            .init_resource::<WaveSpawnQueue>()
+            .add_event::<SpawnPowerUp>()
+            .add_event::<AchievementEvent>()
+            .add_systems(Update, wave_completion_system);
+        app.world_mut().spawn((Transform::default(), Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 }));
+        app.world_mut().resource_mut::<WaveManager>().wave_active = true;
+        app
+    }
+
+    #[test]
+    fn target_dummy_does_not_hold_the_wave_open() {
+        let mut app = completion_app();
+        app.world_mut().spawn((Enemy::default(), TargetDummy::new(5.0)));
+
+        app.update();
+        assert!(!app.world().resource::<WaveManager>().wave_active);
+    }
+
+    #[test]
+    fn living_enemy_holds_the_wave_open() {
+        let mut app = completion_app();
+        app.world_mut().spawn(Enemy::default());
+
+        app.update();
+        assert!(app.world().resource::<WaveManager>().wave_active);
+    }
+
+    #[test]
+    fn clearing_a_wave_sweeps_loose_atp_into_the_player() {
+        let mut app = completion_app();
+        app.init_resource::<WaveClearVacuum>()
+            .add_event::<SpawnParticles>()
+            .add_systems(Update, (wave_clear_vacuum_system, collect_atp_with_energy_transfer).chain().after(wave_completion_system));
+        let player = app.world_mut().query_filtered::<Entity, With<Player>>().single(app.world()).unwrap();
+        app.world_mut().entity_mut(player).insert((Collider { radius: 16.0 }, ATP { amount: 0 }));
+        for corner in [Vec3::new(-550.0, 320.0, 0.0), Vec3::new(550.0, 320.0, 0.0), Vec3::new(0.0, -330.0, 0.0)] {
+            app.world_mut().spawn((Transform::from_translation(corner), Collider { radius: 8.0 }, ATP { amount: 5 }));
+        }
+
+        // Nothing moves while the wave is still being fought
+        let last_enemy = app.world_mut().spawn(Enemy::default()).id();
+        app.update();
+        app.world_mut().despawn(last_enemy);
+
+        let vacuum_window = app.world().resource::<WaveClearVacuum>().duration;
+        let mut elapsed = 0.0;
+        while elapsed < vacuum_window {
+            app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(100));
+            app.update();
+            elapsed += 0.1;
+        }
+
+        assert!(!app.world().resource::<WaveManager>().wave_active);
+        assert_eq!(app.world().get::<ATP>(player).unwrap().amount, 15);
+    }
+
+    #[test]
+    fn second_damage_upgrade_costs_the_growth_factor_more_than_the_first() {
+        let mut app = App::new();
+        app.init_resource::<InputManager>()
+            .init_resource::<CostConfig>()
+            .add_systems(Update, evolution_chamber_interaction);
+        app.world_mut().spawn((Transform::default(), EvolutionChamber));
+        let player = app.world_mut().spawn((
+            Transform::default(),
+            ATP { amount: 1000 },
+            EvolutionSystem::default(),
+            CellularUpgrades::default(),
+            UpgradeLimits::default(),
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+        )).id();
+        let buy_damage = |app: &mut App| {
+            let before = app.world().get::<ATP>(player).unwrap().amount;
+            app.world_mut().resource_mut::<InputManager>().current_states.insert(InputAction::UpgradeDamage, InputState::JustPressed);
+            app.update();
+            before - app.world().get::<ATP>(player).unwrap().amount
+        };
+
+        let first = buy_damage(&mut app);
+        let second = buy_damage(&mut app);
+        let growth = app.world().resource::<CostConfig>().growth_factor;
+        assert_eq!(first, 10);
+        assert_eq!(second, (first as f32 * growth).round() as u32);
+        assert_eq!(app.world().get::<UpgradeLimits>(player).unwrap().damage_level, 2);
+    }
+
+    #[test]
+    fn enemy_beats_a_distant_player_to_loose_atp_and_grows_from_it() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(CorpseAbsorption { enabled: true, ..default() })
+            .add_systems(Update, corpse_atp_absorption_system);
+        app.world_mut().spawn((Transform::from_xyz(0.0, -300.0, 0.0), Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 }));
+        let pickup = app.world_mut().spawn((Transform::from_xyz(100.0, 100.0, 0.0), Collider { radius: 8.0 }, ATP { amount: 5 })).id();
+        let enemy = app.world_mut().spawn((Transform::from_xyz(160.0, 100.0, 0.0), Collider { radius: 16.0 }, Enemy::default(), Health(20))).id();
+        let base_speed = Enemy::default().speed;
+
+        for _ in 0..10 {
+            app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(100));
+            app.update();
+        }
+
+        let absorption = CorpseAbsorption::default();
+        assert!(app.world().get::<PendingDespawn>(pickup).is_some());
+        assert_eq!(app.world().get::<AbsorbedATP>(enemy).map(|absorbed| absorbed.total), Some(5));
+        assert_eq!(app.world().get::<Health>(enemy).unwrap().0, 20 + 5 * absorption.health_per_atp);
+        assert!(app.world().get::<Enemy>(enemy).unwrap().speed > base_speed);
+    }
+
+    #[test]
+    fn apex_enemies_thaw_from_a_cryo_pulse_sooner_than_decomposers() {
+        let mut app = App::new();
+        app.init_resource::<ChemicalEnvironment>()
+            .init_resource::<StatusResistanceConfig>()
+            .add_event::<SpawnParticles>()
+            .add_systems(Update, handle_biological_powerup_collection);
+        app.world_mut().spawn((
+            Transform::default(),
+            Collider { radius: 16.0 },
+            Health(100),
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+        ));
+        app.world_mut().spawn((
+            Transform::default(),
+            Collider { radius: 12.0 },
+            PowerUp { power_type: PowerUpType::CryoPulse { radius: 260.0, freeze_duration: 3.0 }, bob_timer: 0.0, bioluminescent_pulse: 0.0 },
+        ));
+        let mut spawn_enemy = |enemy_type, x| app.world_mut().spawn((
+            Transform::from_xyz(x, 100.0, 0.0),
+            Sprite::default(),
+            Enemy { enemy_type, ..default() },
+        )).id();
+        let apex = spawn_enemy(EnemyType::InfectedMacrophage, -80.0);
+        let decomposer = spawn_enemy(EnemyType::Offspring, 80.0);
+        app.update();
+
+        let freeze = |entity| app.world().get::<Frozen>(entity).map(|frozen| frozen.timer).unwrap();
+        assert_eq!(freeze(decomposer), 3.0);
+        assert!(freeze(apex) < freeze(decomposer));
+    }
+
+    /// Horizontal distance from a loose ATP drop to the player after each second of drifting
+    fn drop_gap_each_second(homing: bool) -> Vec<f32> {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<FluidEnvironment>()
+            .insert_resource(DropHomingConfig { enabled: homing, ..default() })
+            .add_systems(Update, move_atp);
+        app.world_mut().spawn((Transform::from_xyz(-400.0, 0.0, 0.0), Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 }));
+        let drop = app.world_mut().spawn((
+            Transform::default(),
+            ATP { amount: 5 },
+            BioluminescentParticle {
+                base_color: Color::WHITE,
+                pulse_frequency: 2.0,
+                pulse_intensity: 0.5,
+                organic_motion: OrganicMotion { undulation_speed: 2.0, response_to_current: 0.0 },
+            },
+        )).id();
+
+        (0..5).map(|_| {
+            for _ in 0..10 {
+                app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(100));
+                app.update();
+            }
+            app.world().get::<Transform>(drop).unwrap().translation.x + 400.0
+        }).collect()
+    }
+
+    #[test]
+    fn homing_drops_drift_toward_a_distant_player_after_the_delay() {
+        let drifting = drop_gap_each_second(false);
+        let homing = drop_gap_each_second(true);
+        let delay = DropHomingConfig::default().delay;
+
+        // Untouched until the delay, then the pull grows as it ramps up
+        assert_eq!(homing[0], drifting[0]);
+        assert!(delay < 2.0);
+        let closing: Vec<f32> = drifting.iter().zip(&homing).map(|(d, h)| d - h).collect();
+        assert!(closing[1] > 0.0);
+        assert!(closing.windows(2).skip(1).all(|pair| pair[1] > pair[0]));
+        assert!(homing[4] > 100.0, "still well outside any magnet pull");
+    }
+
+    #[test]
+    fn boss_deaths_drop_the_configured_guaranteed_rewards() {
+        let mut app = App::new();
+        app.init_resource::<BossLootConfig>()
+            .init_resource::<RunModifiers>()
+            .init_resource::<GameAssets>()
+            .add_event::<SpawnExplosion>()
+            .add_systems(Update, boss_loot_system);
+
+        app.world_mut().send_event(SpawnExplosion { position: Vec3::ZERO, intensity: 1.0, enemy_type: Some(EnemyType::ViralParticle) });
+        app.update();
+        assert_eq!(app.world_mut().query::<&EvolutionPowerUp>().iter(app.world()).count(), 0);
+        assert_eq!(app.world_mut().query::<&ATP>().iter(app.world()).count(), 0);
+
+        app.world_mut().send_event(SpawnExplosion { position: Vec3::ZERO, intensity: 2.0, enemy_type: Some(EnemyType::InfectedMacrophage) });
+        app.update();
+        let evolutions: Vec<bool> = app.world_mut().query::<&EvolutionPowerUp>().iter(app.world()).map(|pickup| pickup.temporary).collect();
+        assert_eq!(evolutions, vec![false]);
+        let payouts: Vec<u32> = app.world_mut().query::<&ATP>().iter(app.world()).map(|atp| atp.amount).collect();
+        assert_eq!(payouts, vec![60]);
+    }
+}
@@ -3,6 +3,11 @@ use crate::components::*;
 use crate::resources::*;
 use crate::events::*;
 use crate::input::*;
+use crate::enemy_types::*;
+use crate::wave_systems::*;
+use crate::despawn::*;
+use crate::target_dummy::TargetDummy;
+use crate::tidal_mechanics::trigger_king_tide;
 
 pub fn debug_atp_spawner(
     mut commands: Commands,
@@ -11,22 +16,20 @@ pub fn debug_atp_spawner(
 ) {
     if !input_manager.debug_enabled { return; }
     
-    if input_manager.just_pressed(InputAction::DebugSpawnATP) {
-        if let Some(assets) = assets {
-            for i in 0..20 {
-                let x = (i as f32 - 10.0) * 30.0;
-                commands.spawn((
-                    Sprite {
-                        image: assets.multiplier_powerup_texture.clone(),
-                        color: Color::srgb(1.0, 1.0, 0.3),
-                        custom_size: Some(Vec2::splat(18.0)),
-                        ..default()
-                    },
-                    Transform::from_xyz(x, 200.0, 0.0),
-                    ATP { amount: 50 },
-                    Collider { radius: 9.0 },
-                ));
-            }
+    if input_manager.just_pressed(InputAction::DebugSpawnATP) && let Some(assets) = assets {
+        for i in 0..20 {
+            let x = (i as f32 - 10.0) * 30.0;
+            commands.spawn((
+                Sprite {
+                    image: assets.multiplier_powerup_texture.clone(),
+                    color: Color::srgb(1.0, 1.0, 0.3),
+                    custom_size: Some(Vec2::splat(18.0)),
+                    ..default()
+                },
+                Transform::from_xyz(x, 200.0, 0.0),
+                ATP { amount: 50 },
+                Collider { radius: 9.0 },
+            ));
         }
     }
 }
@@ -38,33 +41,134 @@ pub fn debug_spawn_evolution_chamber(
 ) {
     if !input_manager.debug_enabled { return; }
     
-    if input_manager.just_pressed(InputAction::DebugSpawnEvolutionChamber) {
-        if let Some(assets) = assets {
-            commands.spawn((
-                Sprite {
-                    image: assets.enemy_texture.clone(),
-                    color: Color::srgb(0.3, 0.9, 0.6),
-                    custom_size: Some(Vec2::splat(60.0)),
-                    ..default()
-                },
-                Transform::from_xyz(0.0, 200.0, 0.0),
-                EvolutionChamber,
-            ));
-        }
+    if input_manager.just_pressed(InputAction::DebugSpawnEvolutionChamber) && let Some(assets) = assets {
+        commands.spawn((
+            Sprite {
+                image: assets.enemy_texture.clone(),
+                color: Color::srgb(0.3, 0.9, 0.6),
+                custom_size: Some(Vec2::splat(60.0)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 200.0, 0.0),
+            EvolutionChamber,
+        ));
     }
 }
 
 pub fn debug_trigger_king_tide(
     mut commands: Commands,
     input_manager: Res<InputManager>,
+    mut tidal_physics: ResMut<TidalPoolPhysics>,
     mut tidal_events: EventWriter<TidalEvent>,
 ) {
     if !input_manager.debug_enabled { return; }
     
-    if input_manager.just_pressed(InputAction::DebugTriggerKingTide) {
-        tidal_events.write(TidalEvent::KingTideBegin {
-            intensity: 2.0,
-            duration: 15.0,
+    if input_manager.just_pressed(InputAction::DebugTriggerKingTide) && !tidal_physics.king_tide_active {
+        trigger_king_tide(&mut tidal_physics, &mut tidal_events);
+    }
+}
+
+// ===== AI RANGE OVERLAY =====
+/// Translucent circles for each enemy's AI ranges, for tuning in debug builds
+#[derive(Resource, Default)]
+pub struct AiRangeOverlay {
+    pub visible: bool,
+}
+
+impl AiRangeKind {
+    pub fn overlay_color(&self) -> Color {
+        match self {
+            AiRangeKind::Detection => Color::srgba(1.0, 0.3, 0.3, 0.35),
+            AiRangeKind::Aura => Color::srgba(0.3, 1.0, 0.5, 0.35),
+            AiRangeKind::Bond => Color::srgba(0.5, 0.6, 1.0, 0.35),
+        }
+    }
+}
+
+pub fn debug_toggle_ai_ranges(
+    input_manager: Res<InputManager>,
+    mut overlay: ResMut<AiRangeOverlay>,
+) {
+    if !input_manager.debug_enabled { return; }
+
+    if input_manager.just_pressed(InputAction::DebugToggleAiRanges) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// Wipe the current wave's enemies and advance without rewards, for jumping ahead to later content
+pub fn debug_skip_wave(
+    mut commands: Commands,
+    input_manager: Res<InputManager>,
+    mut wave_manager: ResMut<WaveManager>,
+    mut spawn_queue: ResMut<WaveSpawnQueue>,
+    enemy_query: Query<Entity, (Or<(With<Enemy>, With<SpawnPortal>, With<EggSac>)>, Without<TargetDummy>, Without<PendingDespawn>)>,
+    time: Res<Time>,
+) {
+    if !input_manager.debug_enabled { return; }
+    if !input_manager.just_pressed(InputAction::DebugSkipWave) { return; }
+
+    for entity in enemy_query.iter() {
+        commands.entity(entity).safe_despawn();
+    }
+    info!("Debug: skipping wave {}", wave_manager.current_wave);
+    skip_current_wave(&mut wave_manager, &mut spawn_queue, time.elapsed_secs());
+}
+
+pub fn draw_ai_range_overlay(
+    mut gizmos: Gizmos,
+    overlay: Res<AiRangeOverlay>,
+    enemy_query: Query<(&Transform, &Enemy)>,
+) {
+    if !overlay.visible { return; }
+
+    for (transform, enemy) in enemy_query.iter() {
+        for (kind, radius) in enemy.ai_type.indicator_ranges() {
+            gizmos.circle_2d(transform.translation.truncate(), radius, kind.overlay_color());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turret_draws_one_detection_circle_at_its_range() {
+        let turret = EnemyAI::Turret { rotation: 0.0, shoot_timer: 1.0, detection_range: 275.0, windup_timer: 0.0 };
+        assert_eq!(turret.indicator_ranges(), vec![(AiRangeKind::Detection, 275.0)]);
+    }
+
+    #[test]
+    fn enemies_without_ranged_behaviour_draw_nothing() {
+        assert!(EnemyAI::Linear { direction: Vec2::NEG_Y }.indicator_ranges().is_empty());
+    }
+
+    #[test]
+    fn skipping_clears_the_wave_and_advances_to_the_next() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InputManager>()
+            .init_resource::<WaveSpawnQueue>()
+            .insert_resource(WaveManager { current_wave: 3, wave_active: true, enemies_remaining: 5, ..default() })
+            .add_systems(Update, debug_skip_wave);
+        let enemies: Vec<Entity> = (0..2).map(|_| app.world_mut().spawn(Enemy::default()).id()).collect();
+        app.world_mut().resource_mut::<WaveSpawnQueue>().push(QueuedSpawn {
+            enemy_type: EnemyType::ViralParticle,
+            ai_type: EnemyAI::Linear { direction: Vec2::NEG_Y },
+            position: Vec3::new(0.0, 400.0, 0.0),
+            spawn_time: 5.0,
+            via_portal: false,
         });
+
+        let mut input_manager = app.world_mut().resource_mut::<InputManager>();
+        input_manager.debug_enabled = true;
+        input_manager.current_states.insert(InputAction::DebugSkipWave, InputState::JustPressed);
+        app.update();
+
+        assert!(enemies.iter().all(|enemy| app.world().get::<PendingDespawn>(*enemy).is_some()));
+        assert!(app.world().resource::<WaveSpawnQueue>().is_empty());
+        let wave_manager = app.world().resource::<WaveManager>();
+        assert_eq!((wave_manager.current_wave, wave_manager.wave_active), (4, false));
     }
 }
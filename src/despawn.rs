@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::components::{AlreadyDespawned, PendingDespawn};
+use crate::components::PendingDespawn;
 
 // Safer despawning system - prevents double despawns and crashes
 pub fn robust_despawn_system(
@@ -19,10 +19,8 @@ pub fn robust_despawn_system(
     for (entity, mut pending) in pending_query.iter_mut() {
         pending.delay -= time.delta_secs();
         
-        if pending.delay <= 0.0 {
-            if let Ok(mut entity_commands) = commands.get_entity(entity) {
-                entity_commands.try_despawn();
-            }
+        if pending.delay <= 0.0 && let Ok(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.try_despawn();
         }
     }
 }
@@ -3,9 +3,12 @@ use crate::components::*;
 use crate::resources::*;
 use crate::events::*;
 use crate::enemy_types::*;
-use crate::physics::{world_to_grid_pos, sample_current, sample_ph, sample_oxygen};
+use crate::physics::{world_to_grid_pos, sample_current, sample_ph, sample_oxygen, oxygen_gradient};
 use crate::despawn::{SafeDespawn};
+use crate::wave_systems::{WaveManager, WaveSpeedRamp};
+use crate::systems::{fire_pattern, HostileShot};
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
 // ===== CONSTANTS =====
 const UNDULATION_AMPLITUDE: f32 = 8.0;
@@ -16,6 +19,503 @@ const CHEMICAL_AVOIDANCE_STRENGTH: f32 = 40.0;
 const DETECTION_RANGE_DEFAULT: f32 = 250.0;
 const SPAWN_DISTANCE_BASE: f32 = 25.0;
 const FORMATION_SPEED_NORMAL: f32 = 90.0;
+const SPATIAL_GRID_CELL_SIZE: f32 = 64.0;
+const HEALER_PULSE_INTERVAL: f32 = 0.5;
+const TURRET_TELEGRAPH_DURATION: f32 = 0.4; // Seconds a turret winds up before its volley
+const COLONY_TELEGRAPH_DURATION: f32 = 0.5;
+const TELEGRAPH_LINE_WIDTH: f32 = 3.0;
+const TELEGRAPH_LINE_LENGTH: f32 = 90.0;
+const BOSS_PHASE_HEALTH_FRACTION: f32 = 0.5;
+const SHATTER_SHARD_COUNT: u32 = 8;
+const SHATTER_SHARD_SPEED: f32 = 420.0;
+const SHATTER_DAMAGE_FRACTION: f32 = 0.25; // Each shard deals this share of the shattered enemy's max health
+const HEALER_HOVER_Y: f32 = 220.0;
+const BOSS_HOVER_Y: f32 = 240.0;
+const KAMIKAZE_MAX_LEAD_TIME: f32 = 1.5;    // Don't aim further ahead than the player can plausibly hold a course
+const PACK_RADIUS: f32 = 200.0;             // Same-type predators this close hunt together
+const PACK_FLANK_RADIUS: f32 = 80.0;
+const FLOCK_TURN_RATE: f32 = 3.0;
+const FLOCK_PLAYER_PULL: f32 = 0.35;
+const BOSS_FIGURE_EIGHT_RATE: f32 = 0.8;
+const BOSS_CHARGE_CYCLE: f32 = 3.0;         // Seconds per lunge and pull-back
+const BOSS_CHARGE_LUNGE: f32 = 0.9;
+const BOSS_TELEGRAPH_SHAKE: f32 = 120.0;
+const RETREAT_SPEED_MULTIPLIER: f32 = 1.4;
+const GRID_ASSEMBLE_SPEED_MULTIPLIER: f32 = 1.5;
+const GRID_DIVE_SPEED_MULTIPLIER: f32 = 2.2;
+const GRID_SLOT_SNAP_DISTANCE: f32 = 4.0;
+const GRID_DIVE_ARRIVE_DISTANCE: f32 = 12.0;
+
+/// How quickly colonies lose their nerve when members are killed
+#[derive(Resource, Clone)]
+pub struct ColonyMoraleConfig {
+    pub loss_window: f32,
+    pub loss_ratio: f32,
+    pub panic_duration: f32,
+    pub rout_duration: f32,         // How long members scatter after their leader dies
+    pub rout_speed_multiplier: f32,
+}
+
+impl Default for ColonyMoraleConfig {
+    fn default() -> Self {
+        Self {
+            loss_window: 3.0,
+            loss_ratio: 0.5,
+            panic_duration: 3.5,
+            rout_duration: 2.0,
+            rout_speed_multiplier: 1.8,
+        }
+    }
+}
+
+pub const ENEMY_COMBAT_TUNING_PATH: &str = "enemy_combat_tuning.json";
+
+/// How hostile turret fire scales with `WaveManager::difficulty_multiplier`.
+/// Each factor grows by its `*_per_difficulty` for every point above 1.0 and stops at its cap
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnemyCombatTuning {
+    pub enabled: bool,
+    pub speed_per_difficulty: f32,
+    pub max_speed_multiplier: f32,
+    pub damage_per_difficulty: f32,
+    pub max_damage_multiplier: f32,
+    pub cadence_per_difficulty: f32,
+    pub max_cadence_multiplier: f32,
+    pub spread_per_difficulty: f32, // Radians added to fan spreads
+    pub max_extra_spread: f32,
+}
+
+impl Default for EnemyCombatTuning {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            speed_per_difficulty: 0.25,
+            max_speed_multiplier: 1.5,
+            damage_per_difficulty: 0.35,
+            max_damage_multiplier: 2.0,
+            cadence_per_difficulty: 0.3,
+            max_cadence_multiplier: 1.6,
+            spread_per_difficulty: 0.1,
+            max_extra_spread: 0.25,
+        }
+    }
+}
+
+impl EnemyCombatTuning {
+    /// Designer overrides from `path`, defaults when the file is missing or malformed
+    pub fn load_or_default(path: &str) -> Self {
+        let Ok(data) = std::fs::read_to_string(path) else { return Self::default() };
+        serde_json::from_str(&data).unwrap_or_else(|e| {
+            warn!("Ignoring {}: {}", path, e);
+            Self::default()
+        })
+    }
+
+    fn factor(&self, per_difficulty: f32, cap: f32, difficulty: f32) -> f32 {
+        if !self.enabled { return 1.0; }
+        (1.0 + (difficulty - 1.0).max(0.0) * per_difficulty).min(cap.max(1.0))
+    }
+
+    pub fn speed_multiplier(&self, difficulty: f32) -> f32 {
+        self.factor(self.speed_per_difficulty, self.max_speed_multiplier, difficulty)
+    }
+
+    pub fn damage_multiplier(&self, difficulty: f32) -> f32 {
+        self.factor(self.damage_per_difficulty, self.max_damage_multiplier, difficulty)
+    }
+
+    pub fn cadence_multiplier(&self, difficulty: f32) -> f32 {
+        self.factor(self.cadence_per_difficulty, self.max_cadence_multiplier, difficulty)
+    }
+
+    pub fn extra_spread(&self, difficulty: f32) -> f32 {
+        if !self.enabled { return 0.0; }
+        ((difficulty - 1.0).max(0.0) * self.spread_per_difficulty).min(self.max_extra_spread)
+    }
+}
+
+pub const ENEMY_ARCHETYPES_PATH: &str = "assets/enemy_archetypes.ron";
+
+/// Designer-facing movement stats for one enemy type.
+/// `detection_range` and `sensitivity` are left `None` to keep whatever the spawn asked for
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EnemyArchetype {
+    pub speed: f32,
+    pub undulation_amplitude: f32,
+    #[serde(default)]
+    pub detection_range: Option<f32>, // Turrets
+    #[serde(default)]
+    pub sensitivity: Option<f32>,     // Chemotaxis and fluid-flow steering
+}
+
+impl EnemyArchetype {
+    /// The values that were baked into the code before archetypes existed
+    pub fn built_in(enemy_type: EnemyType) -> Self {
+        let (_, _, speed, _) = enemy_type.get_stats();
+        Self {
+            speed,
+            undulation_amplitude: UNDULATION_AMPLITUDE,
+            detection_range: None,
+            sensitivity: None,
+        }
+    }
+
+    /// Copy of `ai` with this archetype's overrides written in
+    pub fn apply_to(&self, ai: &EnemyAI) -> EnemyAI {
+        let mut applied = ai.clone();
+        match &mut applied {
+            EnemyAI::Turret { detection_range, .. } => {
+                if let Some(range) = self.detection_range { *detection_range = range; }
+            }
+            EnemyAI::Chemotaxis { sensitivity, .. } | EnemyAI::FluidFlow { flow_sensitivity: sensitivity, .. } => {
+                if let Some(value) = self.sensitivity { *sensitivity = value; }
+            }
+            _ => {}
+        }
+        applied
+    }
+}
+
+/// Archetype per enemy type, types the table doesn't list use `EnemyArchetype::built_in`
+#[derive(Resource, Clone, Default)]
+pub struct EnemyArchetypes {
+    pub table: HashMap<EnemyType, EnemyArchetype>,
+}
+
+impl EnemyArchetypes {
+    /// Table from the RON file at `path`, built-in values when it is missing or malformed
+    pub fn load_or_default(path: &str) -> Self {
+        let Ok(data) = std::fs::read_to_string(path) else { return Self::default() };
+        match ron::from_str(&data) {
+            Ok(table) => Self { table },
+            Err(e) => {
+                warn!("Ignoring {}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn get(&self, enemy_type: EnemyType) -> EnemyArchetype {
+        self.table.get(&enemy_type).cloned().unwrap_or_else(|| EnemyArchetype::built_in(enemy_type))
+    }
+}
+
+/// Reproductive vesicles lay egg sacs instead of spawning offspring directly
+#[derive(Resource, Clone)]
+pub struct EggSacConfig {
+    pub enabled: bool,
+    pub hatch_time: f32,
+    pub offspring_per_sac: u32,
+    pub health: i32,
+    pub atp_reward: u32,
+}
+
+impl Default for EggSacConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            hatch_time: 4.0,
+            offspring_per_sac: 3,
+            health: 30,
+            atp_reward: 6,
+        }
+    }
+}
+
+/// Missile dodging for `EvasiveManeuver` enemies. A failed roll still starts the cooldown,
+/// so `dodge_chance` is roughly the share of close passes that miss
+#[derive(Resource, Clone)]
+pub struct EvasionConfig {
+    pub enabled: bool,
+    pub dodge_chance: f32,
+}
+
+impl Default for EvasionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            dodge_chance: 0.5,
+        }
+    }
+}
+
+/// Shared sway and dive scheduling for `EnemyAI::GridMember` enemies
+#[derive(Resource, Clone)]
+pub struct GridFormation {
+    pub origin: Vec2,          // Centre of the grid's top row
+    pub slot_spacing: Vec2,
+    pub columns: u32,
+    pub sway_amplitude: f32,
+    pub sway_speed: f32,
+    pub sway_phase: f32,
+    pub dive_interval: f32,
+    pub dive_timer: f32,
+    pub max_divers: usize,
+    pub rejoin_chance: f32,    // Otherwise divers carry on off-screen
+}
+
+impl Default for GridFormation {
+    fn default() -> Self {
+        Self {
+            origin: Vec2::new(0.0, 280.0),
+            slot_spacing: Vec2::new(56.0, 48.0),
+            columns: 8,
+            sway_amplitude: 70.0,
+            sway_speed: 0.8,
+            sway_phase: 0.0,
+            dive_interval: 2.5,
+            dive_timer: 2.5,
+            max_divers: 2,
+            rejoin_chance: 0.6,
+        }
+    }
+}
+
+impl GridFormation {
+    pub fn slot_position(&self, home_slot: UVec2) -> Vec2 {
+        let centred_column = home_slot.x as f32 - (self.columns.saturating_sub(1)) as f32 / 2.0;
+        let sway = self.sway_phase.sin() * self.sway_amplitude;
+        Vec2::new(
+            self.origin.x + centred_column * self.slot_spacing.x + sway,
+            self.origin.y - home_slot.y as f32 * self.slot_spacing.y,
+        )
+    }
+}
+
+/// Advance one grid member and return its new position
+pub fn grid_member_step(state: &mut GridMemberState, position: Vec2, slot_pos: Vec2, speed: f32, dt: f32) -> Vec2 {
+    let move_toward = |target: Vec2, step: f32| {
+        let offset = target - position;
+        if offset.length() <= step { target } else { position + offset.normalize() * step }
+    };
+
+    match state {
+        GridMemberState::Assembling | GridMemberState::Returning => {
+            let next = move_toward(slot_pos, speed * GRID_ASSEMBLE_SPEED_MULTIPLIER * dt);
+            if next.distance(slot_pos) <= GRID_SLOT_SNAP_DISTANCE {
+                *state = GridMemberState::Holding;
+            }
+            next
+        }
+        GridMemberState::Holding => slot_pos,
+        GridMemberState::Diving { target, rejoin } => {
+            let next = move_toward(*target, speed * GRID_DIVE_SPEED_MULTIPLIER * dt);
+            if next.distance(*target) <= GRID_DIVE_ARRIVE_DISTANCE {
+                *state = if *rejoin { GridMemberState::Returning } else { GridMemberState::Exiting };
+            }
+            next
+        }
+        GridMemberState::Exiting => position - Vec2::Y * speed * GRID_DIVE_SPEED_MULTIPLIER * dt,
+    }
+}
+
+/// Sway the grid and periodically send a holding member diving at the player
+pub fn grid_formation_system(
+    mut formation: ResMut<GridFormation>,
+    mut enemy_query: Query<&mut Enemy, Without<PendingDespawn>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    formation.sway_phase += formation.sway_speed * dt;
+
+    formation.dive_timer -= dt;
+    if formation.dive_timer > 0.0 { return; }
+    formation.dive_timer = formation.dive_interval;
+
+    let Ok(player_transform) = player_query.single() else { return };
+    let player_pos = player_transform.translation.truncate();
+
+    let mut divers = 0;
+    let mut candidates = Vec::new();
+    for (index, enemy) in enemy_query.iter().enumerate() {
+        match &enemy.ai_type {
+            EnemyAI::GridMember { state: GridMemberState::Diving { .. }, .. } => divers += 1,
+            EnemyAI::GridMember { state: GridMemberState::Holding, .. } => candidates.push(index),
+            _ => {}
+        }
+    }
+    if divers >= formation.max_divers || candidates.is_empty() { return; }
+
+    let chosen = candidates[rand::random_range(0..candidates.len())];
+    let rejoin = rand::random::<f32>() < formation.rejoin_chance;
+    if let Some(mut enemy) = enemy_query.iter_mut().nth(chosen) && let EnemyAI::GridMember { state, .. } = &mut enemy.ai_type {
+        *state = GridMemberState::Diving { target: player_pos, rejoin };
+    }
+}
+
+// ===== SPATIAL GRID =====
+/// Bucketed enemy positions, rebuilt every frame for cheap neighbour lookups
+#[derive(Resource, Default)]
+pub struct EnemySpatialGrid {
+    pub cells: HashMap<(i32, i32), Vec<(Entity, Vec2)>>,
+}
+
+impl EnemySpatialGrid {
+    pub fn cell_of(pos: Vec2) -> (i32, i32) {
+        ((pos.x / SPATIAL_GRID_CELL_SIZE).floor() as i32, (pos.y / SPATIAL_GRID_CELL_SIZE).floor() as i32)
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.values_mut().for_each(|cell| cell.clear());
+    }
+
+    pub fn insert(&mut self, entity: Entity, pos: Vec2) {
+        self.cells.entry(Self::cell_of(pos)).or_default().push((entity, pos));
+    }
+
+    /// All entities within `radius` of `pos`
+    pub fn query_radius(&self, pos: Vec2, radius: f32) -> Vec<(Entity, Vec2)> {
+        let (min_x, min_y) = Self::cell_of(pos - Vec2::splat(radius));
+        let (max_x, max_y) = Self::cell_of(pos + Vec2::splat(radius));
+        let radius_sq = radius * radius;
+
+        let mut found = Vec::new();
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                if let Some(cell) = self.cells.get(&(x, y)) {
+                    found.extend(cell.iter().filter(|(_, p)| p.distance_squared(pos) <= radius_sq).copied());
+                }
+            }
+        }
+        found
+    }
+}
+
+// ===== FLOCKING =====
+/// A flocking enemy as it was at the start of the frame
+#[derive(Clone, Copy, Debug)]
+pub struct Boid {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub heading: Vec2,
+    pub enemy_type: EnemyType,
+}
+
+/// Items bucketed on the spatial grid's cells, rebuilt once per frame inside `move_enemies`
+pub struct CellGrid<T> {
+    cells: HashMap<(i32, i32), Vec<T>>,
+}
+
+impl<T> Default for CellGrid<T> {
+    fn default() -> Self {
+        Self { cells: HashMap::new() }
+    }
+}
+
+impl<T> CellGrid<T> {
+    pub fn clear(&mut self) {
+        self.cells.values_mut().for_each(|cell| cell.clear());
+    }
+
+    pub fn insert(&mut self, pos: Vec2, item: T) {
+        self.cells.entry(EnemySpatialGrid::cell_of(pos)).or_default().push(item);
+    }
+
+    /// Everything in the cells a `radius` around `pos` overlaps, callers do their own exact distance check
+    pub fn near(&self, pos: Vec2, radius: f32) -> impl Iterator<Item = &T> + '_ {
+        let (min_x, min_y) = EnemySpatialGrid::cell_of(pos - Vec2::splat(radius));
+        let (max_x, max_y) = EnemySpatialGrid::cell_of(pos + Vec2::splat(radius));
+        (min_x..=max_x)
+            .flat_map(move |x| (min_y..=max_y).map(move |y| (x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+    }
+}
+
+pub type FlockGrid = CellGrid<Boid>;
+
+impl FlockGrid {
+    /// Other boids of the same type within `radius`
+    pub fn neighbors(&self, boid: &Boid, radius: f32) -> impl Iterator<Item = &Boid> + '_ {
+        let (entity, position, enemy_type) = (boid.entity, boid.position, boid.enemy_type);
+        let radius_sq = radius * radius;
+        self.near(position, radius).filter(move |other| {
+            other.entity != entity
+                && other.enemy_type == enemy_type
+                && other.position.distance_squared(position) <= radius_sq
+        })
+    }
+}
+
+// ===== CORAL AVOIDANCE =====
+#[derive(Resource, Clone)]
+pub struct CoralAvoidanceConfig {
+    pub enabled: bool,
+    pub margin: f32,                      // Clearance kept from a coral's edge
+    pub repulsion_strength: f32,
+    pub decomposers_seek_harmful: bool,   // Decomposers gather around corrupted coral instead of avoiding it
+    pub attraction_strength: f32,
+}
+
+impl Default for CoralAvoidanceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            margin: 20.0,
+            repulsion_strength: 160.0,
+            decomposers_seek_harmful: true,
+            attraction_strength: 40.0,
+        }
+    }
+}
+
+/// A coral formation as enemies see it
+#[derive(Clone, Copy, Debug)]
+pub struct CoralObstacle {
+    pub position: Vec2,
+    pub radius: f32,
+    pub influence_radius: f32,
+    pub harmful: bool,
+}
+
+/// Push away from every coral `pos` is about to overlap, stronger the deeper it is.
+/// With `seek_harmful`, harmful coral pulls from within its influence radius instead
+pub fn coral_steering<'a>(pos: Vec2, corals: impl Iterator<Item = &'a CoralObstacle>, config: &CoralAvoidanceConfig, seek_harmful: bool) -> Vec2 {
+    let mut steer = Vec2::ZERO;
+    for coral in corals {
+        let offset = pos - coral.position;
+        let distance = offset.length();
+        if seek_harmful && coral.harmful {
+            // Settle at the edge rather than inside it
+            if distance > coral.radius && distance < coral.influence_radius {
+                steer -= offset.normalize_or_zero() * config.attraction_strength;
+            }
+            continue;
+        }
+        let clearance = coral.radius + config.margin;
+        if distance < clearance {
+            let away = offset.try_normalize().unwrap_or(Vec2::Y);
+            steer += away * (1.0 - distance / clearance) * config.repulsion_strength;
+        }
+    }
+    steer
+}
+
+/// Weighted sum of the three boid rules: keep apart, match heading, move towards the group's centre
+pub fn flocking_steer<'a>(boid: &Boid, neighbors: impl Iterator<Item = &'a Boid>, separation: f32, alignment: f32, cohesion: f32) -> Vec2 {
+    let mut away = Vec2::ZERO;
+    let mut heading_sum = Vec2::ZERO;
+    let mut centre_sum = Vec2::ZERO;
+    let mut count = 0;
+
+    for other in neighbors {
+        let offset = boid.position - other.position;
+        // Closer neighbours push harder
+        away += offset / offset.length_squared().max(1.0);
+        heading_sum += other.heading;
+        centre_sum += other.position;
+        count += 1;
+    }
+    if count == 0 { return Vec2::ZERO; }
+
+    let n = count as f32;
+    let separation_force = away.normalize_or_zero() * separation;
+    let alignment_force = (heading_sum / n - boid.heading) * alignment;
+    let cohesion_force = (centre_sum / n - boid.position).normalize_or_zero() * cohesion;
+    separation_force + alignment_force + cohesion_force
+}
 
 // ===== HELPER FUNCTIONS =====
 fn apply_organic_undulation(transform: &mut Transform, time: f32, amplitude: f32) {
@@ -32,6 +532,57 @@ fn apply_current_influence(transform: &mut Transform, fluid_env: &FluidEnvironme
     transform.translation += (current * influence).extend(0.0) * dt;
 }
 
+/// Where a diver leaving `from` at `speed` meets a target moving at `target_velocity`.
+/// Falls back to the target's current position when the diver can't catch it
+pub fn predict_intercept(from: Vec2, speed: f32, target: Vec2, target_velocity: Vec2) -> Vec2 {
+    // Solve |offset + velocity * t| = speed * t for the earliest positive t
+    let offset = target - from;
+    let a = target_velocity.length_squared() - speed * speed;
+    let b = 2.0 * offset.dot(target_velocity);
+    let c = offset.length_squared();
+
+    let time = if a.abs() < f32::EPSILON {
+        (b < 0.0).then(|| -c / b)
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            None
+        } else {
+            let root = discriminant.sqrt();
+            [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+                .into_iter()
+                .filter(|t| *t > 0.0)
+                .reduce(f32::min)
+        }
+    };
+
+    match time {
+        Some(t) => target + target_velocity * t.min(KAMIKAZE_MAX_LEAD_TIME),
+        None => target,
+    }
+}
+
+/// Velocity for a boss pattern `elapsed` seconds after its telegraph ended
+pub fn boss_pattern_velocity(movement: BossMovement, elapsed: f32, pos: Vec2, target: Option<Vec2>) -> Vec2 {
+    match movement {
+        BossMovement::FigureEight { width, height } => {
+            // Derivative of a 1:2 Lissajous curve, so the path closes on itself
+            let w = BOSS_FIGURE_EIGHT_RATE;
+            Vec2::new(width * w * (elapsed * w).cos(), height * 2.0 * w * (elapsed * 2.0 * w).cos())
+        }
+        BossMovement::Charge { speed } => {
+            if elapsed % BOSS_CHARGE_CYCLE < BOSS_CHARGE_LUNGE {
+                target.map_or(Vec2::NEG_Y, |target| (target - pos).normalize_or_zero()) * speed
+            } else {
+                Vec2::new(-pos.x * 0.5, BOSS_HOVER_Y - pos.y) * 1.5
+            }
+        }
+        BossMovement::Retreat { hover_y } => {
+            Vec2::new((elapsed * 2.0).cos() * 180.0, (hover_y - pos.y) * 1.2)
+        }
+    }
+}
+
 fn get_chemical_avoidance(pos: Vec2, chemical_env: &ChemicalEnvironment, enemy: &Enemy) -> Vec2 {
     let local_ph = sample_ph(chemical_env, pos);
     let ph_diff = (local_ph - enemy.chemical_signature.ph_preference).abs();
@@ -51,17 +602,55 @@ fn get_chemical_avoidance(pos: Vec2, chemical_env: &ChemicalEnvironment, enemy:
 // ===== MAIN SYSTEMS =====
 
 pub fn move_enemies(
-    mut enemy_query: Query<(&mut Transform, &mut Enemy)>,
-    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut enemy_query: Query<(Entity, &mut Transform, &mut Enemy, Has<Frozen>, Has<HitStop>, Option<&Berserk>, Option<&Health>, Option<&EcosystemRole>)>,
+    player_query: Query<(&Transform, Has<Stealthed>, Option<&FluidDynamics>), (With<Player>, Without<Enemy>)>,
+    (coral_query, coral_config): (Query<(&Transform, &EnhancedCoral), Without<Enemy>>, Res<CoralAvoidanceConfig>),
     colony_leader_query: Query<&Transform, (With<ColonyLeader>, Without<Enemy>, Without<Player>)>,
     fluid_environment: Res<FluidEnvironment>,
     chemical_environment: Res<ChemicalEnvironment>,
+    grid_formation: Res<GridFormation>,
+    (wave_manager, speed_ramp): (Res<WaveManager>, Res<WaveSpeedRamp>),
+    archetypes: Res<EnemyArchetypes>,
     time: Res<Time>,
+    mut phase_events: EventWriter<BossPhaseChanged>,
+    mut cue_events: EventWriter<TelegraphCue>,
+    mut flock_grid: Local<FlockGrid>,
+    mut coral_grid: Local<CellGrid<CoralObstacle>>,
 ) {
-    let player_pos = player_query.single().ok().map(|t| t.translation.truncate());
-    let dt = time.delta_secs();
+    let player = player_query.single().ok();
+    let player_pos = player.map(|(t, ..)| t.translation.truncate());
+    let player_velocity = player.and_then(|(_, _, fluid)| fluid).map_or(Vec2::ZERO, |fluid| fluid.velocity);
+    let tracked_pos = StealthAbility::trackable_position(player_pos, player.is_some_and(|(_, stealthed, _)| stealthed));
+    let wave_elapsed = if wave_manager.wave_active { time.elapsed_secs() - wave_manager.wave_start_time } else { 0.0 };
+    let dt = time.delta_secs() * speed_ramp.multiplier(wave_elapsed);
+    
+    flock_grid.clear();
+    for (entity, transform, enemy, ..) in enemy_query.iter() {
+        if let EnemyAI::Flocking { heading, .. } = enemy.ai_type {
+            let position = transform.translation.truncate();
+            flock_grid.insert(position, Boid { entity, position, heading, enemy_type: enemy.enemy_type });
+        }
+    }
+    
+    coral_grid.clear();
+    let mut coral_reach: f32 = 0.0;
+    if coral_config.enabled {
+        for (coral_transform, coral) in coral_query.iter() {
+            let radius = coral.size.max_element() * 0.5;
+            let obstacle = CoralObstacle {
+                position: coral_transform.translation.truncate(),
+                radius,
+                influence_radius: coral.influence_radius,
+                harmful: matches!(coral.gameplay_effect, CoralEffect::Harmful { .. }),
+            };
+            coral_reach = coral_reach.max(radius + coral_config.margin).max(obstacle.influence_radius);
+            coral_grid.insert(obstacle.position, obstacle);
+        }
+    }
     
-    for (mut transform, mut enemy) in enemy_query.iter_mut() {
+    for (entity, mut transform, mut enemy, frozen, hit_stopped, berserk, health, role) in enemy_query.iter_mut() {
+        if frozen || hit_stopped { continue; }
+        let dt = berserk.map_or(dt, |rage| dt * rage.speed_multiplier);
         
         let enemy_clone = enemy.clone();
 
@@ -69,7 +658,7 @@ pub fn move_enemies(
             EnemyAI::Static => {}
             
             EnemyAI::Linear { direction } => {
-                apply_organic_undulation(&mut transform, time.elapsed_secs(), UNDULATION_AMPLITUDE);
+                apply_organic_undulation(&mut transform, time.elapsed_secs(), archetypes.get(enemy_clone.enemy_type).undulation_amplitude);
                 let movement = direction.extend(0.0);
                 transform.translation += movement * enemy.speed * dt;
                 apply_current_influence(&mut transform, &fluid_environment, 0.3, dt);
@@ -85,18 +674,49 @@ pub fn move_enemies(
                 apply_current_influence(&mut transform, &fluid_environment, CURRENT_INFLUENCE_WEAK, dt);
             }
             
-            EnemyAI::MiniBoss { timer, .. } => {
-                *timer += dt;
+            EnemyAI::MiniBoss { pattern: phase, timer } => {
+                // Second phase weaves faster and wider
+                let intensity = if *phase > 0 { 1.6 } else { 1.0 };
+                *timer += dt * intensity;
                 transform.translation.y -= enemy_clone.speed * 0.6 * dt;
-                let pattern = Vec2::new((*timer * 0.8).sin() * 100.0, (*timer * 0.5).cos() * 30.0);
+                let pattern = Vec2::new((*timer * 0.8).sin() * 100.0, (*timer * 0.5).cos() * 30.0) * intensity;
                 transform.translation += pattern.extend(0.0) * dt;
             }
             
-            EnemyAI::Kamikaze { target_pos, dive_speed, acquired_target } => {
+            EnemyAI::Boss { phase, phase_timer, patterns } => {
+                let health_fraction = health.map_or(1.0, |health| health.0 as f32 / enemy_clone.health.max(1) as f32);
+                let next_phase = boss_phase_for_health(health_fraction, patterns.len());
+                if next_phase > *phase {
+                    *phase = next_phase;
+                    *phase_timer = 0.0;
+                    if let Some(pattern) = patterns.get(next_phase) {
+                        phase_events.write(BossPhaseChanged { entity, phase: next_phase, movement: pattern.movement, position: transform.translation });
+                        cue_events.write(TelegraphCue { kind: TelegraphCueKind::BossPhaseShift, position: transform.translation });
+                    }
+                }
+                *phase_timer += dt;
+
+                let pos = transform.translation.truncate();
+                let velocity = match patterns.get(*phase) {
+                    // Drift in from the top before the fight starts
+                    _ if pos.y > BOSS_HOVER_Y && *phase == 0 => Vec2::new(0.0, -enemy_clone.speed * 0.6),
+                    Some(pattern) if *phase_timer < pattern.telegraph => {
+                        Vec2::new((*phase_timer * 40.0).sin() * BOSS_TELEGRAPH_SHAKE, 0.0)
+                    }
+                    Some(pattern) => boss_pattern_velocity(pattern.movement, *phase_timer - pattern.telegraph, pos, tracked_pos),
+                    None => Vec2::ZERO,
+                };
+                transform.translation += velocity.extend(0.0) * dt;
+            }
+            
+            EnemyAI::Kamikaze { target_pos, dive_speed, acquired_target, retarget_interval, retarget_timer } => {
                 if let Some(player_pos) = player_pos {
-                    if !*acquired_target {
-                        *target_pos = player_pos;
+                    // Lead the player on acquisition, then re-aim a few times over a long dive
+                    *retarget_timer -= dt;
+                    if !*acquired_target || *retarget_timer <= 0.0 {
+                        *target_pos = predict_intercept(transform.translation.truncate(), *dive_speed, player_pos, player_velocity);
                         *acquired_target = true;
+                        *retarget_timer = *retarget_interval;
                     }
                     let direction = (*target_pos - transform.translation.truncate()).normalize_or_zero();
                     transform.translation += direction.extend(0.0) * *dive_speed * dt;
@@ -138,18 +758,24 @@ pub fn move_enemies(
             }
             
             EnemyAI::Chemotaxis { target_chemical, sensitivity, current_direction } => {
-                if let Some(player_pos) = player_pos {
-                    let distance = transform.translation.distance(player_pos.extend(0.0));
-                    if distance < 350.0 {
-                        let dir_to_player = (player_pos - transform.translation.truncate()).normalize_or_zero();
-                        let chemical_strength = match target_chemical {
-                            ChemicalType::PlayerPheromones => 1.0 / (distance * 0.01 + 1.0),
-                            ChemicalType::OxygenSeeker => sample_oxygen(&chemical_environment, transform.translation.truncate()),
-                            _ => 0.5,
+                // A cloaked player can't be tracked, hunters wander until the cloak drops
+                let pursuit = tracked_pos
+                    .map(|pos| (pos, transform.translation.distance(pos.extend(0.0))))
+                    .filter(|(_, distance)| *distance < 350.0);
+                match pursuit {
+                    Some((player_pos, distance)) => {
+                        let pos = transform.translation.truncate();
+                        let dir_to_player = (player_pos - pos).normalize_or_zero();
+                        let (gradient, chemical_strength) = match target_chemical {
+                            ChemicalType::PlayerPheromones | ChemicalType::PlayerAvoider => (dir_to_player, 1.0 / (distance * 0.01 + 1.0)),
+                            ChemicalType::OxygenSeeker => (dir_to_player, sample_oxygen(&chemical_environment, pos)),
+                            ChemicalType::OxygenAvoider => (oxygen_gradient(&chemical_environment, pos), sample_oxygen(&chemical_environment, pos)),
+                            _ => (dir_to_player, 0.5),
                         };
                         
-                        let influence = chemical_strength * *sensitivity;
-                        *current_direction = current_direction.lerp(dir_to_player, influence * dt);
+                        // Negative sensitivity, or an avoider chemical, turns the attraction into repulsion
+                        let influence = chemical_strength * *sensitivity * target_chemical.steering_sign();
+                        *current_direction = chemotaxis_steer(*current_direction, gradient, influence, dt);
                         
                         let random_influence = Vec2::new(
                             (time.elapsed_secs() * 3.2 + transform.translation.x * 0.01).sin() * 0.2,
@@ -157,7 +783,8 @@ pub fn move_enemies(
                         );
                         *current_direction = (*current_direction + random_influence).normalize_or_zero();
                         transform.translation += current_direction.extend(0.0) * enemy.speed * dt;
-                    } else {
+                    }
+                    None => {
                         let random_turn = (time.elapsed_secs() * 2.5 + transform.translation.x * 0.005).sin();
                         *current_direction = Vec2::from_angle(current_direction.to_angle() + random_turn * 0.8 * dt);
                         transform.translation += current_direction.extend(0.0) * enemy.speed * 0.6 * dt;
@@ -196,6 +823,42 @@ pub fn move_enemies(
                 let angle = (current.x * 0.7 + base_direction.x * 0.3).atan2(current.y * 0.7 + base_direction.y * 0.3) - std::f32::consts::FRAC_PI_2;
                 transform.rotation = Quat::from_rotation_z(angle);
             }
+
+            EnemyAI::Flocking { separation, alignment, cohesion, neighbor_radius, heading } => {
+                let boid = Boid { entity, position: transform.translation.truncate(), heading: *heading, enemy_type: enemy_clone.enemy_type };
+                let mut steer = flocking_steer(&boid, flock_grid.neighbors(&boid, *neighbor_radius), *separation, *alignment, *cohesion);
+                if let Some(player_pos) = tracked_pos {
+                    steer += (player_pos - boid.position).normalize_or_zero() * FLOCK_PLAYER_PULL;
+                }
+                *heading = (*heading + steer * FLOCK_TURN_RATE * dt).normalize_or(Vec2::NEG_Y);
+                transform.translation += heading.extend(0.0) * enemy_clone.speed * dt;
+                apply_current_influence(&mut transform, &fluid_environment, CURRENT_INFLUENCE_WEAK, dt);
+            }
+
+            EnemyAI::Healer { .. } => {
+                // Hang back above the fight and drift side to side
+                if transform.translation.y > HEALER_HOVER_Y {
+                    transform.translation.y -= enemy_clone.speed * 0.6 * dt;
+                }
+                transform.translation.x += (time.elapsed_secs() * 0.7).sin() * enemy_clone.speed * 0.4 * dt;
+                apply_current_influence(&mut transform, &fluid_environment, CURRENT_INFLUENCE_WEAK, dt);
+            }
+
+            EnemyAI::GridMember { home_slot, state } => {
+                let slot_pos = grid_formation.slot_position(*home_slot);
+                let next = grid_member_step(state, transform.translation.truncate(), slot_pos, enemy_clone.speed, dt);
+                transform.translation.x = next.x;
+                transform.translation.y = next.y;
+            }
+        }
+        
+        // Steer around coral, decomposers may be drawn to corrupted growths instead
+        if coral_reach > 0.0 {
+            let pos = transform.translation.truncate();
+            let seek_harmful = coral_config.decomposers_seek_harmful
+                && role.is_some_and(|role| matches!(role.role, EcosystemRoleType::Decomposer));
+            let steer = coral_steering(pos, coral_grid.near(pos, coral_reach), &coral_config, seek_harmful);
+            transform.translation += steer.extend(0.0) * dt;
         }
         
         // Apply chemical effects
@@ -214,10 +877,60 @@ pub fn move_enemies(
     }
 }
 
+pub fn update_enemy_spatial_grid(
+    mut grid: ResMut<EnemySpatialGrid>,
+    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<PendingDespawn>)>,
+) {
+    grid.clear();
+    for (entity, transform) in enemy_query.iter() {
+        grid.insert(entity, transform.translation.truncate());
+    }
+}
+
+pub fn healer_system(
+    grid: Res<EnemySpatialGrid>,
+    mut enemy_query: Query<(Entity, &Transform, &Enemy, &mut Health), (Without<PendingDespawn>, Without<AlreadyDespawned>)>,
+    time: Res<Time>,
+    mut pulse_timer: Local<f32>,
+) {
+    *pulse_timer += time.delta_secs();
+    if *pulse_timer < HEALER_PULSE_INTERVAL { return; }
+    let pulse = std::mem::take(&mut *pulse_timer);
+
+    // Only living healers pulse, so killing one stops the regeneration immediately
+    let healers: Vec<(Entity, Vec2, f32, f32)> = enemy_query.iter()
+        .filter(|(_, _, _, health)| health.0 > 0)
+        .filter_map(|(entity, transform, enemy, _)| match enemy.ai_type {
+            EnemyAI::Healer { heal_rate, aura_radius } => Some((entity, transform.translation.truncate(), heal_rate, aura_radius)),
+            _ => None,
+        })
+        .collect();
+
+    for (healer_entity, healer_pos, heal_rate, aura_radius) in healers {
+        let heal_amount = (heal_rate * pulse).round() as i32;
+        for (ally_entity, _) in grid.query_radius(healer_pos, aura_radius) {
+            if ally_entity == healer_entity { continue; }
+            if let Ok((_, _, ally, mut ally_health)) = enemy_query.get_mut(ally_entity) {
+                ally_health.0 = heal_capped(ally_health.0, heal_amount, ally.health);
+            }
+        }
+    }
+}
+
+/// Adds `amount` to `current` without exceeding `max`; dead enemies are not revived
+pub fn heal_capped(current: i32, amount: i32, max: i32) -> i32 {
+    if current <= 0 || current >= max {
+        return current;
+    }
+    (current + amount).min(max)
+}
+
 pub fn update_spawner_enemies(
     mut commands: Commands,
     mut spawner_query: Query<(Entity, &Transform, &mut Enemy)>,
     mut spawn_events: EventWriter<SpawnEnemy>,
+    egg_config: Res<EggSacConfig>,
+    assets: Option<Res<GameAssets>>,
     time: Res<Time>,
 ) {
     for (_, transform, mut enemy) in spawner_query.iter_mut() {
@@ -228,140 +941,699 @@ pub fn update_spawner_enemies(
                 let spawn_angle = (*minions_spawned as f32 * 1.2) + (time.elapsed_secs() * 0.5).sin();
                 let spawn_distance = SPAWN_DISTANCE_BASE + (time.elapsed_secs() * 2.0).cos() * 10.0;
                 let spawn_offset = Vec2::from_angle(spawn_angle) * spawn_distance;
+                let position = transform.translation + spawn_offset.extend(0.0);
                 
-                let ai_type = if *minions_spawned % 2 == 0 {
-                    EnemyAI::Chemotaxis {
-                        target_chemical: ChemicalType::PlayerPheromones,
-                        sensitivity: 1.0,
-                        current_direction: Vec2::new(0.0, -1.0),
+                match (&assets, egg_config.enabled) {
+                    // Lay a sac the player can pop before it hatches
+                    (Some(assets), true) => {
+                        let offspring = egg_config.offspring_per_sac.min(*max_minions - *minions_spawned);
+                        commands.spawn((
+                            Sprite {
+                                image: assets.particle_texture.clone(),
+                                color: Color::srgba(0.9, 0.5, 0.8, 0.9),
+                                custom_size: Some(Vec2::splat(22.0)),
+                                ..default()
+                            },
+                            Transform::from_translation(position),
+                            EggSac {
+                                hatch_timer: egg_config.hatch_time,
+                                hatch_duration: egg_config.hatch_time,
+                                offspring,
+                                atp_reward: egg_config.atp_reward,
+                            },
+                            Health(egg_config.health),
+                            Collider { radius: 11.0 },
+                        ));
+                        *minions_spawned += offspring;
                     }
-                } else {
-                    EnemyAI::FluidFlow {
-                        flow_sensitivity: 1.5,
-                        base_direction: Vec2::new(spawn_angle.cos() * 0.3, -0.9).normalize(),
+                    _ => {
+                        spawn_events.write(SpawnEnemy {
+                            position,
+                            ai_type: offspring_ai(*minions_spawned, spawn_angle),
+                            enemy_type: EnemyType::Offspring,
+                        });
+                        *minions_spawned += 1;
                     }
-                };
-                
-                spawn_events.write(SpawnEnemy {
-                    position: transform.translation + spawn_offset.extend(0.0),
-                    ai_type,
-                    enemy_type: EnemyType::Offspring,
-                });
+                }
                 
-                *minions_spawned += 1;
                 *spawn_timer = *spawn_rate * (0.8 + (time.elapsed_secs() * 0.3).sin() * 0.2);
             }
         }
     }
 }
 
-pub fn turret_shooting(
+/// Alternate hunters and drifters so a brood doesn't move as one
+fn offspring_ai(index: u32, spawn_angle: f32) -> EnemyAI {
+    if index.is_multiple_of(2) {
+        EnemyAI::Chemotaxis {
+            target_chemical: ChemicalType::PlayerPheromones,
+            sensitivity: 1.0,
+            current_direction: Vec2::new(0.0, -1.0),
+        }
+    } else {
+        EnemyAI::FluidFlow {
+            flow_sensitivity: 1.5,
+            base_direction: Vec2::new(spawn_angle.cos() * 0.3, -0.9).normalize(),
+        }
+    }
+}
+
+/// Count egg sacs down, swelling as they get close, and hatch them into offspring
+pub fn egg_sac_hatch_system(
+    mut commands: Commands,
+    mut sac_query: Query<(Entity, &mut Transform, &mut EggSac), (Without<PendingDespawn>, Without<AlreadyDespawned>)>,
+    mut spawn_events: EventWriter<SpawnEnemy>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut sac) in sac_query.iter_mut() {
+        sac.hatch_timer -= time.delta_secs();
+        
+        let progress = 1.0 - (sac.hatch_timer / sac.hatch_duration).clamp(0.0, 1.0);
+        let pulse = (time.elapsed_secs() * (4.0 + progress * 12.0)).sin() * 0.08 * progress;
+        transform.scale = Vec3::splat(1.0 + progress * 0.4 + pulse);
+        
+        if sac.hatch_timer > 0.0 { continue; }
+        
+        for i in 0..sac.offspring {
+            let angle = i as f32 / sac.offspring.max(1) as f32 * std::f32::consts::TAU;
+            spawn_events.write(SpawnEnemy {
+                position: transform.translation + (Vec2::from_angle(angle) * 12.0).extend(0.0),
+                ai_type: offspring_ai(i, angle),
+                enemy_type: EnemyType::Offspring,
+            });
+        }
+        commands.entity(entity).try_insert(AlreadyDespawned).safe_despawn();
+    }
+}
+
+/// Spawn an aim line on `shooter` towards `direction` that grows over `duration`
+pub fn spawn_attack_telegraph(commands: &mut Commands, texture: &Handle<Image>, shooter: Entity, direction: Vec2, tracks_player: bool, color: Color, duration: f32) {
+    commands.spawn((
+        Sprite {
+            image: texture.clone(),
+            color: color.with_alpha(0.0),
+            custom_size: Some(Vec2::new(TELEGRAPH_LINE_WIDTH, 0.0)),
+            anchor: bevy::sprite::Anchor::BottomCenter,
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, -0.1),
+        AttackTelegraph { elapsed: 0.0, duration, length: TELEGRAPH_LINE_LENGTH, direction, tracks_player },
+        ChildOf(shooter),
+    ));
+}
+
+/// Grow and brighten aim lines over their windup, keep them pointed where the shot will go, then remove them
+pub fn attack_telegraph_system(
+    mut commands: Commands,
+    mut telegraph_query: Query<(Entity, &mut Sprite, &mut Transform, &GlobalTransform, Option<&ChildOf>, &mut AttackTelegraph)>,
+    shooter_query: Query<&GlobalTransform, Without<AttackTelegraph>>,
+    player_query: Query<&Transform, (With<Player>, Without<AttackTelegraph>)>,
+    time: Res<Time>,
+) {
+    let player_position = player_query.single().ok().map(|transform| transform.translation.truncate());
+
+    for (entity, mut sprite, mut transform, global_transform, child_of, mut telegraph) in telegraph_query.iter_mut() {
+        telegraph.elapsed += time.delta_secs();
+        let shooter = child_of.and_then(|child_of| shooter_query.get(child_of.parent()).ok());
+        let Some(shooter) = shooter.filter(|_| telegraph.elapsed < telegraph.duration) else {
+            commands.entity(entity).safe_despawn();
+            continue;
+        };
+
+        if telegraph.tracks_player && let Some(player_position) = player_position {
+            let aim = (player_position - global_transform.translation().truncate()).normalize_or_zero();
+            if aim != Vec2::ZERO { telegraph.direction = aim; }
+        }
+
+        // Undo the shooter's own rotation and scale so the line is aimed and sized in world space
+        let (shooter_scale, shooter_rotation, _) = shooter.to_scale_rotation_translation();
+        let world_rotation = Quat::from_rotation_z(telegraph.direction.to_angle() - std::f32::consts::FRAC_PI_2);
+        transform.rotation = shooter_rotation.inverse() * world_rotation;
+        transform.scale = Vec3::ONE / shooter_scale.max(Vec3::splat(0.01));
+
+        let progress = telegraph.elapsed / telegraph.duration;
+        sprite.custom_size = Some(Vec2::new(TELEGRAPH_LINE_WIDTH, telegraph.length * progress));
+        sprite.color = sprite.color.with_alpha(0.3 + progress * 0.6);
+    }
+}
+
+pub fn turret_shooting(
+    mut commands: Commands,
+    mut turret_query: Query<(Entity, &Transform, &mut Enemy, Option<&AiTier>), Without<Frozen>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut cue_events: EventWriter<TelegraphCue>,
+    ai_tiers: Res<AiTierConfig>,
+    (wave_manager, tuning): (Res<WaveManager>, Res<EnemyCombatTuning>),
+    assets: Option<Res<GameAssets>>,
+    time: Res<Time>,
+) {
+    let Some(assets) = assets else { return };
+    let Ok(player_transform) = player_query.single() else { return };
+    let difficulty = wave_manager.difficulty_multiplier;
+    
+    for (turret_entity, turret_transform, mut enemy, ai_tier) in turret_query.iter_mut() {
+        let enemy_clone = enemy.clone();
+        if let EnemyAI::Turret { shoot_timer, detection_range, windup_timer, .. } = &mut enemy.ai_type {
+            let (color, damage, speed, pattern) = match enemy_clone.enemy_type {
+                EnemyType::BiofilmColony => (Color::srgb(0.6, 0.8, 0.3), 25, 350.0, BulletPattern::Fan { count: 3, spread: 0.6 + tuning.extra_spread(difficulty) }),
+                _ => (Color::srgb(0.8, 0.4, 0.4), 20, 400.0, BulletPattern::Straight),
+            };
+            let damage = (damage as f32 * tuning.damage_multiplier(difficulty)).round() as i32;
+            let speed = speed * tuning.speed_multiplier(difficulty);
+            let direction = (player_transform.translation.truncate() - turret_transform.translation.truncate()).normalize_or_zero();
+            
+            // Winding up: the volley goes off once the telegraph has played out, even if the player left range
+            if *windup_timer > 0.0 {
+                *windup_timer -= time.delta_secs();
+                if *windup_timer > 0.0 { continue; }
+                
+                fire_pattern(
+                    &mut commands,
+                    &assets.projectile_texture,
+                    pattern,
+                    turret_transform.translation + Vec3::new(0.0, -15.0, 0.0),
+                    direction,
+                    time.elapsed_secs(),
+                    HostileShot {
+                        color,
+                        speed,
+                        damage,
+                        organic_trail: enemy_clone.chemical_signature.releases_toxins,
+                    },
+                );
+                
+                // Veteran turrets and harder waves fire more often
+                let fire_rate = ai_tier.map_or(1.0, |tier| ai_tiers.multiplier(tier.0)) * tuning.cadence_multiplier(difficulty);
+                *shoot_timer = (1.2 + (time.elapsed_secs() * 0.8).sin() * 0.3) / fire_rate;
+                continue;
+            }
+            
+            *shoot_timer -= time.delta_secs();
+            let distance = turret_transform.translation.distance(player_transform.translation);
+            if distance <= *detection_range && *shoot_timer <= 0.0 {
+                *windup_timer = TURRET_TELEGRAPH_DURATION;
+                spawn_attack_telegraph(&mut commands, &assets.particle_texture, turret_entity, direction, true, color, TURRET_TELEGRAPH_DURATION);
+                cue_events.write(TelegraphCue { kind: TelegraphCueKind::TurretCharge, position: turret_transform.translation });
+            }
+        }
+    }
+}
+
+/// Boss entrance: roar and banner as it appears, camera drifts in, then combat begins once the timer runs out
+pub fn boss_intro_system(
+    mut commands: Commands,
+    config: Res<BossIntroConfig>,
+    fonts: Res<GameFonts>,
+    mut boss_query: Query<(Entity, &Transform, &Enemy, &mut BossIntro), (Without<Camera2d>, Without<PendingDespawn>)>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), (With<Camera2d>, Without<Enemy>)>,
+    banner_query: Query<Entity, With<BossIntroBanner>>,
+    mut shake_events: EventWriter<AddScreenShake>,
+    mut cue_events: EventWriter<TelegraphCue>,
+    time: Res<Time>,
+) {
+    let mut focus: Option<(Vec3, f32)> = None;
+    let mut intro_ended = false;
+
+    for (entity, transform, enemy, mut intro) in boss_query.iter_mut() {
+        if intro.is_added() {
+            cue_events.write(TelegraphCue { kind: TelegraphCueKind::BossRoar, position: transform.translation });
+            shake_events.write(AddScreenShake { amount: config.roar_shake });
+            spawn_boss_banner(&mut commands, &fonts, enemy.enemy_type.display_name());
+        }
+
+        if intro.tick(time.delta_secs()) {
+            commands.entity(entity).try_remove::<BossIntro>();
+            intro_ended = true;
+        } else if focus.is_none() {
+            focus = Some((transform.translation, intro.progress()));
+        }
+    }
+
+    if let Ok((mut camera_transform, mut projection)) = camera_query.single_mut() {
+        // Swell in and back out over the intro so the camera is home when combat starts
+        let weight = focus.map_or(0.0, |(_, progress)| (progress * std::f32::consts::PI).sin());
+        if let Some((position, _)) = focus {
+            camera_transform.translation += (position.truncate() * config.camera_pull * weight).extend(0.0);
+        }
+        if (focus.is_some() || intro_ended) && let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale = 1.0 + (config.zoom - 1.0) * weight;
+        }
+    }
+
+    if intro_ended && focus.is_none() {
+        for entity in banner_query.iter() {
+            commands.entity(entity).safe_despawn();
+        }
+    }
+}
+
+fn spawn_boss_banner(commands: &mut Commands, fonts: &GameFonts, name: &str) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(30.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BossIntroBanner,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(format!("- {} -", name.to_uppercase())),
+            TextFont { font: fonts.default_font.clone(), font_size: 42.0, ..default() },
+            TextColor(Color::srgb(1.0, 0.35, 0.6)),
+        ));
+    });
+}
+
+/// Leaving play mid-intro must not leave the banner up or the camera zoomed
+pub fn cleanup_boss_intro(
+    mut commands: Commands,
+    banner_query: Query<Entity, With<BossIntroBanner>>,
+    mut projection_query: Query<&mut Projection, With<Camera2d>>,
+) {
+    if banner_query.is_empty() { return; }
+    for entity in banner_query.iter() {
+        commands.entity(entity).safe_despawn();
+    }
+    for mut projection in projection_query.iter_mut() {
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale = 1.0;
+        }
+    }
+}
+
+/// Mini-bosses switch to their second attack pattern at half health, announced with a rumble
+pub fn boss_phase_system(
+    mut boss_query: Query<(&Transform, &mut Enemy, &Health), Without<PendingDespawn>>,
+    mut cue_events: EventWriter<TelegraphCue>,
+) {
+    for (transform, mut enemy, health) in boss_query.iter_mut() {
+        let threshold = (enemy.health as f32 * BOSS_PHASE_HEALTH_FRACTION) as i32;
+        if let EnemyAI::MiniBoss { pattern, .. } = &mut enemy.ai_type && *pattern == 0 && health.0 <= threshold {
+            *pattern = 1;
+            cue_events.write(TelegraphCue { kind: TelegraphCueKind::BossPhaseShift, position: transform.translation });
+        }
+    }
+}
+
+pub fn update_formations(
+    mut colony_leader_query: Query<(&mut Transform, &mut ColonyLeader)>,
+    mut colony_member_query: Query<(&mut Enemy, &Transform), Without<ColonyLeader>>,
+    fluid_environment: Res<FluidEnvironment>,
+    time: Res<Time>,
+) {
+    for (mut leader_transform, mut colony) in colony_leader_query.iter_mut() {
+        colony.pattern_timer += time.delta_secs();
+        
+        apply_current_influence(&mut leader_transform, &fluid_environment, 0.6, time.delta_secs());
+        leader_transform.translation.y -= FORMATION_SPEED_NORMAL * time.delta_secs();
+        
+        let colony_movement = match colony.pattern_type {
+            ColonyPattern::BiofilmFormation => Vec2::new(
+                (colony.pattern_timer * 0.4).sin() * 30.0,
+                (colony.pattern_timer * 0.6).cos() * 15.0,
+            ),
+            ColonyPattern::LinearChain => Vec2::new(
+                (colony.pattern_timer * 1.2).sin() * 40.0,
+                0.0,
+            ),
+            ColonyPattern::CircularCluster => {
+                let radius_pulse = 20.0 + (colony.pattern_timer * 1.5).sin() * 10.0;
+                Vec2::new(
+                    (colony.pattern_timer * 0.8).cos() * radius_pulse,
+                    (colony.pattern_timer * 0.8).sin() * radius_pulse * 0.5,
+                ) * 0.5
+            }
+            ColonyPattern::SymbioticPair => Vec2::new(
+                (colony.pattern_timer * 2.0).sin() * 25.0,
+                (colony.pattern_timer * 1.5).cos() * 12.0,
+            ),
+        };
+        
+        leader_transform.translation += colony_movement.extend(0.0) * time.delta_secs();
+        
+        // Update member positions
+        for (member_index, member_entity) in colony.members.iter().enumerate() {
+            if let Ok((mut member_enemy, _)) = colony_member_query.get_mut(*member_entity)
+                && let EnemyAI::Formation { position_in_formation, leader_offset, .. } = &mut member_enemy.ai_type
+            {
+                let new_pos = colony.pattern_type.get_position(member_index, colony.members.len(), colony.pattern_timer);
+                *position_in_formation = new_pos;
+                *leader_offset = colony_movement * 0.5;
+            }
+        }
+        
+        colony.members.retain(|&member_entity| colony_member_query.get(member_entity).is_ok());
+    }
+}
+
+/// Decorate colony leaders/commanders with a pulsing glow, members are left plain
+pub fn leader_highlight_system(
+    mut commands: Commands,
+    config: Res<LeaderHighlightConfig>,
+    assets: Option<Res<GameAssets>>,
+    leader_query: Query<Entity, (Or<(With<ColonyLeader>, With<ColonyCommander>)>, Without<LeaderHighlight>, Without<PendingDespawn>)>,
+    mut glow_query: Query<(&mut Sprite, &mut Visibility), With<LeaderHighlightGlow>>,
+    time: Res<Time>,
+) {
+    if let Some(assets) = &assets {
+        for entity in leader_query.iter() {
+            commands.entity(entity)
+                .try_insert((LeaderHighlight, Visibility::default()))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Sprite {
+                            image: assets.particle_texture.clone(),
+                            color: config.color.with_alpha(config.min_alpha),
+                            custom_size: Some(Vec2::splat(config.size)),
+                            ..default()
+                        },
+                        Transform::from_xyz(0.0, 0.0, -0.1),
+                        LeaderHighlightGlow,
+                    ));
+                });
+        }
+    }
+
+    let alpha = config.pulse_alpha(time.elapsed_secs());
+    for (mut sprite, mut visibility) in glow_query.iter_mut() {
+        *visibility = if config.enabled { Visibility::Inherited } else { Visibility::Hidden };
+        sprite.color = config.color.with_alpha(alpha);
+    }
+}
+
+pub fn colony_morale_system(
+    mut commands: Commands,
+    mut colony_query: Query<&mut ColonyLeader>,
+    mut member_query: Query<(Entity, &mut Enemy, &Transform, Has<MoraleBroken>), (Without<ColonyLeader>, Without<PendingDespawn>)>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    config: Res<ColonyMoraleConfig>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+    let player_pos = player_query.single().ok().map(|t| t.translation.truncate());
+
+    for mut colony in colony_query.iter_mut() {
+        // Adopt formation cells that were spawned for this colony
+        let colony_id = colony.colony_id;
+        for (entity, enemy, _, _) in member_query.iter() {
+            if let EnemyAI::Formation { formation_id, .. } = enemy.ai_type && formation_id == colony_id && !colony.members.contains(&entity) {
+                colony.members.push(entity);
+            }
+        }
+
+        let alive = colony.members.iter().filter(|&&member| member_query.get(member).is_ok()).count();
+        colony.morale.update_member_count(alive, now);
+
+        if alive == 0 || !colony.morale.is_broken(now, config.loss_window, config.loss_ratio) {
+            continue;
+        }
+
+        colony.morale.recent_losses.clear();
+        for &member_entity in &colony.members {
+            if let Ok((entity, mut enemy, transform, already_broken)) = member_query.get_mut(member_entity) {
+                if already_broken { continue; }
+
+                // Flee away from the player, falling back to scattering upward
+                let away = player_pos
+                    .map(|p| (transform.translation.truncate() - p).normalize_or_zero())
+                    .filter(|d| *d != Vec2::ZERO)
+                    .unwrap_or(Vec2::Y);
+                let previous_ai = std::mem::replace(&mut enemy.ai_type, EnemyAI::Linear { direction: away });
+                enemy.speed *= RETREAT_SPEED_MULTIPLIER;
+
+                commands.entity(entity).try_insert(MoraleBroken {
+                    timer: config.panic_duration,
+                    previous_ai,
+                });
+            }
+        }
+    }
+}
+
+pub fn morale_recovery_system(
+    mut commands: Commands,
+    mut broken_query: Query<(Entity, &mut Enemy, &mut MoraleBroken), Without<PendingDespawn>>,
+    time: Res<Time>,
+) {
+    for (entity, mut enemy, mut broken) in broken_query.iter_mut() {
+        broken.timer -= time.delta_secs();
+        if broken.timer <= 0.0 {
+            enemy.ai_type = broken.previous_ai.clone();
+            enemy.speed /= RETREAT_SPEED_MULTIPLIER;
+            commands.entity(entity).try_remove::<MoraleBroken>();
+        }
+    }
+}
+
+/// Members of a colony whose leader was just removed break formation and scatter.
+/// Leaders are remembered by entity since their `ColonyLeader` is gone by the time the removal is seen
+pub fn colony_leader_death_system(
+    mut commands: Commands,
+    mut removed_leaders: RemovedComponents<ColonyLeader>,
+    leader_query: Query<(Entity, &ColonyLeader)>,
+    mut member_query: Query<(Entity, &mut Enemy, &Transform, Option<&mut MoraleBroken>), (Without<RoutingBehavior>, Without<PendingDespawn>)>,
+    config: Res<ColonyMoraleConfig>,
+    mut known_leaders: Local<HashMap<Entity, u32>>,
+) {
+    for leader in removed_leaders.read() {
+        let Some(colony_id) = known_leaders.remove(&leader) else { continue };
+
+        // Panicking members keep their formation AI in MoraleBroken until the panic ends
+        let orphans: Vec<(Entity, Vec2)> = member_query.iter()
+            .filter(|(_, enemy, _, broken)| {
+                let formation_ai = broken.as_ref().map_or(&enemy.ai_type, |broken| &broken.previous_ai);
+                matches!(formation_ai, EnemyAI::Formation { formation_id, .. } if *formation_id == colony_id)
+            })
+            .map(|(entity, _, transform, _)| (entity, transform.translation.truncate()))
+            .collect();
+        if orphans.is_empty() { continue; }
+        let centre = orphans.iter().map(|(_, pos)| *pos).sum::<Vec2>() / orphans.len() as f32;
+
+        for (entity, pos) in orphans {
+            let Ok((_, mut enemy, _, morale_broken)) = member_query.get_mut(entity) else { continue };
+            // Already fleeing, so let the panic run out into the routed state rather than a leaderless formation
+            if let Some(mut broken) = morale_broken {
+                broken.previous_ai = RoutingBehavior::settled_ai();
+                continue;
+            }
+
+            // Outward from the formation's centre, biased down towards the bottom of the pool
+            let direction = ((pos - centre).normalize_or_zero() + Vec2::NEG_Y).normalize_or(Vec2::NEG_Y);
+            enemy.ai_type = EnemyAI::Static;
+            enemy.speed *= config.rout_speed_multiplier;
+            commands.entity(entity).try_insert(RoutingBehavior {
+                timer: config.rout_duration,
+                direction,
+                formation_id: colony_id,
+            });
+        }
+    }
+
+    known_leaders.clear();
+    known_leaders.extend(leader_query.iter().map(|(entity, leader)| (entity, leader.colony_id)));
+}
+
+/// Carry routing members along their scatter direction, then let them drift off as plain linear movers
+pub fn routing_behavior_system(
+    mut commands: Commands,
+    mut routing_query: Query<(Entity, &mut Transform, &mut Enemy, &mut RoutingBehavior), (Without<Frozen>, Without<PendingDespawn>)>,
+    config: Res<ColonyMoraleConfig>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut enemy, mut routing) in routing_query.iter_mut() {
+        transform.translation += routing.direction.extend(0.0) * enemy.speed * time.delta_secs();
+        routing.timer -= time.delta_secs();
+        if routing.timer <= 0.0 {
+            enemy.ai_type = RoutingBehavior::settled_ai();
+            enemy.speed /= config.rout_speed_multiplier;
+            commands.entity(entity).try_remove::<RoutingBehavior>();
+        }
+    }
+}
+
+/// Sidestep a homing missile that's closing on this enemy. The missile keeps its target,
+/// so after overshooting it turns back in through its normal homing
+pub fn evasive_maneuver_system(
+    mut enemy_query: Query<(Entity, &mut Transform, &mut EvasiveManeuver), (With<Enemy>, Without<Frozen>, Without<HitStop>, Without<PendingDespawn>)>,
+    missile_query: Query<(&Transform, &Projectile, &AutoMissile), (Without<Enemy>, Without<PendingDespawn>)>,
+    config: Res<EvasionConfig>,
+    time: Res<Time>,
+) {
+    if !config.enabled { return; }
+    let dt = time.delta_secs();
+
+    for (entity, mut transform, mut evasion) in enemy_query.iter_mut() {
+        evasion.cooldown_timer = (evasion.cooldown_timer - dt).max(0.0);
+
+        if evasion.dodge_timer > 0.0 {
+            evasion.dodge_timer -= dt;
+            transform.translation += evasion.dodge_velocity.extend(0.0) * dt;
+            continue;
+        }
+        if evasion.cooldown_timer > 0.0 { continue; }
+
+        let own_pos = transform.translation.truncate();
+        let incoming = missile_query.iter().find(|(missile_transform, projectile, missile)| {
+            let offset = own_pos - missile_transform.translation.truncate();
+            missile.target == Some(entity)
+                && offset.length() < evasion.detect_radius
+                && projectile.velocity.dot(offset) > 0.0
+        });
+        let Some((missile_transform, projectile, _)) = incoming else { continue };
+
+        evasion.cooldown_timer = evasion.cooldown;
+        if rand::random::<f32>() < config.dodge_chance {
+            evasion.dodge_velocity = EvasiveManeuver::dodge_velocity(
+                missile_transform.translation.truncate(),
+                projectile.velocity,
+                own_pos,
+                evasion.dodge_speed,
+            );
+            evasion.dodge_timer = evasion.dodge_duration;
+        }
+    }
+}
+
+const FROZEN_TINT: Color = Color::srgb(0.6, 0.85, 1.0);
+
+/// Count down hit-stop frames and release the entity once they run out
+pub fn hit_stop_system(
+    mut commands: Commands,
+    mut hit_stop_query: Query<(Entity, &mut HitStop), Without<PendingDespawn>>,
+) {
+    for (entity, mut hit_stop) in hit_stop_query.iter_mut() {
+        if hit_stop.tick() {
+            commands.entity(entity).try_remove::<HitStop>();
+        }
+    }
+}
+
+/// Enrage capable enemies near each kill, and calm them down once the rage runs out
+pub fn berserk_system(
     mut commands: Commands,
-    mut turret_query: Query<(&Transform, &mut Enemy)>,
-    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
-    assets: Option<Res<GameAssets>>,
+    mut death_events: EventReader<EnemyDied>,
+    mut enemy_query: Query<(Entity, &Transform, &Enemy, Option<&mut Berserk>), Without<PendingDespawn>>,
+    berserk_config: Res<BerserkConfig>,
     time: Res<Time>,
 ) {
-    let Some(assets) = assets else { return };
-    let Ok(player_transform) = player_query.single() else { return };
-    
-    for (turret_transform, mut enemy) in turret_query.iter_mut() {
-        let enemy_clone = enemy.clone();
-        if let EnemyAI::Turret { shoot_timer, detection_range, rotation } = &mut enemy.ai_type {
+    for (entity, _, _, berserk) in enemy_query.iter_mut() {
+        if let Some(mut berserk) = berserk && berserk.tick(time.delta_secs()) {
+            commands.entity(entity).try_remove::<Berserk>();
+        }
+    }
 
-            *shoot_timer -= time.delta_secs();
-            
-            let distance = turret_transform.translation.distance(player_transform.translation);
-            if distance <= *detection_range && *shoot_timer <= 0.0 {
-                let direction = (player_transform.translation.truncate() - turret_transform.translation.truncate()).normalize();
-                
-                let (color, damage, velocity, count) = match enemy_clone.enemy_type {
-                    EnemyType::BiofilmColony => (Color::srgb(0.6, 0.8, 0.3), 25, 350.0, 3),
-                    _ => (Color::srgb(0.8, 0.4, 0.4), 20, 400.0, 1),
-                };
-                
-                for i in 0..count {
-                    let spread_angle = if count > 1 { (i as f32 - 1.0) * 0.3 } else { 0.0 };
-                    let spread_dir = Vec2::new(
-                        direction.x * spread_angle.cos() - direction.y * spread_angle.sin(),
-                        direction.x * spread_angle.sin() + direction.y * spread_angle.cos(),
-                    );
-                    
-                    commands.spawn((
-                        Sprite { image: assets.projectile_texture.clone(), color, ..default() },
-                        Transform::from_translation(turret_transform.translation + Vec3::new(0.0, -15.0, 0.0))
-                            .with_rotation(Quat::from_rotation_z(*rotation)),
-                        Projectile {
-                            velocity: spread_dir * velocity,
-                            damage,
-                            friendly: false,
-                            organic_trail: enemy_clone.chemical_signature.releases_toxins,
-                        },
-                        Collider { radius: 4.0 },
-                    ));
-                }
-                
-                *shoot_timer = 1.2 + (time.elapsed_secs() * 0.8).sin() * 0.3;
+    for event in death_events.read() {
+        let death = event.position.truncate();
+        for (entity, transform, enemy, berserk) in enemy_query.iter_mut() {
+            let Some(buff) = berserk_config.buff_for(&enemy.enemy_type, transform.translation.truncate(), death) else { continue };
+            match berserk {
+                // Fresh losses keep the rage going rather than stacking it
+                Some(mut berserk) => berserk.remaining = buff.remaining,
+                None => { commands.entity(entity).try_insert(buff); }
             }
         }
     }
 }
 
-pub fn update_formations(
-    mut colony_leader_query: Query<(&mut Transform, &mut ColonyLeader)>,
-    mut colony_member_query: Query<(&mut Enemy, &Transform), Without<ColonyLeader>>,
-    fluid_environment: Res<FluidEnvironment>,
+pub fn frozen_status_system(
+    mut commands: Commands,
+    mut frozen_query: Query<(Entity, &Transform, &mut Sprite, &mut Frozen), Without<PendingDespawn>>,
+    mut particle_events: EventWriter<SpawnParticles>,
     time: Res<Time>,
 ) {
-    for (mut leader_transform, mut colony) in colony_leader_query.iter_mut() {
-        colony.pattern_timer += time.delta_secs();
-        
-        apply_current_influence(&mut leader_transform, &fluid_environment, 0.6, time.delta_secs());
-        leader_transform.translation.y -= FORMATION_SPEED_NORMAL * time.delta_secs();
-        
-        let colony_movement = match colony.pattern_type {
-            ColonyPattern::BiofilmFormation => Vec2::new(
-                (colony.pattern_timer * 0.4).sin() * 30.0,
-                (colony.pattern_timer * 0.6).cos() * 15.0,
-            ),
-            ColonyPattern::LinearChain => Vec2::new(
-                (colony.pattern_timer * 1.2).sin() * 40.0,
-                0.0,
-            ),
-            ColonyPattern::CircularCluster => {
-                let radius_pulse = 20.0 + (colony.pattern_timer * 1.5).sin() * 10.0;
-                Vec2::new(
-                    (colony.pattern_timer * 0.8).cos() * radius_pulse,
-                    (colony.pattern_timer * 0.8).sin() * radius_pulse * 0.5,
-                ) * 0.5
-            }
-            ColonyPattern::SymbioticPair => Vec2::new(
-                (colony.pattern_timer * 2.0).sin() * 25.0,
-                (colony.pattern_timer * 1.5).cos() * 12.0,
-            ),
-        };
-        
-        leader_transform.translation += colony_movement.extend(0.0) * time.delta_secs();
-        
-        // Update member positions
-        for (member_index, member_entity) in colony.members.iter().enumerate() {
-            if let Ok((mut member_enemy, _)) = colony_member_query.get_mut(*member_entity) {
-                if let EnemyAI::Formation { position_in_formation, leader_offset, .. } = &mut member_enemy.ai_type {
-                    let new_pos = colony.pattern_type.get_position(member_index, colony.members.len(), colony.pattern_timer);
-                    *position_in_formation = new_pos;
-                    *leader_offset = colony_movement * 0.5;
-                }
-            }
+    for (entity, transform, mut sprite, mut frozen) in frozen_query.iter_mut() {
+        if frozen.tick(time.delta_secs()) {
+            sprite.color = frozen.original_color;
+            commands.entity(entity).try_remove::<Frozen>();
+
+            // Ice shards burst off as the shell shatters
+            particle_events.write(SpawnParticles {
+                position: transform.translation,
+                count: 10,
+                config: ParticleConfig {
+                    color_start: Color::srgb(0.8, 0.95, 1.0),
+                    color_end: Color::srgba(0.5, 0.8, 1.0, 0.0),
+                    velocity_range: (Vec2::new(-120.0, -120.0), Vec2::new(120.0, 120.0)),
+                    lifetime_range: (0.3, 0.7),
+                    size_range: (0.2, 0.5),
+                    gravity: Vec2::new(0.0, -80.0),
+                    organic_motion: false,
+                    bioluminescence: 0.6,
+                },
+            });
+        } else {
+            // Hold the icy tint every frame so pulse and corruption effects can't paint over it
+            sprite.color = FROZEN_TINT;
         }
-        
-        colony.members.retain(|&member_entity| colony_member_query.get(member_entity).is_ok());
+    }
+}
+
+/// Evenly spaced radial directions for a shatter burst
+pub fn shatter_directions(shards: u32) -> Vec<Vec2> {
+    (0..shards)
+        .map(|i| Vec2::from_angle(i as f32 / shards as f32 * std::f32::consts::TAU))
+        .collect()
+}
+
+pub fn shatter_shard_damage(max_health: i32) -> i32 {
+    ((max_health as f32 * SHATTER_DAMAGE_FRACTION) as i32).max(1)
+}
+
+/// Enemies killed while frozen burst into friendly ice shards that chain into the pack
+pub fn frozen_shatter_system(
+    mut commands: Commands,
+    mut shatter_events: EventReader<FrozenShatter>,
+    mut particle_events: EventWriter<SpawnParticles>,
+    assets: Option<Res<GameAssets>>,
+) {
+    let Some(assets) = assets else { return };
+
+    for event in shatter_events.read() {
+        let damage = shatter_shard_damage(event.max_health);
+
+        for direction in shatter_directions(SHATTER_SHARD_COUNT) {
+            commands.spawn((
+                Sprite {
+                    image: assets.projectile_texture.clone(),
+                    color: FROZEN_TINT,
+                    custom_size: Some(Vec2::splat(6.0)),
+                    ..default()
+                },
+                Transform::from_translation(event.position + (direction * 16.0).extend(0.0))
+                    .with_rotation(Quat::from_rotation_z(direction.y.atan2(direction.x) - std::f32::consts::FRAC_PI_2)),
+                Projectile {
+                    velocity: direction * SHATTER_SHARD_SPEED,
+                    damage,
+                    friendly: true,
+                    organic_trail: false,
+                },
+                Collider { radius: 3.0 },
+            ));
+        }
+
+        particle_events.write(SpawnParticles {
+            position: event.position,
+            count: 16,
+            config: ParticleConfig {
+                color_start: Color::srgb(0.85, 0.97, 1.0),
+                color_end: Color::srgba(0.5, 0.8, 1.0, 0.0),
+                velocity_range: (Vec2::new(-200.0, -200.0), Vec2::new(200.0, 200.0)),
+                lifetime_range: (0.3, 0.8),
+                size_range: (0.3, 0.7),
+                gravity: Vec2::ZERO,
+                organic_motion: false,
+                bioluminescence: 0.8,
+            },
+        });
     }
 }
 
 pub fn formation_coordination_system(
     mut commands: Commands,
     mut colony_query: Query<(Entity, &Transform, &mut ColonyCommander)>,
-    member_query: Query<(&Enemy, &ColonyMember, &Transform), Without<ColonyCommander>>,
+    member_query: Query<(&Enemy, &ColonyMember, &Transform), (Without<ColonyCommander>, Without<Frozen>)>,
     player_query: Query<&Transform, (With<Player>, Without<Enemy>, Without<ColonyCommander>)>,
     assets: Option<Res<GameAssets>>,
     time: Res<Time>,
@@ -369,10 +1641,38 @@ pub fn formation_coordination_system(
     let Some(assets) = assets else { return };
     let Ok(player_transform) = player_query.single() else { return };
     
-    for (_, commander_transform, mut colony) in colony_query.iter_mut() {
+    for (commander_entity, commander_transform, mut colony) in colony_query.iter_mut() {
         colony.chemical_timer += time.delta_secs();
         
-        if colony.coordination_pattern.execute(colony.chemical_timer) {
+        // Telegraph first, the volley itself comes once the windup runs out
+        if colony.windup_timer <= 0.0 {
+            if colony.coordination_pattern.execute(colony.chemical_timer) {
+                colony.windup_timer = COLONY_TELEGRAPH_DURATION;
+                match colony.coordination_pattern.clone() {
+                    CoordinationPattern::ChemicalSignaling { .. } => {
+                        for &member_entity in &colony.members {
+                            if let Ok((_, _, member_transform)) = member_query.get(member_entity) {
+                                let direction = (player_transform.translation.truncate() - member_transform.translation.truncate()).normalize_or_zero();
+                                spawn_attack_telegraph(&mut commands, &assets.particle_texture, member_entity, direction, true, Color::srgb(0.8, 0.9, 0.3), COLONY_TELEGRAPH_DURATION);
+                            }
+                        }
+                    }
+                    CoordinationPattern::BiofilmFormation { member_count, rotation_speed } => {
+                        let rotation_offset = (colony.chemical_timer + COLONY_TELEGRAPH_DURATION) * rotation_speed;
+                        let angle_step = std::f32::consts::TAU / member_count as f32;
+                        for i in 0..member_count {
+                            let direction = Vec2::from_angle(angle_step * i as f32 + rotation_offset);
+                            spawn_attack_telegraph(&mut commands, &assets.particle_texture, commander_entity, direction, false, Color::srgb(0.6, 0.8, 0.4), COLONY_TELEGRAPH_DURATION);
+                        }
+                    }
+                    _ => colony.windup_timer = 0.0, // Patterns without a volley have nothing to telegraph
+                }
+            }
+            continue;
+        }
+        
+        colony.windup_timer -= time.delta_secs();
+        if colony.windup_timer <= 0.0 {
             match &colony.coordination_pattern {
                 CoordinationPattern::ChemicalSignaling { .. } => {
                     for &member_entity in &colony.members {
@@ -421,61 +1721,64 @@ pub fn formation_coordination_system(
 pub fn cell_division_system(
     mut commands: Commands,
     mut enemy_query: Query<(Entity, &Transform, &mut Enemy, &Health), Without<PendingDespawn>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
     mut spawn_events: EventWriter<SpawnEnemy>,
     assets: Option<Res<GameAssets>>,
+    split_dispersal: Res<SplitDispersalConfig>,
     time: Res<Time>,
 ) {
+    let player_pos = player_query.single().ok().map(|t| t.translation.truncate());
+
     for (enemy_entity, transform, mut enemy, health) in enemy_query.iter_mut() {
-        if let EnemyAI::CellDivision { division_threshold, division_timer, has_divided } = &mut enemy.ai_type {
-            if health.0 as f32 <= *division_threshold && !*has_divided {
-                *division_timer -= time.delta_secs();
+        if let EnemyAI::CellDivision { division_threshold, division_timer, has_divided } = &mut enemy.ai_type && health.0 as f32 <= *division_threshold && !*has_divided {
+            *division_timer -= time.delta_secs();
                 
-                if *division_timer <= 0.0 {
-                    *has_divided = true;
+            if *division_timer <= 0.0 {
+                *has_divided = true;
                     
-                    // Spawn two offspring
-                    let split_positions = [
-                        transform.translation + Vec3::new(-30.0, 0.0, 0.0),
-                        transform.translation + Vec3::new(30.0, 0.0, 0.0),
-                    ];
+                // Spawn two offspring, launched according to the dispersal pattern
+                let offspring = split_dispersal.offspring(
+                    transform.translation.truncate(),
+                    player_pos,
+                    rand::random::<f32>() * std::f32::consts::TAU,
+                );
                     
-                    for split_pos in split_positions {
-                        spawn_events.write(SpawnEnemy {
-                            position: split_pos,
-                            ai_type: EnemyAI::Linear { direction: Vec2::new(0.0, -1.0) },
-                            enemy_type: EnemyType::Offspring,
-                        });
-                    }
+                for (offset, direction) in offspring {
+                    spawn_events.write(SpawnEnemy {
+                        position: transform.translation + offset.extend(0.0),
+                        ai_type: EnemyAI::Linear { direction },
+                        enemy_type: EnemyType::Offspring,
+                    });
+                }
                     
-                    // Spawn division particles
-                    if let Some(assets) = &assets {
-                        for i in 0..12 {
-                            let angle = (i as f32 / 12.0) * std::f32::consts::TAU;
-                            let offset = Vec2::from_angle(angle) * 20.0;
+                // Spawn division particles
+                if let Some(assets) = &assets {
+                    for i in 0..12 {
+                        let angle = (i as f32 / 12.0) * std::f32::consts::TAU;
+                        let offset = Vec2::from_angle(angle) * 20.0;
                             
-                            commands.spawn((
-                                Sprite {
-                                    image: assets.particle_texture.clone(),
-                                    color: Color::srgb(0.8, 0.9, 0.6),
-                                    custom_size: Some(Vec2::splat(3.0)),
-                                    ..default()
-                                },
-                                Transform::from_translation(transform.translation + offset.extend(0.0)),
-                                Particle {
-                                    velocity: offset * 2.0,
-                                    lifetime: 0.0,
-                                    max_lifetime: 1.0,
-                                    size: 3.0,
-                                    fade_rate: 1.0,
-                                    bioluminescent: true,
-                                    drift_pattern: DriftPattern::Floating,
-                                },
-                            ));
-                        }
+                        commands.spawn((
+                            Sprite {
+                                image: assets.particle_texture.clone(),
+                                color: Color::srgb(0.8, 0.9, 0.6),
+                                custom_size: Some(Vec2::splat(3.0)),
+                                ..default()
+                            },
+                            Transform::from_translation(transform.translation + offset.extend(0.0)),
+                            Particle {
+                                velocity: offset * 2.0,
+                                lifetime: 0.0,
+                                max_lifetime: 1.0,
+                                size: 3.0,
+                                fade_rate: 1.0,
+                                bioluminescent: true,
+                                drift_pattern: DriftPattern::Floating,
+                            },
+                        ));
                     }
-                    
-                    commands.entity(enemy_entity).safe_despawn();
                 }
+                    
+                commands.entity(enemy_entity).safe_despawn();
             }
         }
     }
@@ -497,11 +1800,9 @@ pub fn symbiotic_pair_system(
         .collect();
     
     for (entity, position, partner_entity) in pair_data {
-        if let Some(partner) = partner_entity {
-            if pair_query.get(partner).is_err() {
-                explosion_events.write(SpawnExplosion { position, intensity: 1.2, enemy_type: None });
-                commands.entity(entity).safe_despawn();
-            }
+        if let Some(partner) = partner_entity && pair_query.get(partner).is_err() {
+            explosion_events.write(SpawnExplosion { position, intensity: 1.2, enemy_type: None });
+            commands.entity(entity).safe_despawn();
         }
     }
 }
@@ -522,15 +1823,15 @@ pub fn link_symbiotic_pairs(mut pair_query: Query<(Entity, &Transform, &mut Enem
             let (entity1, _) = chunk[0];
             let (entity2, _) = chunk[1];
             
-            if let Ok((_, _, mut enemy1)) = pair_query.get_mut(entity1) {
-                if let EnemyAI::SymbioticPair { partner_entity, .. } = &mut enemy1.ai_type {
-                    *partner_entity = Some(entity2);
-                }
+            if let Ok((_, _, mut enemy1)) = pair_query.get_mut(entity1)
+                && let EnemyAI::SymbioticPair { partner_entity, .. } = &mut enemy1.ai_type
+            {
+                *partner_entity = Some(entity2);
             }
-            if let Ok((_, _, mut enemy2)) = pair_query.get_mut(entity2) {
-                if let EnemyAI::SymbioticPair { partner_entity, .. } = &mut enemy2.ai_type {
-                    *partner_entity = Some(entity1);
-                }
+            if let Ok((_, _, mut enemy2)) = pair_query.get_mut(entity2)
+                && let EnemyAI::SymbioticPair { partner_entity, .. } = &mut enemy2.ai_type
+            {
+                *partner_entity = Some(entity1);
             }
         }
     }
@@ -571,7 +1872,7 @@ fn spawn_colony_pattern(
             let colony_id = (base_x * 1000.0) as u32;
             spawn_events.write(SpawnEnemy {
                 position: Vec3::new(base_x, 420.0, 0.0),
-                ai_type: EnemyAI::Turret { rotation: 0.0, shoot_timer: 0.0, detection_range: DETECTION_RANGE_DEFAULT },
+                ai_type: EnemyAI::Turret { rotation: 0.0, shoot_timer: 0.0, detection_range: DETECTION_RANGE_DEFAULT, windup_timer: 0.0 },
                 enemy_type: EnemyType::BiofilmColony,
             });
             
@@ -583,12 +1884,14 @@ fn spawn_colony_pattern(
                     pattern_timer: 0.0,
                     pattern_type: ColonyPattern::BiofilmFormation,
                     chemical_communication: true,
+                    morale: ColonyMorale::default(),
                 },
                 ColonyCommander {
                     colony_id,
                     members: Vec::new(),
                     coordination_pattern: CoordinationPattern::ChemicalSignaling { interval: 2.0 },
                     chemical_timer: 0.0,
+                    windup_timer: 0.0,
                 },
             ));
             
@@ -667,41 +1970,135 @@ fn spawn_colony_pattern(
 
 // ===== ENHANCED AI SYSTEMS =====
 
+// ===== PACK HUNTING =====
+/// A predator as the pack planner sees it
+#[derive(Clone, Debug)]
+pub struct PackMember {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub enemy_type: EnemyType,
+    pub hunt_range: f32,
+    pub prey_types: Vec<EnemyType>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PreyCandidate {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub enemy_type: EnemyType,
+    pub value: u32,
+}
+
+/// Shared prey for one predator and the side of it that predator closes in from
+#[derive(Clone, Copy, Debug)]
+pub struct PackAssignment {
+    pub target: Entity,
+    pub target_position: Vec2,
+    pub flank_direction: Vec2,
+}
+
+/// This frame's pack hunts, predators with no entry have nothing in range and go after the player
+#[derive(Resource, Default)]
+pub struct PackTargets {
+    pub assignments: HashMap<Entity, PackAssignment>,
+}
+
+/// Group same-type predators within `pack_radius` of each other, point each pack at the most
+/// valuable prey any member can reach and spread the members evenly around it
+pub fn plan_pack_hunts(predators: &[PackMember], prey: &[PreyCandidate], pack_radius: f32) -> HashMap<Entity, PackAssignment> {
+    let mut assignments = HashMap::new();
+    let mut grouped = vec![false; predators.len()];
+
+    for seed in 0..predators.len() {
+        if grouped[seed] { continue; }
+        let pack: Vec<usize> = (seed..predators.len())
+            .filter(|&i| {
+                !grouped[i]
+                    && predators[i].enemy_type == predators[seed].enemy_type
+                    && predators[i].position.distance(predators[seed].position) <= pack_radius
+            })
+            .collect();
+        pack.iter().for_each(|&i| grouped[i] = true);
+
+        let centre = pack.iter().map(|&i| predators[i].position).sum::<Vec2>() / pack.len() as f32;
+        let target = prey.iter()
+            .filter(|candidate| pack.iter().any(|&i| {
+                let member = &predators[i];
+                member.prey_types.contains(&candidate.enemy_type) && member.position.distance(candidate.position) < member.hunt_range
+            }))
+            .max_by(|a, b| a.value.cmp(&b.value)
+                .then_with(|| b.position.distance_squared(centre).total_cmp(&a.position.distance_squared(centre))));
+        let Some(target) = target else { continue };
+
+        // Order members around the prey so neighbours take neighbouring sides and nobody crosses over
+        let angle_of = |i: usize| (predators[i].position - target.position).to_angle();
+        let mut members = pack;
+        members.sort_by(|&a, &b| angle_of(a).total_cmp(&angle_of(b)));
+
+        let base = (centre - target.position).to_angle();
+        let spread = std::f32::consts::TAU / members.len() as f32;
+        let middle = (members.len() as f32 - 1.0) * 0.5;
+        for (slot, &i) in members.iter().enumerate() {
+            let angle = base + (slot as f32 - middle) * spread;
+            assignments.insert(predators[i].entity, PackAssignment {
+                target: target.entity,
+                target_position: target.position,
+                flank_direction: Vec2::from_angle(angle),
+            });
+        }
+    }
+    assignments
+}
+
 pub fn predator_prey_system(
-    mut predator_query: Query<(&mut Transform, &mut Enemy, &PredatorPreyBehavior), Without<PendingDespawn>>,
+    mut predator_query: Query<(Entity, &mut Transform, &mut Enemy, &PredatorPreyBehavior), Without<PendingDespawn>>,
     prey_query: Query<(Entity, &Transform, &Enemy), (With<Enemy>, Without<PredatorPreyBehavior>, Without<PendingDespawn>)>,
     player_query: Query<&Transform, With<Player>>,
+    mut pack_targets: ResMut<PackTargets>,
     time: Res<Time>,
 ) {
     let Ok(player_transform) = player_query.single() else { return };
     
-    for (mut predator_transform, predator_enemy, behavior) in predator_query.iter_mut() {
-        let mut hunting_target: Option<Vec3> = None;
-        let mut fleeing_from: Option<Vec3> = None;
-        
-        for (_, prey_transform, prey_enemy) in prey_query.iter() {
-            let distance = predator_transform.translation.distance(prey_transform.translation);
-            
-            if behavior.prey_types.contains(&prey_enemy.enemy_type) && distance < behavior.hunt_range {
-                if hunting_target.is_none() || distance < predator_transform.translation.distance(hunting_target.unwrap()) {
-                    hunting_target = Some(prey_transform.translation);
-                }
-            }
-            
-            if behavior.predator_types.contains(&prey_enemy.enemy_type) && distance < behavior.flee_range {
-                fleeing_from = Some(prey_transform.translation);
-                break;
-            }
-        }
+    let predators: Vec<PackMember> = predator_query.iter()
+        .map(|(entity, transform, enemy, behavior)| PackMember {
+            entity,
+            position: transform.translation.truncate(),
+            enemy_type: enemy.enemy_type,
+            hunt_range: behavior.hunt_range,
+            prey_types: behavior.prey_types.clone(),
+        })
+        .collect();
+    let prey: Vec<PreyCandidate> = prey_query.iter()
+        .map(|(entity, transform, enemy)| PreyCandidate {
+            entity,
+            position: transform.translation.truncate(),
+            enemy_type: enemy.enemy_type,
+            value: enemy.enemy_type.get_points(),
+        })
+        .collect();
+    pack_targets.assignments = plan_pack_hunts(&predators, &prey, PACK_RADIUS);
+    
+    for (entity, mut predator_transform, predator_enemy, behavior) in predator_query.iter_mut() {
+        let fleeing_from = prey_query.iter()
+            .map(|(_, prey_transform, prey_enemy)| (prey_transform.translation, prey_enemy))
+            .find(|(prey_pos, prey_enemy)| {
+                behavior.predator_types.contains(&prey_enemy.enemy_type)
+                    && predator_transform.translation.distance(*prey_pos) < behavior.flee_range
+            })
+            .map(|(prey_pos, _)| prey_pos);
         
         let dt = time.delta_secs();
         if let Some(flee_pos) = fleeing_from {
-            let flee_direction = (predator_transform.translation - flee_pos).normalize();
+            let flee_direction = (predator_transform.translation - flee_pos).normalize_or_zero();
             let panic_speed = predator_enemy.speed * (1.0 + behavior.fear_intensity);
             predator_transform.translation += flee_direction * panic_speed * dt;
             predator_transform.rotation *= Quat::from_rotation_z(dt * 8.0);
-        } else if let Some(hunt_pos) = hunting_target {
-            let hunt_direction = (hunt_pos - predator_transform.translation).normalize();
+        } else if let Some(assignment) = pack_targets.assignments.get(&entity) {
+            // Aim for a point off to this member's side of the prey, closing to the prey itself on arrival
+            let pos = predator_transform.translation.truncate();
+            let distance = pos.distance(assignment.target_position);
+            let approach = assignment.target_position + assignment.flank_direction * (distance * 0.5).min(PACK_FLANK_RADIUS);
+            let hunt_direction = (approach - pos).normalize_or_zero().extend(0.0);
             let hunt_speed = predator_enemy.speed * behavior.hunting_speed_bonus;
             predator_transform.translation += hunt_direction * hunt_speed * dt;
             
@@ -710,7 +2107,7 @@ pub fn predator_prey_system(
         } else {
             let player_distance = predator_transform.translation.distance(player_transform.translation);
             if player_distance < behavior.hunt_range * 1.5 {
-                let player_direction = (player_transform.translation - predator_transform.translation).normalize();
+                let player_direction = (player_transform.translation - predator_transform.translation).normalize_or_zero();
                 predator_transform.translation += player_direction * predator_enemy.speed * dt;
             }
         }
@@ -719,8 +2116,8 @@ pub fn predator_prey_system(
 
 pub fn chemical_trail_system(
     mut commands: Commands,
-    player_query: Query<&Transform, With<Player>>,
-    mut trail_query: Query<(Entity, &mut ChemicalTrail, &Transform), Without<Player>>,
+    player_query: Query<&Transform, (With<Player>, Without<Stealthed>)>,
+    mut trail_query: Query<(Entity, &mut ChemicalTrail), (Without<Player>, Without<PendingDespawn>, Without<AlreadyDespawned>)>,
     assets: Option<Res<GameAssets>>,
     time: Res<Time>,
     mut player_trail_timer: Local<f32>,
@@ -760,25 +2157,31 @@ pub fn chemical_trail_system(
         }
     }
     
-    for (trail_entity, mut trail, _) in trail_query.iter_mut() {
+    // Marked as soon as they fade so neither this nor the following system touches them again
+    for (trail_entity, mut trail) in trail_query.iter_mut() {
         trail.strength -= trail.decay_rate * time.delta_secs();
         if trail.strength <= 0.0 {
-            commands.entity(trail_entity).safe_despawn();
+            commands.entity(trail_entity).try_insert(AlreadyDespawned).safe_despawn();
         }
     }
 }
 
 pub fn chemical_trail_following(
-    mut enemy_query: Query<(&mut Transform, &mut Enemy), (With<Enemy>, Without<ChemicalTrail>)>,
-    trail_query: Query<(&Transform, &ChemicalTrail), (With<ChemicalTrail>, Without<Enemy>)>,
+    mut enemy_query: Query<(&mut Transform, &mut Enemy), (Without<ChemicalTrail>, Without<PendingDespawn>)>,
+    trail_query: Query<(&Transform, &ChemicalTrail), (Without<Enemy>, Without<PendingDespawn>, Without<AlreadyDespawned>)>,
+    stealth_query: Query<(), (With<Player>, With<Stealthed>)>,
     time: Res<Time>,
 ) {
+    let player_stealthed = !stealth_query.is_empty();
+
     for (mut enemy_transform, mut enemy) in enemy_query.iter_mut() {
         match &enemy.ai_type {
             EnemyAI::Chemotaxis { .. } | EnemyAI::Linear { .. } => {
                 let mut strongest_trail: Option<(Vec3, f32)> = None;
                 
                 for (trail_transform, trail) in trail_query.iter() {
+                    // The cloak masks the player's pheromones
+                    if player_stealthed && matches!(trail.trail_type, ChemicalTrailType::PlayerPheromone) { continue; }
                     let distance = enemy_transform.translation.distance(trail_transform.translation);
                     if distance < 80.0 {
                         let influence = trail.strength / (distance + 1.0);
@@ -788,22 +2191,21 @@ pub fn chemical_trail_following(
                     }
                 }
                 
-                if let Some((trail_pos, influence)) = strongest_trail {
-                    if influence > 0.1 {
-                        let trail_direction = (trail_pos - enemy_transform.translation).normalize();
-                        let follow_strength = (influence * 60.0).min(enemy.speed * 0.7);
-                        enemy_transform.translation += trail_direction * follow_strength * time.delta_secs();
+                if let Some((trail_pos, influence)) = strongest_trail && influence > 0.1 {
+                    // Standing on the trail has no direction, normalize() would poison the transform with NaN
+                    let trail_direction = (trail_pos - enemy_transform.translation).normalize_or_zero();
+                    let follow_strength = (influence * 60.0).min(enemy.speed * 0.7);
+                    enemy_transform.translation += trail_direction * follow_strength * time.delta_secs();
                         
-                        let trail_2d = trail_direction.truncate();
-                        match &mut enemy.ai_type {
-                            EnemyAI::Chemotaxis { current_direction, .. } => {
-                                *current_direction = (*current_direction + trail_2d * 0.3).normalize();
-                            }
-                            EnemyAI::Linear { direction } => {
-                                *direction = (*direction + trail_2d * 0.1).normalize();
-                            }
-                            _ => {}
+                    let trail_2d = trail_direction.truncate();
+                    match &mut enemy.ai_type {
+                        EnemyAI::Chemotaxis { current_direction, .. } => {
+                            *current_direction = (*current_direction + trail_2d * 0.3).normalize_or(*current_direction);
+                        }
+                        EnemyAI::Linear { direction } => {
+                            *direction = (*direction + trail_2d * 0.1).normalize_or(*direction);
                         }
+                        _ => {}
                     }
                 }
             }
@@ -819,12 +2221,10 @@ pub fn ecosystem_balance_system(
     time: Res<Time>,
 ) {
     let mut role_counts = HashMap::new();
-    let mut total_balance = 0.0;
     
     for (_, role) in enemy_query.iter() {
         let count = role_counts.entry(role.role.clone()).or_insert(0u32);
         *count += 1;
-        total_balance += role.balance_factor;
     }
     
     let apex_count = *role_counts.get(&EcosystemRoleType::Apex).unwrap_or(&0);
@@ -1037,4 +2437,458 @@ impl EnemyType {
             },
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn predator(id: u32, position: Vec2) -> PackMember {
+        PackMember {
+            entity: Entity::from_raw(id),
+            position,
+            enemy_type: EnemyType::InfectedMacrophage,
+            hunt_range: 300.0,
+            prey_types: vec![EnemyType::ViralParticle, EnemyType::AggressiveBacteria],
+        }
+    }
+
+    fn prey(id: u32, position: Vec2, enemy_type: EnemyType) -> PreyCandidate {
+        PreyCandidate { entity: Entity::from_raw(id), position, enemy_type, value: enemy_type.get_points() }
+    }
+
+    #[test]
+    fn pair_flanks_the_same_prey_from_opposite_sides() {
+        let predators = [predator(1, Vec2::new(-20.0, 100.0)), predator(2, Vec2::new(20.0, 100.0))];
+        let targets = [prey(10, Vec2::ZERO, EnemyType::ViralParticle)];
+        let plan = plan_pack_hunts(&predators, &targets, PACK_RADIUS);
+
+        let a = plan[&Entity::from_raw(1)];
+        let b = plan[&Entity::from_raw(2)];
+        assert_eq!(a.target, Entity::from_raw(10));
+        assert_eq!(b.target, Entity::from_raw(10));
+        assert!(a.flank_direction.dot(b.flank_direction) < -0.99);
+    }
+
+    #[test]
+    fn pack_picks_the_most_valuable_prey() {
+        let predators = [predator(1, Vec2::new(0.0, 100.0))];
+        let targets = [
+            prey(10, Vec2::new(0.0, 90.0), EnemyType::ViralParticle),
+            prey(11, Vec2::new(0.0, -100.0), EnemyType::AggressiveBacteria),
+        ];
+        let plan = plan_pack_hunts(&predators, &targets, PACK_RADIUS);
+        assert_eq!(plan[&Entity::from_raw(1)].target, Entity::from_raw(11));
+    }
+
+    #[test]
+    fn no_prey_leaves_predators_unassigned() {
+        let predators = [predator(1, Vec2::ZERO), predator(2, Vec2::X * 10.0)];
+        assert!(plan_pack_hunts(&predators, &[], PACK_RADIUS).is_empty());
+    }
+
+    #[test]
+    fn dodge_is_perpendicular_and_away_from_the_missile_line() {
+        let velocity = EvasiveManeuver::dodge_velocity(Vec2::ZERO, Vec2::Y * 500.0, Vec2::new(5.0, 50.0), 280.0);
+        assert!(velocity.dot(Vec2::Y).abs() < 1e-3);
+        assert!(velocity.x > 0.0);
+        assert!((velocity.length() - 280.0).abs() < 1e-3);
+
+        let velocity = EvasiveManeuver::dodge_velocity(Vec2::ZERO, Vec2::Y * 500.0, Vec2::new(-5.0, 50.0), 280.0);
+        assert!(velocity.x < 0.0);
+    }
+
+    #[test]
+    fn shipped_archetype_table_parses_and_matches_built_in_values() {
+        let path = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), ENEMY_ARCHETYPES_PATH);
+        let data = std::fs::read_to_string(&path).expect("enemy_archetypes.ron ships with the game");
+        let table: HashMap<EnemyType, EnemyArchetype> = ron::from_str(&data).expect("enemy_archetypes.ron parses");
+
+        assert_eq!(table.len(), EnemyType::ALL.len());
+        for enemy_type in EnemyType::ALL {
+            assert_eq!(table[&enemy_type], EnemyArchetype::built_in(enemy_type));
+        }
+    }
+
+    /// Another system hard-despawning a trail in the same frame the trail systems mark it
+    fn despawn_weak_trails(mut commands: Commands, trail_query: Query<(Entity, &ChemicalTrail)>) {
+        for (entity, trail) in trail_query.iter() {
+            if trail.strength < 0.5 {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+
+    #[test]
+    fn trail_systems_survive_trails_despawned_mid_frame() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, (
+                despawn_weak_trails,
+                chemical_trail_system,
+                chemical_trail_following,
+                crate::despawn::robust_despawn_system,
+            ).chain_ignore_deferred());
+
+        let enemy = app.world_mut().spawn((Transform::default(), Enemy::default())).id();
+        for i in 0..8 {
+            app.world_mut().spawn((
+                Transform::from_xyz(i as f32 * 8.0 - 30.0, 20.0, 0.0),
+                ChemicalTrail {
+                    trail_type: ChemicalTrailType::PlayerPheromone,
+                    strength: if i % 2 == 0 { 0.02 } else { 1.0 },
+                    decay_rate: 0.4,
+                    creation_timer: 0.0,
+                },
+            ));
+        }
+
+        for _ in 0..40 {
+            app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(100));
+            app.update();
+        }
+
+        let mut trail_query = app.world_mut().query::<&ChemicalTrail>();
+        assert_eq!(trail_query.iter(app.world()).count(), 0);
+        assert!(app.world().get::<Transform>(enemy).unwrap().translation.is_finite());
+    }
+
+    fn grid_state(enemy: &Enemy) -> &GridMemberState {
+        match &enemy.ai_type {
+            EnemyAI::GridMember { state, .. } => state,
+            _ => panic!("not a grid member"),
+        }
+    }
+
+    #[test]
+    fn diving_grid_member_leaves_its_slot_while_the_rest_hold() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(GridFormation { dive_timer: 0.0, max_divers: 1, sway_speed: 0.0, ..default() })
+            .add_systems(Update, grid_formation_system);
+        let player_pos = Vec2::new(0.0, -250.0);
+        app.world_mut().spawn((
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+            Transform::from_translation(player_pos.extend(0.0)),
+        ));
+        let members: Vec<Entity> = (0..3).map(|column| app.world_mut().spawn(Enemy {
+            ai_type: EnemyAI::GridMember { home_slot: UVec2::new(column, 0), state: GridMemberState::Holding },
+            ..default()
+        }).id()).collect();
+
+        app.update();
+
+        let formation = app.world().resource::<GridFormation>().clone();
+        let mut divers = 0;
+        for entity in members {
+            let enemy = app.world().get::<Enemy>(entity).unwrap();
+            let EnemyAI::GridMember { home_slot, .. } = &enemy.ai_type else { unreachable!() };
+            let slot = formation.slot_position(*home_slot);
+            let mut state = grid_state(enemy).clone();
+            let next = grid_member_step(&mut state, slot, slot, 100.0, 0.1);
+
+            match grid_state(enemy) {
+                GridMemberState::Diving { target, .. } => {
+                    divers += 1;
+                    assert_eq!(*target, player_pos);
+                    assert!(next.distance(player_pos) < slot.distance(player_pos));
+                }
+                GridMemberState::Holding => assert_eq!(next, slot),
+                other => panic!("unexpected grid state {other:?}"),
+            }
+        }
+        assert_eq!(divers, 1);
+    }
+
+    fn healer_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<EnemySpatialGrid>()
+            .add_systems(Update, (update_enemy_spatial_grid, healer_system).chain());
+        app
+    }
+
+    fn pulse(app: &mut App) {
+        app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_secs_f32(HEALER_PULSE_INTERVAL));
+        app.update();
+    }
+
+    #[test]
+    fn healer_tops_up_nearby_allies_to_their_max_until_it_dies() {
+        let mut app = healer_app();
+        let healer = app.world_mut().spawn((
+            Enemy { ai_type: EnemyAI::Healer { heal_rate: 10.0, aura_radius: 100.0 }, ..default() },
+            Transform::default(),
+            Health(20),
+        )).id();
+        let ally = app.world_mut().spawn((Enemy { health: 20, ..default() }, Transform::from_xyz(40.0, 0.0, 0.0), Health(10))).id();
+        let outsider = app.world_mut().spawn((Enemy { health: 20, ..default() }, Transform::from_xyz(400.0, 0.0, 0.0), Health(10))).id();
+
+        pulse(&mut app);
+        assert_eq!(app.world().get::<Health>(ally).unwrap().0, 15);
+        pulse(&mut app);
+        pulse(&mut app);
+        assert_eq!(app.world().get::<Health>(ally).unwrap().0, 20);
+        assert_eq!(app.world().get::<Health>(outsider).unwrap().0, 10);
+
+        app.world_mut().despawn(healer);
+        app.world_mut().get_mut::<Health>(ally).unwrap().0 = 10;
+        pulse(&mut app);
+        assert_eq!(app.world().get::<Health>(ally).unwrap().0, 10);
+    }
+
+    fn formation_cell(colony_id: u32) -> Enemy {
+        Enemy {
+            ai_type: EnemyAI::Formation { formation_id: colony_id, position_in_formation: Vec2::ZERO, leader_offset: Vec2::ZERO, formation_timer: 0.0 },
+            ..default()
+        }
+    }
+
+    #[test]
+    fn losing_most_of_a_colony_quickly_routs_the_survivors_for_a_while() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ColonyMoraleConfig>()
+            .add_systems(Update, (colony_morale_system, morale_recovery_system).chain());
+        app.world_mut().spawn(ColonyLeader {
+            colony_id: 7,
+            members: Vec::new(),
+            pattern_timer: 0.0,
+            pattern_type: ColonyPattern::LinearChain,
+            chemical_communication: false,
+            morale: ColonyMorale::default(),
+        });
+        let members: Vec<Entity> = (0..4)
+            .map(|i| app.world_mut().spawn((formation_cell(7), Transform::from_xyz(i as f32 * 30.0, 200.0, 0.0))).id())
+            .collect();
+        let step = |app: &mut App, secs: f32| {
+            app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_secs_f32(secs));
+            app.update();
+        };
+
+        step(&mut app, 0.1);
+        app.world_mut().despawn(members[0]);
+        app.world_mut().despawn(members[1]);
+        step(&mut app, 0.1);
+
+        for &survivor in &members[2..] {
+            assert!(app.world().get::<MoraleBroken>(survivor).is_some());
+            assert!(matches!(app.world().get::<Enemy>(survivor).unwrap().ai_type, EnemyAI::Linear { .. }));
+        }
+
+        let panic_duration = app.world().resource::<ColonyMoraleConfig>().panic_duration;
+        step(&mut app, panic_duration + 0.1);
+        for &survivor in &members[2..] {
+            assert!(app.world().get::<MoraleBroken>(survivor).is_none());
+            assert!(matches!(app.world().get::<Enemy>(survivor).unwrap().ai_type, EnemyAI::Formation { formation_id: 7, .. }));
+        }
+    }
+
+    fn freeze_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<GameAssets>()
+            .init_resource::<FluidEnvironment>()
+            .init_resource::<ChemicalEnvironment>()
+            .init_resource::<GridFormation>()
+            .init_resource::<WaveManager>()
+            .init_resource::<WaveSpeedRamp>()
+            .init_resource::<EnemyArchetypes>()
+            .init_resource::<CoralAvoidanceConfig>()
+            .init_resource::<AiTierConfig>()
+            .init_resource::<EnemyCombatTuning>()
+            .add_event::<BossPhaseChanged>()
+            .add_event::<TelegraphCue>()
+            .add_event::<SpawnParticles>()
+            .add_systems(Update, (move_enemies, turret_shooting, frozen_status_system).chain());
+        app.world_mut().spawn((
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+            Transform::from_xyz(0.0, -250.0, 0.0),
+        ));
+        app
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_secs_f32(secs));
+        app.update();
+    }
+
+    #[test]
+    fn frozen_enemies_neither_move_nor_shoot_until_they_thaw() {
+        let mut app = freeze_app();
+        let frozen = |timer| Frozen { timer, original_color: Color::WHITE };
+        let drifter = app.world_mut().spawn((Enemy::default(), Transform::from_xyz(0.0, 200.0, 0.0), Sprite::default(), frozen(1.0))).id();
+        let turret = app.world_mut().spawn((
+            Enemy { ai_type: EnemyAI::Turret { rotation: 0.0, shoot_timer: 0.0, detection_range: 1000.0, windup_timer: 0.0 }, ..default() },
+            Transform::from_xyz(100.0, 200.0, 0.0),
+            Sprite::default(),
+            frozen(1.0),
+        )).id();
+
+        advance(&mut app, 0.5);
+        assert_eq!(app.world().get::<Transform>(drifter).unwrap().translation.y, 200.0);
+        assert!(matches!(app.world().get::<Enemy>(turret).unwrap().ai_type, EnemyAI::Turret { windup_timer, .. } if windup_timer == 0.0));
+        assert!(app.world().resource::<Events<TelegraphCue>>().is_empty());
+
+        advance(&mut app, 0.6);
+        assert!(app.world().get::<Frozen>(drifter).is_none());
+        advance(&mut app, 0.1);
+        assert!(app.world().get::<Transform>(drifter).unwrap().translation.y < 200.0);
+        assert!(matches!(app.world().get::<Enemy>(turret).unwrap().ai_type, EnemyAI::Turret { windup_timer, .. } if windup_timer > 0.0));
+    }
+
+    #[test]
+    fn turret_winding_up_queues_a_positional_charge_cue() {
+        let mut app = freeze_app();
+        app.world_mut().spawn((
+            Enemy { ai_type: EnemyAI::Turret { rotation: 0.0, shoot_timer: 0.0, detection_range: 1000.0, windup_timer: 0.0 }, ..default() },
+            Transform::from_xyz(100.0, 200.0, 0.0),
+            Sprite::default(),
+        ));
+        advance(&mut app, 0.1);
+
+        let cues: Vec<_> = app.world_mut().resource_mut::<Events<TelegraphCue>>().drain().collect();
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].kind, TelegraphCueKind::TurretCharge);
+        assert_eq!(cues[0].position, Vec3::new(100.0, 200.0, 0.0));
+        assert!(cues[0].kind.sfx() == crate::audio::SfxType::TurretCharge);
+    }
+
+    #[test]
+    fn ramped_waves_speed_enemies_up_late_and_reset_for_the_next_wave() {
+        let mut app = freeze_app();
+        app.insert_resource(WaveSpeedRamp { enabled: true, ..default() });
+        app.world_mut().resource_mut::<WaveManager>().wave_active = true;
+        let drifter = app.world_mut().spawn((Enemy::default(), Transform::from_xyz(0.0, 200.0, 0.0))).id();
+        let step = |app: &mut App| {
+            app.world_mut().get_mut::<Transform>(drifter).unwrap().translation = Vec3::new(0.0, 200.0, 0.0);
+            advance(app, 0.1);
+            200.0 - app.world().get::<Transform>(drifter).unwrap().translation.y
+        };
+
+        let early = step(&mut app);
+        let ramp_duration = app.world().resource::<WaveSpeedRamp>().ramp_duration;
+        advance(&mut app, ramp_duration);
+        let late = step(&mut app);
+        assert!(late > early * 1.2, "late {late} vs early {early}");
+
+        let now = app.world().resource::<Time>().elapsed_secs();
+        app.world_mut().resource_mut::<WaveManager>().wave_start_time = now;
+        let next_wave = step(&mut app);
+        assert!((next_wave - early).abs() < early * 0.05, "next wave {next_wave} vs early {early}");
+    }
+
+    #[test]
+    fn colony_leaders_are_highlighted_but_their_members_are_not() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<GameAssets>()
+            .init_resource::<LeaderHighlightConfig>()
+            .add_systems(Update, leader_highlight_system);
+        let leader = app.world_mut().spawn((
+            Enemy::default(),
+            ColonyLeader {
+                colony_id: 3,
+                members: Vec::new(),
+                pattern_timer: 0.0,
+                pattern_type: ColonyPattern::BiofilmFormation,
+                chemical_communication: true,
+                morale: ColonyMorale::default(),
+            },
+        )).id();
+        let member = app.world_mut().spawn((
+            formation_cell(3),
+            ColonyMember { colony_id: 3, role: ColonyRole::Worker, last_signal_time: 0.0 },
+        )).id();
+
+        app.update();
+        app.update();
+
+        assert!(app.world().get::<LeaderHighlight>(leader).is_some());
+        assert!(app.world().get::<LeaderHighlight>(member).is_none());
+        let glows = app.world_mut().query_filtered::<&ChildOf, With<LeaderHighlightGlow>>()
+            .iter(app.world()).map(|parent| parent.parent()).collect::<Vec<_>>();
+        assert_eq!(glows, vec![leader]);
+    }
+
+    #[test]
+    fn away_from_player_dispersal_launches_offspring_away_from_the_player() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(SplitDispersalConfig { pattern: SplitDispersal::AwayFromPlayer, ..default() })
+            .add_event::<SpawnEnemy>()
+            .add_systems(Update, cell_division_system);
+        let player_pos = Vec2::new(0.0, -250.0);
+        let parent_pos = Vec2::new(120.0, 100.0);
+        app.world_mut().spawn((
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+            Transform::from_translation(player_pos.extend(0.0)),
+        ));
+        app.world_mut().spawn((
+            Enemy { ai_type: EnemyAI::CellDivision { division_threshold: 50.0, division_timer: 0.0, has_divided: false }, ..default() },
+            Transform::from_translation(parent_pos.extend(0.0)),
+            Health(10),
+        ));
+        app.update();
+
+        let offspring: Vec<SpawnEnemy> = app.world_mut().resource_mut::<Events<SpawnEnemy>>().drain().collect();
+        assert_eq!(offspring.len(), 2);
+        let away = parent_pos - player_pos;
+        for child in offspring {
+            let EnemyAI::Linear { direction } = child.ai_type else { panic!("offspring should travel in a line") };
+            assert!(direction.dot(away) > 0.0);
+            assert!((child.position.truncate() - parent_pos).dot(away) > 0.0);
+        }
+    }
+
+    #[test]
+    fn cloaked_player_sends_chemotaxis_hunters_wandering_until_the_cloak_drops() {
+        let mut app = freeze_app();
+        let player = app.world_mut().query_filtered::<Entity, With<Player>>().single(app.world()).unwrap();
+        app.world_mut().entity_mut(player).insert(Stealthed { remaining: 5.0 });
+        let hunter = app.world_mut().spawn((
+            Enemy {
+                ai_type: EnemyAI::Chemotaxis { target_chemical: ChemicalType::PlayerPheromones, sensitivity: 20.0, current_direction: Vec2::Y },
+                ..default()
+            },
+            Transform::from_xyz(100.0, -100.0, 0.0),
+        )).id();
+        let distance = |app: &App| {
+            app.world().get::<Transform>(hunter).unwrap().translation.truncate().distance(Vec2::new(0.0, -250.0))
+        };
+
+        let start = distance(&app);
+        for _ in 0..10 { advance(&mut app, 0.1); }
+        let while_cloaked = distance(&app);
+        assert!(while_cloaked > start, "hunter should drift off while the player is cloaked");
+
+        app.world_mut().entity_mut(player).remove::<Stealthed>();
+        for _ in 0..20 { advance(&mut app, 0.1); }
+        assert!(distance(&app) < while_cloaked, "hunter should close in again once the cloak drops");
+    }
+
+    #[test]
+    fn nearby_kills_enrage_capable_allies_for_a_while() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(BerserkConfig { enabled: true, ..default() })
+            .add_event::<EnemyDied>()
+            .add_systems(Update, berserk_system);
+        let config = app.world().resource::<BerserkConfig>().clone();
+        let bacteria = |app: &mut App, x: f32| {
+            app.world_mut().spawn((Enemy { enemy_type: EnemyType::AggressiveBacteria, ..default() }, Transform::from_xyz(x, 0.0, 0.0))).id()
+        };
+        let ally = bacteria(&mut app, 60.0);
+        let distant = bacteria(&mut app, config.radius * 3.0);
+        let incapable = app.world_mut().spawn((Enemy { enemy_type: EnemyType::ViralParticle, ..default() }, Transform::from_xyz(40.0, 0.0, 0.0))).id();
+
+        app.world_mut().send_event(EnemyDied { position: Vec3::ZERO, enemy_type: Some(EnemyType::SwarmCell) });
+        advance(&mut app, 0.1);
+        assert!(app.world().get::<Berserk>(ally).is_some());
+        assert!(app.world().get::<Berserk>(distant).is_none());
+        assert!(app.world().get::<Berserk>(incapable).is_none());
+
+        advance(&mut app, config.duration + 0.1);
+        assert!(app.world().get::<Berserk>(ally).is_none());
+    }
+}
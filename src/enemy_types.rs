@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component, Clone, Debug)]
 pub struct Enemy {
@@ -19,7 +20,7 @@ pub struct ChemicalSignature {
     pub releases_toxins: bool,
 }
 
-#[derive(Clone, PartialEq, Copy, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Copy, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum EnemyType {
     // Updated names for biological theme
     ViralParticle,          // Basic
@@ -39,8 +40,9 @@ pub enum EnemyAI {
     Linear { direction: Vec2 },
     Sine { amplitude: f32, frequency: f32, phase: f32 },
     MiniBoss { pattern: usize, timer: f32 },
-    Kamikaze { target_pos: Vec2, dive_speed: f32, acquired_target: bool },
-    Turret { rotation: f32, shoot_timer: f32, detection_range: f32 },
+    Boss { phase: usize, phase_timer: f32, patterns: Vec<BossPattern> }, // One pattern per equal slice of the health bar
+    Kamikaze { target_pos: Vec2, dive_speed: f32, acquired_target: bool, retarget_interval: f32, retarget_timer: f32 },
+    Turret { rotation: f32, shoot_timer: f32, detection_range: f32, windup_timer: f32 }, // Volley fires when a running windup reaches zero
     Formation { 
         formation_id: u32, 
         position_in_formation: Vec2, 
@@ -74,6 +76,119 @@ pub enum EnemyAI {
         flow_sensitivity: f32,
         base_direction: Vec2,
     },
+    Flocking { // Boids steering against nearby enemies of the same type
+        separation: f32,
+        alignment: f32,
+        cohesion: f32,
+        neighbor_radius: f32,
+        heading: Vec2,
+    },
+    Healer { // Restores health to damaged allies within its aura
+        heal_rate: f32,
+        aura_radius: f32,
+    },
+    GridMember { // Arcade-style grid that sways at the top and sends out divers
+        home_slot: UVec2, // (column, row) in the GridFormation
+        state: GridMemberState,
+    },
+}
+
+/// Which AI parameter a range indicator circle visualises
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AiRangeKind {
+    Detection,
+    Aura,
+    Bond,
+}
+
+impl EnemyAI {
+    /// Radii this behaviour acts within, read straight from the variant's fields
+    pub fn indicator_ranges(&self) -> Vec<(AiRangeKind, f32)> {
+        match self {
+            EnemyAI::Turret { detection_range, .. } => vec![(AiRangeKind::Detection, *detection_range)],
+            EnemyAI::Healer { aura_radius, .. } => vec![(AiRangeKind::Aura, *aura_radius)],
+            EnemyAI::SymbioticPair { bond_distance, .. } => vec![(AiRangeKind::Bond, *bond_distance)],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Copy of this behaviour made sharper by `multiplier`: wider senses, stronger tracking, shorter cooldowns
+    pub fn scaled_for_tier(&self, multiplier: f32) -> Self {
+        let mut scaled = self.clone();
+        match &mut scaled {
+            EnemyAI::Sine { frequency, .. } => *frequency *= multiplier,
+            EnemyAI::Kamikaze { dive_speed, retarget_interval, .. } => {
+                *dive_speed *= multiplier;
+                *retarget_interval /= multiplier;
+            }
+            EnemyAI::Turret { detection_range, .. } => *detection_range *= multiplier,
+            EnemyAI::Spawner { spawn_rate, .. } => *spawn_rate /= multiplier, // Seconds between offspring
+            EnemyAI::Chemotaxis { sensitivity, .. } => *sensitivity *= multiplier,
+            EnemyAI::FluidFlow { flow_sensitivity, .. } => *flow_sensitivity *= multiplier,
+            EnemyAI::Healer { heal_rate, aura_radius } => {
+                *heal_rate *= multiplier;
+                *aura_radius *= multiplier;
+            }
+            _ => {}
+        }
+        scaled
+    }
+}
+
+/// How a boss moves while one of its phases is active
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BossMovement {
+    FigureEight { width: f32, height: f32 },
+    Charge { speed: f32 },   // Lunges at the player, then pulls back to its hover line
+    Retreat { hover_y: f32 }, // Backs off to the top of the pool and strafes
+}
+
+/// A boss phase, the boss shudders in place for `telegraph` seconds before the movement starts
+#[derive(Clone, Debug, PartialEq)]
+pub struct BossPattern {
+    pub movement: BossMovement,
+    pub telegraph: f32,
+}
+
+impl BossPattern {
+    /// Sweeping opener, charges once wounded and a desperate retreat for the last third
+    pub fn default_sequence() -> Vec<BossPattern> {
+        vec![
+            BossPattern { movement: BossMovement::FigureEight { width: 160.0, height: 50.0 }, telegraph: 0.0 },
+            BossPattern { movement: BossMovement::Charge { speed: 320.0 }, telegraph: 1.2 },
+            BossPattern { movement: BossMovement::Retreat { hover_y: 320.0 }, telegraph: 1.5 },
+        ]
+    }
+}
+
+impl EnemyAI {
+    pub fn boss() -> Self {
+        EnemyAI::Boss { phase: 0, phase_timer: 0.0, patterns: BossPattern::default_sequence() }
+    }
+
+    pub fn is_boss(&self) -> bool {
+        matches!(self, EnemyAI::MiniBoss { .. } | EnemyAI::Boss { .. })
+    }
+}
+
+/// Phase for the remaining health fraction, each pattern gets an equal slice of the health bar
+pub fn boss_phase_for_health(health_fraction: f32, phase_count: usize) -> usize {
+    if phase_count == 0 { return 0; }
+    let lost = (1.0 - health_fraction.clamp(0.0, 1.0)) * phase_count as f32;
+    (lost as usize).min(phase_count - 1)
+}
+
+/// Veteran level an enemy spawned with, 0 for the baseline behaviour
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct AiTier(pub u32);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridMemberState {
+    Assembling,                            // Flying in to the home slot
+    Holding,                               // Locked to the swaying slot
+    Diving { target: Vec2, rejoin: bool }, // Dive-bombing the player's last position
+    Returning,                             // Climbing back to the home slot
+    Exiting,                               // Carrying on off the bottom of the screen
 }
 
 #[derive(Clone, Debug)]
@@ -82,6 +197,24 @@ pub enum ChemicalType {
     NutrientGradient,
     ToxinAvoidance,
     OxygenSeeker,
+    PlayerAvoider, // Skittish prey, scatters as the player closes in
+    OxygenAvoider, // Anaerobes, flee oxygen-rich water
+}
+
+impl ChemicalType {
+    /// Avoiders steer down their gradient instead of up it
+    pub fn steering_sign(&self) -> f32 {
+        match self {
+            ChemicalType::PlayerAvoider | ChemicalType::OxygenAvoider => -1.0,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Turn `current_direction` towards `gradient_direction`, or away from it when `influence` is negative
+pub fn chemotaxis_steer(current_direction: Vec2, gradient_direction: Vec2, influence: f32, dt: f32) -> Vec2 {
+    let target = if influence < 0.0 { -gradient_direction } else { gradient_direction };
+    current_direction.lerp(target, influence.abs() * dt)
 }
 
 #[derive(Component)]
@@ -91,6 +224,96 @@ pub struct ColonyLeader {
     pub pattern_timer: f32,
     pub pattern_type: ColonyPattern,
     pub chemical_communication: bool, // New: uses chemical signals
+    pub morale: ColonyMorale,
+}
+
+// Recent member losses, used to break a colony's nerve when it's being cut down quickly
+#[derive(Clone, Default)]
+pub struct ColonyMorale {
+    pub recent_losses: Vec<f32>, // Timestamps of member deaths
+    pub tracked_members: usize,
+    pub peak_members: usize,
+}
+
+impl ColonyMorale {
+    pub fn update_member_count(&mut self, alive: usize, now: f32) {
+        if alive < self.tracked_members {
+            let lost = self.tracked_members - alive;
+            self.recent_losses.extend(std::iter::repeat_n(now, lost));
+        }
+        self.tracked_members = alive;
+        self.peak_members = self.peak_members.max(alive);
+    }
+
+    /// True once at least `loss_ratio` of the colony has died within the last `loss_window` seconds
+    pub fn is_broken(&mut self, now: f32, loss_window: f32, loss_ratio: f32) -> bool {
+        self.recent_losses.retain(|&t| now - t <= loss_window);
+        self.peak_members > 0 && self.recent_losses.len() as f32 >= self.peak_members as f32 * loss_ratio
+    }
+}
+
+// Survivors of a broken colony scatter for a while before regrouping
+#[derive(Component)]
+pub struct MoraleBroken {
+    pub timer: f32,
+    pub previous_ai: EnemyAI,
+}
+
+// Stationary egg laid by a reproductive vesicle, hatches into offspring unless it's popped first
+#[derive(Component)]
+pub struct EggSac {
+    pub hatch_timer: f32,
+    pub hatch_duration: f32,
+    pub offspring: u32,
+    pub atp_reward: u32, // Dropped when popped before hatching
+}
+
+// Members of a colony whose leader died, scattering before they fall back to drifting straight down
+#[derive(Component)]
+pub struct RoutingBehavior {
+    pub timer: f32,
+    pub direction: Vec2,
+    pub formation_id: u32,
+}
+
+impl RoutingBehavior {
+    /// What a member falls back to once the rout is over
+    pub fn settled_ai() -> EnemyAI {
+        EnemyAI::Linear { direction: Vec2::NEG_Y }
+    }
+}
+
+// Agile enemies sidestep homing missiles aimed at them, the missile overshoots and has to come round again
+#[derive(Component, Clone, Debug)]
+pub struct EvasiveManeuver {
+    pub detect_radius: f32,
+    pub dodge_speed: f32,
+    pub dodge_duration: f32,
+    pub cooldown: f32,
+    pub cooldown_timer: f32,
+    pub dodge_timer: f32,
+    pub dodge_velocity: Vec2,
+}
+
+impl EvasiveManeuver {
+    pub fn new(detect_radius: f32, dodge_speed: f32, cooldown: f32) -> Self {
+        Self {
+            detect_radius,
+            dodge_speed,
+            dodge_duration: 0.2,
+            cooldown,
+            cooldown_timer: 0.0,
+            dodge_timer: 0.0,
+            dodge_velocity: Vec2::ZERO,
+        }
+    }
+
+    /// Sidestep at right angles to the missile's flight, towards whichever side of its line we're already on
+    pub fn dodge_velocity(missile_pos: Vec2, missile_velocity: Vec2, own_pos: Vec2, speed: f32) -> Vec2 {
+        let across = missile_velocity.perp().normalize_or_zero();
+        let side = if across.dot(own_pos - missile_pos) < 0.0 { -1.0 } else { 1.0 };
+        across * side * speed
+    }
 }
 
 #[derive(Clone)]
@@ -110,6 +333,18 @@ pub struct ToxinProducer {
 
 // Enemy stats configuration with biological properties
 impl EnemyType {
+    pub const ALL: [EnemyType; 9] = [
+        EnemyType::ViralParticle,
+        EnemyType::AggressiveBacteria,
+        EnemyType::ParasiticProtozoa,
+        EnemyType::InfectedMacrophage,
+        EnemyType::SuicidalSpore,
+        EnemyType::BiofilmColony,
+        EnemyType::SwarmCell,
+        EnemyType::ReproductiveVesicle,
+        EnemyType::Offspring,
+    ];
+
     pub fn get_stats(&self) -> (i32, f32, f32, Color) {
         match self {
             EnemyType::ViralParticle => (20, 15.0, 50.0, Color::srgb(0.8, 0.9, 1.0)), // Reduced from 150.0
@@ -212,6 +447,86 @@ impl EnemyType {
             EnemyType::Offspring => "Newly spawned cell with basic mobility",
         }
     }
+
+    /// Reverse of `get_biological_description`, kill events carry the description rather than the type
+    pub fn from_description(description: &str) -> Option<EnemyType> {
+        EnemyType::ALL.iter().copied().find(|enemy_type| enemy_type.get_biological_description() == description)
+    }
+
+    /// Only the quick, light cells can get out of a missile's way
+    pub fn evasive_maneuver(&self) -> Option<EvasiveManeuver> {
+        match self {
+            EnemyType::ViralParticle => Some(EvasiveManeuver::new(90.0, 280.0, 1.5)),
+            EnemyType::AggressiveBacteria => Some(EvasiveManeuver::new(80.0, 240.0, 2.0)),
+            EnemyType::Offspring => Some(EvasiveManeuver::new(70.0, 320.0, 1.2)),
+            _ => None,
+        }
+    }
+
+    pub fn bullet_pattern(&self) -> BulletPattern {
+        match self {
+            EnemyType::AggressiveBacteria => BulletPattern::AimedBurst { count: 3, speed_step: 40.0 },
+            EnemyType::ParasiticProtozoa => BulletPattern::Fan { count: 5, spread: 0.8 },
+            EnemyType::InfectedMacrophage => BulletPattern::Spiral { arms: 4, turn_rate: 1.2 },
+            EnemyType::BiofilmColony => BulletPattern::Ring { count: 10 },
+            _ => BulletPattern::Straight,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            EnemyType::ViralParticle => "Viral Particle",
+            EnemyType::AggressiveBacteria => "Aggressive Bacteria",
+            EnemyType::ParasiticProtozoa => "Parasitic Protozoa",
+            EnemyType::InfectedMacrophage => "Infected Macrophage",
+            EnemyType::SuicidalSpore => "Suicidal Spore",
+            EnemyType::BiofilmColony => "Biofilm Colony",
+            EnemyType::SwarmCell => "Swarm Cell",
+            EnemyType::ReproductiveVesicle => "Reproductive Vesicle",
+            EnemyType::Offspring => "Offspring",
+        }
+    }
+}
+
+// ===== BULLET PATTERNS =====
+/// Shapes a single enemy volley, all patterns are built around the direction to the player
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BulletPattern {
+    Straight,                                // One shot at the player
+    Fan { count: u32, spread: f32 },         // Shots spread evenly across `spread` radians, centred on the player
+    Ring { count: u32 },                     // Evenly spaced shots in every direction
+    Spiral { arms: u32, turn_rate: f32 },    // Ring of `arms` shots that rotates `turn_rate` radians per second
+    AimedBurst { count: u32, speed_step: f32 }, // Stacked shots at the player, each one a little faster
+}
+
+impl BulletPattern {
+    /// Velocity of every projectile in one volley, `phase` is the elapsed time used to turn spirals
+    pub fn velocities(&self, aim: Vec2, speed: f32, phase: f32) -> Vec<Vec2> {
+        let aim = if aim == Vec2::ZERO { Vec2::NEG_Y } else { aim.normalize() };
+        let base_angle = aim.y.atan2(aim.x);
+        let at_angle = |angle: f32| Vec2::new(angle.cos(), angle.sin()) * speed;
+
+        match *self {
+            BulletPattern::Straight => vec![aim * speed],
+            BulletPattern::Fan { count, spread } => {
+                if count <= 1 { return vec![aim * speed]; }
+                let step = spread / (count - 1) as f32;
+                (0..count).map(|i| at_angle(base_angle - spread * 0.5 + step * i as f32)).collect()
+            }
+            BulletPattern::Ring { count } => {
+                let step = std::f32::consts::TAU / count.max(1) as f32;
+                (0..count.max(1)).map(|i| at_angle(base_angle + step * i as f32)).collect()
+            }
+            BulletPattern::Spiral { arms, turn_rate } => {
+                let step = std::f32::consts::TAU / arms.max(1) as f32;
+                let offset = phase * turn_rate;
+                (0..arms.max(1)).map(|i| at_angle(base_angle + offset + step * i as f32)).collect()
+            }
+            BulletPattern::AimedBurst { count, speed_step } => {
+                (0..count.max(1)).map(|i| aim * (speed + speed_step * i as f32)).collect()
+            }
+        }
+    }
 }
 
 impl ColonyPattern {
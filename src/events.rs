@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use crate::components::{PowerUpType, ParticleConfig, ExplosionType};
-use crate::enemy_types::{EnemyAI, EnemyType};
+use crate::enemy_types::{EnemyAI, EnemyType, BossMovement};
 use crate::card_system::{CardType};
 
 #[derive(Event)]
@@ -10,6 +10,34 @@ pub struct SpawnExplosion {
     pub enemy_type: Option<EnemyType>,
 }
 
+#[derive(Event)]
+pub struct ProjectileSplit {
+    pub position: Vec3,
+    pub velocity: Vec2,
+    pub damage: i32,
+    pub fragments: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TelegraphCueKind {
+    TurretCharge,     // Turret is about to fire
+    BossPhaseShift,   // Mini-boss switching attack phase
+    BossRoar,         // Mini-boss arriving, played as its intro starts
+}
+
+/// Audio companion to a visual telegraph, played positionally at the source
+#[derive(Event)]
+pub struct TelegraphCue {
+    pub kind: TelegraphCueKind,
+    pub position: Vec3,
+}
+
+#[derive(Event)]
+pub struct FrozenShatter {
+    pub position: Vec3,
+    pub max_health: i32, // Shard damage scales off the shattered enemy
+}
+
 #[derive(Event)]
 pub struct SpawnEnemy {
     pub position: Vec3,
@@ -17,6 +45,29 @@ pub struct SpawnEnemy {
     pub enemy_type: EnemyType,
 }
 
+/// A boss crossed a health threshold and switched to its next pattern
+#[derive(Event)]
+pub struct BossPhaseChanged {
+    pub entity: Entity,
+    pub phase: usize,
+    pub movement: BossMovement,
+    pub position: Vec3,
+}
+
+/// An egg sac was destroyed before it hatched
+#[derive(Event)]
+pub struct EggSacPopped {
+    pub position: Vec3,
+    pub atp_reward: u32,
+}
+
+/// Broadcast wherever an enemy is killed, so nearby allies can react
+#[derive(Event)]
+pub struct EnemyDied {
+    pub position: Vec3,
+    pub enemy_type: Option<EnemyType>,
+}
+
 #[derive(Event)]
 pub struct SpawnPowerUp {
     pub position: Vec3,
@@ -34,6 +85,7 @@ pub struct SpawnParticles {
 pub struct PlayerHit {
     pub position: Vec3,
     pub damage: i32,
+    pub source: &'static str, // What landed the hit, for the damage log
 }
 
 #[derive(Event)]
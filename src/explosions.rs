@@ -5,7 +5,6 @@ use crate::resources::*;
 use crate::events::*;
 use crate::enemy_types::*;
 use crate::despawn::*;
-use crate::hanabi_particles::*;
 
 #[derive(Resource)]
 pub struct EntityPools {
@@ -351,11 +350,18 @@ impl Default for Particle {
 // ===== 
 
 
+// Soft ring used instead of layered flashes when photosensitive-safe mode is on
+#[derive(Component)]
+pub struct SafeExplosion {
+    pub base_color: Color,
+}
+
 pub fn consolidated_explosion_system(
     mut commands: Commands,
-    mut explosion_query: Query<(Entity, &mut Explosion, &mut Transform, &mut Sprite), Without<PendingDespawn>>,
+    mut explosion_query: Query<(Entity, &mut Explosion, &mut Transform, &mut Sprite, Option<&SafeExplosion>), Without<PendingDespawn>>,
     mut explosion_events: EventReader<SpawnExplosion>,
     mut shake_events: EventWriter<AddScreenShake>,
+    accessibility: Res<AccessibilitySettings>,
     assets: Option<Res<GameAssets>>,
     time: Res<Time>,
 ) {
@@ -378,10 +384,17 @@ pub fn consolidated_explosion_system(
                 _ => ExplosionType::Standard,
             };
             
-            let layers = create_explosion_layers(&explosion_type, event.intensity);
             let shake_amount = calculate_shake_amount(&explosion_type, event.intensity);
+            if !accessibility.reduce_motion {
+                shake_events.write(AddScreenShake { amount: shake_amount });
+            }
             
-            shake_events.write(AddScreenShake { amount: shake_amount });
+            if accessibility.photosensitive_safe {
+                spawn_safe_explosion(&mut commands, &assets, event, explosion_type, accessibility.max_flash_brightness);
+                continue;
+            }
+            
+            let layers = create_explosion_layers(&explosion_type, event.intensity);
             
             // Get explosion color for lighting
             let light_color = get_explosion_light_color(&explosion_type);
@@ -401,7 +414,7 @@ pub fn consolidated_explosion_system(
                     timer: 0.0,
                     max_time: 2.0,
                     intensity: event.intensity,
-                    explosion_type: explosion_type.clone(),
+                    explosion_type,
                     layers,
                     current_layer_index: 0,
                 },
@@ -425,7 +438,7 @@ pub fn consolidated_explosion_system(
         }
         
         // Update existing explosions with dynamic lighting
-        for (entity, mut explosion, mut transform, mut sprite) in explosion_query.iter_mut() {
+        for (entity, mut explosion, mut transform, mut sprite, safe) in explosion_query.iter_mut() {
             explosion.timer += time.delta_secs();
             
             if explosion.timer >= explosion.max_time {
@@ -434,6 +447,13 @@ pub fn consolidated_explosion_system(
                 continue;
             }
             
+            if let Some(safe) = safe {
+                let progress = explosion.timer / explosion.max_time;
+                transform.scale = Vec3::splat(explosion.intensity * (1.0 + progress * 2.0));
+                sprite.color = safe_explosion_color(safe.base_color, progress, accessibility.max_flash_brightness);
+                continue;
+            }
+            
             let explosion_clone = explosion.clone();
             
             // Process explosion layers in sequence
@@ -482,6 +502,42 @@ pub fn consolidated_explosion_system(
     }
 }
 
+fn spawn_safe_explosion(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    event: &SpawnExplosion,
+    explosion_type: ExplosionType,
+    brightness_cap: f32,
+) {
+    // Muted tint, no light, no flash layers: just a ring that grows and fades
+    let base_color = get_explosion_color(&explosion_type).mix(&Color::srgb(0.3, 0.4, 0.5), 0.4);
+
+    commands.spawn((
+        Sprite {
+            image: assets.explosion_texture.clone(),
+            color: safe_explosion_color(base_color, 0.0, brightness_cap),
+            custom_size: Some(Vec2::splat(32.0 * event.intensity)),
+            ..default()
+        },
+        Transform::from_translation(event.position),
+        Explosion {
+            timer: 0.0,
+            max_time: 1.2,
+            intensity: event.intensity,
+            explosion_type,
+            layers: Vec::new(),
+            current_layer_index: 0,
+        },
+        SafeExplosion { base_color },
+    ));
+}
+
+/// Fade-out colour for safe explosions, always within the brightness cap
+pub fn safe_explosion_color(base_color: Color, progress: f32, brightness_cap: f32) -> Color {
+    let alpha = 0.5 * (1.0 - progress.clamp(0.0, 1.0));
+    cap_color_brightness(base_color.with_alpha(alpha), brightness_cap)
+}
+
 // Helper function for explosion light colors
 fn get_explosion_light_color(explosion_type: &ExplosionType) -> Color {
     match explosion_type {
@@ -542,7 +598,7 @@ fn get_explosion_color(explosion_type: &ExplosionType) -> Color {
 
 // Update create_explosion_layers to include MiniBlast for small explosions
 fn create_explosion_layers(explosion_type: &ExplosionType, intensity: f32) -> Vec<ExplosionLayer> {
-    let layers = match explosion_type {
+    match explosion_type {
         ExplosionType::Biological { toxin_release, membrane_rupture } => {
             let mut bio_layers = vec![
                 ExplosionLayer {
@@ -617,9 +673,7 @@ fn create_explosion_layers(explosion_type: &ExplosionType, intensity: f32) -> Ve
                 ]
             }
         }
-    };
-    
-    layers
+    }
 }
 
 // Layer update functions
@@ -909,7 +963,7 @@ pub fn spawn_explosion_system(
                     timer: 0.0,
                     max_time: 1.5,
                     intensity: event.intensity,
-                    explosion_type: explosion_type.clone(),
+                    explosion_type,
                     layers,
                     current_layer_index: 0,
                 },
@@ -937,7 +991,7 @@ fn spawn_shockwave(
         _ => Color::srgb(1.0, 0.8, 0.4),
     };
     
-    let layers = create_explosion_layers(&explosion_type, intensity);
+    let layers = create_explosion_layers(explosion_type, intensity);
 
     commands.spawn((
         Sprite {
@@ -951,9 +1005,47 @@ fn spawn_shockwave(
             timer: 0.0,
             max_time: 0.4,
             intensity,
-            explosion_type: explosion_type.clone(),
+            explosion_type: *explosion_type,
             layers,
             current_layer_index: 0,
         },
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brightness(color: Color) -> f32 {
+        let srgba = color.to_srgba();
+        srgba.red.max(srgba.green).max(srgba.blue) * srgba.alpha
+    }
+
+    #[test]
+    fn safe_explosions_stay_under_the_brightness_cap_for_their_whole_life() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<GameAssets>()
+            .insert_resource(AccessibilitySettings { photosensitive_safe: true, reduce_motion: false, max_flash_brightness: 0.3 })
+            .add_event::<SpawnExplosion>()
+            .add_event::<AddScreenShake>()
+            .add_systems(Update, consolidated_explosion_system);
+        app.world_mut().send_event(SpawnExplosion { position: Vec3::ZERO, intensity: 2.0, enemy_type: Some(EnemyType::InfectedMacrophage) });
+
+        let mut frames_checked = 0;
+        for _ in 0..15 {
+            app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(100));
+            app.update();
+
+            let mut explosions = app.world_mut().query_filtered::<&Sprite, With<Explosion>>();
+            for sprite in explosions.iter(app.world()) {
+                assert!(brightness(sprite.color) <= 0.3 + 1e-4, "flash too bright: {:?}", sprite.color);
+                frames_checked += 1;
+            }
+        }
+        assert!(frames_checked > 5);
+
+        let mut lights = app.world_mut().query::<&PointLight>();
+        assert_eq!(lights.iter(app.world()).count(), 0);
+    }
+}
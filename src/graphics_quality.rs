@@ -0,0 +1,148 @@
+// src/graphics_quality.rs - Low/Medium/High presets over GraphicsSettings for weaker machines
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::resources::{GraphicsSettings, LightingQuality};
+use crate::constants::{MAX_PARTICLES, MAX_TRAIL_SEGMENTS};
+use crate::save_versioning::*;
+
+// ===== CONSTANTS =====
+const GRAPHICS_SAVE_PATH: &str = "graphics_settings.json";
+const MIN_TRAIL_POINTS: usize = 2;
+
+pub const LOW_PARTICLE_DENSITY: f32 = 0.25;
+pub const MEDIUM_PARTICLE_DENSITY: f32 = 0.5;
+pub const HIGH_PARTICLE_DENSITY: f32 = 1.0;
+
+// ===== QUALITY PRESETS =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GraphicsQuality {
+    Low,
+    Medium,
+    #[default]
+    High,
+}
+
+impl GraphicsQuality {
+    pub fn particle_density(&self) -> f32 {
+        match self {
+            GraphicsQuality::Low => LOW_PARTICLE_DENSITY,
+            GraphicsQuality::Medium => MEDIUM_PARTICLE_DENSITY,
+            GraphicsQuality::High => HIGH_PARTICLE_DENSITY,
+        }
+    }
+
+    pub fn lighting_quality(&self) -> LightingQuality {
+        match self {
+            GraphicsQuality::Low => LightingQuality::Low,
+            GraphicsQuality::Medium => LightingQuality::Medium,
+            GraphicsQuality::High => LightingQuality::High,
+        }
+    }
+
+    pub fn post_processing(&self) -> bool {
+        !matches!(self, GraphicsQuality::Low)
+    }
+
+    pub fn next(&self) -> GraphicsQuality {
+        match self {
+            GraphicsQuality::Low => GraphicsQuality::Medium,
+            GraphicsQuality::Medium => GraphicsQuality::High,
+            GraphicsQuality::High => GraphicsQuality::Low,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            GraphicsQuality::Low => "Low",
+            GraphicsQuality::Medium => "Medium",
+            GraphicsQuality::High => "High",
+        }
+    }
+}
+
+// ===== EFFECT BUDGET =====
+/// Every particle and trail spawner sizes itself from these, so one density setting governs them all
+impl GraphicsSettings {
+    /// Max live particle effects, new bursts are dropped and old particles culled past this
+    pub fn particle_budget(&self) -> usize {
+        (MAX_PARTICLES as f32 * self.particle_density).round() as usize
+    }
+
+    pub fn allows_particles(&self, live_effects: usize) -> bool {
+        live_effects < self.particle_budget()
+    }
+
+    /// Segments kept by a trail that runs `full_length` segments at full density
+    pub fn trail_points(&self, full_length: usize) -> usize {
+        ((full_length as f32 * self.particle_density).round() as usize).clamp(MIN_TRAIL_POINTS, full_length)
+    }
+
+    pub fn trail_max_points(&self) -> usize {
+        self.trail_points(MAX_TRAIL_SEGMENTS)
+    }
+
+    /// Multiplier on ambient background particles
+    pub fn background_detail(&self) -> f32 {
+        self.particle_density
+    }
+
+    pub fn select(&mut self, quality: GraphicsQuality) {
+        self.quality = quality;
+        self.particle_density = quality.particle_density();
+        self.lighting_quality = quality.lighting_quality();
+        self.post_processing = quality.post_processing();
+    }
+
+    pub fn cycle(&mut self) {
+        self.select(self.quality.next());
+    }
+
+    pub fn save(&mut self) {
+        self.version = Self::CURRENT_VERSION;
+        save_versioned(GRAPHICS_SAVE_PATH, &*self);
+    }
+}
+
+impl VersionedSave for GraphicsSettings {
+    const CURRENT_VERSION: u32 = 1;
+    const LABEL: &'static str = "graphics settings";
+}
+
+pub fn load_graphics_settings(mut commands: Commands) {
+    let settings = load_versioned::<GraphicsSettings>(GRAPHICS_SAVE_PATH).unwrap_or_default();
+    commands.insert_resource(settings);
+}
+
+// ===== PLUGIN =====
+pub struct GraphicsQualityPlugin;
+
+impl Plugin for GraphicsQualityPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GraphicsSettings>()
+            .add_systems(Startup, load_graphics_settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selecting_low_shrinks_the_particle_budget_and_trails() {
+        let mut settings = GraphicsSettings::default();
+        settings.select(GraphicsQuality::Low);
+
+        assert_eq!(settings.particle_budget(), 50);
+        assert_eq!(settings.trail_max_points(), MIN_TRAIL_POINTS);
+        assert!(!settings.post_processing);
+        assert_eq!(settings.lighting_quality, LightingQuality::Low);
+    }
+
+    #[test]
+    fn high_quality_uses_the_full_budget() {
+        let settings = GraphicsSettings::default();
+        assert_eq!(settings.particle_budget(), MAX_PARTICLES);
+        assert_eq!(settings.trail_max_points(), MAX_TRAIL_SEGMENTS);
+    }
+}
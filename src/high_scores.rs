@@ -1,29 +1,22 @@
 use bevy::prelude::*;
 use crate::components::*;
 use crate::resources::*;
+use crate::save_versioning::*;
 use std::fs;
 use std::path::Path;
 
 pub fn load_high_scores_from_file(mut game_score: ResMut<GameScore>) {
     let save_path = get_save_path();
     
-    match fs::read_to_string(&save_path) {
-        Ok(content) => {
-            match serde_json::from_str::<HighScoreData>(&content) {
-                Ok(data) => {
-                    game_score.high_scores = data.scores.iter().map(|entry| entry.score).collect();
-                    game_score.high_score_data = Some(data);
-                    println!("Loaded {} high scores from {}", game_score.high_scores.len(), save_path);
-                }
-                Err(e) => {
-                    println!("Failed to parse high scores: {}", e);
-                    game_score.high_score_data = Some(HighScoreData::default());
-                    game_score.high_scores = game_score.high_score_data.as_ref().unwrap().scores.iter().map(|e| e.score).collect();
-                }
-            }
+    // Missing or incompatible files fall back to defaults (incompatible ones are backed up first)
+    match load_versioned::<HighScoreData>(&save_path) {
+        Some(data) => {
+            game_score.high_scores = data.scores.iter().map(|entry| entry.score).collect();
+            game_score.high_score_data = Some(data);
+            println!("Loaded {} high scores from {}", game_score.high_scores.len(), save_path);
         }
-        Err(_) => {
-            println!("No save file found, creating default high scores");
+        None => {
+            println!("No usable save file found, creating default high scores");
             game_score.high_score_data = Some(HighScoreData::default());
             game_score.high_scores = game_score.high_score_data.as_ref().unwrap().scores.iter().map(|e| e.score).collect();
         }
@@ -40,6 +33,7 @@ pub fn save_high_score_to_file(
     }
     
     let mut high_score_data = game_score.high_score_data.take().unwrap_or_default();
+    high_score_data.version = HighScoreData::CURRENT_VERSION;
     
     // Get current game stats
     let current_evolution = player_query.single()
@@ -63,7 +57,7 @@ pub fn save_high_score_to_file(
     
     // Add to scores and sort
     high_score_data.scores.push(new_entry);
-    high_score_data.scores.sort_by(|a, b| b.score.cmp(&a.score));
+    high_score_data.scores.sort_by_key(|entry| std::cmp::Reverse(entry.score));
     high_score_data.scores.truncate(10); // Keep top 10
     
     // Update statistics
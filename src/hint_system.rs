@@ -0,0 +1,489 @@
+// src/hint_system.rs - Adaptive tutorial hints driven by balance issue detection
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use crate::components::*;
+use crate::resources::*;
+use crate::balance_systems::*;
+use crate::input::*;
+use crate::despawn::*;
+use crate::wave_systems::WaveManager;
+
+// ===== CONSTANTS =====
+const HINT_GLOBAL_COOLDOWN: f32 = 12.0;   // Minimum gap between any two hints
+const HINT_REPEAT_COOLDOWN: f32 = 60.0;   // Minimum gap before the same hint shows again
+const HINT_DISPLAY_TIME: f32 = 6.0;
+const LOW_HEALTH_THRESHOLD: i32 = 25;
+const SUGGESTION_DISPLAY_TIME: f32 = 10.0;
+
+// ===== HINT TYPES =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TutorialHint {
+    CollectAtp,
+    UseEmergencySpore,
+    VisitEvolutionChamber,
+    KeepMoving,
+}
+
+impl TutorialHint {
+    pub fn message(&self) -> &'static str {
+        match self {
+            TutorialHint::CollectAtp => "Collect ATP from defeated cells",
+            TutorialHint::UseEmergencySpore => "Press Shift+Space for an emergency spore",
+            TutorialHint::VisitEvolutionChamber => "Spend ATP at an evolution chamber to boost your damage",
+            TutorialHint::KeepMoving => "Keep moving - grab Flagella power-ups to speed up",
+        }
+    }
+
+    /// Which hint, if any, helps with a detected balance issue
+    pub fn for_issue(issue_type: &BalanceIssueType) -> Option<TutorialHint> {
+        match issue_type {
+            BalanceIssueType::ATPStarvation => Some(TutorialHint::CollectAtp),
+            BalanceIssueType::InvincibilityTooShort => Some(TutorialHint::UseEmergencySpore),
+            BalanceIssueType::WeaponUnderPowered => Some(TutorialHint::VisitEvolutionChamber),
+            BalanceIssueType::ProgressionTooSlow => Some(TutorialHint::KeepMoving),
+            _ => None,
+        }
+    }
+}
+
+// ===== HINT STATE =====
+#[derive(Resource)]
+pub struct HintSystem {
+    pub enabled: bool,
+    pub global_cooldown: f32,
+    pub repeat_cooldown: f32,
+    pub last_hint_time: Option<f32>,
+    pub last_shown: HashMap<TutorialHint, f32>,
+    pub dismissed: HashSet<TutorialHint>,
+    pub processed_issues: usize, // Balance issues already considered this session
+}
+
+impl Default for HintSystem {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            global_cooldown: HINT_GLOBAL_COOLDOWN,
+            repeat_cooldown: HINT_REPEAT_COOLDOWN,
+            last_hint_time: None,
+            last_shown: HashMap::new(),
+            dismissed: HashSet::new(),
+            processed_issues: 0,
+        }
+    }
+}
+
+impl HintSystem {
+    /// Rate-limited gate: returns true and records the hint if it may be shown at `now`
+    pub fn try_show(&mut self, hint: TutorialHint, now: f32) -> bool {
+        if !self.enabled || self.dismissed.contains(&hint) {
+            return false;
+        }
+        if self.last_hint_time.is_some_and(|last| now - last < self.global_cooldown) {
+            return false;
+        }
+        if self.last_shown.get(&hint).is_some_and(|last| now - last < self.repeat_cooldown) {
+            return false;
+        }
+
+        self.last_hint_time = Some(now);
+        self.last_shown.insert(hint, now);
+        true
+    }
+
+    /// First hint the unseen tail of the issue log calls for that the rate limit lets through
+    pub fn consume_issues(&mut self, issues: &[BalanceIssue], now: f32) -> Option<TutorialHint> {
+        // The session log is cleared between runs
+        if issues.len() < self.processed_issues {
+            self.processed_issues = 0;
+        }
+
+        let fresh = &issues[self.processed_issues..];
+        self.processed_issues = issues.len();
+
+        fresh.iter()
+            .filter_map(|issue| TutorialHint::for_issue(&issue.issue_type))
+            .find(|hint| self.try_show(*hint, now))
+    }
+
+    pub fn dismiss(&mut self, hint: TutorialHint) {
+        self.dismissed.insert(hint);
+    }
+}
+
+// ===== DIFFICULTY SUGGESTIONS =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DifficultySuggestion {
+    Easier,
+    Harder,
+}
+
+impl DifficultySuggestion {
+    pub fn message(&self) -> &'static str {
+        match self {
+            DifficultySuggestion::Easier => "Rough start - next run, try it without challenge modifiers",
+            DifficultySuggestion::Harder => "Flawless start - pick a challenge modifier next run for bonus score",
+        }
+    }
+}
+
+/// Reads the first few waves of a run and suggests an easier or harder setup once
+#[derive(Resource, Clone)]
+pub struct DifficultyAutoDetect {
+    pub enabled: bool,
+    pub evaluate_after_wave: u32,  // Judged once this many waves are cleared
+    pub easier_deaths: u32,        // Lives lost that call for an easier setup
+    pub easier_hits: usize,        // Hits taken that call for an easier setup
+    pub harder_max_hits: usize,    // A flawless start takes at most this many hits
+    pub harder_max_time: f32,      // ...and clears the early waves within this many seconds
+    pub deaths: u32,
+    pub run_time: f32,
+    pub last_lives: Option<i32>,
+    pub suggestion: Option<DifficultySuggestion>,
+    pub evaluated: bool,
+}
+
+impl Default for DifficultyAutoDetect {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            evaluate_after_wave: 2,
+            easier_deaths: 1,
+            easier_hits: 12,
+            harder_max_hits: 2,
+            harder_max_time: 120.0,
+            deaths: 0,
+            run_time: 0.0,
+            last_lives: None,
+            suggestion: None,
+            evaluated: false,
+        }
+    }
+}
+
+impl DifficultyAutoDetect {
+    /// Count lives lost since the last sample
+    pub fn track_lives(&mut self, lives: i32) {
+        if let Some(last) = self.last_lives && lives < last {
+            self.deaths += (last - lives) as u32;
+        }
+        self.last_lives = Some(lives);
+    }
+
+    pub fn evaluate(&self, hits_taken: usize) -> Option<DifficultySuggestion> {
+        if self.deaths >= self.easier_deaths || hits_taken >= self.easier_hits {
+            Some(DifficultySuggestion::Easier)
+        } else if self.deaths == 0 && hits_taken <= self.harder_max_hits && self.run_time <= self.harder_max_time {
+            Some(DifficultySuggestion::Harder)
+        } else {
+            None
+        }
+    }
+}
+
+// ===== COMPONENTS =====
+#[derive(Component)]
+pub struct HintNotification {
+    pub hint: TutorialHint,
+    pub timer: f32,
+}
+
+#[derive(Component)]
+pub struct DifficultySuggestionPrompt {
+    pub timer: f32,
+}
+
+// ===== SYSTEMS =====
+pub fn hint_system(
+    mut commands: Commands,
+    mut hints: ResMut<HintSystem>,
+    balance_analyzer: Res<BalanceAnalyzer>,
+    player_query: Query<&Health, With<Player>>,
+    notification_query: Query<(), With<HintNotification>>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+    let issues = &balance_analyzer.real_time_balance.current_session.balance_issues;
+
+    // One hint on screen at a time, issues raised meanwhile are skipped rather than queued
+    if !notification_query.is_empty() {
+        hints.processed_issues = issues.len();
+        return;
+    }
+
+    let mut hint = hints.consume_issues(issues, now);
+
+    // Low health is urgent enough to check directly instead of waiting on the analyzer
+    if hint.is_none() && player_query.single().is_ok_and(|health| health.0 <= LOW_HEALTH_THRESHOLD) {
+        hint = Some(TutorialHint::UseEmergencySpore).filter(|hint| hints.try_show(*hint, now));
+    }
+
+    if let Some(hint) = hint {
+        spawn_hint_notification(&mut commands, hint);
+    }
+}
+
+fn spawn_hint_notification(commands: &mut Commands, hint: TutorialHint) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            bottom: Val::Px(120.0),
+            width: Val::Px(420.0),
+            margin: UiRect::left(Val::Px(-210.0)),
+            padding: UiRect::all(Val::Px(10.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.2, 0.25, 0.85)),
+        BorderColor(Color::srgb(0.4, 0.9, 0.8)),
+        HintNotification { hint, timer: HINT_DISPLAY_TIME },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(hint.message()),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+
+        parent.spawn((
+            Text::new("[H] dismiss"),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.6, 0.8, 0.8)),
+        ));
+    });
+}
+
+pub fn update_hint_notifications(
+    mut commands: Commands,
+    mut hints: ResMut<HintSystem>,
+    input_manager: Res<InputManager>,
+    mut notification_query: Query<(Entity, &mut HintNotification), Without<PendingDespawn>>,
+    time: Res<Time>,
+) {
+    let dismissing = input_manager.just_pressed(InputAction::DismissHint);
+
+    for (entity, mut notification) in notification_query.iter_mut() {
+        notification.timer -= time.delta_secs();
+
+        // Dismissing silences that hint for the rest of the session
+        if dismissing {
+            hints.dismiss(notification.hint);
+        }
+
+        if dismissing || notification.timer <= 0.0 {
+            commands.entity(entity).safe_despawn();
+        }
+    }
+}
+
+/// Judge the opening waves once, then show a dismissible prompt with the suggestion
+pub fn difficulty_suggestion_system(
+    mut commands: Commands,
+    mut auto_detect: ResMut<DifficultyAutoDetect>,
+    wave_manager: Res<WaveManager>,
+    damage_log: Res<DamageLog>,
+    player_query: Query<&Player>,
+    time: Res<Time>,
+) {
+    if !auto_detect.enabled || auto_detect.evaluated { return; }
+
+    auto_detect.run_time += time.delta_secs();
+    if let Ok(player) = player_query.single() {
+        auto_detect.track_lives(player.lives);
+    }
+
+    // current_wave counts up as each wave completes
+    if wave_manager.current_wave <= auto_detect.evaluate_after_wave { return; }
+
+    let hits_taken = damage_log.entries.iter()
+        .filter(|entry| entry.direction == DamageDirection::Taken)
+        .count();
+    auto_detect.evaluated = true;
+    auto_detect.suggestion = auto_detect.evaluate(hits_taken);
+
+    if let Some(suggestion) = auto_detect.suggestion {
+        info!("Difficulty suggestion: {:?}", suggestion);
+        spawn_suggestion_prompt(&mut commands, suggestion);
+    }
+}
+
+fn spawn_suggestion_prompt(commands: &mut Commands, suggestion: DifficultySuggestion) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Px(90.0),
+            width: Val::Px(460.0),
+            margin: UiRect::left(Val::Px(-230.0)),
+            padding: UiRect::all(Val::Px(10.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.2, 0.12, 0.05, 0.85)),
+        BorderColor(Color::srgb(1.0, 0.75, 0.3)),
+        DifficultySuggestionPrompt { timer: SUGGESTION_DISPLAY_TIME },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(suggestion.message()),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+
+        parent.spawn((
+            Text::new("[H] dismiss"),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.9, 0.8, 0.6)),
+        ));
+    });
+}
+
+pub fn update_difficulty_suggestion_prompt(
+    mut commands: Commands,
+    input_manager: Res<InputManager>,
+    mut prompt_query: Query<(Entity, &mut DifficultySuggestionPrompt), Without<PendingDespawn>>,
+    time: Res<Time>,
+) {
+    let dismissing = input_manager.just_pressed(InputAction::DismissHint);
+
+    for (entity, mut prompt) in prompt_query.iter_mut() {
+        prompt.timer -= time.delta_secs();
+        if dismissing || prompt.timer <= 0.0 {
+            commands.entity(entity).safe_despawn();
+        }
+    }
+}
+
+pub fn reset_difficulty_auto_detect(mut auto_detect: ResMut<DifficultyAutoDetect>) {
+    *auto_detect = DifficultyAutoDetect { enabled: auto_detect.enabled, ..default() };
+}
+
+pub fn cleanup_difficulty_suggestion_prompt(mut commands: Commands, prompt_query: Query<Entity, With<DifficultySuggestionPrompt>>) {
+    for entity in prompt_query.iter() {
+        commands.entity(entity).safe_despawn();
+    }
+}
+
+/// Fresh rate limits and dismissals for each new run
+pub fn reset_hint_system(mut commands: Commands) {
+    commands.insert_resource(HintSystem::default());
+}
+
+pub fn cleanup_hint_notifications(mut commands: Commands, notification_query: Query<Entity, With<HintNotification>>) {
+    for entity in notification_query.iter() {
+        commands.entity(entity).safe_despawn();
+    }
+}
+
+// ===== PLUGIN =====
+pub struct HintPlugin;
+
+impl Plugin for HintPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<HintSystem>()
+            .init_resource::<DifficultyAutoDetect>()
+            .add_systems(OnExit(GameState::TitleScreen), (reset_hint_system, reset_difficulty_auto_detect))
+            .add_systems(OnExit(GameState::GameOver), reset_difficulty_auto_detect)
+            .add_systems(OnExit(GameState::Playing), (cleanup_hint_notifications, cleanup_difficulty_suggestion_prompt))
+            .add_systems(Update, (
+                hint_system,
+                update_hint_notifications,
+                difficulty_suggestion_system,
+                update_difficulty_suggestion_prompt,
+            ).run_if(in_state(IsPaused::Running)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn hint_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<HintSystem>()
+            .init_resource::<BalanceAnalyzer>()
+            .add_systems(Update, hint_system);
+        app
+    }
+
+    fn raise_atp_starvation(app: &mut App) {
+        app.world_mut().resource_mut::<BalanceAnalyzer>().real_time_balance.current_session.balance_issues.push(BalanceIssue {
+            issue_type: BalanceIssueType::ATPStarvation,
+            severity: 0.8,
+            description: String::new(),
+            suggested_fix: String::new(),
+        });
+    }
+
+    fn shown_hints(app: &mut App) -> Vec<TutorialHint> {
+        let mut query = app.world_mut().query::<&HintNotification>();
+        query.iter(app.world()).map(|notification| notification.hint).collect()
+    }
+
+    fn clear_notifications(app: &mut App) {
+        let shown: Vec<Entity> = app.world_mut().query_filtered::<Entity, With<HintNotification>>().iter(app.world()).collect();
+        for entity in shown {
+            app.world_mut().despawn(entity);
+        }
+    }
+
+    #[test]
+    fn atp_starvation_shows_the_collect_atp_hint_once_within_the_rate_limit() {
+        let mut app = hint_app();
+        raise_atp_starvation(&mut app);
+        app.update();
+        assert_eq!(shown_hints(&mut app), vec![TutorialHint::CollectAtp]);
+
+        // Past the global gap but inside the repeat cooldown, the same issue stays quiet
+        clear_notifications(&mut app);
+        app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(HINT_GLOBAL_COOLDOWN + 1.0));
+        raise_atp_starvation(&mut app);
+        app.update();
+        assert!(shown_hints(&mut app).is_empty());
+    }
+
+    #[test]
+    fn dismissed_hints_never_come_back() {
+        let mut app = hint_app();
+        app.world_mut().resource_mut::<HintSystem>().dismiss(TutorialHint::CollectAtp);
+        raise_atp_starvation(&mut app);
+        app.update();
+        assert!(shown_hints(&mut app).is_empty());
+    }
+
+    fn suggestion_after_early_waves(lives_lost: i32) -> Option<DifficultySuggestion> {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<DifficultyAutoDetect>()
+            .init_resource::<WaveManager>()
+            .init_resource::<DamageLog>()
+            .add_systems(Update, difficulty_suggestion_system);
+        let player = app.world_mut().spawn(Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 }).id();
+        let step = |app: &mut App| {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs(5));
+            app.update();
+        };
+
+        step(&mut app);
+        app.world_mut().get_mut::<Player>(player).unwrap().lives -= lives_lost;
+        step(&mut app);
+        let evaluate_after = app.world().resource::<DifficultyAutoDetect>().evaluate_after_wave;
+        app.world_mut().resource_mut::<WaveManager>().current_wave = evaluate_after + 1;
+        step(&mut app);
+
+        let auto_detect = app.world().resource::<DifficultyAutoDetect>();
+        assert!(auto_detect.evaluated);
+        auto_detect.suggestion
+    }
+
+    #[test]
+    fn rough_starts_suggest_easier_and_flawless_starts_suggest_harder() {
+        assert_eq!(suggestion_after_early_waves(2), Some(DifficultySuggestion::Easier));
+        assert_eq!(suggestion_after_early_waves(0), Some(DifficultySuggestion::Harder));
+    }
+}
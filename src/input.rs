@@ -2,7 +2,9 @@ use bevy::prelude::*;
 use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::resources::{GameState, IsPaused};
+use crate::resources::{GameState, IsPaused, RestartConfirmation};
+use crate::components::RestartConfirmDialog;
+use crate::despawn::SafeDespawn;
 
 // ===== INPUT ACTIONS =====
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -16,15 +18,22 @@ pub enum InputAction {
     // Combat
     Shoot,
     EmergencySpore,  // Space bar special attack
+    ToggleBeamLock,  // Lock beams onto the nearest enemy
+    CycleEvolution,  // Switch between owned evolutions
+    Cloak,           // Stealth, chemotaxis enemies lose the player's trail
     
     // Game Control
     Pause,
     Restart,
+    DismissHint,
     
     // Debug (remove in release)
     DebugSpawnATP,
     DebugSpawnEvolutionChamber,
     DebugTriggerKingTide,
+    DebugSpawnTargetDummy,
+    DebugToggleAiRanges,
+    DebugSkipWave,
     
     // Evolution Chamber Upgrades
     UpgradeDamage,         // 1
@@ -39,6 +48,11 @@ pub enum InputAction {
 
     UpgradeMagnetRadius,
     UpgradeMagnetStrength,    
+
+    // Weapon mod slots (socketed at an evolution chamber)
+    SocketPierceMod,
+    SocketHomingMod,
+    SocketSplitMod,
 }
 
 // ===== INPUT BINDINGS =====
@@ -109,6 +123,11 @@ pub struct InputManager {
     // Input blocking (for UI, pause, etc.)
     pub blocked_actions: Vec<InputAction>,
     
+    // Input buffering, presses of buffered actions are held until consumed or expired
+    pub input_buffer: HashMap<InputAction, u32>, // Frames remaining per buffered press
+    pub buffered_actions: Vec<InputAction>,
+    pub buffer_frames: u32,                       // 0 disables buffering
+    
     // Debug mode
     pub debug_enabled: bool,
 }
@@ -125,6 +144,14 @@ impl Default for InputManager {
             ai_states: HashMap::new(),
             ai_analog: HashMap::new(),
             blocked_actions: Vec::new(),
+            input_buffer: HashMap::new(),
+            buffered_actions: vec![
+                InputAction::EmergencySpore,
+                InputAction::ToggleBeamLock,
+                InputAction::CycleEvolution,
+                InputAction::Cloak,
+            ],
+            buffer_frames: 4,
             debug_enabled: cfg!(debug_assertions),
         };
         
@@ -205,6 +232,36 @@ impl InputManager {
             mouse: Some(MouseButton::Right),
         });
         
+        self.bind_action(ToggleBeamLock, InputBinding {
+            keyboard: Some(KeyboardBinding { key: KeyCode::KeyQ, modifier: None }),
+            gamepad: Some(GamepadBinding { 
+                button: Some(GamepadButton::LeftTrigger), 
+                axis: None, 
+                axis_negative: false 
+            }),
+            mouse: None,
+        });
+        
+        self.bind_action(CycleEvolution, InputBinding {
+            keyboard: Some(KeyboardBinding { key: KeyCode::KeyE, modifier: None }),
+            gamepad: Some(GamepadBinding { 
+                button: Some(GamepadButton::RightTrigger), 
+                axis: None, 
+                axis_negative: false 
+            }),
+            mouse: None,
+        });
+        
+        self.bind_action(Cloak, InputBinding {
+            keyboard: Some(KeyboardBinding { key: KeyCode::KeyF, modifier: None }),
+            gamepad: Some(GamepadBinding { 
+                button: Some(GamepadButton::West), 
+                axis: None, 
+                axis_negative: false 
+            }),
+            mouse: None,
+        });
+        
         // Game control
         self.bind_action(Pause, InputBinding {
             keyboard: Some(KeyboardBinding { key: KeyCode::KeyP, modifier: None }),
@@ -226,6 +283,16 @@ impl InputManager {
             mouse: None,
         });
         
+        self.bind_action(DismissHint, InputBinding {
+            keyboard: Some(KeyboardBinding { key: KeyCode::KeyH, modifier: None }),
+            gamepad: Some(GamepadBinding { 
+                button: Some(GamepadButton::North), 
+                axis: None, 
+                axis_negative: false 
+            }),
+            mouse: None,
+        });
+        
         // Evolution chamber upgrades
         for (i, action) in [
             UpgradeDamage, UpgradeMetabolic, UpgradeCellular, UpgradeEnzyme,
@@ -254,6 +321,19 @@ impl InputManager {
             });
         }
         
+        // Weapon mod sockets
+        for (action, key) in [
+            (SocketPierceMod, KeyCode::KeyZ),
+            (SocketHomingMod, KeyCode::KeyX),
+            (SocketSplitMod, KeyCode::KeyC),
+        ] {
+            self.bind_action(action, InputBinding {
+                keyboard: Some(KeyboardBinding { key, modifier: None }),
+                gamepad: None,
+                mouse: None,
+            });
+        }
+        
         // Debug bindings (only in debug builds)
         if cfg!(debug_assertions) {
             self.bind_action(DebugSpawnATP, InputBinding {
@@ -273,6 +353,24 @@ impl InputManager {
                 gamepad: None,
                 mouse: None,
             });
+            
+            self.bind_action(DebugSpawnTargetDummy, InputBinding {
+                keyboard: Some(KeyboardBinding { key: KeyCode::F5, modifier: None }),
+                gamepad: None,
+                mouse: None,
+            });
+            
+            self.bind_action(DebugToggleAiRanges, InputBinding {
+                keyboard: Some(KeyboardBinding { key: KeyCode::F6, modifier: None }),
+                gamepad: None,
+                mouse: None,
+            });
+            
+            self.bind_action(DebugSkipWave, InputBinding {
+                keyboard: Some(KeyboardBinding { key: KeyCode::F7, modifier: None }),
+                gamepad: None,
+                mouse: None,
+            });
         }
     }
     
@@ -282,10 +380,8 @@ impl InputManager {
     }
     
     pub fn add_keyboard_binding(&mut self, action: InputAction, key: KeyCode) {
-        if let Some(binding) = self.bindings.get_mut(&action) {
-            if binding.keyboard.is_none() {
-                binding.keyboard = Some(KeyboardBinding { key, modifier: None });
-            }
+        if let Some(binding) = self.bindings.get_mut(&action) && binding.keyboard.is_none() {
+            binding.keyboard = Some(KeyboardBinding { key, modifier: None });
         }
     }
     
@@ -293,6 +389,14 @@ impl InputManager {
         self.bindings.remove(&action);
     }
     
+    /// Short name of the key bound to `action` for on-screen prompts, e.g. "R" for KeyCode::KeyR
+    pub fn key_label(&self, action: InputAction) -> Option<String> {
+        let key = self.bindings.get(&action)?.keyboard.as_ref()?.key;
+        let name = format!("{:?}", key);
+        let label = name.strip_prefix("Key").or_else(|| name.strip_prefix("Digit")).unwrap_or(&name);
+        Some(label.to_string())
+    }
+    
     // ===== INPUT QUERY METHODS =====
     pub fn action_state(&self, action: InputAction) -> InputState {
         if self.blocked_actions.contains(&action) {
@@ -330,6 +434,37 @@ impl InputManager {
         self.action_state(action).just_released()
     }
     
+    // ===== INPUT BUFFER =====
+    /// Hold a press of a buffered action for buffer_frames frames
+    pub fn buffer_press(&mut self, action: InputAction) {
+        if self.buffer_frames == 0 || !self.buffered_actions.contains(&action) { return; }
+        self.input_buffer.insert(action, self.buffer_frames);
+    }
+    
+    /// Age buffered presses by one frame, dropping the expired ones
+    pub fn tick_buffer(&mut self) {
+        self.input_buffer.retain(|_, frames| {
+            *frames = frames.saturating_sub(1);
+            *frames > 0
+        });
+    }
+    
+    /// True on the press frame or while the press is still buffered
+    pub fn buffered_press(&self, action: InputAction) -> bool {
+        if self.blocked_actions.contains(&action) { return false; }
+        if self.ai_override { return self.just_pressed(action); }
+        self.just_pressed(action) || self.input_buffer.contains_key(&action)
+    }
+    
+    /// Like buffered_press, but uses up the buffered press so it only fires once
+    pub fn consume_press(&mut self, action: InputAction) -> bool {
+        let pressed = self.buffered_press(action);
+        if pressed {
+            self.input_buffer.remove(&action);
+        }
+        pressed
+    }
+    
     // ===== MOVEMENT VECTOR HELPERS =====
     pub fn movement_vector(&self) -> Vec2 {
         let x = self.action_value(InputAction::MoveRight) - self.action_value(InputAction::MoveLeft);
@@ -381,14 +516,14 @@ impl InputManager {
     pub fn block_all_input(&mut self) {
         use InputAction::*;
         self.blocked_actions = vec![
-            MoveLeft, MoveRight, MoveUp, MoveDown, Shoot, EmergencySpore,
-            Pause, Restart, UpgradeDamage, UpgradeMetabolic, UpgradeCellular,
+            MoveLeft, MoveRight, MoveUp, MoveDown, Shoot, EmergencySpore, ToggleBeamLock, CycleEvolution, Cloak,
+            Pause, Restart, DismissHint, UpgradeDamage, UpgradeMetabolic, UpgradeCellular,
             UpgradeEnzyme, UpgradeBioluminescence, UpgradeSpore, EvolvePseudopod,
-            EvolveSymbiotic, EvolveBioluminescent
+            EvolveSymbiotic, EvolveBioluminescent, SocketPierceMod, SocketHomingMod, SocketSplitMod
         ];
         
         if self.debug_enabled {
-            self.blocked_actions.extend([DebugSpawnATP, DebugSpawnEvolutionChamber, DebugTriggerKingTide]);
+            self.blocked_actions.extend([DebugSpawnATP, DebugSpawnEvolutionChamber, DebugTriggerKingTide, DebugSpawnTargetDummy, DebugToggleAiRanges, DebugSkipWave]);
         }
     }
     
@@ -415,6 +550,7 @@ pub fn input_update_system(
 ) {
     // Store previous states for edge detection
     input_manager.previous_states = input_manager.current_states.clone();
+    input_manager.tick_buffer();
     
     // Auto-detect gamepad if none is active
     if input_manager.active_gamepad.is_none() {
@@ -428,6 +564,7 @@ pub fn input_update_system(
     // Update all input states
     for (&action, binding) in &input_manager.bindings.clone() {
         let mut pressed = false;
+        let mut tapped = false; // Pressed at some point this frame, even if already released
         let mut analog_value = 0.0f32;
         
         // Check keyboard input
@@ -442,6 +579,7 @@ pub fn input_update_system(
                 pressed = true;
                 analog_value = 1.0;
             }
+            tapped |= keyboard.just_pressed(kb_binding.key);
         }
         
         // Check mouse input
@@ -450,36 +588,38 @@ pub fn input_update_system(
                 pressed = true;
                 analog_value = 1.0;
             }
+            tapped |= mouse.just_pressed(mouse_button);
         }
         
         // Check gamepad input using the Gamepad component directly
-        if let (Some(gamepad_binding), Some(gamepad_entity)) = (&binding.gamepad, input_manager.active_gamepad) {
-            if let Ok((_entity, gamepad)) = gamepad_query.get(gamepad_entity) {
-                // Check button
-                if let Some(button_type) = gamepad_binding.button {
-                    // In Bevy 0.16.1, use gamepad.pressed() directly
-                    if gamepad.pressed(button_type) {
-                        pressed = true;
-                        analog_value = 1.0;
-                    }
+        if let (Some(gamepad_binding), Some(gamepad_entity)) = (&binding.gamepad, input_manager.active_gamepad)
+            && let Ok((_entity, gamepad)) = gamepad_query.get(gamepad_entity)
+        {
+            // Check button
+            if let Some(button_type) = gamepad_binding.button {
+                // In Bevy 0.16.1, use gamepad.pressed() directly
+                if gamepad.pressed(button_type) {
+                    pressed = true;
+                    analog_value = 1.0;
                 }
+                tapped |= gamepad.just_pressed(button_type);
+            }
                 
-                // Check axis
-                if let Some((axis_type, threshold)) = gamepad_binding.axis {
-                    // In Bevy 0.16.1, use gamepad.get() directly
-                    if let Some(axis_value) = gamepad.get(axis_type) {
-                        let abs_value = axis_value.abs();
-                        if abs_value >= threshold {
-                            let normalized_value = if gamepad_binding.axis_negative {
-                                if axis_value < -threshold { -axis_value } else { 0.0 }
-                            } else {
-                                if axis_value > threshold { axis_value } else { 0.0 }
-                            };
+            // Check axis
+            if let Some((axis_type, threshold)) = gamepad_binding.axis {
+                // In Bevy 0.16.1, use gamepad.get() directly
+                if let Some(axis_value) = gamepad.get(axis_type) {
+                    let abs_value = axis_value.abs();
+                    if abs_value >= threshold {
+                        let normalized_value = if gamepad_binding.axis_negative {
+                            if axis_value < -threshold { -axis_value } else { 0.0 }
+                        } else {
+                            if axis_value > threshold { axis_value } else { 0.0 }
+                        };
                             
-                            analog_value = normalized_value.clamp(0.0, 1.0);
-                            if analog_value > 0.0 {
-                                pressed = true;
-                            }
+                        analog_value = normalized_value.clamp(0.0, 1.0);
+                        if analog_value > 0.0 {
+                            pressed = true;
                         }
                     }
                 }
@@ -492,12 +632,16 @@ pub fn input_update_system(
         // Determine input state based on current and previous frames
         let previous_state = input_manager.previous_states.get(&action).copied().unwrap_or(InputState::Released);
         let new_state = match (previous_state.is_pressed(), pressed) {
+            (false, false) if tapped => InputState::JustPressed, // Pressed and released within one frame
             (false, false) => InputState::Released,
             (false, true) => InputState::JustPressed,
             (true, true) => InputState::Pressed,
             (true, false) => InputState::JustReleased,
         };
         
+        if new_state.just_pressed() {
+            input_manager.buffer_press(action);
+        }
         input_manager.current_states.insert(action, new_state);
     }
 }
@@ -540,7 +684,6 @@ pub fn handle_pause_input(
         match current_state.get() {
             IsPaused::Running => { next_state.set(IsPaused::Paused); },
             IsPaused::Paused => { next_state.set(IsPaused::Running); },
-            _ => {}
         }
     }
 }
@@ -548,13 +691,59 @@ pub fn handle_pause_input(
 // System for handling restart input
 pub fn handle_restart_input(
     input_manager: Res<InputManager>,
+    mut confirmation: ResMut<RestartConfirmation>,
     mut next_state: ResMut<NextState<GameState>>,
+    time: Res<Time<Real>>,
 ) {
-    if input_manager.just_pressed(InputAction::Restart) {
+    // Ticking every frame would mark the resource changed and rebuild the prompt, only flag when it lapses
+    let was_pending = confirmation.is_pending();
+    confirmation.bypass_change_detection().tick(time.delta_secs());
+    if was_pending != confirmation.is_pending() {
+        confirmation.set_changed();
+    }
+
+    if input_manager.just_pressed(InputAction::Restart) && confirmation.request() {
         next_state.set(GameState::Playing);
     }
 }
 
+/// Show the confirm prompt only while a restart is waiting on its second press
+pub fn restart_confirm_dialog_system(
+    mut commands: Commands,
+    confirmation: Res<RestartConfirmation>,
+    input_manager: Res<InputManager>,
+    dialog_query: Query<Entity, With<RestartConfirmDialog>>,
+) {
+    if !confirmation.is_changed() { return; }
+
+    match (confirmation.is_pending(), dialog_query.is_empty()) {
+        (true, true) => {
+            commands.spawn((
+                Text::new(format!(
+                    "Restart run? Press {} again to confirm",
+                    input_manager.key_label(InputAction::Restart).unwrap_or_else(|| "Restart".to_string()),
+                )),
+                TextFont { font_size: 24.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.8, 0.3)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(45.0),
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                TextLayout::new_with_justify(JustifyText::Center),
+                RestartConfirmDialog,
+            ));
+        }
+        (false, false) => {
+            for entity in dialog_query.iter() {
+                commands.entity(entity).safe_despawn();
+            }
+        }
+        _ => {}
+    }
+}
 
 // ===== PLUGIN SETUP =====
 pub struct InputPlugin;
@@ -563,13 +752,15 @@ impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<InputManager>()
+            .init_resource::<RestartConfirmation>()
             .add_systems(PreUpdate, (
                 gamepad_connection_system,
                 input_update_system,
             ))
             .add_systems(Update, (
                 handle_restart_input,
-            ));
+                restart_confirm_dialog_system,
+            ).chain());
     }
 }
 
@@ -634,4 +825,85 @@ impl AIInput for InputManager {
     fn release_action(&mut self, action: InputAction) {
         self.set_ai_action(action, InputState::JustReleased);
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<InputManager>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .add_systems(Update, input_update_system);
+        app
+    }
+
+    /// Press and release within a single frame, then let the next frames run with nothing held
+    fn tap_spore(app: &mut App) {
+        let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        mouse.press(MouseButton::Right);
+        mouse.release(MouseButton::Right);
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().clear();
+    }
+
+    #[test]
+    fn a_tap_inside_one_frame_is_still_caught_on_the_next_run() {
+        let mut app = input_app();
+        tap_spore(&mut app);
+        assert!(app.world().resource::<InputManager>().just_pressed(InputAction::EmergencySpore));
+
+        app.update();
+        let mut input_manager = app.world_mut().resource_mut::<InputManager>();
+        assert!(!input_manager.just_pressed(InputAction::EmergencySpore));
+        assert!(input_manager.consume_press(InputAction::EmergencySpore));
+        assert!(!input_manager.consume_press(InputAction::EmergencySpore));
+    }
+
+    #[test]
+    fn buffered_presses_expire_after_the_window() {
+        let mut app = input_app();
+        let buffer_frames = app.world().resource::<InputManager>().buffer_frames;
+        tap_spore(&mut app);
+
+        for _ in 1..buffer_frames {
+            app.update();
+            assert!(app.world().resource::<InputManager>().buffered_press(InputAction::EmergencySpore));
+        }
+        app.update();
+        assert!(!app.world().resource::<InputManager>().buffered_press(InputAction::EmergencySpore));
+    }
+
+    #[test]
+    fn restart_asks_for_confirmation_before_resetting_the_run() {
+        use bevy::state::app::StatesPlugin;
+        use crate::components::PendingDespawn;
+
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .insert_state(GameState::GameOver)
+            .init_resource::<InputManager>()
+            .init_resource::<RestartConfirmation>()
+            .init_resource::<Time<Real>>()
+            .add_systems(Update, (handle_restart_input, restart_confirm_dialog_system).chain());
+        let press_restart = |app: &mut App, pressed: bool| {
+            let state = if pressed { InputState::JustPressed } else { InputState::Released };
+            app.world_mut().resource_mut::<InputManager>().current_states.insert(InputAction::Restart, state);
+            app.update();
+        };
+        let dialogs = |app: &mut App| {
+            app.world_mut().query_filtered::<(), (With<RestartConfirmDialog>, Without<PendingDespawn>)>().iter(app.world()).count()
+        };
+
+        press_restart(&mut app, true);
+        press_restart(&mut app, false);
+        assert_eq!(dialogs(&mut app), 1);
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::GameOver);
+
+        press_restart(&mut app, true);
+        press_restart(&mut app, false);
+        assert_eq!(dialogs(&mut app), 0);
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Playing);
+    }
+}
@@ -0,0 +1,294 @@
+// src/leaderboard_submission.rs - Daily challenge submissions that carry the setup and score ledger of a run
+//
+// Nothing here proves a score. The checksum is unkeyed and the ledger comes from the client, so a
+// forged ledger with a recomputed checksum passes `check_submission_consistency`; it only catches
+// corrupted or hand-edited files and obviously impossible ledgers before they are sent.
+//
+// Replay verification is deliberately not attempted. The replay code and seeds rebuild a run's
+// setup, not its play: wave spawns and enemy AI draw from unseeded RNG, player input is never
+// recorded and the simulation steps on variable frame time. Re-simulating a run needs all three
+// fixed first (seeded gameplay RNG, a recorded input stream, a fixed timestep), and then a server
+// to run it
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use crate::resources::*;
+use crate::loadout_presets::*;
+use crate::time_attack::*;
+use crate::replay_codes::*;
+
+// ===== CONSTANTS =====
+pub const SUBMISSION_VERSION: u8 = 1;
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// ===== CONFIG =====
+#[derive(Resource, Clone)]
+pub struct LeaderboardSubmissionConfig {
+    pub enabled: bool,
+    pub max_points_per_frame: u32, // Anything above this in one frame can't come from normal play
+    pub output_path: String,
+}
+
+impl Default for LeaderboardSubmissionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_points_per_frame: 20_000,
+            output_path: "leaderboard_submission.json".to_string(),
+        }
+    }
+}
+
+// ===== SCORE LEDGER =====
+/// Points gained on a given frame of the run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoreLedgerEntry {
+    pub frame: u32,
+    pub points: u32,
+}
+
+/// Every score change of the current run, in order
+#[derive(Resource, Default, Clone)]
+pub struct ScoreLedger {
+    pub frame: u32,
+    pub last_score: u32,
+    pub entries: Vec<ScoreLedgerEntry>,
+}
+
+impl ScoreLedger {
+    /// Log the difference since last frame, a score that went down means a new run started
+    pub fn observe(&mut self, score: u32) {
+        if score < self.last_score {
+            *self = Self::default();
+        }
+        self.frame += 1;
+        if score > self.last_score {
+            self.entries.push(ScoreLedgerEntry { frame: self.frame, points: score - self.last_score });
+        }
+        self.last_score = score;
+    }
+}
+
+// ===== SUBMISSION =====
+/// Score plus the setup and ledger it is checked against
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardSubmission {
+    pub version: u8,
+    pub seed: u64,
+    pub replay_code: String,
+    pub claimed_score: u32,
+    pub ledger: Vec<ScoreLedgerEntry>,
+    pub checksum: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubmissionError {
+    UnsupportedVersion(u8),
+    InvalidReplayCode(ReplayCodeError),
+    SeedMismatch { expected: u64, found: u64 },
+    ChecksumMismatch,
+    FramesOutOfOrder(u32),
+    ImplausibleGain { frame: u32, points: u32 },
+    ScoreMismatch { claimed: u32, replayed: u32 },
+}
+
+impl fmt::Display for SubmissionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionError::UnsupportedVersion(v) => write!(f, "unsupported submission version {}", v),
+            SubmissionError::InvalidReplayCode(e) => write!(f, "invalid replay code: {}", e),
+            SubmissionError::SeedMismatch { expected, found } => write!(f, "seed {:016x} is not the challenge seed {:016x}", found, expected),
+            SubmissionError::ChecksumMismatch => write!(f, "submission was modified after it was written"),
+            SubmissionError::FramesOutOfOrder(frame) => write!(f, "replay frame {} is out of order", frame),
+            SubmissionError::ImplausibleGain { frame, points } => write!(f, "{} points on frame {} is not reachable", points, frame),
+            SubmissionError::ScoreMismatch { claimed, replayed } => write!(f, "claimed {} but the ledger adds up to {}", claimed, replayed),
+        }
+    }
+}
+
+impl LeaderboardSubmission {
+    pub fn new(setup: &ReplaySetup, claimed_score: u32, ledger: &[ScoreLedgerEntry]) -> Self {
+        let replay_code = encode_replay_code(setup);
+        let checksum = submission_checksum(setup.environment_seed, &replay_code, ledger);
+        Self {
+            version: SUBMISSION_VERSION,
+            seed: setup.environment_seed,
+            replay_code,
+            claimed_score,
+            ledger: ledger.to_vec(),
+            checksum,
+        }
+    }
+}
+
+/// FNV-1a over the seed, setup and ledger, so careless edits to any of them are caught.
+/// It is not a signature, anyone can recompute it. The claimed score is left out on purpose,
+/// it has to match the ledger instead
+pub fn submission_checksum(seed: u64, replay_code: &str, ledger: &[ScoreLedgerEntry]) -> u64 {
+    let mut hash = FNV_OFFSET;
+    let mut feed = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    };
+    feed(&seed.to_le_bytes());
+    feed(replay_code.as_bytes());
+    for entry in ledger {
+        feed(&entry.frame.to_le_bytes());
+        feed(&entry.points.to_le_bytes());
+    }
+    hash
+}
+
+/// Sum the ledger frame by frame and return the score it reaches
+pub fn ledger_score(ledger: &[ScoreLedgerEntry], max_points_per_frame: u32) -> Result<u32, SubmissionError> {
+    let mut score: u32 = 0;
+    let mut last_frame = 0;
+    for entry in ledger {
+        if entry.frame <= last_frame {
+            return Err(SubmissionError::FramesOutOfOrder(entry.frame));
+        }
+        if entry.points > max_points_per_frame {
+            return Err(SubmissionError::ImplausibleGain { frame: entry.frame, points: entry.points });
+        }
+        score = score.saturating_add(entry.points);
+        last_frame = entry.frame;
+    }
+    Ok(score)
+}
+
+/// Check that a submission agrees with itself, `expected_seed` is the daily challenge seed when
+/// there is one. Passing means the file is well formed, not that the score was earned
+pub fn check_submission_consistency(submission: &LeaderboardSubmission, expected_seed: Option<u64>, max_points_per_frame: u32) -> Result<u32, SubmissionError> {
+    if submission.version != SUBMISSION_VERSION {
+        return Err(SubmissionError::UnsupportedVersion(submission.version));
+    }
+
+    let setup = decode_replay_code(&submission.replay_code).map_err(SubmissionError::InvalidReplayCode)?;
+    if setup.environment_seed != submission.seed {
+        return Err(SubmissionError::SeedMismatch { expected: submission.seed, found: setup.environment_seed });
+    }
+    if let Some(expected) = expected_seed && submission.seed != expected {
+        return Err(SubmissionError::SeedMismatch { expected, found: submission.seed });
+    }
+
+    if submission_checksum(submission.seed, &submission.replay_code, &submission.ledger) != submission.checksum {
+        return Err(SubmissionError::ChecksumMismatch);
+    }
+
+    let replayed = ledger_score(&submission.ledger, max_points_per_frame)?;
+    if replayed != submission.claimed_score {
+        return Err(SubmissionError::ScoreMismatch { claimed: submission.claimed_score, replayed });
+    }
+    Ok(replayed)
+}
+
+// ===== SYSTEMS =====
+pub fn record_score_ledger(
+    config: Res<LeaderboardSubmissionConfig>,
+    game_score: Res<GameScore>,
+    mut ledger: ResMut<ScoreLedger>,
+) {
+    if !config.enabled { return; }
+    ledger.observe(game_score.current);
+}
+
+/// Package the finished run and check it before writing, so a malformed file never gets submitted
+pub fn write_leaderboard_submission(
+    config: Res<LeaderboardSubmissionConfig>,
+    game_score: Res<GameScore>,
+    ledger: Res<ScoreLedger>,
+    environment_seed: Res<EnvironmentSeed>,
+    spread_rng: Res<WeaponSpreadRng>,
+    run_modifiers: Res<RunModifiers>,
+    time_attack: Res<TimeAttack>,
+    loadouts: Res<LoadoutPresets>,
+) {
+    if !config.enabled || game_score.current == 0 { return; }
+
+    let setup = ReplaySetup::capture(&environment_seed, &spread_rng, &run_modifiers, &time_attack, &loadouts);
+    let submission = LeaderboardSubmission::new(&setup, game_score.current, &ledger.entries);
+
+    if let Err(e) = check_submission_consistency(&submission, Some(environment_seed.seed), config.max_points_per_frame) {
+        warn!("Not writing leaderboard submission: {}", e);
+        return;
+    }
+
+    match serde_json::to_string_pretty(&submission) {
+        Ok(json) => match std::fs::write(&config.output_path, json) {
+            Ok(()) => info!("Leaderboard submission for {} points written to {}", submission.claimed_score, config.output_path),
+            Err(e) => warn!("Failed to write leaderboard submission: {}", e),
+        },
+        Err(e) => warn!("Failed to serialize leaderboard submission: {}", e),
+    }
+}
+
+// ===== PLUGIN =====
+pub struct LeaderboardSubmissionPlugin;
+
+impl Plugin for LeaderboardSubmissionPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<LeaderboardSubmissionConfig>()
+            .init_resource::<ScoreLedger>()
+            .add_systems(Update, record_score_ledger.run_if(in_state(GameState::Playing)))
+            .add_systems(OnEnter(GameState::GameOver), write_leaderboard_submission);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: u64 = 0x1234_5678_9abc_def0;
+
+    fn setup() -> ReplaySetup {
+        ReplaySetup { environment_seed: SEED, spread_seed: WeaponSpreadRng::default().seed, run_modifiers: Vec::new(), time_attack: false, loadout: None }
+    }
+
+    fn ledger() -> Vec<ScoreLedgerEntry> {
+        vec![ScoreLedgerEntry { frame: 3, points: 100 }, ScoreLedgerEntry { frame: 9, points: 250 }]
+    }
+
+    #[test]
+    fn consistent_submission_passes() {
+        let submission = LeaderboardSubmission::new(&setup(), 350, &ledger());
+        assert_eq!(check_submission_consistency(&submission, Some(SEED), 20_000), Ok(350));
+    }
+
+    #[test]
+    fn edited_ledger_fails_the_checksum() {
+        let mut submission = LeaderboardSubmission::new(&setup(), 350, &ledger());
+        submission.ledger[0].points = 1_000;
+        assert_eq!(check_submission_consistency(&submission, Some(SEED), 20_000), Err(SubmissionError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn inflated_claim_is_rejected() {
+        let submission = LeaderboardSubmission::new(&setup(), 9_999, &ledger());
+        assert_eq!(
+            check_submission_consistency(&submission, Some(SEED), 20_000),
+            Err(SubmissionError::ScoreMismatch { claimed: 9_999, replayed: 350 }),
+        );
+    }
+
+    #[test]
+    fn wrong_challenge_seed_is_rejected() {
+        let submission = LeaderboardSubmission::new(&setup(), 350, &ledger());
+        assert_eq!(
+            check_submission_consistency(&submission, Some(42), 20_000),
+            Err(SubmissionError::SeedMismatch { expected: 42, found: SEED }),
+        );
+    }
+
+    #[test]
+    fn out_of_order_and_oversized_frames_are_rejected() {
+        let backwards = [ScoreLedgerEntry { frame: 5, points: 10 }, ScoreLedgerEntry { frame: 5, points: 10 }];
+        assert_eq!(ledger_score(&backwards, 20_000), Err(SubmissionError::FramesOutOfOrder(5)));
+
+        let spike = [ScoreLedgerEntry { frame: 1, points: 50_000 }];
+        assert_eq!(ledger_score(&spike, 20_000), Err(SubmissionError::ImplausibleGain { frame: 1, points: 50_000 }));
+    }
+}
@@ -160,6 +160,7 @@ pub fn spawn_entity_lights(
     enemy_query: Query<(Entity, &Enemy), Without<PointLight2d>>,
     // Projectile lights
     projectile_query: Query<(Entity, &Projectile), Without<PointLight2d>>,
+    graphics: Res<GraphicsSettings>,
 ) {
     // Player bioluminescent glow
     for player_entity in player_query.iter() {
@@ -178,6 +179,9 @@ pub fn spawn_entity_lights(
         ));
     }
     
+    // Low quality keeps only the player glow
+    if !graphics.post_processing { return; }
+    
     // Enemy bioluminescent lights (selective for performance)
     for (enemy_entity, enemy) in enemy_query.iter() {
         let (should_glow, color, intensity) = match enemy.enemy_type {
@@ -221,7 +225,13 @@ pub fn spawn_entity_lights(
 pub fn spawn_explosion_lights(
     mut commands: Commands,
     mut explosion_events: EventReader<SpawnExplosion>,
+    graphics: Res<GraphicsSettings>,
 ) {
+    if !graphics.post_processing {
+        explosion_events.clear();
+        return;
+    }
+    
     for explosion in explosion_events.read() {
         // Different light intensities based on explosion type
         let (intensity, color, radius) = match &explosion.enemy_type {
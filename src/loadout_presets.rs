@@ -0,0 +1,249 @@
+// src/loadout_presets.rs - Named evolution loadouts saved between runs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::resources::IsPaused;
+use crate::save_versioning::*;
+
+// ===== CONSTANTS =====
+const LOADOUT_SAVE_PATH: &str = "loadout_presets.json";
+const MAX_LOADOUT_PRESETS: usize = 5;
+
+// ===== PRESET TYPES =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PresetUpgrade {
+    Damage,
+    Metabolic,
+    Cellular,
+    Enzyme,
+    Bioluminescence,
+    MagnetRadius,
+    MagnetStrength,
+}
+
+impl PresetUpgrade {
+    pub const ALL: [PresetUpgrade; 7] = [
+        PresetUpgrade::Damage,
+        PresetUpgrade::Metabolic,
+        PresetUpgrade::Cellular,
+        PresetUpgrade::Enzyme,
+        PresetUpgrade::Bioluminescence,
+        PresetUpgrade::MagnetRadius,
+        PresetUpgrade::MagnetStrength,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PresetUpgrade::Damage => "Membrane Reinforcement",
+            PresetUpgrade::Metabolic => "Metabolic Enhancement",
+            PresetUpgrade::Cellular => "Cellular Integrity",
+            PresetUpgrade::Enzyme => "Enzyme Production",
+            PresetUpgrade::Bioluminescence => "Bioluminescence",
+            PresetUpgrade::MagnetRadius => "Magnet Radius",
+            PresetUpgrade::MagnetStrength => "Magnet Strength",
+        }
+    }
+
+    pub fn current_level(&self, limits: &UpgradeLimits) -> u32 {
+        match self {
+            PresetUpgrade::Damage => limits.damage_level,
+            PresetUpgrade::Metabolic => limits.metabolic_level,
+            PresetUpgrade::Cellular => limits.cellular_level,
+            PresetUpgrade::Enzyme => limits.enzyme_level,
+            PresetUpgrade::Bioluminescence => limits.bioluminescence_level,
+            PresetUpgrade::MagnetRadius => limits.magnet_radius_level,
+            PresetUpgrade::MagnetStrength => limits.magnet_strength_level,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpgradeTarget {
+    pub upgrade: PresetUpgrade,
+    pub level: u32,
+}
+
+/// A starting evolution plus a buying plan
+/// Only the evolution is granted at run start; the plan is bought with ATP and the Evolution Chamber points at its next step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoadoutPreset {
+    pub name: String,
+    pub evolution: EvolutionType,
+    #[serde(alias = "upgrade_priorities")]
+    pub upgrade_plan: Vec<UpgradeTarget>,
+}
+
+impl LoadoutPreset {
+    /// Snapshot a build, highest upgrade levels first so the priorities mirror what was invested in most
+    pub fn from_build(name: String, evolution_system: &EvolutionSystem, limits: &UpgradeLimits) -> Self {
+        let mut upgrade_plan: Vec<UpgradeTarget> = PresetUpgrade::ALL.iter()
+            .map(|upgrade| UpgradeTarget { upgrade: *upgrade, level: upgrade.current_level(limits) })
+            .filter(|target| target.level > 0)
+            .collect();
+        upgrade_plan.sort_by_key(|target| std::cmp::Reverse(target.level));
+
+        Self {
+            name,
+            evolution: evolution_system.primary_evolution.clone(),
+            upgrade_plan,
+        }
+    }
+
+    /// First upgrade still short of its target, in priority order
+    pub fn next_upgrade(&self, limits: &UpgradeLimits) -> Option<PresetUpgrade> {
+        self.upgrade_plan.iter()
+            .find(|target| target.upgrade.current_level(limits) < target.level)
+            .map(|target| target.upgrade)
+    }
+}
+
+// ===== PRESET STORAGE =====
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+pub struct LoadoutPresets {
+    #[serde(default)]
+    pub version: u32,
+    pub presets: Vec<LoadoutPreset>,
+    pub selected: Option<usize>,
+    #[serde(skip)]
+    pub last_build: Option<LoadoutPreset>, // Most recent run's build, offered for saving on the title screen
+    #[serde(skip)]
+    pub shared: Option<LoadoutPreset>,     // Loadout from a replay code, used this session but never saved
+}
+
+impl VersionedSave for LoadoutPresets {
+    const CURRENT_VERSION: u32 = 1;
+    const LABEL: &'static str = "loadout presets";
+}
+
+impl LoadoutPresets {
+    /// A shared loadout from a replay code wins over the saved selection until it's cycled away
+    pub fn selected_preset(&self) -> Option<&LoadoutPreset> {
+        self.shared.as_ref().or_else(|| self.selected.and_then(|index| self.presets.get(index)))
+    }
+
+    /// Step through None -> each saved preset -> None, the first step just drops a shared loadout
+    pub fn cycle_selected(&mut self) {
+        if self.shared.take().is_some() { return; }
+        self.selected = match self.selected {
+            None if !self.presets.is_empty() => Some(0),
+            Some(index) if index + 1 < self.presets.len() => Some(index + 1),
+            _ => None,
+        };
+    }
+
+    /// Store a preset, replacing the oldest once the list is full, and select it
+    pub fn save_preset(&mut self, preset: LoadoutPreset) {
+        if self.presets.len() >= MAX_LOADOUT_PRESETS {
+            self.presets.remove(0);
+        }
+        self.presets.push(preset);
+        self.selected = Some(self.presets.len() - 1);
+    }
+
+    pub fn delete_selected(&mut self) {
+        if self.shared.take().is_some() { return; }
+        if let Some(index) = self.selected.take() && index < self.presets.len() {
+            self.presets.remove(index);
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self.selected_preset() {
+            Some(preset) => format!("{} ({})", preset.name, preset.evolution.get_display_name()),
+            None => "Default".to_string(),
+        }
+    }
+
+    pub fn starting_evolution(&self) -> EvolutionSystem {
+        let mut evolution_system = EvolutionSystem::default();
+        if let Some(preset) = self.selected_preset() {
+            evolution_system.primary_evolution = preset.evolution.clone();
+        }
+        evolution_system
+    }
+
+    pub fn save(&mut self) {
+        self.version = Self::CURRENT_VERSION;
+        save_versioned(LOADOUT_SAVE_PATH, &*self);
+    }
+}
+
+pub fn load_loadout_presets(mut commands: Commands) {
+    let presets = load_versioned::<LoadoutPresets>(LOADOUT_SAVE_PATH).unwrap_or_default();
+    commands.insert_resource(presets);
+}
+
+// ===== SYSTEMS =====
+/// Keep a snapshot of the live build so it can be saved as a preset after the run
+pub fn record_last_build(
+    mut loadouts: ResMut<LoadoutPresets>,
+    player_query: Query<(&EvolutionSystem, &UpgradeLimits), (With<Player>, Or<(Changed<EvolutionSystem>, Changed<UpgradeLimits>)>)>,
+) {
+    let Ok((evolution_system, limits)) = player_query.single() else { return };
+    let name = format!("Loadout {}", loadouts.presets.len() + 1);
+    loadouts.last_build = Some(LoadoutPreset::from_build(name, evolution_system, limits));
+}
+
+/// The first player cell exists before the title screen, so it follows the selection while on it
+/// Later runs respawn the player from `starting_evolution`
+pub fn apply_loadout_to_player(
+    loadouts: Res<LoadoutPresets>,
+    mut player_query: Query<&mut EvolutionSystem, With<Player>>,
+) {
+    let primary_evolution = loadouts.starting_evolution().primary_evolution;
+    for mut evolution_system in player_query.iter_mut() {
+        evolution_system.primary_evolution = primary_evolution.clone();
+    }
+}
+
+// ===== PLUGIN =====
+pub struct LoadoutPresetPlugin;
+
+impl Plugin for LoadoutPresetPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<LoadoutPresets>()
+            .add_systems(Startup, load_loadout_presets)
+            .add_systems(Update, record_last_build.run_if(in_state(IsPaused::Running)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invested_build() -> (EvolutionSystem, UpgradeLimits) {
+        let evolution_system = EvolutionSystem {
+            primary_evolution: EvolutionType::PseudopodNetwork { damage: 12, tendril_count: 5, spread_angle: 0.8, fire_rate: 0.15 },
+            ..default()
+        };
+        let limits = UpgradeLimits { damage_level: 3, magnet_radius_level: 1, ..default() };
+        (evolution_system, limits)
+    }
+
+    #[test]
+    fn saved_preset_reloads_with_the_same_evolution_and_plan() {
+        let (evolution_system, limits) = invested_build();
+        let mut loadouts = LoadoutPresets::default();
+        loadouts.save_preset(LoadoutPreset::from_build("Tendrils".to_string(), &evolution_system, &limits));
+
+        let json = serde_json::to_string(&loadouts).unwrap();
+        let reloaded: LoadoutPresets = serde_json::from_str(&json).unwrap();
+        let preset = reloaded.selected_preset().unwrap();
+
+        assert_eq!(reloaded.starting_evolution().primary_evolution, evolution_system.primary_evolution);
+        assert_eq!(preset.upgrade_plan, vec![
+            UpgradeTarget { upgrade: PresetUpgrade::Damage, level: 3 },
+            UpgradeTarget { upgrade: PresetUpgrade::MagnetRadius, level: 1 },
+        ]);
+        // A fresh cell follows the plan from its first step
+        assert_eq!(preset.next_upgrade(&UpgradeLimits::default()), Some(PresetUpgrade::Damage));
+    }
+
+    #[test]
+    fn presets_saved_before_the_rename_still_load_their_plan() {
+        let json = r#"{"name":"Old","evolution":{"CytoplasmicSpray":{"damage":10,"fire_rate":0.1}},"upgrade_priorities":[{"upgrade":"Enzyme","level":2}]}"#;
+        let preset: LoadoutPreset = serde_json::from_str(json).unwrap();
+        assert_eq!(preset.upgrade_plan, vec![UpgradeTarget { upgrade: PresetUpgrade::Enzyme, level: 2 }]);
+    }
+}
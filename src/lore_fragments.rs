@@ -0,0 +1,254 @@
+// src/lore_fragments.rs - Environmental DNA fragments that unlock lore entries
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::resources::*;
+use crate::enemy_types::*;
+use crate::achievements::*;
+use crate::save_versioning::*;
+use crate::despawn::*;
+
+// ===== CONSTANTS =====
+const UNLOCK_SAVE_PATH: &str = "lore_unlocks.json";
+const FRAGMENT_SPAWN_INTERVAL: f32 = 45.0;
+const FRAGMENT_SPAWN_CHANCE: f32 = 0.35;
+const FRAGMENT_SAFE_DISTANCE: f32 = 160.0;   // Minimum gap to any enemy or hostile shot
+const FRAGMENT_SPAWN_CANDIDATES: usize = 8;
+const FRAGMENT_DRIFT_SPEED: f32 = 35.0;
+const FRAGMENT_COLLECT_RADIUS: f32 = 14.0;
+const FRAGMENT_SCORE: u32 = 500;
+
+pub const LORE_ENTRIES: [(&str, &str); 8] = [
+    ("Primordial Soup", "Before the tide pools, chemistry alone stirred the shallows. Something learned to copy itself."),
+    ("The First Membrane", "A fragile lipid wall turned chemistry into a self. Inside and outside were never the same again."),
+    ("Endosymbiosis", "One cell swallowed another and neither died. The mitochondria still remember being free."),
+    ("Viral Drift", "The viral particles carry borrowed code from a thousand hosts, none of it their own."),
+    ("Quorum", "Bacteria whisper in molecules. When enough agree, the colony moves as one organism."),
+    ("The Macrophage Fall", "The pool's guardians were corrupted first. Infected sentinels now hunt what they once protected."),
+    ("King Tide", "Every lunar cycle the sea floods the pool, scattering colonies and rewriting the balance of power."),
+    ("Tidal Memory", "Each fragment you gather is a page of the pool's genome. Together they tell how life began here."),
+];
+
+// ===== COMPONENTS =====
+#[derive(Component)]
+pub struct DnaFragment {
+    pub lore_index: usize,
+    pub pulse_phase: f32,
+}
+
+// ===== UNLOCK STATE =====
+/// Persistent meta-progression for collected fragments and unlocked lore
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+pub struct UnlockState {
+    #[serde(default)]
+    pub version: u32,
+    pub fragments_collected: u32,
+    pub unlocked_lore: Vec<usize>,
+}
+
+impl VersionedSave for UnlockState {
+    const CURRENT_VERSION: u32 = 1;
+    const LABEL: &'static str = "lore unlocks";
+}
+
+impl UnlockState {
+    pub fn unlocked_lore_count(&self) -> usize {
+        self.unlocked_lore.len()
+    }
+
+    pub fn is_unlocked(&self, lore_index: usize) -> bool {
+        self.unlocked_lore.contains(&lore_index)
+    }
+
+    /// Next entry a fresh fragment should reveal, None once everything is unlocked
+    pub fn next_locked_entry(&self) -> Option<usize> {
+        (0..LORE_ENTRIES.len()).find(|index| !self.is_unlocked(*index))
+    }
+
+    /// Credit a collected fragment, returns true if it revealed a new lore entry
+    pub fn collect_fragment(&mut self, lore_index: usize) -> bool {
+        self.fragments_collected += 1;
+        if lore_index >= LORE_ENTRIES.len() || self.is_unlocked(lore_index) {
+            return false;
+        }
+        self.unlocked_lore.push(lore_index);
+        true
+    }
+}
+
+pub fn load_unlock_state(mut commands: Commands) {
+    let unlock_state = load_versioned::<UnlockState>(UNLOCK_SAVE_PATH).unwrap_or_default();
+    commands.insert_resource(unlock_state);
+}
+
+// ===== SAFE SPAWN SELECTION =====
+/// Pick the candidate furthest from every threat, rejecting all of them if none clears `min_distance`
+pub fn pick_safe_fragment_spot(candidates: &[Vec2], threats: &[Vec2], min_distance: f32) -> Option<Vec2> {
+    candidates
+        .iter()
+        .map(|candidate| {
+            let clearance = threats.iter()
+                .map(|threat| candidate.distance(*threat))
+                .fold(f32::INFINITY, f32::min);
+            (*candidate, clearance)
+        })
+        .filter(|(_, clearance)| *clearance >= min_distance)
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(candidate, _)| candidate)
+}
+
+// ===== SYSTEMS =====
+pub fn dna_fragment_spawn_system(
+    mut commands: Commands,
+    unlock_state: Res<UnlockState>,
+    fragment_query: Query<&DnaFragment>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    projectile_query: Query<(&Transform, &Projectile), Without<Enemy>>,
+    assets: Option<Res<GameAssets>>,
+    time: Res<Time>,
+    mut spawn_timer: Local<f32>,
+) {
+    let Some(assets) = assets else { return };
+
+    *spawn_timer += time.delta_secs();
+    if *spawn_timer < FRAGMENT_SPAWN_INTERVAL { return; }
+    *spawn_timer = 0.0;
+
+    // Rare, one at a time, and only while there is lore left to find
+    if !fragment_query.is_empty() { return; }
+    let Some(lore_index) = unlock_state.next_locked_entry() else { return };
+    if rand::random::<f32>() > FRAGMENT_SPAWN_CHANCE { return; }
+
+    let candidates: Vec<Vec2> = (0..FRAGMENT_SPAWN_CANDIDATES)
+        .map(|_| Vec2::new(rand::random_range(-500.0..500.0), rand::random_range(50.0..320.0)))
+        .collect();
+    let threats: Vec<Vec2> = enemy_query.iter()
+        .map(|transform| transform.translation.truncate())
+        .chain(projectile_query.iter()
+            .filter(|(_, projectile)| !projectile.friendly)
+            .map(|(transform, _)| transform.translation.truncate()))
+        .collect();
+
+    let Some(position) = pick_safe_fragment_spot(&candidates, &threats, FRAGMENT_SAFE_DISTANCE) else { return };
+
+    commands.spawn((
+        Sprite {
+            image: assets.particle_texture.clone(),
+            color: Color::srgb(0.6, 0.4, 1.0),
+            custom_size: Some(Vec2::splat(20.0)),
+            ..default()
+        },
+        Transform::from_translation(position.extend(0.3)),
+        DnaFragment { lore_index, pulse_phase: 0.0 },
+        Collider { radius: FRAGMENT_COLLECT_RADIUS },
+    ));
+}
+
+pub fn dna_fragment_drift_system(
+    mut commands: Commands,
+    mut fragment_query: Query<(Entity, &mut Transform, &mut Sprite, &mut DnaFragment), Without<PendingDespawn>>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut transform, mut sprite, mut fragment) in fragment_query.iter_mut() {
+        fragment.pulse_phase += dt * 3.0;
+        transform.translation.y -= FRAGMENT_DRIFT_SPEED * dt;
+        transform.translation.x += fragment.pulse_phase.sin() * 12.0 * dt;
+        transform.rotate_z(dt * 0.8);
+        sprite.color.set_alpha(0.7 + fragment.pulse_phase.sin() * 0.3);
+
+        if transform.translation.y < -420.0 {
+            commands.entity(entity).safe_despawn();
+        }
+    }
+}
+
+pub fn dna_fragment_collection_system(
+    mut commands: Commands,
+    mut unlock_state: ResMut<UnlockState>,
+    mut game_score: ResMut<GameScore>,
+    mut achievement_events: EventWriter<AchievementEvent>,
+    fragment_query: Query<(Entity, &Transform, &Collider, &DnaFragment), Without<PendingDespawn>>,
+    player_query: Query<(&Transform, &Collider), With<Player>>,
+) {
+    let Ok((player_transform, player_collider)) = player_query.single() else { return };
+
+    for (entity, transform, collider, fragment) in fragment_query.iter() {
+        let distance = player_transform.translation.distance(transform.translation);
+        if distance >= player_collider.radius + collider.radius { continue; }
+
+        if unlock_state.collect_fragment(fragment.lore_index) {
+            info!("Lore unlocked: {}", LORE_ENTRIES[fragment.lore_index].0);
+        }
+
+        game_score.current += FRAGMENT_SCORE;
+        achievement_events.write(AchievementEvent::StoryFragmentFound);
+        commands.entity(entity).safe_despawn();
+    }
+}
+
+/// Write unlocks to disk whenever a collection changes them
+pub fn save_unlock_state_system(mut unlock_state: ResMut<UnlockState>) {
+    unlock_state.version = UnlockState::CURRENT_VERSION;
+    save_versioned(UNLOCK_SAVE_PATH, &*unlock_state);
+}
+
+// ===== PLUGIN =====
+pub struct LoreFragmentPlugin;
+
+impl Plugin for LoreFragmentPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<UnlockState>()
+            .add_systems(Startup, load_unlock_state)
+            .add_systems(Update, (
+                dna_fragment_spawn_system,
+                dna_fragment_drift_system,
+                dna_fragment_collection_system,
+                save_unlock_state_system.run_if(resource_changed::<UnlockState>),
+            ).chain().run_if(in_state(IsPaused::Running)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collection_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<UnlockState>()
+            .init_resource::<GameScore>()
+            .add_event::<AchievementEvent>()
+            .add_systems(Update, (dna_fragment_collection_system, robust_despawn_system).chain());
+        app
+    }
+
+    #[test]
+    fn touching_a_fragment_unlocks_its_lore_and_despawns_it_once() {
+        let mut app = collection_app();
+        app.world_mut().spawn((
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+            Transform::default(),
+            Collider { radius: 16.0 },
+        ));
+        let fragment = app.world_mut().spawn((
+            Transform::from_xyz(10.0, 0.0, 0.0),
+            Collider { radius: FRAGMENT_COLLECT_RADIUS },
+            DnaFragment { lore_index: 2, pulse_phase: 0.0 },
+        )).id();
+
+        app.update();
+        let unlock_state = app.world().resource::<UnlockState>();
+        assert_eq!(unlock_state.unlocked_lore_count(), 1);
+        assert!(unlock_state.is_unlocked(2));
+        assert_eq!(app.world().resource::<Events<AchievementEvent>>().len(), 1);
+
+        // Marked for despawn, so a second overlapping frame can't collect it again
+        app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(20));
+        app.update();
+        assert_eq!(app.world().resource::<UnlockState>().fragments_collected, 1);
+        assert!(app.world().get_entity(fragment).is_err());
+    }
+}
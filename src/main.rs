@@ -1,3 +1,6 @@
+// Bevy systems take their queries and resources as arguments
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
 use bevy::prelude::*;
 use bevy::window::WindowResolution;
 use bevy::sprite::Anchor;
@@ -6,11 +9,8 @@ use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use Cosmic_Tidal_Pool::*;
 use crate::lighting::PerformantLightingPlugin;
 use cosmic_ui::prelude::*;
-use crate::balance_systems::*;
-use crate::achievements::*;
-use crate::missile_trails::*;
-use crate::pause_system::*;
-use crate::despawn::*;
+use crate::save_versioning::{load_versioned, save_versioned};
+use crate::constants::PLAYER_STARTING_LIVES;
 
 fn main() {
     App::new()
@@ -35,6 +35,15 @@ fn main() {
         // .register_hud::<BiologicalGameHUD>()      // Register your HUD (generates all update systems!)
 
         .add_plugins(ConsolidatedPausePlugin) // Replaces all scattered pause systems
+        .add_plugins(LoreFragmentPlugin)      // DNA fragment collectibles and lore unlocks
+        .add_plugins(HintPlugin)              // Adaptive tutorial hints from balance issues
+        .add_plugins(LoadoutPresetPlugin)     // Saved evolution loadouts applied at run start
+        .add_plugins(TargetDummyPlugin)       // F5: practice dummy with live DPS readout
+        .add_plugins(GraphicsQualityPlugin)   // Low/Medium/High effect budgets, persisted
+        .add_plugins(BestiaryPlugin)          // Enemy entries unlocked on first kill, persisted
+        .add_plugins(TimeAttackPlugin)        // Optional countdown mode, kills and waves add time
+        .add_plugins(ReplayCodePlugin)        // Shareable seed + difficulty + loadout codes
+        .add_plugins(LeaderboardSubmissionPlugin) // Daily challenge submissions with a consistency-checked score ledger
 
         .add_sub_state::<IsPaused>()
 
@@ -47,20 +56,76 @@ fn main() {
         .init_resource::<GameStarted>()          // Game initialization flag
         .init_resource::<ShootingState>()        // Weapon firing rate modifiers
         .init_resource::<ScreenShakeResource>()  // Screen shake for impact feedback
+        .init_resource::<ComboState>()           // Kill chain tracking and combo weapon buff
+        .init_resource::<RunModifiers>()         // Challenge modifiers chosen on the title screen
+        .init_resource::<WeaponSpreadRng>()      // Seeded RNG for projectile accuracy spread
+        .init_resource::<EquippedMods>()         // Weapon mods socketed at evolution chambers
 
         // ===== BIOLOGICAL SYSTEMS RESOURCES =====
         .init_resource::<FluidEnvironment>()     // Water current simulation grid
         .init_resource::<ChemicalEnvironment>()  // pH zones and oxygen simulation
+        .init_resource::<HitStopConfig>()        // Few-frame freeze on heavy hits
+        .init_resource::<DamageCapConfig>()      // Per-hit damage ceiling on bosses
+        .init_resource::<SplitDispersalConfig>() // Offspring launch pattern on cell division
+        .init_resource::<WaveEnvironmentCurve>() // pH, oxygen and turbulence by wave number
+        .init_resource::<DropHomingConfig>()     // Delayed drift of drops toward the player
+        .init_resource::<BossLootConfig>()       // Guaranteed rewards per boss type
+        .init_resource::<StealthAbility>()       // Cloak duration and cooldown
+        .init_resource::<ScorePopupConfig>()     // Kill score popups styled by multiplier
+        .init_resource::<BossWaveTelegraphConfig>() // Wave counter warning ahead of boss waves
+        .init_resource::<BerserkConfig>()        // Pack enemies enrage when a nearby ally dies
+        .init_resource::<HazardWarningConfig>()  // HUD banner for hostile water and vent heat
+        .init_resource::<LeaderHighlightConfig>() // Priority-target glow on colony leaders
+        .init_resource::<AtpCostHintConfig>()    // Time-to-afford readout at evolution chambers
+        .init_resource::<AiTierConfig>()         // Veteran AI parameters on later waves
+        .init_resource::<BossIntroConfig>()      // Scripted entrance for mini-bosses
+        .init_resource::<DeathLinger>()          // Slow-motion linger on the fatal hit
+        .init_resource::<InputDisplayConfig>()   // Streamer overlay of held inputs
+        .init_resource::<HudVisibility>()        // Player toggles for optional HUD widgets
+        .init_resource::<EnvironmentSeed>()      // Seed for reproducible pool layouts (zones, vents, currents)
         .init_resource::<TidalPoolPhysics>()     // Tide mechanics and king tide events
         .init_resource::<BioluminescenceManager>() // Organic lighting system
         .init_resource::<EcosystemState>()       // Environmental health tracking
+        .init_resource::<EcosystemCollapse>()    // Endgame collapse when health stays critical
+        .init_resource::<EcosystemAtpTrickle>()  // Passive ATP income from a healthy pool
+        .init_resource::<WaveClearVacuum>()      // Pull leftover pickups to the player on wave clear
+        .init_resource::<CorpseAbsorption>()     // Optional: enemies feed on ATP the player leaves behind
+        .init_resource::<CostConfig>()           // Repeat evolution chamber purchases get pricier
+        .init_resource::<TrailIntensityConfig>() // Player wake brightens with movement speed
+        .init_resource::<KnockbackConfig>()      // Player shots push enemies back
+        .init_resource::<StatusResistanceConfig>() // Higher ecosystem ranks shrug off knockback and freeze
+        .init_resource::<ProjectileContrastConfig>() // Outline shots that blend into their backdrop
+        .init_resource::<ControlDistortion>()    // King tide hazard that scrambles player input
+        .init_resource::<SporeAiming>()          // Optional slow-motion aiming for the emergency spore
+        .init_resource::<OwnedEvolutions>()      // Evolutions available to cycle between mid-run
         .init_resource::<TidalState>()           // Tidal event state tracking
         .init_resource::<AchievementManager>()   // Steam-ready achievement system
         .init_resource::<TidalFeedbackSystem>()  // Visual feedback for tidal effects
         .init_resource::<DiagnosticsStore>()
         .init_resource::<MenuSettings>() 
+        .init_resource::<AccessibilitySettings>()
+        .init_resource::<TelegraphAudioConfig>()   // Positional audio cues for enemy telegraphs
+        .init_resource::<EnemySpawnAudioConfig>()  // Per-type entry sounds when enemies spawn
         .init_resource::<WaveManager>()
+        .init_resource::<WaveGraceConfig>()
+        .init_resource::<WaveSpawnQueue>()
+        .init_resource::<ThreatMeter>()
+        .init_resource::<SpawnPortalConfig>()
+        .init_resource::<SpawnEdgeConfig>()
+        .init_resource::<WavePreviewConfig>()   // Enemy roster shown before each wave
+        .init_resource::<AiRangeOverlay>()      // Debug circles for enemy AI ranges
+        .init_resource::<WaveSpeedRamp>()       // Optional enemy speed-up over the course of a wave
+        .init_resource::<EnemySpatialGrid>()
+        .init_resource::<ColonyMoraleConfig>()
+        .init_resource::<CoralAvoidanceConfig>() // Enemies steer around coral formations
+        .init_resource::<PackTargets>()         // Shared prey and flank sides for predator packs
+        .init_resource::<EvasionConfig>()       // How often agile enemies sidestep homing missiles
+        .init_resource::<EggSacConfig>()        // Spawner egg sacs: hatch time, brood size, ATP when popped
+        .insert_resource(EnemyCombatTuning::load_or_default(ENEMY_COMBAT_TUNING_PATH)) // Turret fire scaling, overridable from JSON
+        .insert_resource(EnemyArchetypes::load_or_default(ENEMY_ARCHETYPES_PATH)) // Per-type movement stats, overridable from RON
+        .init_resource::<GridFormation>()
         .init_resource::<BalanceAnalyzer>()
+        .init_resource::<DamageLog>()           // Per-run hits dealt and taken, exported with balance data
         .init_resource::<CardCollection>()
         .init_resource::<StageProgress>()
         .init_resource::<PauseMenuState>()
@@ -71,6 +136,12 @@ fn main() {
 
         // ===== CORE GAME EVENTS =====
         .add_event::<SpawnExplosion>()          // Biological cell bursts and explosions
+        .add_event::<EnemyDied>()               // Kill positions for ally reactions
+        .add_event::<BossPhaseChanged>()        // Boss pattern switches for UI and audio
+        .add_event::<EggSacPopped>()            // Egg sacs destroyed before hatching
+        .add_event::<ProjectileSplit>()         // Split-on-hit weapon mod fragments
+        .add_event::<FrozenShatter>()           // Frozen kills burst into damaging ice shards
+        .add_event::<TelegraphCue>()            // Audio cues paired with enemy attack telegraphs
         .add_event::<SpawnEnemy>()              // Dynamic enemy spawning with AI types
         .add_event::<SpawnPowerUp>()            // Biological evolution power-ups
         .add_event::<SpawnParticles>()          // Organic particle effects system
@@ -95,8 +166,7 @@ fn main() {
             load_persistent_achievements,
             init_particle_pool,             // Pre-allocate particle system
             init_fluid_environment,         // Initialize water current simulation
-            init_chemical_zones,            // Place initial pH and oxygen zones
-            init_current_generator,         // Set up thermal vents and major currents
+            (load_environment_seed, init_chemical_zones, init_current_generator).chain(), // Saved or passed pool code, then its zones, vents and currents
             setup_achievement_system,       // Initialize Steam-ready achievements
             init_procedural_background,     // Set up dynamic background generation
         ))
@@ -116,6 +186,7 @@ fn main() {
             setup_biological_ui,            // Create UI with biological terminology
             setup_fps_ui,
             setup_wave_ui,
+            setup_hazard_warning_ui,
             setup_upgrade_indicators_ui,
         ))
 
@@ -127,6 +198,8 @@ fn main() {
             // handle_pause_input,     // ESC/P key pause toggle
 
             fps_text_update_system,
+            hud_visibility_system,
+            input_display_system.run_if(in_state(GameState::Playing)),
             update_upgrade_indicators,
             enhanced_evolution_ui_with_limits,            
         ))
@@ -136,16 +209,23 @@ fn main() {
 
             // Core player and enemy interaction
             // handle_input_legacy,             // Process keyboard/gamepad input
-            biological_movement_system,      // Player movement with fluid dynamics
+            (king_tide_schedule_system, update_king_tide, control_distortion_system).chain().before(biological_movement_system), // King tides and the input distortion they telegraph
+            (biological_movement_system, stealth_system), // Player movement with fluid dynamics, cloak ability
             enhanced_shooting_system,        // Evolution-based weapon systems
+            beam_lock_system,                // Toggle and maintain the beam target lock
+            weapon_mod_socket_system,        // Socket pierce/homing/split mods at a chamber
+            homing_mod_system,               // Steer homing-modded shots toward nearby enemies
+            projectile_split_system,         // Spawn fragments from split-on-hit shots
+            combo_decay_system,              // Drop the combo when kills stop chaining
 
-            atp_magnet_system,
+            (atp_magnet_system, wave_clear_vacuum_system, corpse_atp_absorption_system), // Magnet pull, wave clear sweep, enemies eating loose ATP
 
             // spawn_enemies,               // Wave-based enemy spawning, replaced by following 3 functions
             wave_progression_system,
-            wave_spawning_system,
+            (threat_meter_system, wave_spawning_system).chain(), // Hold spawns while the screen is crowded
+            spawn_portal_system,            // Portals wind up before releasing their enemy
             environmental_hazard_system,
-            wave_completion_system,
+            wave_completion_system.before(wave_progression_system), // Pay the clear rewards before progression moves on
 
             spawn_biological_powerups,      // ATP and evolution power-ups
             spawn_evolution_powerups,       // Advanced evolutionary upgrades
@@ -172,8 +252,7 @@ fn main() {
         .add_systems(Update, (
             // Ecosystem simulation
             adaptive_difficulty_system,      // Scale challenge to player evolution
-            // chemical_trail_system,          // Pheromone tracking for AI - can ause a panic with despawn
-            // chemical_trail_following,       // Enemies follow chemical trails
+            (chemical_trail_system, chemical_trail_following).chain(), // Pheromone trails that hunters follow
             ecosystem_balance_system,       // Population dynamics simulation
 
             // Environmental storytelling
@@ -222,6 +301,7 @@ fn main() {
            
             // Currency and upgrade systems
             move_biological_powerups,       // Organic floating animation for power-ups
+            shield_barrier_system,          // Expire deployed shield barriers
             move_atp,                       // ATP energy particles with current response
             collect_atp_with_energy_transfer, // Enhanced ATP collection with particles
         ).run_if(in_state(IsPaused::Running)))
@@ -233,6 +313,7 @@ fn main() {
             
             enemy_flash_system,             // Flash enemies white when hit
             screen_shake_system,            // Camera shake for impacts
+            death_linger_system.after(screen_shake_system).run_if(in_state(IsPaused::Running)), // Slow-mo zoom on the fatal hit
 
             // Advanced tidal feedback systems
             // ALLEGED CRASH POINT
@@ -243,6 +324,7 @@ fn main() {
             update_tidal_wave_effects,      // King tide wave propagation
             tidal_audio_feedback_system,    // Sound cues for tidal events
             tidal_movement_response_system, // Enhanced player response to currents
+            projectile_contrast_system,     // Halo shots that blend into the backdrop
         ).run_if(in_state(IsPaused::Running)))
 
         // ===== BALANCE ANALYSIS SYSTEMS (ADD TO UPDATE) =====
@@ -266,10 +348,22 @@ fn main() {
         .add_systems(Update, (
             enemy_shooting,                 // Enemy projectile attacks
             turret_shooting,                // Biofilm colony ranged attacks
-            move_enemies,                   // All enemy movement AI patterns
-            update_spawner_enemies,         // Reproductive vesicle offspring spawning
+            attack_telegraph_system,        // Aim lines growing ahead of turret and colony volleys
+            (move_enemies, predator_prey_system, evasive_maneuver_system).chain(), // Movement AI, then pack hunts and missile sidesteps
+            frozen_status_system,           // Freeze countdown, tint and shatter on thaw
+            berserk_system,                 // Enrage pack enemies near a fallen ally
+            hit_stop_system,                // Release enemies from heavy-hit freezes
+            frozen_shatter_system,          // Frozen kills burst into ice shards
+            boss_intro_system.after(screen_shake_system), // Mini-boss entrance before it starts attacking
+            boss_phase_system,              // Mini-boss phase change at half health
+            (update_enemy_spatial_grid, healer_system).chain(), // Support enemies mending nearby allies
+            (update_spawner_enemies, egg_sac_hatch_system, cell_division_system), // Egg sacs from vesicles, wounded cells splitting
             update_formations,              // Colony coordination and movement
+            leader_highlight_system,        // Glow on colony leaders for priority targeting
             formation_coordination_system,  // Chemical signaling between colony members
+            (colony_morale_system, morale_recovery_system), // Colonies scatter after heavy losses
+            (colony_leader_death_system, routing_behavior_system), // Orphaned members rout when their leader dies
+            grid_formation_system,          // Sway the arcade grid and send out divers
             procedural_colony_spawning,     // Dynamic enemy group generation
         ).run_if(in_state(IsPaused::Running)))
 
@@ -281,6 +375,7 @@ fn main() {
             evolution_chamber_interaction,  // Player upgrades at evolution chambers
             handle_biological_powerup_collection, // Power-up effects application
             damage_text_system,             // Floating combat damage numbers
+            deployed_zone_system,           // Player-deployed zones expire and burn intolerant enemies
         ).run_if(in_state(IsPaused::Running)))
 
         // missile trail systems 
@@ -300,9 +395,9 @@ fn main() {
             //update_current_field,           // Current indicator visualization
             organic_ai_system,              // Biological AI behaviors (chemotaxis, etc.)
             //generate_procedural_currents,   // Dynamic current pattern generation
-            cell_division_system,           // Enemy reproduction mechanics
             symbiotic_pair_system,          // Paired organism death mechanics
             thermal_vent_effects_system,    // Heat effects and thermal particles
+            hazard_warning_system,          // Acid/oxygen/heat banner, only meaningful while the damage above runs
             //dynamic_chemical_zone_system,   // Adaptive chemical zone spawning
             //scroll_thermal_vents,           // Move thermal vents with current
         ).run_if(in_state(GameState::None)))
@@ -311,7 +406,6 @@ fn main() {
         .add_systems(Update, (
             advanced_tidal_system,          // King tide events, tidal cycles
             process_tidal_events,           // Handle tidal event responses
-            update_king_tide,               // King tide duration and effects
             update_tidal_debris,            // Debris movement during king tides
         ).run_if(in_state(GameState::None)))
 
@@ -319,20 +413,26 @@ fn main() {
         .add_systems(Update, (
             //apply_chemical_damage_system,   // pH and oxygen damage to entities
             pheromone_communication_system, // Colony chemical coordination
-            ecosystem_monitoring_system,    // Track ecosystem health metrics
         ).run_if(in_state(GameState::None)))
 
+        // ===== ECOSYSTEM HEALTH =====
+        .add_systems(Update, (
+            ecosystem_monitoring_system,    // Track ecosystem health metrics
+            ecosystem_collapse_system,      // Escalating collapse on sustained low health
+            ecosystem_atp_trickle_system,   // Passive ATP scaled by ecosystem health
+        ).chain().run_if(in_state(IsPaused::Running)))
+
         // ===== EVENT PROCESSING SYSTEMS =====
         .add_systems(Update, (
             spawn_explosion_system,         // Create explosion entities from events
             spawn_enemy_system,             // Create enemy entities from events
             spawn_powerup_system,           // Create power-up entities from events
             // spawn_particles_system replaced by hanabi systems
-            spawn_atp_on_death,             // Drop ATP currency when enemies die
+            (spawn_atp_on_death, egg_sac_reward_system), // Drop ATP currency when enemies die or egg sacs pop
+            boss_loot_system,               // Guaranteed evolution/ATP rewards from bosses
             handle_player_hit,              // Process player damage and lives
             update_health_bar,              // Update UI health display
             check_game_over,                // Transition to game over state
-            handle_restart_input,           // R key restart functionality
         ).run_if(in_state(IsPaused::Running)))
 
         // ===== USER INTERFACE SYSTEMS =====
@@ -342,6 +442,7 @@ fn main() {
             update_tidal_ui,                // Tide status indicator
             update_biological_ui,           // ATP, score, lives, ecosystem status
             wave_ui_system,
+            wave_preview_system,            // Upcoming wave roster during the break
             update_evolution_ui,
         ).run_if(in_state(IsPaused::Running)))
 
@@ -350,11 +451,16 @@ fn main() {
             debug_atp_spawner,              // F2: Spawn 1000 ATP for testing
             debug_spawn_evolution_chamber,  // F3: Spawn evolution chamber
             debug_trigger_king_tide,        // F4: Force trigger king tide event
+            debug_toggle_ai_ranges,         // F6: Toggle enemy AI range circles
+            debug_skip_wave,                // F7: Clear the current wave and advance, no rewards
+            draw_ai_range_overlay,
 
         ).run_if(in_state(IsPaused::Running)))
 
         .add_systems(Update, (
             enhanced_shooting_system, 
+            spore_aiming_system,            // Optional slow-motion aiming for the emergency spore
+            (track_owned_evolutions, cycle_evolution_system).chain(), // E: cycle between owned evolutions
             wing_cannon_collision_system, 
             auto_missile_system,
 
@@ -373,6 +479,10 @@ fn main() {
             finalize_balance_session,
             save_balance_data_system,
             save_achievements_on_exit,
+            cancel_spore_aiming,
+            cancel_death_linger,
+            cleanup_boss_intro,
+            cleanup_input_display,
         ))
 
         // When transitioning TO game over state
@@ -386,6 +496,8 @@ fn main() {
         .add_systems(OnExit(GameState::GameOver), (
         cleanup_game_over_ui,
         reset_biological_game_state,
+        reset_achievement_session_stats,
+        reseed_pool_layout,
     ))
         
         // Game over input handling
@@ -412,12 +524,10 @@ pub fn setup_achievement_system(mut commands: Commands) {
     let mut achievement_manager = achievements::initialize_achievements();
     
     // Load saved progress if available
-    if let Ok(save_data) = std::fs::read_to_string("achievements.json") {
-        if let Ok(loaded_data) = serde_json::from_str::<achievements::AchievementSaveData>(&save_data) {
-            achievement_manager.unlocked_achievements = loaded_data.unlocked_achievements;
-            achievement_manager.lifetime_stats = loaded_data.lifetime_stats;
-            println!("Loaded {} unlocked achievements", achievement_manager.unlocked_achievements.len());
-        }
+    if let Some(loaded_data) = load_versioned::<achievements::AchievementSaveData>("achievements.json") {
+        achievement_manager.unlocked_achievements = loaded_data.unlocked_achievements;
+        achievement_manager.lifetime_stats = loaded_data.lifetime_stats;
+        println!("Loaded {} unlocked achievements", achievement_manager.unlocked_achievements.len());
     }
     
     commands.insert_resource(achievement_manager);
@@ -439,7 +549,7 @@ pub fn spawn_biological_player(mut commands: Commands, asset_server: Res<AssetSe
         Player {
             speed: 400.0,
             roll_factor: 0.3,
-            lives: 3,
+            lives: PLAYER_STARTING_LIVES,
             invincible_timer: 0.0,
             cell_membrane_thickness: 1.0,
         },
@@ -551,44 +661,47 @@ pub fn init_fluid_environment(mut commands: Commands) {
         cell_size: 20.0,
         tidal_phase: 0.0,
         turbulence_intensity: 0.3,
+        base_turbulence: 0.3,
     });
 }
 
 /// Place initial chemical zones for pH and oxygen simulation
-pub fn init_chemical_zones(mut commands: Commands) {
-    commands.insert_resource(ChemicalEnvironment {
-        ph_zones: vec![
-            resources::ChemicalZone {
-                position: Vec2::new(-200.0, 100.0),
-                radius: 150.0,
-                ph_level: 5.5, // Acidic zone
-                intensity: 0.8,
-                center: Vec2::new(0.0,0.0),
-                oxygen_level: 0.0,
-                toxicity: 0.8,
-            },
-            resources::ChemicalZone {
-                position: Vec2::new(200.0, -100.0),
-                radius: 120.0,
-                ph_level: 8.5, // Alkaline zone
-                intensity: 0.6,
-                center: Vec2::new(0.0,0.0),
-                oxygen_level: 0.0,
-                toxicity: 0.6,                
-            },
-        ],
-        oxygen_zones: vec![
-            OxygenZone {
-                position: Vec2::new(0.0, 200.0),
-                radius: 180.0,
-                oxygen_level: 0.9,
-                depletion_rate: 0.1,
-            },
-        ],
-        base_ph: 7.0,
-        base_oxygen: 0.5,
-        diffusion_rate: 0.1,
-    });
+pub fn init_chemical_zones(mut commands: Commands, environment_seed: Res<EnvironmentSeed>) {
+    commands.insert_resource(ChemicalEnvironment::from_seed(environment_seed.seed));
+}
+
+const ENVIRONMENT_SEED_SAVE_PATH: &str = "environment_seed.json";
+const POOL_CODE_ARG: &str = "--pool";
+
+/// Saved pool seed, replaced by `--pool <CODE>` when one is passed. A passed code is saved for later runs
+pub fn load_environment_seed(mut commands: Commands) {
+    let mut environment_seed = load_versioned::<EnvironmentSeed>(ENVIRONMENT_SEED_SAVE_PATH).unwrap_or_default();
+
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(code) = args.iter().position(|arg| arg == POOL_CODE_ARG).and_then(|index| args.get(index + 1)) {
+        match EnvironmentSeed::from_pool_code(code) {
+            Some(shared) => {
+                environment_seed.seed = shared.seed;
+                environment_seed.randomize_each_run = false;
+                save_versioned(ENVIRONMENT_SEED_SAVE_PATH, &environment_seed);
+            }
+            None => warn!("Ignoring pool code {}: expected up to 16 hex digits", code),
+        }
+    }
+
+    info!("Pool code: {}", environment_seed.pool_code());
+    commands.insert_resource(environment_seed);
+}
+
+/// Roll the next run's pool layout, the same seed always rebuilds the same pool
+pub fn reseed_pool_layout(mut commands: Commands, mut environment_seed: ResMut<EnvironmentSeed>) {
+    environment_seed.next_run();
+    if environment_seed.randomize_each_run {
+        save_versioned(ENVIRONMENT_SEED_SAVE_PATH, &*environment_seed);
+    }
+    info!("Pool code: {}", environment_seed.pool_code());
+    commands.insert_resource(ChemicalEnvironment::from_seed(environment_seed.seed));
+    commands.insert_resource(CurrentGenerator::from_seed(environment_seed.seed));
 }
 
 
@@ -602,17 +715,17 @@ pub fn reset_biological_game_state(
     mut input_state: ResMut<OldInputState>,
     mut game_started: ResMut<GameStarted>,
     mut shooting_state: ResMut<ShootingState>,
-    (mut fluid_environment, mut chemical_environment) : (ResMut<FluidEnvironment>,ResMut<ChemicalEnvironment>),
+    (mut fluid_environment, mut chemical_environment, mut spread_rng) : (ResMut<FluidEnvironment>,ResMut<ChemicalEnvironment>, ResMut<WeaponSpreadRng>),
     mut wave_manager: ResMut<WaveManager>,
     // Despawn all game entities
-    (enemy_query, projectile_query): (Query<Entity, With<Enemy>>,Query<Entity, (With<Projectile>, Without<PendingDespawn>)>),
+    (enemy_query, projectile_query): (Query<Entity, Or<(With<Enemy>, With<EggSac>)>>,Query<Entity, (With<Projectile>, Without<PendingDespawn>)>),
     explosion_query: Query<Entity, With<Explosion>>,
-    (powerup_query,weapon_powerup_query): (Query<Entity, With<PowerUp>>, Query<Entity, With<EvolutionPowerUp>>),
+    (powerup_query, weapon_powerup_query, fragment_barrier_query): (Query<Entity, With<PowerUp>>, Query<Entity, With<EvolutionPowerUp>>, Query<Entity, Or<(With<DnaFragment>, With<ShieldBarrier>)>>),
     (currency_entity_query, upgrade_station_query): (Query<Entity, (With<ATP>, Without<Player>)>, Query<Entity, With<EvolutionChamber>>),
     (particle_query, emitter_query): (Query<Entity, With<Particle>>,Query<Entity, With<ParticleEmitter>>),
-    (laser_query, smart_bomb_query): (Query<Entity, With<LaserBeam>>, Query<Entity, With<SporeWave>>),
+    (laser_query, smart_bomb_query, portal_query): (Query<Entity, With<LaserBeam>>, Query<Entity, With<SporeWave>>, Query<Entity, With<SpawnPortal>>),
     (player_query, upgrade_ui_query) : (Query<Entity, With<Player>>, Query<Entity, With<EvolutionUI>>),
-    assets: Option<Res<GameAssets>>,
+    (assets, run_modifiers, loadouts): (Option<Res<GameAssets>>, Res<RunModifiers>, Res<LoadoutPresets>),
 ) {
     if !game_started.0 {
         game_started.0 = true;
@@ -625,12 +738,14 @@ pub fn reset_biological_game_state(
         .chain(explosion_query.iter())
         .chain(powerup_query.iter())
         .chain(weapon_powerup_query.iter())
+        .chain(fragment_barrier_query.iter())
         .chain(currency_entity_query.iter())
         .chain(upgrade_station_query.iter())
         .chain(particle_query.iter())
         .chain(emitter_query.iter())
         .chain(laser_query.iter())
         .chain(smart_bomb_query.iter())
+        .chain(portal_query.iter())
         .chain(player_query.iter())
         .chain(upgrade_ui_query.iter()) {
         commands.entity(entity)
@@ -641,6 +756,17 @@ pub fn reset_biological_game_state(
     game_score.current = 0;
     game_score.score_multiplier = 1.0;
     game_score.multiplier_timer = 0.0;
+    commands.insert_resource(ComboState::default());
+    commands.insert_resource(EquippedMods::default());
+    commands.insert_resource(EcosystemState::default());
+    commands.insert_resource(EcosystemCollapse::default());
+    commands.insert_resource(EcosystemAtpTrickle::default());
+    commands.insert_resource(ControlDistortion::default());
+    commands.insert_resource(TidalPoolPhysics::default());
+    commands.insert_resource(TidalState::default());
+    commands.insert_resource(OwnedEvolutions::default());
+    commands.insert_resource(DamageLog::default());
+    spread_rng.reseed(); // Same seed, same volleys, every run
 
     wave_manager.current_wave = 1;
     wave_manager.wave_active = false;
@@ -658,10 +784,12 @@ pub fn reset_biological_game_state(
     // Reset fluid environment
     fluid_environment.tidal_phase = 0.0;
     fluid_environment.turbulence_intensity = 0.3;
+    fluid_environment.base_turbulence = 0.3;
     
     // Reset chemical environment
     chemical_environment.base_ph = 7.0;
     chemical_environment.base_oxygen = 0.5;
+    chemical_environment.clear_deployed_zones();
     
     // Respawn biological player
     if let Some(assets) = assets {
@@ -676,11 +804,11 @@ pub fn reset_biological_game_state(
             Player {
                 speed: 400.0,
                 roll_factor: 0.3,
-                lives: 3,
+                lives: run_modifiers.starting_lives(PLAYER_STARTING_LIVES),
                 invincible_timer: 3.0,
                 cell_membrane_thickness: 1.0,
             },
-            EvolutionSystem::default(),
+            loadouts.starting_evolution(),
             ATP { amount: 0 },
             CellularUpgrades::default(),
             Collider { radius: 16.0 },
@@ -788,8 +916,8 @@ pub fn update_organic_particles(
 }
 
 // fix for the fluid_dynamics_system panic
-pub fn init_current_generator(mut commands: Commands) {
-    commands.insert_resource(CurrentGenerator::default());
+pub fn init_current_generator(mut commands: Commands, environment_seed: Res<EnvironmentSeed>) {
+    commands.insert_resource(CurrentGenerator::from_seed(environment_seed.seed));
 }
 
 
@@ -802,7 +930,7 @@ pub fn finalize_balance_session(
     wave_manager: Res<WaveManager>,
     time: Res<Time>,
 ) {
-    if let Ok((player, evolution_system, atp)) = player_query.single() {
+    if let Ok((_player, evolution_system, _atp)) = player_query.single() {
 
         let mut balance_analyzer_clone = balance_analyzer.clone();
         let session = &mut balance_analyzer.real_time_balance.current_session;
@@ -842,8 +970,8 @@ pub fn finalize_balance_session(
 }
 
 // System to save balance data to file
-pub fn save_balance_data_system(balance_analyzer: Res<BalanceAnalyzer>) {
-    save_balance_data(&balance_analyzer);
+pub fn save_balance_data_system(balance_analyzer: Res<BalanceAnalyzer>, damage_log: Res<DamageLog>) {
+    save_balance_data(&balance_analyzer, &damage_log);
 }
 
 // Enhanced achievement tracking for balance analysis
@@ -858,7 +986,7 @@ pub fn enhanced_balance_achievement_tracking(
         let session = &mut balance_analyzer.real_time_balance.current_session;
         
         match event {
-            AchievementEvent::EnemyKilled(enemy_type) => {
+            AchievementEvent::EnemyKilled(_enemy_type) => {
                 // Track kills per weapon type (would need weapon identification)
                 for weapon_stats in balance_analyzer.weapon_stats.values_mut() {
                     weapon_stats.kill_count += 1; // Simplified
@@ -873,10 +1001,10 @@ pub fn enhanced_balance_achievement_tracking(
             }
             AchievementEvent::ShotFired => {
                 // Update accuracy tracking for current weapon
-                if let Some(current_weapon) = session.evolutions_used.last() {
-                    if let Some(weapon_stats) = balance_analyzer_clone.weapon_stats.get_mut(current_weapon) {
-                        // Would need to track shots per weapon
-                    }
+                if let Some(current_weapon) = session.evolutions_used.last()
+                    && let Some(_weapon_stats) = balance_analyzer_clone.weapon_stats.get_mut(current_weapon)
+                {
+                    // Would need to track shots per weapon
                 }
             }
             AchievementEvent::ShotHit => {
@@ -922,7 +1050,7 @@ pub fn enhanced_atp_collection_tracking(
 pub fn apply_balance_adjustments_to_gameplay(
     balance_analyzer: Res<BalanceAnalyzer>,
     mut projectile_query: Query<&mut Projectile>,
-    mut evolution_query: Query<&mut EvolutionSystem>,
+    _evolution_query: Query<&mut EvolutionSystem>,
     mut player_query: Query<&mut Player>,
 ) {
     // Apply active balance adjustments to live gameplay
@@ -966,7 +1094,7 @@ pub fn balance_aware_enemy_spawning(
     player_query: Query<(&EvolutionSystem, &CellularUpgrades)>,
 ) {
     if let Ok((evolution_system, upgrades)) = player_query.single() {
-        let player_power_level = calculate_current_player_power(&evolution_system, &upgrades);
+        let player_power_level = calculate_current_player_power(evolution_system, upgrades);
         
         // Adjust spawn rates based on balance analysis
         let economy_health = balance_analyzer.atp_economy.economy_health;
@@ -1020,28 +1148,24 @@ pub fn detailed_weapon_performance_tracking(
     projectile_query: Query<&Projectile, Added<Projectile>>,
     player_query: Query<&EvolutionSystem, With<Player>>,
     mut collision_events: EventReader<EnemyHit>,
-    time: Res<Time>,
+    _time: Res<Time>,
 ) {
     if let Ok(evolution_system) = player_query.single() {
         let current_weapon = evolution_system.primary_evolution.get_display_name().to_string();
         
         // Track new projectiles fired
         let projectiles_fired = projectile_query.iter().filter(|p| p.friendly).count() as u32;
-        if projectiles_fired > 0 {
-            if let Some(weapon_stats) = balance_analyzer.weapon_stats.get_mut(&current_weapon) {
-                // Update fire rate and usage
-                weapon_stats.usage_frequency += projectiles_fired;
-            }
+        if projectiles_fired > 0 && let Some(weapon_stats) = balance_analyzer.weapon_stats.get_mut(&current_weapon) {
+            // Update fire rate and usage
+            weapon_stats.usage_frequency += projectiles_fired;
         }
         
         // Track hits for accuracy
         let hits_this_frame = collision_events.read().count() as u32;
-        if hits_this_frame > 0 {
-            if let Some(weapon_stats) = balance_analyzer.weapon_stats.get_mut(&current_weapon) {
-                // Update accuracy (simplified calculation)
-                if weapon_stats.usage_frequency > 0 {
-                    weapon_stats.accuracy_rate = hits_this_frame as f32 / weapon_stats.usage_frequency as f32;
-                }
+        if hits_this_frame > 0 && let Some(weapon_stats) = balance_analyzer.weapon_stats.get_mut(&current_weapon) {
+            // Update accuracy (simplified calculation)
+            if weapon_stats.usage_frequency > 0 {
+                weapon_stats.accuracy_rate = hits_this_frame as f32 / weapon_stats.usage_frequency as f32;
             }
         }
     }
@@ -1060,7 +1184,7 @@ pub fn balance_influenced_powerup_spawning(
     if *spawn_timer >= 20.0 { // Check every 20 seconds
         *spawn_timer = 0.0;
         
-        if let Ok((transform, health, evolution_system)) = player_query.single() {
+        if let Ok((transform, health, _evolution_system)) = player_query.single() {
             let mut spawn_powerup = false;
             let mut powerup_type = PowerUpType::CellularRegeneration { amount: 30 };
             
@@ -1115,11 +1239,10 @@ pub fn balance_achievements_system(
     
     // Perfect Balance achievement - maintain good balance for extended time
     if balance_analyzer.atp_economy.economy_health > 0.6 && 
-       balance_analyzer.atp_economy.economy_health < 0.8 {
-        if !tracked_achievements.contains(&"perfect_balance".to_string()) {
-            tracked_achievements.push("perfect_balance".to_string());
-            // Would trigger achievement after sustained balance
-        }
+       balance_analyzer.atp_economy.economy_health < 0.8 &&
+       !tracked_achievements.contains(&"perfect_balance".to_string()) {
+        tracked_achievements.push("perfect_balance".to_string());
+        // Would trigger achievement after sustained balance
     }
     
     // Weapon Master achievement - high efficiency with multiple weapons
@@ -1147,12 +1270,12 @@ pub fn dynamic_difficulty_from_balance(
     player_query: Query<(&Player, &Health, &EvolutionSystem)>,
     time: Res<Time>,
 ) {
-    if let Ok((player, health, evolution_system)) = player_query.single() {
+    if let Ok((_player, health, evolution_system)) = player_query.single() {
         let mut difficulty_adjustment = 1.0;
         
         // Analyze player performance vs expected performance
         let expected_wave = (time.elapsed_secs() / 45.0) as u32; // Expect 1 wave per 45 seconds
-        let wave_performance = wave_manager.current_wave as f32 / expected_wave.max(1) as f32;
+        let _wave_performance = wave_manager.current_wave as f32 / expected_wave.max(1) as f32;
         
         // Check weapon efficiency
         let current_weapon = evolution_system.primary_evolution.get_display_name();
@@ -1199,7 +1322,7 @@ pub fn balanced_evolution_chamber_spawning(
     if *spawn_timer >= 90.0 && chamber_query.is_empty() {
         *spawn_timer = 0.0;
         
-        if let Ok((transform, atp, evolution_system)) = player_query.single() {
+        if let Ok((_transform, atp, evolution_system)) = player_query.single() {
             let should_spawn = if balance_analyzer.atp_economy.economy_health > 0.7 {
                 // Player has good ATP economy, offer evolution opportunity
                 true
@@ -1214,28 +1337,26 @@ pub fn balanced_evolution_chamber_spawning(
                     .unwrap_or(false)
             };
             
-            if should_spawn {
-                if let Some(assets) = assets {
-                    commands.spawn((
-                        Sprite {
-                            image: assets.enemy_texture.clone(),
-                            color: Color::srgb(0.3, 0.9, 0.6),
-                            custom_size: Some(Vec2::splat(60.0)),
-                            ..default()
-                        },
-                        Transform::from_xyz(0.0, 380.0, 0.0),
-                        EvolutionChamber,
-                        BioluminescentParticle {
-                            base_color: Color::srgb(0.3, 0.9, 0.6),
-                            pulse_frequency: 1.0,
-                            pulse_intensity: 0.6,
-                            organic_motion: OrganicMotion {
-                                undulation_speed: 0.8,
-                                response_to_current: 0.2,
-                            },
+            if should_spawn && let Some(assets) = assets {
+                commands.spawn((
+                    Sprite {
+                        image: assets.enemy_texture.clone(),
+                        color: Color::srgb(0.3, 0.9, 0.6),
+                        custom_size: Some(Vec2::splat(60.0)),
+                        ..default()
+                    },
+                    Transform::from_xyz(0.0, 380.0, 0.0),
+                    EvolutionChamber,
+                    BioluminescentParticle {
+                        base_color: Color::srgb(0.3, 0.9, 0.6),
+                        pulse_frequency: 1.0,
+                        pulse_intensity: 0.6,
+                        organic_motion: OrganicMotion {
+                            undulation_speed: 0.8,
+                            response_to_current: 0.2,
                         },
-                    ));
-                }
+                    },
+                ));
             }
         }
     }
@@ -1253,14 +1374,12 @@ pub fn balance_performance_monitor(
     if *performance_timer >= 5.0 {
         *performance_timer = 0.0;
         
-        if let Some(fps) = diagnostics.get(&bevy::diagnostic::FrameTimeDiagnosticsPlugin::FPS) {
-            if let Some(fps_value) = fps.smoothed() {
-                // If balance system is causing performance issues
-                if fps_value < 30.0 && balance_analyzer.debug_mode {
-                    println!("Warning: Balance system may be impacting performance. FPS: {:.1}", fps_value);
+        if let Some(fps) = diagnostics.get(&bevy::diagnostic::FrameTimeDiagnosticsPlugin::FPS) && let Some(fps_value) = fps.smoothed() {
+            // If balance system is causing performance issues
+            if fps_value < 30.0 && balance_analyzer.debug_mode {
+                println!("Warning: Balance system may be impacting performance. FPS: {:.1}", fps_value);
                     
-                    // Could automatically disable some balance features here
-                }
+                // Could automatically disable some balance features here
             }
         }
     }
@@ -1298,31 +1417,29 @@ pub fn render_magnet_field(
     player_query: Query<(&Transform, &CellularUpgrades), With<Player>>,
     existing_field_query: Query<Entity, With<MagnetFieldVisual>>,
     assets: Option<Res<GameAssets>>,
-    time: Res<Time>,
+    _time: Res<Time>,
 ) {
-    if let Some(assets) = assets {
-        if let Ok((player_transform, upgrades)) = player_query.single() {
-            // Remove old field visual
-            for entity in existing_field_query.iter() {
-                commands.entity(entity).safe_despawn();
-            }
+    if let Some(assets) = assets && let Ok((player_transform, upgrades)) = player_query.single() {
+        // Remove old field visual
+        for entity in existing_field_query.iter() {
+            commands.entity(entity).safe_despawn();
+        }
             
-            // Create new field visual if magnet is upgraded
-            if upgrades.magnet_radius > 0.0 || upgrades.magnet_strength > 0.0 {
-                let radius = 80.0 + upgrades.magnet_radius;
-                let alpha = 0.1 + (upgrades.magnet_strength * 0.05).min(0.15);
+        // Create new field visual if magnet is upgraded
+        if upgrades.magnet_radius > 0.0 || upgrades.magnet_strength > 0.0 {
+            let radius = 80.0 + upgrades.magnet_radius;
+            let alpha = 0.1 + (upgrades.magnet_strength * 0.05).min(0.15);
                 
-                commands.spawn((
-                    Sprite {
-                        image: assets.particle_texture.clone(),
-                        color: Color::srgba(0.3, 0.8, 1.0, alpha),
-                        custom_size: Some(Vec2::splat(radius * 2.0)),
-                        ..default()
-                    },
-                    Transform::from_translation(player_transform.translation + Vec3::new(0.0, 0.0, -0.1)),
-                    MagnetFieldVisual,
-                ));
-            }
+            commands.spawn((
+                Sprite {
+                    image: assets.particle_texture.clone(),
+                    color: Color::srgba(0.3, 0.8, 1.0, alpha),
+                    custom_size: Some(Vec2::splat(radius * 2.0)),
+                    ..default()
+                },
+                Transform::from_translation(player_transform.translation + Vec3::new(0.0, 0.0, -0.1)),
+                MagnetFieldVisual,
+            ));
         }
     }
 }
\ No newline at end of file
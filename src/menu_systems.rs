@@ -8,7 +8,12 @@ use rand::Rng;
 use crate::components::*;
 use crate::resources::*;
 use crate::input::*;
-use crate::despawn::*;
+use crate::lore_fragments::{UnlockState, LORE_ENTRIES};
+use crate::bestiary::Bestiary;
+use crate::enemy_types::EnemyType;
+use crate::loadout_presets::*;
+use crate::time_attack::*;
+use crate::constants::PLAYER_STARTING_LIVES;
 
 // ===== CONSTANTS =====
 const LOADING_BAR_WIDTH: f32 = 400.0;
@@ -240,6 +245,9 @@ pub fn setup_title_screen(
     mut commands: Commands,
     fonts: Res<GameFonts>,
     game_score: Res<GameScore>,
+    run_modifiers: Res<RunModifiers>,
+    loadouts: Res<LoadoutPresets>,
+    time_attack: Res<TimeAttack>,
 ) {
     // Animated background
     spawn_menu_background(&mut commands);
@@ -283,8 +291,40 @@ pub fn setup_title_screen(
         
         // Menu buttons
         spawn_button(parent, "PLAY", MenuAction::Play, fonts.default_font.clone());
+        spawn_button(parent, "MODIFIERS", MenuAction::CycleRunModifier, fonts.default_font.clone());
+        parent.spawn((
+            Text::new(format!("Challenge: {}", run_modifiers.label())),
+            TextFont { font: fonts.default_font.clone(), font_size: SMALL_TEXT, ..default() },
+            TextColor(GOLD_COLOR),
+            Node { margin: UiRect::all(Val::Px(5.0)), ..default() },
+            RunModifierText,
+        ));
+        spawn_button(parent, "TIME ATTACK", MenuAction::ToggleTimeAttack, fonts.default_font.clone());
+        parent.spawn((
+            Text::new(format!("Mode: {}", time_attack.label())),
+            TextFont { font: fonts.default_font.clone(), font_size: SMALL_TEXT, ..default() },
+            TextColor(GOLD_COLOR),
+            Node { margin: UiRect::all(Val::Px(5.0)), ..default() },
+            TimeAttackText,
+        ));
+        spawn_button(parent, "LOADOUT", MenuAction::CycleLoadout, fonts.default_font.clone());
+        parent.spawn((
+            Text::new(format!("Loadout: {}", loadouts.label())),
+            TextFont { font: fonts.default_font.clone(), font_size: SMALL_TEXT, ..default() },
+            TextColor(GOLD_COLOR),
+            Node { margin: UiRect::all(Val::Px(5.0)), ..default() },
+            LoadoutText,
+        ));
+        if loadouts.last_build.is_some() {
+            spawn_button(parent, "SAVE LAST BUILD", MenuAction::SaveLoadout, fonts.default_font.clone());
+        }
+        if !loadouts.presets.is_empty() {
+            spawn_button(parent, "DELETE LOADOUT", MenuAction::DeleteLoadout, fonts.default_font.clone());
+        }
         spawn_button(parent, "SETTINGS", MenuAction::Settings, fonts.default_font.clone());
         spawn_button(parent, "HIGH SCORES", MenuAction::HighScores, fonts.default_font.clone());
+        spawn_button(parent, "DNA ARCHIVE", MenuAction::LoreArchive, fonts.default_font.clone());
+        spawn_button(parent, "BESTIARY", MenuAction::Bestiary, fonts.default_font.clone());
         spawn_button(parent, "QUIT", MenuAction::Quit, fonts.default_font.clone());
         
         // Copyright (bottom)
@@ -323,14 +363,14 @@ fn spawn_menu_background(commands: &mut Commands) {
             AnimatedParticle {
                 velocity,
                 lifetime: rng.random_range(0.0..10.0),
-                pulse_phase: rng.random_range(0.0..6.28),
+                pulse_phase: rng.random_range(0.0..std::f32::consts::TAU),
             },
         ));
     }
 }
 
 // ===== SETTINGS MENU =====
-pub fn setup_settings_menu(mut commands: Commands, fonts: Res<GameFonts>, audio_settings: Res<AudioMenuSettings>) {
+pub fn setup_settings_menu(mut commands: Commands, fonts: Res<GameFonts>, audio_settings: Res<AudioMenuSettings>, graphics: Res<GraphicsSettings>, hud_visibility: Res<HudVisibility>, restart_confirmation: Res<RestartConfirmation>) {
     commands.spawn((
         Node {
             width: Val::Percent(100.0),
@@ -354,6 +394,43 @@ pub fn setup_settings_menu(mut commands: Commands, fonts: Res<GameFonts>, audio_
         // Graphics section
         spawn_text(parent, "Graphics", fonts.default_font.clone(), 32.0, TEXT_COLOR);
         spawn_button(parent, "Toggle Fullscreen", MenuAction::ToggleFullscreen, fonts.default_font.clone());
+        spawn_button(parent, "Effects Quality", MenuAction::CycleGraphicsQuality, fonts.default_font.clone());
+        parent.spawn((
+            Text::new(format!("Quality: {}", graphics.quality.display_name())),
+            TextFont { font: fonts.default_font.clone(), font_size: SMALL_TEXT, ..default() },
+            TextColor(GOLD_COLOR),
+            Node { margin: UiRect::all(Val::Px(5.0)), ..default() },
+            GraphicsQualityText,
+        ));
+        
+        // HUD section
+        spawn_text(parent, "HUD", fonts.default_font.clone(), 32.0, TEXT_COLOR);
+        parent.spawn(Node { flex_direction: FlexDirection::Row, ..default() }).with_children(|row| {
+            for element in HudElement::ALL {
+                spawn_button(row, element.display_name(), MenuAction::ToggleHudElement(element), fonts.default_font.clone());
+            }
+        });
+        parent.spawn((
+            Text::new(format!("HUD: {}", hud_visibility.label())),
+            TextFont { font: fonts.default_font.clone(), font_size: SMALL_TEXT, ..default() },
+            TextColor(GOLD_COLOR),
+            Node { margin: UiRect::all(Val::Px(5.0)), ..default() },
+            HudVisibilityText,
+        ));
+        
+        // Accessibility section
+        spawn_text(parent, "Accessibility", fonts.default_font.clone(), 32.0, TEXT_COLOR);
+        spawn_button(parent, "Toggle Photosensitive Mode", MenuAction::TogglePhotosensitiveMode, fonts.default_font.clone());
+        spawn_button(parent, "Toggle Slow-Mo Spore Aiming", MenuAction::ToggleSporeAiming, fonts.default_font.clone());
+        spawn_button(parent, "Toggle Input Display", MenuAction::ToggleInputDisplay, fonts.default_font.clone());
+        spawn_button(parent, "Toggle Restart Confirmation", MenuAction::ToggleRestartConfirmation, fonts.default_font.clone());
+        parent.spawn((
+            Text::new(format!("Restart confirmation: {}", restart_confirmation.label())),
+            TextFont { font: fonts.default_font.clone(), font_size: SMALL_TEXT, ..default() },
+            TextColor(GOLD_COLOR),
+            Node { margin: UiRect::all(Val::Px(5.0)), ..default() },
+            RestartConfirmText,
+        ));
         
         // Controls section
         spawn_text(parent, "Controls", fonts.default_font.clone(), 32.0, TEXT_COLOR);
@@ -413,6 +490,15 @@ pub fn menu_button_system(
     mut exit: EventWriter<AppExit>,
     mut windows: Query<&mut Window, With<PrimaryWindow>>,
     mut input_manager: ResMut<InputManager>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+    mut run_modifiers: ResMut<RunModifiers>,
+    mut loadouts: ResMut<LoadoutPresets>,
+    mut graphics: ResMut<GraphicsSettings>,
+    mut spore_aiming: ResMut<SporeAiming>,
+    mut time_attack: ResMut<TimeAttack>,
+    mut hud_visibility: ResMut<HudVisibility>,
+    mut input_display: ResMut<InputDisplayConfig>,
+    mut restart_confirmation: ResMut<RestartConfirmation>,
 ) {
     for (interaction, button, mut color) in interaction_query.iter_mut() {
         match *interaction {
@@ -421,6 +507,8 @@ pub fn menu_button_system(
                     MenuAction::Play => next_state.set(GameState::Playing),
                     MenuAction::Settings => next_state.set(GameState::Settings),
                     MenuAction::HighScores => next_state.set(GameState::HighScores),
+                    MenuAction::LoreArchive => next_state.set(GameState::LoreArchive),
+                    MenuAction::Bestiary => next_state.set(GameState::Bestiary),
                     MenuAction::Back => next_state.set(GameState::TitleScreen),
                     MenuAction::Quit => { exit.write(AppExit::Success); }
                     MenuAction::ToggleFullscreen => {
@@ -434,6 +522,54 @@ pub fn menu_button_system(
                     MenuAction::ResetControls => {
                         input_manager.setup_default_bindings();
                     },
+                    MenuAction::TogglePhotosensitiveMode => {
+                        accessibility.photosensitive_safe = !accessibility.photosensitive_safe;
+                        info!("Photosensitive-safe effects: {}", accessibility.photosensitive_safe);
+                    },
+                    MenuAction::ToggleSporeAiming => {
+                        spore_aiming.enabled = !spore_aiming.enabled;
+                        info!("Emergency spore aiming: {}", spore_aiming.enabled);
+                    },
+                    MenuAction::CycleRunModifier => {
+                        run_modifiers.cycle_preset();
+                        info!("Run modifiers: {}", run_modifiers.label());
+                    },
+                    MenuAction::ToggleTimeAttack => {
+                        time_attack.enabled = !time_attack.enabled;
+                        info!("Game mode: {}", time_attack.label());
+                    },
+                    MenuAction::CycleLoadout => {
+                        loadouts.cycle_selected();
+                        loadouts.save();
+                    },
+                    MenuAction::SaveLoadout => {
+                        if let Some(build) = loadouts.last_build.take() {
+                            info!("Saved loadout: {}", build.name);
+                            loadouts.save_preset(build);
+                            loadouts.save();
+                        }
+                    },
+                    MenuAction::DeleteLoadout => {
+                        loadouts.delete_selected();
+                        loadouts.save();
+                    },
+                    MenuAction::CycleGraphicsQuality => {
+                        graphics.cycle();
+                        graphics.save();
+                        info!("Graphics quality: {}", graphics.quality.display_name());
+                    },
+                    MenuAction::ToggleInputDisplay => {
+                        input_display.enabled = !input_display.enabled;
+                        info!("Input display: {}", input_display.enabled);
+                    },
+                    MenuAction::ToggleRestartConfirmation => {
+                        restart_confirmation.enabled = !restart_confirmation.enabled;
+                        info!("Restart confirmation: {}", restart_confirmation.enabled);
+                    },
+                    MenuAction::ToggleHudElement(element) => {
+                        hud_visibility.toggle(element);
+                        info!("HUD: {}", hud_visibility.label());
+                    },
                     _ => {},
                 }
                 *color = BackgroundColor(BUTTON_PRESSED);
@@ -444,6 +580,73 @@ pub fn menu_button_system(
     }
 }
 
+// ===== RUN MODIFIER SELECTION =====
+pub fn update_run_modifier_text(
+    run_modifiers: Res<RunModifiers>,
+    mut text_query: Query<&mut Text, With<RunModifierText>>,
+) {
+    if !run_modifiers.is_changed() { return; }
+
+    for mut text in text_query.iter_mut() {
+        **text = format!("Challenge: {}", run_modifiers.label());
+    }
+}
+
+pub fn update_loadout_text(
+    loadouts: Res<LoadoutPresets>,
+    mut text_query: Query<&mut Text, With<LoadoutText>>,
+) {
+    if !loadouts.is_changed() { return; }
+
+    for mut text in text_query.iter_mut() {
+        **text = format!("Loadout: {}", loadouts.label());
+    }
+}
+
+pub fn update_graphics_quality_text(
+    graphics: Res<GraphicsSettings>,
+    mut text_query: Query<&mut Text, With<GraphicsQualityText>>,
+) {
+    if !graphics.is_changed() { return; }
+
+    for mut text in text_query.iter_mut() {
+        **text = format!("Quality: {}", graphics.quality.display_name());
+    }
+}
+
+pub fn update_hud_visibility_text(
+    hud_visibility: Res<HudVisibility>,
+    mut text_query: Query<&mut Text, With<HudVisibilityText>>,
+) {
+    if !hud_visibility.is_changed() { return; }
+
+    for mut text in text_query.iter_mut() {
+        **text = format!("HUD: {}", hud_visibility.label());
+    }
+}
+
+pub fn update_restart_confirmation_text(
+    restart_confirmation: Res<RestartConfirmation>,
+    mut text_query: Query<&mut Text, With<RestartConfirmText>>,
+) {
+    if !restart_confirmation.is_changed() { return; }
+
+    for mut text in text_query.iter_mut() {
+        **text = format!("Restart confirmation: {}", restart_confirmation.label());
+    }
+}
+
+/// The player cell exists before the title screen, so apply life limits as the run begins.
+/// Lives come from the base count, so a trip through the menus can never stack a modifier.
+pub fn apply_run_modifiers_to_player(
+    run_modifiers: Res<RunModifiers>,
+    mut player_query: Query<&mut Player>,
+) {
+    for mut player in player_query.iter_mut() {
+        player.lives = run_modifiers.starting_lives(PLAYER_STARTING_LIVES);
+    }
+}
+
 // ===== SLIDER INTERACTION SYSTEM =====
 pub fn audio_slider_system(
     mut slider_query: Query<(&Interaction, &AudioSlider, &Node), Changed<Interaction>>,
@@ -462,25 +665,25 @@ pub fn audio_slider_system(
     for (interaction, slider, _node) in slider_query.iter_mut() {
         if *interaction != Interaction::Pressed { continue; }
         
-        if let Ok((camera, camera_transform)) = camera_query.single() {
-            if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) {
-                let slider_progress = ((world_pos.x + SLIDER_WIDTH * 0.5) / SLIDER_WIDTH).clamp(0.0, 1.0);
+        if let Ok((camera, camera_transform)) = camera_query.single()
+            && let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos)
+        {
+            let slider_progress = ((world_pos.x + SLIDER_WIDTH * 0.5) / SLIDER_WIDTH).clamp(0.0, 1.0);
                 
-                // Update audio settings
-                match slider.slider_type {
-                    SliderType::Master => audio_settings.master_volume = slider_progress,
-                    SliderType::SFX => audio_settings.sfx_volume = slider_progress,
-                    SliderType::Music => audio_settings.music_volume = slider_progress,
-                }
+            // Update audio settings
+            match slider.slider_type {
+                SliderType::Master => audio_settings.master_volume = slider_progress,
+                SliderType::SFX => audio_settings.sfx_volume = slider_progress,
+                SliderType::Music => audio_settings.music_volume = slider_progress,
+            }
                 
-                // Update visuals
-                for mut fill_node in fill_query.iter_mut() {
-                    fill_node.width = Val::Px(SLIDER_WIDTH * slider_progress);
-                }
+            // Update visuals
+            for mut fill_node in fill_query.iter_mut() {
+                fill_node.width = Val::Px(SLIDER_WIDTH * slider_progress);
+            }
                 
-                for mut text in volume_text_query.iter_mut() {
-                    **text = format!("{:.0}%", slider_progress * 100.0);
-                }
+            for mut text in volume_text_query.iter_mut() {
+                **text = format!("{:.0}%", slider_progress * 100.0);
             }
         }
     }
@@ -544,6 +747,84 @@ pub fn cleanup_high_scores_menu(mut commands: Commands, query: Query<Entity, Wit
     }
 }
 
+// ===== LORE ARCHIVE =====
+pub fn setup_lore_menu(mut commands: Commands, fonts: Res<GameFonts>, unlock_state: Res<UnlockState>) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        BackgroundColor(BG_COLOR),
+        LoreMenu,
+    )).with_children(|parent| {
+        spawn_text(parent, "DNA ARCHIVE", fonts.default_font.clone(), 48.0, ACCENT_COLOR);
+        spawn_text(parent, &format!("Entries: {}/{} | Fragments gathered: {}",
+            unlock_state.unlocked_lore_count(), LORE_ENTRIES.len(), unlock_state.fragments_collected),
+            fonts.default_font.clone(), SMALL_TEXT, GOLD_COLOR);
+
+        for (index, (title, text)) in LORE_ENTRIES.iter().enumerate() {
+            if unlock_state.is_unlocked(index) {
+                spawn_text(parent, title, fonts.default_font.clone(), SMALL_TEXT, ACCENT_COLOR);
+                spawn_text(parent, text, fonts.default_font.clone(), TINY_TEXT, TEXT_COLOR);
+            } else {
+                spawn_text(parent, "??? - Sequence not recovered", fonts.default_font.clone(), SMALL_TEXT, Color::srgb(0.5, 0.5, 0.5));
+            }
+        }
+
+        spawn_button(parent, "BACK", MenuAction::Back, fonts.default_font.clone());
+    });
+}
+
+pub fn cleanup_lore_menu(mut commands: Commands, query: Query<Entity, With<LoreMenu>>) {
+    for entity in query.iter() {
+        commands.entity(entity).try_despawn();
+    }
+}
+
+pub fn setup_bestiary_menu(mut commands: Commands, fonts: Res<GameFonts>, bestiary: Res<Bestiary>) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        BackgroundColor(BG_COLOR),
+        BestiaryMenu,
+    )).with_children(|parent| {
+        spawn_text(parent, "BESTIARY", fonts.default_font.clone(), 48.0, ACCENT_COLOR);
+        spawn_text(parent, &format!("Discovered: {}/{}", bestiary.discovered_count(), EnemyType::ALL.len()),
+            fonts.default_font.clone(), SMALL_TEXT, GOLD_COLOR);
+
+        for enemy_type in EnemyType::ALL {
+            let Some(entry) = bestiary.entry(enemy_type) else {
+                spawn_text(parent, "??? - Not yet defeated", fonts.default_font.clone(), SMALL_TEXT, Color::srgb(0.5, 0.5, 0.5));
+                continue;
+            };
+            let (health, _, speed, _) = enemy_type.get_stats();
+            spawn_text(parent, &format!("{} - {} defeated", enemy_type.display_name(), entry.kills),
+                fonts.default_font.clone(), SMALL_TEXT, ACCENT_COLOR);
+            spawn_text(parent, &format!("{} | HP {} | Speed {:.0} | {} pts",
+                enemy_type.get_biological_description(), health, speed, enemy_type.get_points()),
+                fonts.default_font.clone(), TINY_TEXT, TEXT_COLOR);
+        }
+
+        spawn_button(parent, "BACK", MenuAction::Back, fonts.default_font.clone());
+    });
+}
+
+pub fn cleanup_bestiary_menu(mut commands: Commands, query: Query<Entity, With<BestiaryMenu>>) {
+    for entity in query.iter() {
+        commands.entity(entity).try_despawn();
+    }
+}
+
 // ===== GLOBAL INPUT HANDLING =====
 pub fn global_input_system(
     keyboard: Res<ButtonInput<KeyCode>>,
@@ -553,13 +834,13 @@ pub fn global_input_system(
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     // Alt+Enter for fullscreen
-    if keyboard.just_pressed(KeyCode::Enter) && (keyboard.pressed(KeyCode::AltLeft) || keyboard.pressed(KeyCode::AltRight)) {
-        if let Ok(mut window) = windows.single_mut() {
-            window.mode = match window.mode {
-                WindowMode::Windowed => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
-                _ => WindowMode::Windowed,
-            };
-        }
+    if keyboard.just_pressed(KeyCode::Enter) && (keyboard.pressed(KeyCode::AltLeft) || keyboard.pressed(KeyCode::AltRight))
+        && let Ok(mut window) = windows.single_mut()
+    {
+        window.mode = match window.mode {
+            WindowMode::Windowed => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            _ => WindowMode::Windowed,
+        };
     }
     
     // Volume control
@@ -573,7 +854,7 @@ pub fn global_input_system(
     // ESC key navigation
     if keyboard.just_pressed(KeyCode::Escape) {
         match current_state.get() {
-            GameState::Settings | GameState::HighScores => next_state.set(GameState::TitleScreen),
+            GameState::Settings | GameState::HighScores | GameState::LoreArchive | GameState::Bestiary => next_state.set(GameState::TitleScreen),
             _ => {}
         }
     }
@@ -602,17 +883,76 @@ impl Plugin for MenuSystemsPlugin {
             
             // Title screen
             .add_systems(OnEnter(GameState::TitleScreen), setup_title_screen)
-            .add_systems(Update, (menu_button_system, update_menu_animations).run_if(in_state(GameState::TitleScreen)))
+            .add_systems(Update, (menu_button_system, update_menu_animations, update_run_modifier_text, update_loadout_text).run_if(in_state(GameState::TitleScreen)))
+            .add_systems(Update, apply_loadout_to_player.run_if(in_state(GameState::TitleScreen).and(resource_changed::<LoadoutPresets>)))
             .add_systems(OnExit(GameState::TitleScreen), cleanup_title_screen)
+            .add_systems(OnTransition { exited: GameState::TitleScreen, entered: GameState::Playing }, apply_run_modifiers_to_player)
             
             // Settings menu
             .add_systems(OnEnter(GameState::Settings), setup_settings_menu)
-            .add_systems(Update, (menu_button_system, audio_slider_system).run_if(in_state(GameState::Settings)))
+            .add_systems(Update, (menu_button_system, audio_slider_system, update_graphics_quality_text, update_hud_visibility_text, update_restart_confirmation_text).run_if(in_state(GameState::Settings)))
             .add_systems(OnExit(GameState::Settings), cleanup_settings_menu)
             
             // High scores
             .add_systems(OnEnter(GameState::HighScores), setup_high_scores_menu)
             .add_systems(Update, menu_button_system.run_if(in_state(GameState::HighScores)))
-            .add_systems(OnExit(GameState::HighScores), cleanup_high_scores_menu);
+            .add_systems(OnExit(GameState::HighScores), cleanup_high_scores_menu)
+            
+            // Lore archive
+            .add_systems(OnEnter(GameState::LoreArchive), setup_lore_menu)
+            .add_systems(Update, menu_button_system.run_if(in_state(GameState::LoreArchive)))
+            .add_systems(OnExit(GameState::LoreArchive), cleanup_lore_menu)
+            
+            // Bestiary
+            .add_systems(OnEnter(GameState::Bestiary), setup_bestiary_menu)
+            .add_systems(Update, menu_button_system.run_if(in_state(GameState::Bestiary)))
+            .add_systems(OnExit(GameState::Bestiary), cleanup_bestiary_menu);
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    fn title_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RunModifiers>()
+            .add_systems(OnTransition { exited: GameState::TitleScreen, entered: GameState::Playing }, apply_run_modifiers_to_player);
+        go(&mut app, GameState::TitleScreen);
+        app
+    }
+
+    fn go(app: &mut App, state: GameState) {
+        app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+        app.update();
+    }
+
+    fn spawn_player(app: &mut App, lives: i32) -> Entity {
+        app.world_mut().spawn(Player { speed: 400.0, roll_factor: 0.3, lives, invincible_timer: 0.0, cell_membrane_thickness: 1.0 }).id()
+    }
+
+    #[test]
+    fn modifiers_apply_once_when_the_run_starts() {
+        let mut app = title_app();
+        let player = spawn_player(&mut app, PLAYER_STARTING_LIVES);
+        app.world_mut().resource_mut::<RunModifiers>().active.push(RunModifier::IronCell);
+
+        go(&mut app, GameState::Settings);
+        assert_eq!(app.world().get::<Player>(player).unwrap().lives, PLAYER_STARTING_LIVES);
+
+        go(&mut app, GameState::TitleScreen);
+        go(&mut app, GameState::Playing);
+        assert_eq!(app.world().get::<Player>(player).unwrap().lives, 1);
+    }
+
+    #[test]
+    fn lives_come_from_the_base_count_not_the_live_player() {
+        let mut app = title_app();
+        let player = spawn_player(&mut app, 1);
+
+        go(&mut app, GameState::Playing);
+        assert_eq!(app.world().get::<Player>(player).unwrap().lives, PLAYER_STARTING_LIVES);
+    }
+}
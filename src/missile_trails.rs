@@ -5,7 +5,6 @@ use crate::resources::*;
 use crate::despawn::*;
 
 // ===== CONSTANTS =====
-const MAX_TRAIL_SEGMENTS: usize = 8;           // Maximum segments per trail
 const TRAIL_SEGMENT_DISTANCE: f32 = 15.0;      // Distance between segments
 const TRAIL_FADE_TIME: f32 = 0.8;              // Time for trail to fade completely
 const TRAIL_WIDTH: f32 = 3.0;                  // Base width of trail segments
@@ -75,7 +74,10 @@ pub fn setup_missile_trails(
     )>,
     symbiotic_query: Query<&MissileProjectile>,
     auto_query: Query<&AutoMissile>,
+    graphics: Res<GraphicsSettings>,
 ) {
+    let max_segments = graphics.trail_max_points();
+
     for missile_entity in missile_query.iter() {
         // Determine trail type based on missile components
         let trail_type = if symbiotic_query.get(missile_entity).map(|m| m.symbiotic).unwrap_or(false) {
@@ -87,7 +89,7 @@ pub fn setup_missile_trails(
         };
         
         commands.entity(missile_entity).insert(MissileTrail {
-            segments: Vec::with_capacity(MAX_TRAIL_SEGMENTS),
+            segments: Vec::with_capacity(max_segments),
             last_position: Vec3::ZERO,
             trail_type,
         });
@@ -100,10 +102,12 @@ pub fn update_missile_trails(
     mut trail_query: Query<(Entity, &Transform, &mut MissileTrail)>,
     mut trail_renderer_query: Query<(Entity, &mut Transform, &mut Sprite, &TrailRenderer), Without<MissileTrail>>,
     assets: Option<Res<GameAssets>>,
+    graphics: Res<GraphicsSettings>,
     time: Res<Time>,
 ) {
     let Some(assets) = assets else { return };
     let dt = time.delta_secs();
+    let max_segments = graphics.trail_max_points();
     
     // Update missile trails
     for (missile_entity, missile_transform, mut trail) in trail_query.iter_mut() {
@@ -121,8 +125,8 @@ pub fn update_missile_trails(
             });
             trail.last_position = current_pos;
             
-            // Remove old segments if we exceed max
-            if trail.segments.len() > MAX_TRAIL_SEGMENTS {
+            // Remove old segments if we exceed the graphics settings' max
+            while trail.segments.len() > max_segments {
                 trail.segments.remove(0);
             }
         }
@@ -182,7 +186,7 @@ pub fn update_missile_trails(
 pub fn cleanup_missile_trails(
     mut commands: Commands,
     trail_renderer_query: Query<(Entity, &TrailRenderer)>,
-    trail_query: Query<&MissileTrail, (Without<PendingDespawn>)>,
+    trail_query: Query<&MissileTrail, Without<PendingDespawn>>,
 ) {
     // Clean up trail renderers for despawned missiles
     for (renderer_entity, trail_renderer) in trail_renderer_query.iter() {
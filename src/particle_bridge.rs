@@ -3,6 +3,7 @@ use bevy_hanabi::prelude::*;
 use crate::components::*;
 use crate::events::*;
 use crate::hanabi_particles::*;
+use crate::resources::GraphicsSettings;
 use crate::{GameState, IsPaused};
 
 // Bridge system to convert old particle events to hanabi effects
@@ -10,8 +11,16 @@ pub fn bridge_particle_events(
     mut commands: Commands,
     mut particle_events: EventReader<SpawnParticles>,
     effects: Res<ParticleEffects>,
+    graphics: Res<GraphicsSettings>,
+    live_effects: Query<(), With<HanabiParticleEffect>>,
 ) {
+    let mut live = live_effects.iter().count();
+
     for event in particle_events.read() {
+        // Over budget, drop cosmetic bursts rather than queue them
+        if !graphics.allows_particles(live) { continue; }
+        live += 1;
+
         let effect_handle = if event.config.organic_motion {
             effects.bioluminescent_trail.clone()
         } else {
@@ -73,8 +82,15 @@ pub fn spawn_explosion_hanabi_effect(
     mut commands: Commands,
     mut explosion_events: EventReader<SpawnExplosion>,
     effects: Res<ParticleEffects>,
+    graphics: Res<GraphicsSettings>,
+    live_effects: Query<(), With<HanabiParticleEffect>>,
 ) {
+    let mut live = live_effects.iter().count();
+
     for event in explosion_events.read() {
+        if !graphics.allows_particles(live) { continue; }
+        live += 1;
+
         let effect_handle = if event.enemy_type.is_some() {
             effects.cell_burst.clone()
         } else {
@@ -2,25 +2,24 @@
 use bevy::prelude::*;
 use crate::components::*;
 use crate::resources::*;
-use crate::input::*;
 use crate::despawn::*;
 
 // ===== CONSTANTS =====
 const MENU_ITEM_HEIGHT: f32 = 40.0;
 const MENU_PADDING: f32 = 20.0;
 const MENU_WIDTH: f32 = 600.0;
-const EVOLUTION_MENU_ITEMS: usize = 11;
 
-// Enhanced evolution costs and limits
-const MEMBRANE_REINFORCEMENT_COSTS: [u32; 5] = [10, 15, 25, 40, 60];
-const WING_CANNON_COSTS: [u32; 5] = [25, 40, 60, 85, 120];
-const MISSILE_SYSTEM_COSTS: [u32; 5] = [35, 55, 80, 110, 150];
-const METABOLIC_COSTS: [u32; 5] = [15, 25, 40, 60, 90];
-const CELLULAR_COSTS: [u32; 5] = [20, 35, 55, 80, 115];
-const ENZYME_COSTS: [u32; 5] = [25, 45, 70, 100, 140];
-const BIOLUMINESCENCE_COSTS: [u32; 3] = [30, 60, 100];
-const MAGNET_RADIUS_COSTS: [u32; 4] = [25, 45, 70, 100];
-const MAGNET_STRENGTH_COSTS: [u32; 4] = [30, 50, 75, 105];
+// Base price of each upgrade's first purchase, repeats are priced by CostConfig
+const MEMBRANE_REINFORCEMENT_COST: u32 = 10;
+const WING_CANNON_COST: u32 = 25;
+const MISSILE_SYSTEM_COST: u32 = 35;
+const METABOLIC_COST: u32 = 15;
+const CELLULAR_COST: u32 = 20;
+const ENZYME_COST: u32 = 25;
+const BIOLUMINESCENCE_COST: u32 = 30;
+const EMERGENCY_SPORE_COST: u32 = 20;
+const MAGNET_RADIUS_COST: u32 = 25;
+const MAGNET_STRENGTH_COST: u32 = 30;
 
 #[derive(Component)]
 pub struct PauseMenuRoot;
@@ -56,11 +55,10 @@ pub struct PauseMenuState {
 }
 
 impl UpgradeType {
-    pub fn get_display_info(&self, limits: &UpgradeLimits, atp: u32) -> (String, String, u32, bool, bool) {
+    pub fn get_display_info(&self, limits: &UpgradeLimits, atp: u32, cost_config: &CostConfig) -> (String, String, u32, bool, bool) {
         match self {
             UpgradeType::MembraneReinforcement => {
-                let level = limits.damage_level as usize;
-                let cost = if level < MEMBRANE_REINFORCEMENT_COSTS.len() { MEMBRANE_REINFORCEMENT_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(MEMBRANE_REINFORCEMENT_COST, limits.damage_level);
                 (
                     format!("Membrane Reinforcement [{}/{}]", limits.damage_level, limits.damage_max),
                     "Enhanced projectile damage and size. Adds additional projectiles at higher tiers.".to_string(),
@@ -70,8 +68,7 @@ impl UpgradeType {
                 )
             }
             UpgradeType::WingCannons => {
-                let level = limits.wing_cannon_level as usize;
-                let cost = if level < WING_CANNON_COSTS.len() { WING_CANNON_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(WING_CANNON_COST, limits.wing_cannon_level);
                 (
                     format!("Wing Cannons [{}/{}]", limits.wing_cannon_level, limits.wing_cannon_max),
                     "Side-mounted piercing cannons. Yellow to green projectile evolution.".to_string(),
@@ -81,8 +78,7 @@ impl UpgradeType {
                 )
             }
             UpgradeType::MissileSystem => {
-                let level = limits.missile_level as usize;
-                let cost = if level < MISSILE_SYSTEM_COSTS.len() { MISSILE_SYSTEM_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(MISSILE_SYSTEM_COST, limits.missile_level);
                 (
                     format!("Missile System [{}/{}]", limits.missile_level, limits.missile_max),
                     "Auto-targeting guided missiles. Prioritizes stronger enemies.".to_string(),
@@ -92,8 +88,7 @@ impl UpgradeType {
                 )
             }
             UpgradeType::MetabolicEnhancement => {
-                let level = limits.metabolic_level as usize;
-                let cost = if level < METABOLIC_COSTS.len() { METABOLIC_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(METABOLIC_COST, limits.metabolic_level);
                 (
                     format!("Metabolic Enhancement [{}/{}]", limits.metabolic_level, limits.metabolic_max),
                     "Increased movement speed and fire rate efficiency.".to_string(),
@@ -103,8 +98,7 @@ impl UpgradeType {
                 )
             }
             UpgradeType::CellularIntegrity => {
-                let level = limits.cellular_level as usize;
-                let cost = if level < CELLULAR_COSTS.len() { CELLULAR_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(CELLULAR_COST, limits.cellular_level);
                 (
                     format!("Cellular Integrity [{}/{}]", limits.cellular_level, limits.cellular_max),
                     "Increased maximum health points for better survival.".to_string(),
@@ -114,8 +108,7 @@ impl UpgradeType {
                 )
             }
             UpgradeType::EnzymeProduction => {
-                let level = limits.enzyme_level as usize;
-                let cost = if level < ENZYME_COSTS.len() { ENZYME_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(ENZYME_COST, limits.enzyme_level);
                 (
                     format!("Enzyme Production [{}/{}]", limits.enzyme_level, limits.enzyme_max),
                     "Immunity to environmental toxins and enhanced projectile effects.".to_string(),
@@ -125,8 +118,7 @@ impl UpgradeType {
                 )
             }
             UpgradeType::Bioluminescence => {
-                let level = limits.bioluminescence_level as usize;
-                let cost = if level < BIOLUMINESCENCE_COSTS.len() { BIOLUMINESCENCE_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(BIOLUMINESCENCE_COST, limits.bioluminescence_level);
                 (
                     format!("Bioluminescence [{}/{}]", limits.bioluminescence_level, limits.bioluminescence_max),
                     "Enhanced coordination and visual effects for all abilities.".to_string(),
@@ -139,14 +131,13 @@ impl UpgradeType {
                 (
                     "Emergency Spore [+1]".to_string(),
                     "Additional emergency reproductive blast charge.".to_string(),
-                    20,
-                    atp >= 20,
+                    EMERGENCY_SPORE_COST,
+                    atp >= EMERGENCY_SPORE_COST,
                     true // Always available if you have ATP
                 )
             }
             UpgradeType::MagnetRadius => {
-                let level = limits.magnet_radius_level as usize;
-                let cost = if level < MAGNET_RADIUS_COSTS.len() { MAGNET_RADIUS_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(MAGNET_RADIUS_COST, limits.magnet_radius_level);
                 (
                     format!("ATP Absorption Range [{}/{}]", limits.magnet_radius_level, limits.magnet_radius_max),
                     "Increases ATP collection radius by 25px per level.".to_string(),
@@ -156,8 +147,7 @@ impl UpgradeType {
                 )
             }
             UpgradeType::MagnetStrength => {
-                let level = limits.magnet_strength_level as usize;
-                let cost = if level < MAGNET_STRENGTH_COSTS.len() { MAGNET_STRENGTH_COSTS[level] } else { 0 };
+                let cost = cost_config.scaled_cost(MAGNET_STRENGTH_COST, limits.magnet_strength_level);
                 (
                     format!("ATP Absorption Force [{}/{}]", limits.magnet_strength_level, limits.magnet_strength_max),
                     "Increases magnetic pull force for faster ATP collection.".to_string(),
@@ -177,6 +167,7 @@ pub fn setup_enhanced_pause_menu(
     mut commands: Commands,
     fonts: Res<GameFonts>,
     player_query: Query<(&ATP, &UpgradeLimits), With<Player>>,
+    cost_config: Res<CostConfig>,
 ) {
     if let Ok((atp, limits)) = player_query.single() {
         commands.spawn((
@@ -213,7 +204,7 @@ pub fn setup_enhanced_pause_menu(
                 
                 // ATP Display
                 menu.spawn((
-                    Text::new(&format!("Available ATP: {}⚡", atp.amount)),
+                    Text::new(format!("Available ATP: {}⚡", atp.amount)),
                     TextFont { font: fonts.default_font.clone(), font_size: 18.0, ..default() },
                     TextColor(Color::srgb(1.0, 1.0, 0.3)),
                     Node { margin: UiRect::bottom(Val::Px(20.0)), align_self: AlignSelf::Center, ..default() },
@@ -235,7 +226,7 @@ pub fn setup_enhanced_pause_menu(
                 ];
 
                 for (index, upgrade_type) in upgrade_types.into_iter().enumerate() {
-                    let (title, description, cost, can_afford, can_upgrade) = upgrade_type.get_display_info(&limits, atp.amount);
+                    let (title, description, cost, can_afford, can_upgrade) = upgrade_type.get_display_info(limits, atp.amount, &cost_config);
                     
                     let text_color = if index == 0 { // Selected item
                         Color::srgb(1.0, 1.0, 0.3)
@@ -268,7 +259,7 @@ pub fn setup_enhanced_pause_menu(
                         // Title with cost
                         let title_text = if matches!(upgrade_type, UpgradeType::ExitMenu) {
                             title
-                        } else if cost > 0 {
+                        } else if cost > 0 && can_upgrade {
                             format!("{} - {} ATP", title, cost)
                         } else {
                             title
@@ -322,66 +313,6 @@ pub fn setup_enhanced_pause_menu(
     }
 }
 
-fn process_upgrade(
-    upgrade_type: &UpgradeType,
-    atp: &mut ATP,
-    limits: &mut UpgradeLimits,
-    evolution_system: &mut EvolutionSystem,
-    upgrades: &mut CellularUpgrades,
-) {
-    let (_, _, cost, can_afford, can_upgrade) = upgrade_type.get_display_info(&limits, atp.amount);
-    
-    if !can_afford || !can_upgrade { return; }
-
-    atp.amount = atp.amount.saturating_sub(cost);
-
-    match upgrade_type {
-        UpgradeType::MembraneReinforcement => {
-            limits.damage_level += 1;
-            upgrades.damage_amplification *= 1.2; // 20% increase per level
-        }
-        UpgradeType::WingCannons => {
-            limits.wing_cannon_level += 1;
-            // Wing cannon functionality will be implemented in weapon systems
-        }
-        UpgradeType::MissileSystem => {
-            limits.missile_level += 1;
-            // Missile system functionality will be implemented in weapon systems
-        }
-        UpgradeType::MetabolicEnhancement => {
-            limits.metabolic_level += 1;
-            upgrades.metabolic_rate *= 1.15;
-            upgrades.movement_efficiency *= 1.1;
-        }
-        UpgradeType::CellularIntegrity => {
-            limits.cellular_level += 1;
-            upgrades.max_health += 30;
-        }
-        UpgradeType::EnzymeProduction => {
-            limits.enzyme_level += 1;
-            evolution_system.cellular_adaptations.extremophile_traits = true;
-        }
-        UpgradeType::Bioluminescence => {
-            limits.bioluminescence_level += 1;
-            evolution_system.cellular_adaptations.biofilm_formation = true;
-        }
-        UpgradeType::EmergencySpore => {
-            if evolution_system.emergency_spores < 5 { // Cap at 5
-                evolution_system.emergency_spores += 1;
-            }
-        }
-        UpgradeType::MagnetRadius => {
-            limits.magnet_radius_level += 1;
-            upgrades.magnet_radius += 25.0;
-        }
-        UpgradeType::MagnetStrength => {
-            limits.magnet_strength_level += 1;
-            upgrades.magnet_strength += 0.4;
-        }
-        UpgradeType::ExitMenu => {} // Handled above
-    }
-}
-
 pub fn cleanup_pause_menu_system(
     mut commands: Commands,
     pause_menu_query: Query<Entity, With<PauseMenuRoot>>,
@@ -1,11 +1,12 @@
 // src/consolidated_pause_system.rs - Proper Bevy 0.16.1 SubState pause system
 use bevy::prelude::*;
+use bevy::window::WindowFocused;
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
 use crate::components::*;
 use crate::resources::*;
 use crate::input::*;
 use crate::pause_menu::*;
 use crate::constants::*;
-use crate::weapon_systems::{setup_player_weapons};
 use crate::despawn::*;
 
 // ===== CONSOLIDATED PAUSE INPUT HANDLING =====
@@ -39,6 +40,152 @@ pub fn unified_pause_input_system(
     }
 }
 
+// ===== FOCUS LOSS AUTO-PAUSE =====
+
+pub fn focus_loss_pause_system(
+    mut focus_events: EventReader<WindowFocused>,
+    settings: Res<MenuSettings>,
+    current_pause_state: Res<State<IsPaused>>,
+    mut next_pause_state: ResMut<NextState<IsPaused>>,
+    mut menu_state: ResMut<PauseMenuState>,
+    mut paused_by_focus_loss: Local<bool>,
+) {
+    for event in focus_events.read() {
+        let transition = focus_pause_transition(
+            event.focused,
+            current_pause_state.get(),
+            &settings,
+            *paused_by_focus_loss,
+        );
+
+        match transition {
+            Some(IsPaused::Paused) => {
+                next_pause_state.set(IsPaused::Paused);
+                menu_state.menu_active = true;
+                menu_state.selected_index = 0;
+                *paused_by_focus_loss = true;
+            }
+            Some(IsPaused::Running) => {
+                next_pause_state.set(IsPaused::Running);
+                menu_state.menu_active = false;
+                *paused_by_focus_loss = false;
+            }
+            None => {
+                // Any manual unpause clears the auto-resume claim
+                if current_pause_state.get() == &IsPaused::Running {
+                    *paused_by_focus_loss = false;
+                }
+            }
+        }
+    }
+}
+
+/// Pause when focus is lost mid-run; only resume on refocus if the pause was ours and the setting allows it
+pub fn focus_pause_transition(
+    focused: bool,
+    current: &IsPaused,
+    settings: &MenuSettings,
+    paused_by_focus_loss: bool,
+) -> Option<IsPaused> {
+    match (focused, current) {
+        (false, IsPaused::Running) if settings.pause_on_focus_loss => Some(IsPaused::Paused),
+        (true, IsPaused::Paused) if paused_by_focus_loss && settings.auto_resume_on_focus => Some(IsPaused::Running),
+        _ => None,
+    }
+}
+
+// ===== GAMEPAD DISCONNECT AUTO-PAUSE =====
+
+#[derive(Component)]
+pub struct ReconnectControllerPrompt;
+
+pub fn gamepad_disconnect_pause_system(
+    mut commands: Commands,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    input_manager: Res<InputManager>,
+    settings: Res<MenuSettings>,
+    fonts: Res<GameFonts>,
+    current_pause_state: Res<State<IsPaused>>,
+    mut next_pause_state: ResMut<NextState<IsPaused>>,
+    mut menu_state: ResMut<PauseMenuState>,
+    prompt_query: Query<Entity, With<ReconnectControllerPrompt>>,
+    (mut paused_by_disconnect, mut last_active_gamepad): (Local<bool>, Local<Option<Entity>>),
+) {
+    // The input plugin may already have cleared the pad this frame, so compare against last frame's
+    let active_gamepad = last_active_gamepad.or(input_manager.active_gamepad);
+    let mut pausing = false;
+
+    for event in connection_events.read() {
+        let connected = matches!(event.connection, GamepadConnection::Connected { .. });
+        let transition = gamepad_pause_transition(
+            connected,
+            active_gamepad == Some(event.gamepad),
+            current_pause_state.get(),
+            &settings,
+            *paused_by_disconnect,
+        );
+
+        match transition {
+            Some(IsPaused::Paused) => {
+                next_pause_state.set(IsPaused::Paused);
+                menu_state.menu_active = true;
+                menu_state.selected_index = 0;
+                *paused_by_disconnect = true;
+                pausing = true;
+                spawn_reconnect_prompt(&mut commands, &fonts);
+            }
+            Some(IsPaused::Running) => {
+                next_pause_state.set(IsPaused::Running);
+                menu_state.menu_active = false;
+                *paused_by_disconnect = false;
+            }
+            None => {}
+        }
+    }
+
+    // A manual unpause (keyboard) also takes the prompt down
+    if current_pause_state.get() == &IsPaused::Running && !pausing {
+        *paused_by_disconnect = false;
+        for entity in prompt_query.iter() {
+            commands.entity(entity).safe_despawn();
+        }
+    }
+
+    *last_active_gamepad = input_manager.active_gamepad;
+}
+
+/// Pause when the pad in use drops mid-run; resume on any reconnect, but only if the pause was ours
+pub fn gamepad_pause_transition(
+    connected: bool,
+    was_active_gamepad: bool,
+    current: &IsPaused,
+    settings: &MenuSettings,
+    paused_by_disconnect: bool,
+) -> Option<IsPaused> {
+    match (connected, current) {
+        (false, IsPaused::Running) if was_active_gamepad && settings.pause_on_gamepad_disconnect => Some(IsPaused::Paused),
+        (true, IsPaused::Paused) if paused_by_disconnect => Some(IsPaused::Running),
+        _ => None,
+    }
+}
+
+fn spawn_reconnect_prompt(commands: &mut Commands, fonts: &GameFonts) {
+    commands.spawn((
+        Text::new("Controller disconnected - reconnect to resume"),
+        TextFont { font: fonts.default_font.clone(), font_size: 22.0, ..default() },
+        TextColor(Color::srgb(1.0, 0.8, 0.3)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(40.0),
+            left: Val::Percent(50.0),
+            margin: UiRect::left(Val::Px(-240.0)),
+            ..default()
+        },
+        GlobalZIndex(100),
+        ReconnectControllerPrompt,
+    ));
+}
+
 // ===== PAUSE MENU NAVIGATION =====
 // Consolidated and improved from pause_menu.rs
 
@@ -56,6 +203,7 @@ pub fn enhanced_pause_menu_navigation(
     ), With<Player>>,
     current_state: Res<State<IsPaused>>,
     mut next_state: ResMut<NextState<IsPaused>>,
+    cost_config: Res<CostConfig>,
 ) {
     // Only process navigation when actually paused
     if current_state.get() != &IsPaused::Paused || !menu_state.menu_active {
@@ -108,7 +256,7 @@ pub fn enhanced_pause_menu_navigation(
                     }
                     _ => {
                         // new
-                        process_evolution_upgrade(&upgrade_type, &mut atp, &mut limits, &mut evolution_system, &mut upgrades);
+                        process_evolution_upgrade(&upgrade_type, &mut atp, &mut limits, &mut evolution_system, &mut upgrades, &cost_config);
                     }
                 }
             }
@@ -197,8 +345,9 @@ fn process_evolution_upgrade(
     limits: &mut UpgradeLimits,
     evolution_system: &mut EvolutionSystem,
     upgrades: &mut CellularUpgrades,
+    cost_config: &CostConfig,
 ) {
-    let (_, _, cost, can_afford, can_upgrade) = upgrade_type.get_display_info(&limits, atp.amount);
+    let (_, _, cost, can_afford, can_upgrade) = upgrade_type.get_display_info(limits, atp.amount, cost_config);
     
     if !can_afford || !can_upgrade { 
         info!("can't afford, or no upgrade left");
@@ -295,12 +444,13 @@ pub fn on_pause_enter(
     fonts: Res<GameFonts>,
     player_query: Query<(&ATP, &UpgradeLimits), With<Player>>,
     mut menu_state: ResMut<PauseMenuState>,
+    cost_config: Res<CostConfig>,
 ) {
     menu_state.menu_active = true;
     menu_state.selected_index = 0;
     
     // Set up pause menu UI
-    setup_enhanced_pause_menu(commands, fonts, player_query);
+    setup_enhanced_pause_menu(commands, fonts, player_query, cost_config);
 }
 
 pub fn on_pause_exit(
@@ -335,6 +485,8 @@ pub fn pause_input_blocking_system(
                 //InputAction::MoveDown,
                 //InputAction::Shoot,
                 InputAction::EmergencySpore,
+                InputAction::CycleEvolution,
+                InputAction::Cloak,
                 InputAction::Restart,
             ];
             
@@ -344,6 +496,9 @@ pub fn pause_input_blocking_system(
                     InputAction::DebugSpawnATP,
                     InputAction::DebugSpawnEvolutionChamber,
                     InputAction::DebugTriggerKingTide,
+                    InputAction::DebugSpawnTargetDummy,
+                    InputAction::DebugToggleAiRanges,
+                    InputAction::DebugSkipWave,
                 ]);
             }
         }
@@ -365,6 +520,8 @@ impl Plugin for ConsolidatedPausePlugin {
             // Remove duplicate pause systems and consolidate
             .add_systems(Update, (
                 unified_pause_input_system,
+                focus_loss_pause_system,
+                gamepad_disconnect_pause_system,
                 pause_input_blocking_system,
             ).run_if(in_state(GameState::Playing)))
             
@@ -407,3 +564,88 @@ pub fn pause_performance_monitor(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    fn playing_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .add_sub_state::<IsPaused>()
+            .init_resource::<MenuSettings>()
+            .init_resource::<PauseMenuState>()
+            .add_event::<WindowFocused>()
+            .add_systems(Update, focus_loss_pause_system);
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Playing);
+        app.update();
+        app
+    }
+
+    fn pause_state(app: &App) -> IsPaused {
+        *app.world().resource::<State<IsPaused>>().get()
+    }
+
+    #[test]
+    fn losing_focus_mid_run_pauses() {
+        let mut app = playing_app();
+        assert_eq!(pause_state(&app), IsPaused::Running);
+
+        app.world_mut().send_event(WindowFocused { window: Entity::PLACEHOLDER, focused: false });
+        app.update();
+        app.update();
+
+        assert_eq!(pause_state(&app), IsPaused::Paused);
+        assert!(app.world().resource::<PauseMenuState>().menu_active);
+    }
+
+    #[test]
+    fn focus_loss_is_ignored_when_the_setting_is_off() {
+        let mut app = playing_app();
+        app.world_mut().resource_mut::<MenuSettings>().pause_on_focus_loss = false;
+
+        app.world_mut().send_event(WindowFocused { window: Entity::PLACEHOLDER, focused: false });
+        app.update();
+        app.update();
+
+        assert_eq!(pause_state(&app), IsPaused::Running);
+    }
+
+    fn gamepad_app(active_gamepad: Option<Entity>) -> App {
+        let mut app = playing_app();
+        app.init_resource::<InputManager>()
+            .insert_resource(GameFonts { default_font: Handle::default() })
+            .add_event::<GamepadConnectionEvent>()
+            .add_systems(Update, gamepad_disconnect_pause_system);
+        app.world_mut().resource_mut::<InputManager>().active_gamepad = active_gamepad;
+        app
+    }
+
+    fn send_connection(app: &mut App, gamepad: Entity, connection: GamepadConnection) {
+        app.world_mut().send_event(GamepadConnectionEvent { gamepad, connection });
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn active_gamepad_disconnecting_pauses_until_it_reconnects() {
+        let gamepad = Entity::from_raw(42);
+        let mut app = gamepad_app(Some(gamepad));
+
+        send_connection(&mut app, gamepad, GamepadConnection::Disconnected);
+        assert_eq!(pause_state(&app), IsPaused::Paused);
+
+        send_connection(&mut app, gamepad, GamepadConnection::Connected { name: "Pad".into(), vendor_id: None, product_id: None });
+        assert_eq!(pause_state(&app), IsPaused::Running);
+    }
+
+    #[test]
+    fn unused_gamepad_disconnecting_does_not_pause() {
+        let mut app = gamepad_app(None);
+
+        send_connection(&mut app, Entity::from_raw(42), GamepadConnection::Disconnected);
+        assert_eq!(pause_state(&app), IsPaused::Running);
+    }
+}
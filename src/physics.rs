@@ -40,4 +40,13 @@ pub fn sample_oxygen(chemical_env: &ChemicalEnvironment, position: Vec2) -> f32
         }
     }
     oxygen.clamp(0.0, 1.0)
+}
+
+/// Direction oxygen increases fastest at `position`, zero in uniform water
+pub fn oxygen_gradient(chemical_env: &ChemicalEnvironment, position: Vec2) -> Vec2 {
+    const STEP: f32 = 8.0;
+    Vec2::new(
+        sample_oxygen(chemical_env, position + Vec2::X * STEP) - sample_oxygen(chemical_env, position - Vec2::X * STEP),
+        sample_oxygen(chemical_env, position + Vec2::Y * STEP) - sample_oxygen(chemical_env, position - Vec2::Y * STEP),
+    ).normalize_or_zero()
 }
\ No newline at end of file
@@ -5,17 +5,19 @@ use crate::resources::*;
 use crate::input::*;
 use crate::physics::*;
 use crate::despawn::*;
+use crate::constants::PLAYER_TRAIL_SEGMENTS;
 
 /// Enhanced player movement with fluid dynamics and organic motion
 pub fn biological_movement_system(
     mut player_query: Query<(&mut Transform, &mut FluidDynamics, &Player)>,
     input_manager: Res<InputManager>, // Changed from InputState
     fluid_environment: Res<FluidEnvironment>,
+    distortion: Res<ControlDistortion>,
     time: Res<Time>,
 ) {
     if let Ok((mut transform, mut fluid, player)) = player_query.single_mut() {
-        // Get movement vector from input manager
-        let movement = input_manager.movement_vector(); // Smooth analog movement
+        // Get movement vector from input manager, scrambled while a king tide distortion is active
+        let movement = distortion.apply(input_manager.movement_vector()); // Smooth analog movement
         
         // Player input creates thrust against fluid resistance
         let thrust = movement * player.speed * 2.0;
@@ -52,8 +54,11 @@ pub fn biological_movement_system(
 // Bioluminescent trail system (replaces engine particles)
 pub fn spawn_bioluminescent_trail(
     mut commands: Commands,
-    player_query: Query<&Transform, With<EngineTrail>>,
+    player_query: Query<(&Transform, Option<&FluidDynamics>), With<EngineTrail>>,
     input_manager: Res<InputManager>,
+    trail_config: Res<TrailIntensityConfig>,
+    graphics: Res<GraphicsSettings>,
+    live_particles: Query<(), With<Particle>>,
     assets: Option<Res<GameAssets>>,
     time: Res<Time>,
     mut trail_segments: Local<Vec<Vec3>>,
@@ -62,22 +67,34 @@ pub fn spawn_bioluminescent_trail(
     *spawn_timer -= time.delta_secs();
     
     if *spawn_timer <= 0.0 {
-        for transform in player_query.iter() {
-            let intensity = input_manager.movement_vector().length().max(0.2);
+        let mut next_interval = 0.05;
+        for (transform, fluid) in player_query.iter() {
+            // Brighter, denser wake the faster the cell is actually moving through the water
+            let intensity = match fluid {
+                Some(fluid) if trail_config.enabled => {
+                    let speed = fluid.velocity.length();
+                    next_interval = trail_config.spawn_interval(speed);
+                    trail_config.intensity(speed)
+                }
+                _ => input_manager.movement_vector().length().max(0.2),
+            };
             
             // Add new trail segment
             trail_segments.push(transform.translation + Vec3::new(0.0, -18.0, -0.1));
             
-            // Keep only last 15 segments
-            if trail_segments.len() > 15 {
+            // Keep only as many segments as the graphics settings allow
+            let max_segments = graphics.trail_points(PLAYER_TRAIL_SEGMENTS);
+            while trail_segments.len() > max_segments {
                 trail_segments.remove(0);
             }
             
-            // Spawn connected membrane segments
+            // Spawn connected membrane segments, sharing the particle budget with every other effect
             if let Some(assets) = &assets {
-                for (i, &segment_pos) in trail_segments.iter().enumerate() {
+                let live_before = live_particles.iter().count();
+                for (live, (i, &segment_pos)) in (live_before..).zip(trail_segments.iter().enumerate()) {
+                    if !graphics.allows_particles(live) { break; }
                     let age = i as f32 / trail_segments.len() as f32;
-                    let alpha = age * 0.6 * intensity;
+                    let alpha = (age * 0.6 * intensity).min(1.0);
                     let width = (age * 8.0 + 2.0) * intensity;
                     
                     commands.spawn((
@@ -102,7 +119,35 @@ pub fn spawn_bioluminescent_trail(
             }
         }
         
-        *spawn_timer = 0.05;
+        *spawn_timer = next_interval;
+    }
+}
+
+/// Cloak on the Cloak action, fading the player out until it wears off
+pub fn stealth_system(
+    mut commands: Commands,
+    mut input_manager: ResMut<InputManager>,
+    mut stealth: ResMut<StealthAbility>,
+    mut player_query: Query<(Entity, &mut Sprite, Option<&mut Stealthed>), With<Player>>,
+    time: Res<Time>,
+) {
+    stealth.tick(time.delta_secs());
+    let Ok((player_entity, mut sprite, stealthed)) = player_query.single_mut() else { return };
+
+    match stealthed {
+        Some(mut stealthed) => {
+            stealthed.remaining -= time.delta_secs();
+            if stealthed.remaining <= 0.0 {
+                commands.entity(player_entity).try_remove::<Stealthed>();
+                sprite.color = sprite.color.with_alpha(1.0);
+            }
+        }
+        None => {
+            if stealth.ready() && input_manager.consume_press(InputAction::Cloak) && let Some(duration) = stealth.activate() {
+                commands.entity(player_entity).try_insert(Stealthed { remaining: duration });
+                sprite.color = sprite.color.with_alpha(stealth.alpha);
+            }
+        }
     }
 }
 
@@ -245,3 +290,53 @@ pub fn update_biological_effects(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// Trail bursts laid down over one second and the brightest segment among them
+    fn trail_over_one_second(speed: f32) -> (usize, f32) {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InputManager>()
+            .init_resource::<TrailIntensityConfig>()
+            .init_resource::<GraphicsSettings>()
+            .init_resource::<GameAssets>()
+            .add_systems(Update, spawn_bioluminescent_trail);
+        app.world_mut().spawn((
+            Transform::default(),
+            FluidDynamics { velocity: Vec2::X * speed, viscosity_resistance: 0.8, buoyancy: 0.0, current_influence: 0.0 },
+            EngineTrail,
+        ));
+
+        let (mut bursts, mut brightest) = (0, 0.0_f32);
+        for _ in 0..100 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(10));
+            app.update();
+
+            let spawned: Vec<(Entity, f32)> = app.world_mut().query_filtered::<(Entity, &Sprite), With<Particle>>()
+                .iter(app.world())
+                .map(|(entity, sprite)| (entity, sprite.color.alpha()))
+                .collect();
+            if !spawned.is_empty() {
+                bursts += 1;
+            }
+            for (entity, alpha) in spawned {
+                brightest = brightest.max(alpha);
+                app.world_mut().despawn(entity);
+            }
+        }
+        (bursts, brightest)
+    }
+
+    #[test]
+    fn fast_player_leaves_a_denser_brighter_wake_than_a_still_one() {
+        let (still_bursts, still_alpha) = trail_over_one_second(0.0);
+        let (fast_bursts, fast_alpha) = trail_over_one_second(400.0);
+
+        assert!(fast_bursts > still_bursts, "{fast_bursts} bursts moving vs {still_bursts} still");
+        assert!(fast_alpha > still_alpha, "{fast_alpha} alpha moving vs {still_alpha} still");
+    }
+}
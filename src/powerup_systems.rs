@@ -102,6 +102,9 @@ pub fn spawn_powerup_system(
                 PowerUpType::Osmoregulation { .. } => (assets.shield_powerup_texture.clone(), Color::srgb(0.3, 0.8, 0.9)),
                 PowerUpType::BinaryFission { .. } => (assets.rapidfire_powerup_texture.clone(), Color::srgb(1.0, 0.9, 0.3)),
                 PowerUpType::MagneticField { .. } => (assets.multiplier_powerup_texture.clone(), Color::srgb(0.4, 0.9, 0.4)),
+                PowerUpType::CryoPulse { .. } => (assets.shield_powerup_texture.clone(), Color::srgb(0.7, 0.9, 1.0)),
+                PowerUpType::ShieldBarrier { .. } => (assets.shield_powerup_texture.clone(), Color::srgb(0.5, 0.9, 1.0)),
+                PowerUpType::DeployZone { kind, .. } => (assets.shield_powerup_texture.clone(), kind.color().with_alpha(1.0)),
             };
             
             commands.spawn((
@@ -131,3 +134,21 @@ pub fn spawn_powerup_system(
     }
 }
 
+
+/// Count down deployed shield barriers, flickering as they run out
+pub fn shield_barrier_system(
+    mut commands: Commands,
+    mut barrier_query: Query<(Entity, &mut ShieldBarrier, &mut Sprite), Without<PendingDespawn>>,
+    time: Res<Time>,
+) {
+    for (entity, mut barrier, mut sprite) in barrier_query.iter_mut() {
+        if barrier.tick(time.delta_secs()) {
+            commands.entity(entity).safe_despawn();
+            continue;
+        }
+        if barrier.timer < 2.0 {
+            let flicker = (barrier.timer * 20.0).sin() * 0.5 + 0.5;
+            sprite.color.set_alpha(0.3 + flicker * 0.4);
+        }
+    }
+}
@@ -0,0 +1,453 @@
+// src/replay_codes.rs - Shareable codes that pack a run's pool and spread seeds, difficulty and loadout
+use bevy::prelude::*;
+use std::fmt;
+use crate::components::*;
+use crate::resources::*;
+use crate::loadout_presets::*;
+use crate::time_attack::*;
+
+// ===== CONSTANTS =====
+pub const REPLAY_CODE_VERSION: u8 = 3;
+const REPLAY_CODE_ARG: &str = "--replay";
+const MAX_SHARED_NAME_BYTES: usize = 24;
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+const FLAG_TIME_ATTACK: u8 = 1 << 0;
+const FLAG_LOADOUT: u8 = 1 << 1;
+
+// ===== REPLAY SETUP =====
+/// Everything needed to start an identical run
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaySetup {
+    pub environment_seed: u64,
+    pub spread_seed: u64,
+    pub run_modifiers: Vec<RunModifier>,
+    pub time_attack: bool,
+    pub loadout: Option<LoadoutPreset>,
+}
+
+impl ReplaySetup {
+    pub fn capture(environment_seed: &EnvironmentSeed, spread_rng: &WeaponSpreadRng, run_modifiers: &RunModifiers, time_attack: &TimeAttack, loadouts: &LoadoutPresets) -> Self {
+        Self {
+            environment_seed: environment_seed.seed,
+            spread_seed: spread_rng.seed,
+            run_modifiers: run_modifiers.active.clone(),
+            time_attack: time_attack.enabled,
+            loadout: loadouts.selected_preset().cloned(),
+        }
+    }
+
+    /// Write the setup into the run resources. A loadout that isn't saved locally is only selected
+    /// for the session, so applying a code never evicts one of the player's own presets
+    pub fn apply(&self, environment_seed: &mut EnvironmentSeed, spread_rng: &mut WeaponSpreadRng, run_modifiers: &mut RunModifiers, time_attack: &mut TimeAttack, loadouts: &mut LoadoutPresets) {
+        environment_seed.seed = self.environment_seed;
+        environment_seed.randomize_each_run = false;
+        *spread_rng = WeaponSpreadRng::from_seed(self.spread_seed);
+        run_modifiers.active = self.run_modifiers.clone();
+        time_attack.enabled = self.time_attack;
+        loadouts.shared = None;
+        match &self.loadout {
+            Some(preset) => match loadouts.presets.iter().position(|saved| saved == preset) {
+                Some(index) => loadouts.selected = Some(index),
+                None => loadouts.shared = Some(preset.clone()),
+            },
+            None => loadouts.selected = None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayCodeError {
+    InvalidCharacter(char),
+    Truncated,
+    UnsupportedVersion(u8),
+    UnknownEvolution(u8),
+    UnknownUpgrade(u8),
+    ChecksumMismatch,
+}
+
+impl fmt::Display for ReplayCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayCodeError::InvalidCharacter(c) => write!(f, "invalid character '{}'", c),
+            ReplayCodeError::Truncated => write!(f, "code is incomplete"),
+            ReplayCodeError::UnsupportedVersion(v) => write!(f, "unsupported code version {} (expected {})", v, REPLAY_CODE_VERSION),
+            ReplayCodeError::UnknownEvolution(tag) => write!(f, "unknown evolution {}", tag),
+            ReplayCodeError::UnknownUpgrade(tag) => write!(f, "unknown upgrade {}", tag),
+            ReplayCodeError::ChecksumMismatch => write!(f, "code is corrupted (checksum mismatch)"),
+        }
+    }
+}
+
+// ===== ENCODING =====
+/// Layout v3: version, pool seed, spread seed, modifier bits, flags, the optional loadout, then a checksum of it all
+pub fn encode_replay_code(setup: &ReplaySetup) -> String {
+    let mut bytes = vec![REPLAY_CODE_VERSION];
+    bytes.extend_from_slice(&setup.environment_seed.to_le_bytes());
+    bytes.extend_from_slice(&setup.spread_seed.to_le_bytes());
+
+    let modifier_bits = RunModifier::ALL.iter().enumerate()
+        .filter(|(_, modifier)| setup.run_modifiers.contains(modifier))
+        .fold(0u8, |bits, (index, _)| bits | (1 << index));
+    bytes.push(modifier_bits);
+
+    let mut flags = 0;
+    if setup.time_attack { flags |= FLAG_TIME_ATTACK; }
+    if setup.loadout.is_some() { flags |= FLAG_LOADOUT; }
+    bytes.push(flags);
+
+    if let Some(preset) = &setup.loadout {
+        write_evolution(&mut bytes, &preset.evolution);
+
+        bytes.push(preset.upgrade_plan.len() as u8);
+        for target in &preset.upgrade_plan {
+            let index = PresetUpgrade::ALL.iter().position(|upgrade| *upgrade == target.upgrade).unwrap_or(0);
+            bytes.push(index as u8);
+            bytes.extend_from_slice(&target.level.to_le_bytes());
+        }
+
+        let name = truncate_name(&preset.name);
+        bytes.push(name.len() as u8);
+        bytes.extend_from_slice(name.as_bytes());
+    }
+
+    let checksum = checksum(&bytes);
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    base64_encode(&bytes)
+}
+
+pub fn decode_replay_code(code: &str) -> Result<ReplaySetup, ReplayCodeError> {
+    let bytes = base64_decode(code.trim())?;
+    let mut reader = ByteReader { bytes: &bytes, position: 0 };
+
+    let version = reader.u8()?;
+    if version != REPLAY_CODE_VERSION {
+        return Err(ReplayCodeError::UnsupportedVersion(version));
+    }
+
+    let environment_seed = reader.u64()?;
+    let spread_seed = reader.u64()?;
+    let modifier_bits = reader.u8()?;
+    let run_modifiers = RunModifier::ALL.iter().enumerate()
+        .filter(|(index, _)| modifier_bits & (1 << index) != 0)
+        .map(|(_, modifier)| *modifier)
+        .collect();
+
+    let flags = reader.u8()?;
+    let loadout = if flags & FLAG_LOADOUT != 0 {
+        let evolution = read_evolution(&mut reader)?;
+
+        let count = reader.u8()?;
+        let mut upgrade_plan = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let index = reader.u8()?;
+            let upgrade = *PresetUpgrade::ALL.get(index as usize).ok_or(ReplayCodeError::UnknownUpgrade(index))?;
+            upgrade_plan.push(UpgradeTarget { upgrade, level: reader.u32()? });
+        }
+
+        let name_len = reader.u8()? as usize;
+        let name = String::from_utf8_lossy(reader.take(name_len)?).into_owned();
+        Some(LoadoutPreset { name, evolution, upgrade_plan })
+    } else {
+        None
+    };
+
+    let payload_len = reader.position;
+    if reader.u16()? != checksum(&bytes[..payload_len]) {
+        return Err(ReplayCodeError::ChecksumMismatch);
+    }
+
+    Ok(ReplaySetup {
+        environment_seed,
+        spread_seed,
+        run_modifiers,
+        time_attack: flags & FLAG_TIME_ATTACK != 0,
+        loadout,
+    })
+}
+
+/// Fletcher-16, enough to catch a mistyped or half-pasted code
+fn checksum(bytes: &[u8]) -> u16 {
+    let (sum1, sum2) = bytes.iter().fold((0u16, 0u16), |(sum1, sum2), byte| {
+        let sum1 = (sum1 + *byte as u16) % 255;
+        (sum1, (sum2 + sum1) % 255)
+    });
+    (sum2 << 8) | sum1
+}
+
+fn truncate_name(name: &str) -> &str {
+    if name.len() <= MAX_SHARED_NAME_BYTES { return name; }
+    let mut end = MAX_SHARED_NAME_BYTES;
+    while !name.is_char_boundary(end) { end -= 1; }
+    &name[..end]
+}
+
+// Floats are stored as raw bits so a decoded evolution matches the original exactly
+fn write_evolution(bytes: &mut Vec<u8>, evolution: &EvolutionType) {
+    match evolution {
+        EvolutionType::CytoplasmicSpray { damage, fire_rate } => {
+            bytes.push(0);
+            bytes.extend_from_slice(&damage.to_le_bytes());
+            bytes.extend_from_slice(&fire_rate.to_bits().to_le_bytes());
+        }
+        EvolutionType::PseudopodNetwork { damage, fire_rate, tendril_count, spread_angle } => {
+            bytes.push(1);
+            bytes.extend_from_slice(&damage.to_le_bytes());
+            bytes.extend_from_slice(&fire_rate.to_bits().to_le_bytes());
+            bytes.extend_from_slice(&tendril_count.to_le_bytes());
+            bytes.extend_from_slice(&spread_angle.to_bits().to_le_bytes());
+        }
+        EvolutionType::BioluminescentBeam { damage, charge_time, duration, width } => {
+            bytes.push(2);
+            bytes.extend_from_slice(&damage.to_le_bytes());
+            for value in [charge_time, duration, width] {
+                bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+        }
+        EvolutionType::SymbioticHunters { damage, fire_rate, homing_strength, blast_radius } => {
+            bytes.push(3);
+            bytes.extend_from_slice(&damage.to_le_bytes());
+            for value in [fire_rate, homing_strength, blast_radius] {
+                bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+        }
+        EvolutionType::EnzymeBurst { damage, fire_rate, acid_damage } => {
+            bytes.push(4);
+            bytes.extend_from_slice(&damage.to_le_bytes());
+            for value in [fire_rate, acid_damage] {
+                bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+        }
+        EvolutionType::ToxinCloud { damage_per_second, cloud_radius, duration } => {
+            bytes.push(5);
+            bytes.extend_from_slice(&damage_per_second.to_le_bytes());
+            for value in [cloud_radius, duration] {
+                bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+        }
+        EvolutionType::ElectricDischarge { damage, chain_count, range } => {
+            bytes.push(6);
+            bytes.extend_from_slice(&damage.to_le_bytes());
+            bytes.extend_from_slice(&chain_count.to_le_bytes());
+            bytes.extend_from_slice(&range.to_bits().to_le_bytes());
+        }
+    }
+}
+
+fn read_evolution(reader: &mut ByteReader) -> Result<EvolutionType, ReplayCodeError> {
+    let tag = reader.u8()?;
+    Ok(match tag {
+        0 => EvolutionType::CytoplasmicSpray { damage: reader.i32()?, fire_rate: reader.f32()? },
+        1 => EvolutionType::PseudopodNetwork {
+            damage: reader.i32()?,
+            fire_rate: reader.f32()?,
+            tendril_count: reader.u32()?,
+            spread_angle: reader.f32()?,
+        },
+        2 => EvolutionType::BioluminescentBeam {
+            damage: reader.i32()?,
+            charge_time: reader.f32()?,
+            duration: reader.f32()?,
+            width: reader.f32()?,
+        },
+        3 => EvolutionType::SymbioticHunters {
+            damage: reader.i32()?,
+            fire_rate: reader.f32()?,
+            homing_strength: reader.f32()?,
+            blast_radius: reader.f32()?,
+        },
+        4 => EvolutionType::EnzymeBurst { damage: reader.i32()?, fire_rate: reader.f32()?, acid_damage: reader.f32()? },
+        5 => EvolutionType::ToxinCloud { damage_per_second: reader.i32()?, cloud_radius: reader.f32()?, duration: reader.f32()? },
+        6 => EvolutionType::ElectricDischarge { damage: reader.i32()?, chain_count: reader.u32()?, range: reader.f32()? },
+        _ => return Err(ReplayCodeError::UnknownEvolution(tag)),
+    })
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], ReplayCodeError> {
+        let end = self.position + count;
+        let slice = self.bytes.get(self.position..end).ok_or(ReplayCodeError::Truncated)?;
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ReplayCodeError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self) -> Result<u8, ReplayCodeError> { Ok(self.take(1)?[0]) }
+    fn u16(&mut self) -> Result<u16, ReplayCodeError> { Ok(u16::from_le_bytes(self.array()?)) }
+    fn u32(&mut self) -> Result<u32, ReplayCodeError> { Ok(u32::from_le_bytes(self.array()?)) }
+    fn i32(&mut self) -> Result<i32, ReplayCodeError> { Ok(i32::from_le_bytes(self.array()?)) }
+    fn u64(&mut self) -> Result<u64, ReplayCodeError> { Ok(u64::from_le_bytes(self.array()?)) }
+    fn f32(&mut self) -> Result<f32, ReplayCodeError> { Ok(f32::from_bits(self.u32()?)) }
+}
+
+// ===== BASE64 (URL-safe, unpadded) =====
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut code = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk.iter().enumerate().fold(0u32, |acc, (i, byte)| acc | (*byte as u32) << (16 - i * 8));
+        for i in 0..=chunk.len() {
+            code.push(BASE64_ALPHABET[((buffer >> (18 - i * 6)) & 0x3F) as usize] as char);
+        }
+    }
+    code
+}
+
+fn base64_decode(code: &str) -> Result<Vec<u8>, ReplayCodeError> {
+    let mut bytes = Vec::with_capacity(code.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in code.chars() {
+        let value = BASE64_ALPHABET.iter().position(|symbol| *symbol as char == c)
+            .ok_or(ReplayCodeError::InvalidCharacter(c))?;
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Ok(bytes)
+}
+
+// ===== RESOURCES =====
+/// Code passed with `--replay <CODE>`, applied to the first run
+#[derive(Resource, Default)]
+pub struct PendingReplayCode(pub Option<ReplaySetup>);
+
+// ===== SYSTEMS =====
+pub fn read_replay_code_arg(mut pending: ResMut<PendingReplayCode>) {
+    let args: Vec<String> = std::env::args().collect();
+    let Some(code) = args.iter().position(|arg| arg == REPLAY_CODE_ARG).and_then(|index| args.get(index + 1)) else { return };
+
+    match decode_replay_code(code) {
+        Ok(setup) => {
+            info!("Loaded replay code {}", code);
+            pending.0 = Some(setup);
+        }
+        Err(e) => warn!("Ignoring replay code {}: {}", code, e),
+    }
+}
+
+/// Swap in a shared setup before the run modifiers and loadout reach the player
+pub fn apply_replay_code(
+    mut commands: Commands,
+    mut pending: ResMut<PendingReplayCode>,
+    mut environment_seed: ResMut<EnvironmentSeed>,
+    mut spread_rng: ResMut<WeaponSpreadRng>,
+    mut run_modifiers: ResMut<RunModifiers>,
+    mut time_attack: ResMut<TimeAttack>,
+    mut loadouts: ResMut<LoadoutPresets>,
+) {
+    let Some(setup) = pending.0.take() else { return };
+    setup.apply(&mut environment_seed, &mut spread_rng, &mut run_modifiers, &mut time_attack, &mut loadouts);
+    commands.insert_resource(ChemicalEnvironment::from_seed(environment_seed.seed));
+    commands.insert_resource(CurrentGenerator::from_seed(environment_seed.seed));
+}
+
+pub fn log_replay_code(
+    environment_seed: Res<EnvironmentSeed>,
+    spread_rng: Res<WeaponSpreadRng>,
+    run_modifiers: Res<RunModifiers>,
+    time_attack: Res<TimeAttack>,
+    loadouts: Res<LoadoutPresets>,
+) {
+    let setup = ReplaySetup::capture(&environment_seed, &spread_rng, &run_modifiers, &time_attack, &loadouts);
+    info!("Replay code: {}", encode_replay_code(&setup));
+}
+
+// ===== PLUGIN =====
+pub struct ReplayCodePlugin;
+
+impl Plugin for ReplayCodePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PendingReplayCode>()
+            .add_systems(Startup, read_replay_code_arg)
+            .add_systems(OnEnter(GameState::TitleScreen), apply_replay_code)
+            .add_systems(OnExit(GameState::TitleScreen), log_replay_code);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngCore;
+
+    fn shared_setup() -> ReplaySetup {
+        ReplaySetup {
+            environment_seed: 0xDEAD_BEEF_1234_5678,
+            spread_seed: 0x0BAD_5EED,
+            run_modifiers: RunModifier::ALL.iter().step_by(2).copied().collect(),
+            time_attack: true,
+            loadout: Some(LoadoutPreset {
+                name: "Beam rush".to_string(),
+                evolution: EvolutionType::BioluminescentBeam { damage: 40, charge_time: 0.75, duration: 1.5, width: 12.5 },
+                upgrade_plan: vec![
+                    UpgradeTarget { upgrade: PresetUpgrade::ALL[0], level: 3 },
+                    UpgradeTarget { upgrade: PresetUpgrade::ALL[1], level: 1 },
+                ],
+            }),
+        }
+    }
+
+    #[test]
+    fn decoding_an_encoded_setup_returns_it_unchanged() {
+        let setup = shared_setup();
+        assert_eq!(decode_replay_code(&encode_replay_code(&setup)), Ok(setup));
+
+        let bare = ReplaySetup { environment_seed: 7, spread_seed: 9, run_modifiers: Vec::new(), time_attack: false, loadout: None };
+        assert_eq!(decode_replay_code(&encode_replay_code(&bare)), Ok(bare));
+    }
+
+    #[test]
+    fn truncated_codes_are_rejected() {
+        let code = encode_replay_code(&shared_setup());
+        for len in [0, 1, 4, code.len() / 2, code.len() - 3] {
+            assert_eq!(decode_replay_code(&code[..len]), Err(ReplayCodeError::Truncated), "prefix of {} chars", len);
+        }
+    }
+
+    #[test]
+    fn codes_with_a_bad_checksum_are_rejected() {
+        let setup = shared_setup();
+        let mut bytes = base64_decode(&encode_replay_code(&setup)).unwrap();
+        bytes[1] ^= 0x01; // Flip a bit of the pool seed
+        assert_eq!(decode_replay_code(&base64_encode(&bytes)), Err(ReplayCodeError::ChecksumMismatch));
+
+        let mut bytes = base64_decode(&encode_replay_code(&setup)).unwrap();
+        *bytes.last_mut().unwrap() ^= 0xFF;
+        assert_eq!(decode_replay_code(&base64_encode(&bytes)), Err(ReplayCodeError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn applying_a_code_reseeds_spread_and_keeps_saved_presets() {
+        let mut loadouts = LoadoutPresets::default();
+        for i in 0..5 {
+            loadouts.save_preset(LoadoutPreset {
+                name: format!("Saved {}", i),
+                evolution: EvolutionType::CytoplasmicSpray { damage: 10 + i, fire_rate: 0.1 },
+                upgrade_plan: Vec::new(),
+            });
+        }
+        let saved = loadouts.presets.clone();
+        let mut spread_rng = WeaponSpreadRng::default();
+        let setup = shared_setup();
+
+        setup.apply(&mut EnvironmentSeed::default(), &mut spread_rng, &mut RunModifiers::default(), &mut TimeAttack::default(), &mut loadouts);
+        assert_eq!(loadouts.presets, saved);
+        assert_eq!(loadouts.selected_preset(), setup.loadout.as_ref());
+        assert_eq!(spread_rng.seed, setup.spread_seed);
+        assert_eq!(spread_rng.rng.next_u64(), WeaponSpreadRng::from_seed(setup.spread_seed).rng.next_u64());
+    }
+}
@@ -2,8 +2,13 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap,HashSet};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use crate::pause_menu::*;
+use crate::save_versioning::VersionedSave;
 use crate::stage_summary::*;
+use crate::constants::{SPREAD_RNG_SEED, ENVIRONMENT_SEED};
+use crate::graphics_quality::GraphicsQuality;
+use crate::components::EcosystemRoleType;
 
 // ===== FONTS =====
 #[derive(Resource)]
@@ -12,7 +17,7 @@ pub struct GameFonts {
 }
 
 // ===== CORE ASSETS =====
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct GameAssets {
     // Player & General
     pub player_texture: Handle<Image>,
@@ -70,6 +75,8 @@ pub enum GameState {
     TitleScreen,
     Settings,
     HighScores,
+    LoreArchive,
+    Bestiary,
     Playing,
     StageSummary, // New state
     Paused,
@@ -121,6 +128,9 @@ pub struct MenuSettings {
     pub resolution: (f32, f32),
     pub show_fps: bool,
     pub particles_enabled: bool,
+    pub pause_on_focus_loss: bool,
+    pub auto_resume_on_focus: bool,
+    pub pause_on_gamepad_disconnect: bool, // Resumes by itself once a controller reconnects
 }
 
 impl Default for MenuSettings {
@@ -130,10 +140,197 @@ impl Default for MenuSettings {
             resolution: (1280.0, 720.0),
             show_fps: false,
             particles_enabled: true,
+            pause_on_focus_loss: true,
+            auto_resume_on_focus: false,
+            pause_on_gamepad_disconnect: true,
+        }
+    }
+}
+
+// ===== HUD VISIBILITY =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HudElement {
+    Fps,
+    WaveCounter,
+    EcosystemStatus,
+    Multiplier,
+}
+
+impl HudElement {
+    pub const ALL: [HudElement; 4] = [
+        HudElement::Fps,
+        HudElement::WaveCounter,
+        HudElement::EcosystemStatus,
+        HudElement::Multiplier,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            HudElement::Fps => "FPS",
+            HudElement::WaveCounter => "Wave",
+            HudElement::EcosystemStatus => "Ecosystem",
+            HudElement::Multiplier => "Multiplier",
+        }
+    }
+}
+
+/// Which optional HUD widgets are shown, for players who want a cleaner screen
+#[derive(Resource, Clone)]
+pub struct HudVisibility {
+    pub fps: bool,
+    pub wave_counter: bool,
+    pub ecosystem_status: bool,
+    pub multiplier: bool,
+}
+
+impl Default for HudVisibility {
+    fn default() -> Self {
+        Self {
+            fps: true,
+            wave_counter: true,
+            ecosystem_status: true,
+            multiplier: true,
+        }
+    }
+}
+
+impl HudVisibility {
+    fn flag(&mut self, element: HudElement) -> &mut bool {
+        match element {
+            HudElement::Fps => &mut self.fps,
+            HudElement::WaveCounter => &mut self.wave_counter,
+            HudElement::EcosystemStatus => &mut self.ecosystem_status,
+            HudElement::Multiplier => &mut self.multiplier,
+        }
+    }
+
+    pub fn is_visible(&self, element: HudElement) -> bool {
+        match element {
+            HudElement::Fps => self.fps,
+            HudElement::WaveCounter => self.wave_counter,
+            HudElement::EcosystemStatus => self.ecosystem_status,
+            HudElement::Multiplier => self.multiplier,
+        }
+    }
+
+    pub fn toggle(&mut self, element: HudElement) {
+        let flag = self.flag(element);
+        *flag = !*flag;
+    }
+
+    pub fn label(&self) -> String {
+        let hidden: Vec<&str> = HudElement::ALL.iter()
+            .filter(|element| !self.is_visible(**element))
+            .map(HudElement::display_name)
+            .collect();
+        if hidden.is_empty() {
+            "All shown".to_string()
+        } else {
+            format!("Hidden: {}", hidden.join(", "))
+        }
+    }
+}
+
+// ===== INPUT DISPLAY =====
+/// On-screen overlay of the held inputs, for streaming and teaching
+#[derive(Resource, Clone)]
+pub struct InputDisplayConfig {
+    pub enabled: bool,
+    pub active_color: Color,
+    pub idle_color: Color,
+}
+
+impl Default for InputDisplayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            active_color: Color::srgba(0.3, 1.0, 0.6, 0.9),
+            idle_color: Color::srgba(0.1, 0.2, 0.3, 0.6),
+        }
+    }
+}
+
+impl InputDisplayConfig {
+    pub fn indicator_color(&self, pressed: bool) -> Color {
+        if pressed { self.active_color } else { self.idle_color }
+    }
+}
+
+// ===== RESTART CONFIRMATION =====
+/// Restart needs a second press within `window` seconds, so a stray R doesn't end a good run
+#[derive(Resource, Clone)]
+pub struct RestartConfirmation {
+    pub enabled: bool,
+    pub window: f32,
+    pub pending: Option<f32>, // Seconds left to confirm
+}
+
+impl Default for RestartConfirmation {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window: 3.0,
+            pending: None,
         }
     }
 }
 
+impl RestartConfirmation {
+    /// Register a restart press, returns true when the restart should go ahead
+    pub fn request(&mut self) -> bool {
+        if !self.enabled || self.pending.is_some() {
+            self.pending = None;
+            return true;
+        }
+        self.pending = Some(self.window);
+        false
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn label(&self) -> &'static str {
+        if self.enabled { "On" } else { "Off" }
+    }
+
+    /// Let an unconfirmed request lapse
+    pub fn tick(&mut self, dt: f32) {
+        if let Some(remaining) = self.pending {
+            self.pending = (remaining > dt).then_some(remaining - dt);
+        }
+    }
+}
+
+// ===== ACCESSIBILITY =====
+#[derive(Resource, Clone)]
+pub struct AccessibilitySettings {
+    pub photosensitive_safe: bool,
+    pub reduce_motion: bool,
+    pub max_flash_brightness: f32, // Cap on brightness * alpha for safe effects
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            photosensitive_safe: false,
+            reduce_motion: false,
+            max_flash_brightness: 0.6,
+        }
+    }
+}
+
+/// Scale a colour so its brightest channel times alpha stays under `cap`
+pub fn cap_color_brightness(color: Color, cap: f32) -> Color {
+    let srgba = color.to_srgba();
+    let brightness = srgba.red.max(srgba.green).max(srgba.blue) * srgba.alpha;
+    if brightness <= cap || brightness <= 0.0 {
+        return color;
+    }
+    let scale = cap / brightness;
+    Color::srgba(srgba.red * scale, srgba.green * scale, srgba.blue * scale, srgba.alpha)
+}
+
 // ===== PARTICLE SYSTEM =====
 #[derive(Resource)]
 pub struct ParticlePool {
@@ -175,6 +372,7 @@ pub struct FluidEnvironment {
     pub cell_size: f32,        // 20.0 matches your biological systems
     pub tidal_phase: f32,
     pub turbulence_intensity: f32,
+    pub base_turbulence: f32,  // Calm-tide turbulence, raised by WaveEnvironmentCurve
 }
 
 
@@ -186,6 +384,7 @@ impl Default for FluidEnvironment {
            cell_size: 16.0,
            tidal_phase: 0.0, 
            turbulence_intensity: 0.0,
+           base_turbulence: 0.3,
        }
    }
 }
@@ -229,6 +428,7 @@ pub struct ChemicalZone {
     pub center: Vec2,
     pub toxicity: f32,
     pub oxygen_level: f32,    
+    pub lifetime: Option<f32>, // Seconds left on a player-deployed zone, None for permanent ones
 }
 
 #[derive(Clone)]
@@ -237,8 +437,13 @@ pub struct OxygenZone {
     pub radius: f32,
     pub oxygen_level: f32,
     pub depletion_rate: f32,
+    pub lifetime: Option<f32>,
 }
 
+const DEPLOYED_ACIDIC_PH: f32 = 4.0;
+const DEPLOYED_ALKALINE_PH: f32 = 10.0;
+const DEPLOYED_HYPOXIC_OXYGEN: f32 = 0.05;
+
 impl Default for ChemicalEnvironment {
     fn default() -> Self {
         Self {
@@ -251,6 +456,60 @@ impl Default for ChemicalEnvironment {
     }
 }
 
+impl ChemicalEnvironment {
+    /// Add a transient zone at `position` that expires after `duration` seconds
+    pub fn deploy_zone(&mut self, kind: crate::components::DeployedZoneKind, position: Vec2, radius: f32, duration: f32) {
+        use crate::components::DeployedZoneKind;
+
+        let ph_level = match kind {
+            DeployedZoneKind::Acidic => DEPLOYED_ACIDIC_PH,
+            DeployedZoneKind::Alkaline => DEPLOYED_ALKALINE_PH,
+            DeployedZoneKind::Hypoxic => {
+                self.oxygen_zones.push(OxygenZone {
+                    position,
+                    radius,
+                    oxygen_level: DEPLOYED_HYPOXIC_OXYGEN,
+                    depletion_rate: 0.0,
+                    lifetime: Some(duration),
+                });
+                return;
+            }
+        };
+
+        self.ph_zones.push(ChemicalZone {
+            position,
+            radius,
+            ph_level,
+            intensity: 1.0,
+            center: position,
+            toxicity: 1.0,
+            oxygen_level: 0.0,
+            lifetime: Some(duration),
+        });
+    }
+
+    /// Count down deployed zones and drop the expired ones, permanent zones are untouched
+    pub fn tick_deployed_zones(&mut self, dt: f32) {
+        for lifetime in self.ph_zones.iter_mut().filter_map(|zone| zone.lifetime.as_mut())
+            .chain(self.oxygen_zones.iter_mut().filter_map(|zone| zone.lifetime.as_mut()))
+        {
+            *lifetime -= dt;
+        }
+        self.ph_zones.retain(|zone| zone.lifetime.is_none_or(|lifetime| lifetime > 0.0));
+        self.oxygen_zones.retain(|zone| zone.lifetime.is_none_or(|lifetime| lifetime > 0.0));
+    }
+
+    pub fn in_deployed_zone(&self, position: Vec2) -> bool {
+        self.ph_zones.iter().any(|zone| zone.lifetime.is_some() && position.distance(zone.position) < zone.radius)
+            || self.oxygen_zones.iter().any(|zone| zone.lifetime.is_some() && position.distance(zone.position) < zone.radius)
+    }
+
+    pub fn clear_deployed_zones(&mut self) {
+        self.ph_zones.retain(|zone| zone.lifetime.is_none());
+        self.oxygen_zones.retain(|zone| zone.lifetime.is_none());
+    }
+}
+
 // ===== CURRENT GENERATION =====
 #[derive(Resource)]
 pub struct CurrentGenerator {
@@ -309,71 +568,1122 @@ impl Default for CurrentGenerator {
     }
 }
 
-// ===== BIOLUMINESCENCE =====
-#[derive(Resource)]
-pub struct BioluminescenceManager {
-    pub ambient_glow: f32,
-    pub pulse_sources: Vec<BioluminescentSource>,
-    pub player_glow_intensity: f32,
-    pub environment_reactivity: f32,
+// ===== POOL LAYOUT SEED =====
+/// Seed behind a run's pool layout, so the same code always rebuilds the same zones, vents and currents.
+/// The default seed is the hand-placed baseline pool
+#[derive(Resource, Clone, Serialize, Deserialize)]
+pub struct EnvironmentSeed {
+    #[serde(default)]
+    pub version: u32,
+    pub seed: u64,
+    pub randomize_each_run: bool, // Roll a fresh layout on every restart instead of keeping this one
+}
+
+impl Default for EnvironmentSeed {
+    fn default() -> Self {
+        Self {
+            version: Self::CURRENT_VERSION,
+            seed: ENVIRONMENT_SEED,
+            randomize_each_run: false,
+        }
+    }
+}
+
+impl VersionedSave for EnvironmentSeed {
+    const CURRENT_VERSION: u32 = 1;
+    const LABEL: &'static str = "environment seed";
+}
+
+impl EnvironmentSeed {
+    /// Shareable form of the seed
+    pub fn pool_code(&self) -> String {
+        format!("{:016X}", self.seed)
+    }
+
+    /// Seed from a shared pool code, None when the code isn't hex
+    pub fn from_pool_code(code: &str) -> Option<Self> {
+        let seed = u64::from_str_radix(code.trim(), 16).ok()?;
+        Some(Self { seed, ..default() })
+    }
+
+    /// Called as a new run begins
+    pub fn next_run(&mut self) {
+        if self.randomize_each_run {
+            self.seed = rand::random();
+        }
+    }
+}
+
+impl ChemicalEnvironment {
+    /// The original hand-placed pool, used when no pool code was given
+    pub fn baseline() -> Self {
+        let zone = |position: Vec2, radius: f32, ph_level: f32, intensity: f32| ChemicalZone {
+            position,
+            radius,
+            ph_level,
+            intensity,
+            center: Vec2::ZERO,
+            oxygen_level: 0.0,
+            toxicity: intensity,
+            lifetime: None,
+        };
+        Self {
+            ph_zones: vec![
+                zone(Vec2::new(-200.0, 100.0), 150.0, 5.5, 0.8), // Acidic zone
+                zone(Vec2::new(200.0, -100.0), 120.0, 8.5, 0.6), // Alkaline zone
+            ],
+            oxygen_zones: vec![
+                OxygenZone {
+                    position: Vec2::new(0.0, 200.0),
+                    radius: 180.0,
+                    oxygen_level: 0.9,
+                    depletion_rate: 0.1,
+                    lifetime: None,
+                },
+            ],
+            ..default()
+        }
+    }
+
+    /// Starting acidic, alkaline and oxygen-rich zones for a seed
+    pub fn from_seed(seed: u64) -> Self {
+        if seed == ENVIRONMENT_SEED {
+            return Self::baseline();
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut ph_zone = |ph_range: std::ops::Range<f32>, x_range: std::ops::Range<f32>| {
+            let position = Vec2::new(rng.random_range(x_range), rng.random_range(-250.0..250.0));
+            let intensity = rng.random_range(0.5..0.9);
+            ChemicalZone {
+                position,
+                radius: rng.random_range(110.0..170.0),
+                ph_level: rng.random_range(ph_range),
+                intensity,
+                center: Vec2::ZERO,
+                oxygen_level: 0.0,
+                toxicity: intensity,
+                lifetime: None,
+            }
+        };
+        let ph_zones = vec![
+            ph_zone(5.0..6.0, -500.0..-50.0), // Acidic zone on the left
+            ph_zone(8.0..9.0, 50.0..500.0),   // Alkaline zone on the right
+        ];
+
+        Self {
+            ph_zones,
+            oxygen_zones: vec![
+                OxygenZone {
+                    position: Vec2::new(rng.random_range(-300.0..300.0), rng.random_range(100.0..300.0)),
+                    radius: rng.random_range(150.0..210.0),
+                    oxygen_level: rng.random_range(0.8..1.0),
+                    depletion_rate: 0.1,
+                    lifetime: None,
+                },
+            ],
+            base_ph: 7.0,
+            base_oxygen: 0.5,
+            diffusion_rate: 0.1,
+        }
+    }
+}
+
+impl CurrentGenerator {
+    /// Thermal vents, the main current and the noise offset that shapes the starting currents for a seed
+    pub fn from_seed(seed: u64) -> Self {
+        if seed == ENVIRONMENT_SEED {
+            return Self::default();
+        }
+        // Offset so vents don't line up with the chemical zones rolled from the same seed
+        let mut rng = StdRng::seed_from_u64(seed.rotate_left(17) ^ 0x9e37_79b9_7f4a_7c15);
+        let thermal_vents = (0..2)
+            .map(|_| ThermalVent {
+                position: Vec2::new(rng.random_range(-500.0..500.0), rng.random_range(-250.0..250.0)),
+                strength: rng.random_range(130.0..210.0),
+                temperature: rng.random_range(32.0..42.0),
+                active: true,
+            })
+            .collect();
+        let major_currents = vec![
+            MajorCurrent {
+                start_pos: Vec2::new(-600.0, rng.random_range(-300.0..300.0)),
+                end_pos: Vec2::new(600.0, rng.random_range(-300.0..300.0)),
+                strength: rng.random_range(80.0..120.0),
+                width: rng.random_range(120.0..180.0),
+            },
+        ];
+
+        Self {
+            noise_offset: Vec2::new(rng.random_range(0.0..1000.0), rng.random_range(0.0..1000.0)),
+            tidal_cycle: 0.0,
+            thermal_vents,
+            major_currents,
+            update_timer: 0.0,
+        }
+    }
+}
+
+// ===== BIOLUMINESCENCE =====
+#[derive(Resource)]
+pub struct BioluminescenceManager {
+    pub ambient_glow: f32,
+    pub pulse_sources: Vec<BioluminescentSource>,
+    pub player_glow_intensity: f32,
+    pub environment_reactivity: f32,
+}
+
+#[derive(Clone)]
+pub struct BioluminescentSource {
+    pub position: Vec2,
+    pub color: Color,
+    pub intensity: f32,
+    pub pulse_frequency: f32,
+    pub radius: f32,
+    pub organic_variation: f32,
+}
+
+impl Default for BioluminescenceManager {
+    fn default() -> Self {
+        Self {
+            ambient_glow: 0.1,
+            pulse_sources: Vec::new(),
+            player_glow_intensity: 0.8,
+            environment_reactivity: 1.0,
+        }
+    }
+}
+
+// ===== ECOSYSTEM STATE =====
+#[derive(Resource)]
+pub struct EcosystemState {
+    pub health: f32,
+    pub infection_level: f32,
+    pub symbiotic_activity: f32,
+    pub nutrient_density: f32,
+    pub ph_stability: f32,
+    pub oxygen_circulation: f32,
+    pub population_balance: BiomePopulation,
+}
+
+#[derive(Clone)]
+pub struct BiomePopulation {
+    pub beneficial_microbes: u32,
+    pub neutral_organisms: u32,
+    pub pathogenic_threats: u32,
+    pub symbiotic_pairs: u32,
+}
+
+impl Default for EcosystemState {
+    fn default() -> Self {
+        Self {
+            health: 1.0,
+            infection_level: 0.0,
+            symbiotic_activity: 0.5,
+            nutrient_density: 0.7,
+            ph_stability: 0.8,
+            oxygen_circulation: 0.9,
+            population_balance: BiomePopulation {
+                beneficial_microbes: 50,
+                neutral_organisms: 30,
+                pathogenic_threats: 20,
+                symbiotic_pairs: 10,
+            },
+        }
+    }
+}
+
+// ===== ECOSYSTEM COLLAPSE =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollapseTransition {
+    Triggered,
+    Recovered,
+}
+
+/// Endgame climax that kicks in when ecosystem health stays critical for too long
+#[derive(Resource, Clone)]
+pub struct EcosystemCollapse {
+    pub active: bool,
+    pub intensity: f32,          // 0..1, escalates while the collapse continues
+    pub critical_health: f32,
+    pub recovery_health: f32,
+    pub trigger_duration: f32,   // Seconds below critical before collapsing
+    pub recovery_duration: f32,  // Seconds above recovery before clearing
+    pub escalation_rate: f32,
+    pub low_timer: f32,
+    pub recovery_timer: f32,
+}
+
+impl Default for EcosystemCollapse {
+    fn default() -> Self {
+        Self {
+            active: false,
+            intensity: 0.0,
+            critical_health: 0.35,
+            recovery_health: 0.55,
+            trigger_duration: 6.0,
+            recovery_duration: 3.0,
+            escalation_rate: 0.08,
+            low_timer: 0.0,
+            recovery_timer: 0.0,
+        }
+    }
+}
+
+impl EcosystemCollapse {
+    pub fn update(&mut self, health: f32, dt: f32) -> Option<CollapseTransition> {
+        if !self.active {
+            self.low_timer = if health < self.critical_health { self.low_timer + dt } else { 0.0 };
+            if self.low_timer >= self.trigger_duration {
+                self.active = true;
+                self.low_timer = 0.0;
+                self.recovery_timer = 0.0;
+                self.intensity = 0.0;
+                return Some(CollapseTransition::Triggered);
+            }
+            return None;
+        }
+
+        self.intensity = (self.intensity + self.escalation_rate * dt).min(1.0);
+        self.recovery_timer = if health >= self.recovery_health { self.recovery_timer + dt } else { 0.0 };
+        if self.recovery_timer >= self.recovery_duration {
+            self.active = false;
+            self.intensity = 0.0;
+            self.recovery_timer = 0.0;
+            return Some(CollapseTransition::Recovered);
+        }
+        None
+    }
+
+    pub fn enemy_health_multiplier(&self) -> f32 {
+        if self.active { 1.0 + self.intensity * 0.5 } else { 1.0 }
+    }
+
+    /// Applied on top of the tidal turbulence each frame rather than folded into it
+    pub fn turbulence_multiplier(&self) -> f32 {
+        if self.active { 1.3 + self.intensity * 0.5 } else { 1.0 }
+    }
+}
+
+// ===== ECOSYSTEM ATP TRICKLE =====
+/// Passive ATP income that rewards keeping the pool healthy
+#[derive(Resource, Clone)]
+pub struct EcosystemAtpTrickle {
+    pub enabled: bool,
+    pub interval: f32,
+    pub max_atp_per_interval: u32, // Paid out at full ecosystem health
+    pub min_health: f32,           // No trickle at or below this health
+    pub timer: f32,
+}
+
+impl Default for EcosystemAtpTrickle {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval: 5.0,
+            max_atp_per_interval: 10,
+            min_health: 0.4,
+            timer: 0.0,
+        }
+    }
+}
+
+impl EcosystemAtpTrickle {
+    /// ATP granted for one interval, scaling linearly from nothing at `min_health` to the max at full health
+    pub fn atp_for_health(&self, health: f32) -> u32 {
+        let scale = ((health - self.min_health) / (1.0 - self.min_health)).clamp(0.0, 1.0);
+        (self.max_atp_per_interval as f32 * scale).round() as u32
+    }
+
+    /// Advance the interval timer, returning the payout when an interval completes
+    pub fn tick(&mut self, health: f32, dt: f32) -> Option<u32> {
+        if !self.enabled { return None; }
+        self.timer += dt;
+        if self.timer < self.interval { return None; }
+        self.timer -= self.interval;
+        Some(self.atp_for_health(health)).filter(|amount| *amount > 0)
+    }
+}
+
+// ===== CORPSE ATP ABSORPTION =====
+/// Living enemies can swallow ATP left by the dead, growing tougher unless the player gets there first
+#[derive(Resource, Clone)]
+pub struct CorpseAbsorption {
+    pub enabled: bool,
+    pub range: f32,            // Enemies start drawing in loose ATP from this far away
+    pub pull_speed: f32,
+    pub health_per_atp: i32,
+    pub speed_per_atp: f32,    // Fractional speed gain per ATP absorbed
+    pub max_absorbed: u32,     // An enemy stops feeding once it has eaten this much
+}
+
+impl Default for CorpseAbsorption {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            range: 120.0,
+            pull_speed: 90.0,
+            health_per_atp: 4,
+            speed_per_atp: 0.03,
+            max_absorbed: 20,
+        }
+    }
+}
+
+impl CorpseAbsorption {
+    /// An enemy claims a pickup when it is in range and closer to it than the player
+    pub fn claims(&self, atp_pos: Vec2, enemy_pos: Vec2, player_pos: Option<Vec2>) -> bool {
+        let enemy_distance = atp_pos.distance(enemy_pos);
+        enemy_distance <= self.range
+            && player_pos.is_none_or(|player_pos| enemy_distance < atp_pos.distance(player_pos))
+    }
+
+    /// Health gained and speed multiplier for eating `amount` ATP, limited by what's left of the cap
+    pub fn buff(&self, amount: u32, already_absorbed: u32) -> (i32, f32, u32) {
+        let eaten = amount.min(self.max_absorbed.saturating_sub(already_absorbed));
+        (eaten as i32 * self.health_per_atp, 1.0 + eaten as f32 * self.speed_per_atp, eaten)
+    }
+}
+
+// ===== WAVE CLEAR VACUUM =====
+/// Pulls every loose pickup to the player right after a wave is cleared
+#[derive(Resource, Clone)]
+pub struct WaveClearVacuum {
+    pub enabled: bool,
+    pub duration: f32,
+    pub base_speed: f32,
+    pub acceleration: f32, // Pickups speed up the longer the pass runs so stragglers still arrive in time
+    pub elapsed: Option<f32>,
+}
+
+impl Default for WaveClearVacuum {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration: 1.5,
+            base_speed: 500.0,
+            acceleration: 1200.0,
+            elapsed: None,
+        }
+    }
+}
+
+impl WaveClearVacuum {
+    pub fn trigger(&mut self) {
+        if self.enabled {
+            self.elapsed = Some(0.0);
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.elapsed.is_some()
+    }
+
+    /// Advance the pass, returning the current pull speed or None once it has finished
+    pub fn tick(&mut self, dt: f32) -> Option<f32> {
+        let elapsed = self.elapsed.as_mut()?;
+        *elapsed += dt;
+        if *elapsed >= self.duration {
+            self.elapsed = None;
+            return None;
+        }
+        Some(self.base_speed + self.acceleration * *elapsed)
+    }
+
+    /// Move a pickup toward the player without overshooting
+    pub fn pull(from: Vec3, to: Vec3, speed: f32, dt: f32) -> Vec3 {
+        let offset = (to - from).truncate();
+        let step = speed * dt;
+        if offset.length() <= step {
+            to.truncate().extend(from.z)
+        } else {
+            from + (offset.normalize() * step).extend(0.0)
+        }
+    }
+}
+
+// ===== PLAYER TRAIL INTENSITY =====
+/// Scales the bioluminescent wake with how fast the player cell is moving
+#[derive(Resource, Clone)]
+pub struct TrailIntensityConfig {
+    pub enabled: bool,
+    pub full_speed: f32,      // Velocity at which the trail reaches max_intensity
+    pub min_intensity: f32,   // Faint wake left while drifting in place
+    pub max_intensity: f32,
+    pub slow_interval: f32,   // Seconds between trail segments when stationary
+    pub fast_interval: f32,   // Seconds between trail segments at full speed
+}
+
+impl Default for TrailIntensityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            full_speed: 400.0,
+            min_intensity: 0.2,
+            max_intensity: 1.4,
+            slow_interval: 0.08,
+            fast_interval: 0.03,
+        }
+    }
+}
+
+impl TrailIntensityConfig {
+    fn speed_ratio(&self, speed: f32) -> f32 {
+        (speed / self.full_speed.max(1.0)).clamp(0.0, 1.0)
+    }
+
+    /// Alpha and width multiplier for trail segments
+    pub fn intensity(&self, speed: f32) -> f32 {
+        self.min_intensity + (self.max_intensity - self.min_intensity) * self.speed_ratio(speed)
+    }
+
+    /// Time until the next trail segment, faster movement lays segments down more often
+    pub fn spawn_interval(&self, speed: f32) -> f32 {
+        self.slow_interval + (self.fast_interval - self.slow_interval) * self.speed_ratio(speed)
+    }
+}
+
+// ===== ENEMY KNOCKBACK =====
+/// Player shots push enemies along the projectile's path, harder hits push further
+#[derive(Resource, Clone)]
+pub struct KnockbackConfig {
+    pub enabled: bool,
+    pub default_strength: f32, // Used by projectiles without a Knockback override
+    pub per_damage: f32,       // Pixels of displacement per point of damage at strength 1.0
+    pub max_distance: f32,     // Cap so big crits don't launch enemies off screen
+}
+
+impl Default for KnockbackConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default_strength: 1.0,
+            per_damage: 0.4,
+            max_distance: 18.0,
+        }
+    }
+}
+
+impl KnockbackConfig {
+    /// Offset applied to an enemy hit by a projectile travelling along `velocity`
+    pub fn displacement(&self, velocity: Vec2, damage: i32, strength: f32) -> Vec2 {
+        if !self.enabled || strength <= 0.0 || damage <= 0 {
+            return Vec2::ZERO;
+        }
+        let distance = (damage as f32 * self.per_damage * strength).min(self.max_distance);
+        velocity.normalize_or_zero() * distance
+    }
+}
+
+// ===== STATUS RESISTANCE =====
+/// Fraction of knockback and freeze each ecosystem rank shrugs off, apex predators resist the most
+#[derive(Resource, Clone)]
+pub struct StatusResistanceConfig {
+    pub enabled: bool,
+    pub apex: f32,
+    pub primary: f32,
+    pub secondary: f32,
+    pub decomposer: f32,
+    pub symbiont: f32,
+}
+
+impl Default for StatusResistanceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            apex: 0.6,
+            primary: 0.3,
+            secondary: 0.15,
+            decomposer: 0.0,
+            symbiont: 0.1,
+        }
+    }
+}
+
+impl StatusResistanceConfig {
+    pub fn resistance(&self, role: &EcosystemRoleType) -> f32 {
+        if !self.enabled { return 0.0; }
+        let resistance = match role {
+            EcosystemRoleType::Apex => self.apex,
+            EcosystemRoleType::Primary => self.primary,
+            EcosystemRoleType::Secondary => self.secondary,
+            EcosystemRoleType::Decomposer => self.decomposer,
+            EcosystemRoleType::Symbiont => self.symbiont,
+        };
+        resistance.clamp(0.0, 1.0)
+    }
+
+    /// Duration or magnitude of a status after the target's resistance is applied
+    pub fn scale(&self, role: &EcosystemRoleType, amount: f32) -> f32 {
+        amount * (1.0 - self.resistance(role))
+    }
+}
+
+// ===== LEADER HIGHLIGHT =====
+/// Pulsing glow on colony leaders so players can pick out the priority target
+#[derive(Resource, Clone)]
+pub struct LeaderHighlightConfig {
+    pub enabled: bool,
+    pub color: Color,
+    pub size: f32,
+    pub pulse_speed: f32,
+    pub min_alpha: f32,
+    pub max_alpha: f32,
+}
+
+impl Default for LeaderHighlightConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            color: Color::srgb(1.0, 0.85, 0.3),
+            size: 56.0,
+            pulse_speed: 2.5,
+            min_alpha: 0.15,
+            max_alpha: 0.4, // Kept subtle so it doesn't compete with projectiles
+        }
+    }
+}
+
+impl LeaderHighlightConfig {
+    pub fn pulse_alpha(&self, elapsed: f32) -> f32 {
+        let wave = (elapsed * self.pulse_speed).sin() * 0.5 + 0.5;
+        self.min_alpha + (self.max_alpha - self.min_alpha) * wave
+    }
+}
+
+// ===== HIT-STOP =====
+/// Heavy hits pause the struck enemy for a few frames to sell the impact
+#[derive(Resource, Clone)]
+pub struct HitStopConfig {
+    pub enabled: bool,
+    pub heavy_hit_damage: i32,        // Hits below this don't freeze
+    pub min_frames: u32,
+    pub max_frames: u32,
+    pub damage_per_extra_frame: i32,  // Damage above the threshold needed for each extra frame
+    pub freeze_projectile: bool,      // Also pause a piercing shot that carries on
+}
+
+impl Default for HitStopConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            heavy_hit_damage: 30,
+            min_frames: 2,
+            max_frames: 6,
+            damage_per_extra_frame: 20,
+            freeze_projectile: false,
+        }
+    }
+}
+
+impl HitStopConfig {
+    pub fn frames_for(&self, damage: i32) -> Option<u32> {
+        if !self.enabled || damage < self.heavy_hit_damage { return None; }
+        let extra = (damage - self.heavy_hit_damage) / self.damage_per_extra_frame.max(1);
+        Some((self.min_frames + extra as u32).min(self.max_frames))
+    }
+}
+
+// ===== BOSS WAVE TELEGRAPH =====
+/// Tints the wave counter and adds a warning when the upcoming wave has a boss
+#[derive(Resource, Clone)]
+pub struct BossWaveTelegraphConfig {
+    pub enabled: bool,
+    pub color: Color,
+    pub label: String,
+}
+
+impl Default for BossWaveTelegraphConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            color: Color::srgb(1.0, 0.35, 0.3),
+            label: "BOSS INCOMING".to_string(),
+        }
+    }
+}
+
+impl BossWaveTelegraphConfig {
+    /// Counter color and warning suffix, None when no telegraph should show
+    pub fn styling(&self, upcoming_is_boss: bool) -> Option<(Color, &str)> {
+        (self.enabled && upcoming_is_boss).then_some((self.color, self.label.as_str()))
+    }
+}
+
+// ===== SCORE POPUPS =====
+/// Floating "+points" text on kills, growing and warming as the score multiplier climbs
+#[derive(Resource, Clone)]
+pub struct ScorePopupConfig {
+    pub enabled: bool,
+    pub base_font_size: f32,
+    pub max_font_size: f32,
+    pub max_multiplier: f32,    // Multiplier at which popups reach max size and hot_color
+    pub cool_color: Color,      // 1x
+    pub hot_color: Color,
+}
+
+impl Default for ScorePopupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            base_font_size: 12.0,
+            max_font_size: 26.0,
+            max_multiplier: 4.0,
+            cool_color: Color::srgb(0.7, 0.95, 1.0),
+            hot_color: Color::srgb(1.0, 0.35, 0.1),
+        }
+    }
+}
+
+impl ScorePopupConfig {
+    /// Font size and color for a popup at the given multiplier
+    pub fn style(&self, multiplier: f32) -> (f32, Color) {
+        let heat = ((multiplier.max(1.0) - 1.0) / (self.max_multiplier - 1.0).max(0.01)).clamp(0.0, 1.0);
+        let size = self.base_font_size + (self.max_font_size - self.base_font_size) * heat;
+        let (cool, hot) = (self.cool_color.to_srgba(), self.hot_color.to_srgba());
+        let color = Color::srgb(
+            cool.red + (hot.red - cool.red) * heat,
+            cool.green + (hot.green - cool.green) * heat,
+            cool.blue + (hot.blue - cool.blue) * heat,
+        );
+        (size, color)
+    }
+}
+
+// ===== STEALTH =====
+/// Cloak ability, hides the player from chemotaxis hunters for a few seconds
+#[derive(Resource, Clone)]
+pub struct StealthAbility {
+    pub enabled: bool,
+    pub duration: f32,
+    pub cooldown: f32,
+    pub cooldown_remaining: f32,
+    pub alpha: f32, // Player sprite alpha while cloaked
+}
+
+impl Default for StealthAbility {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration: 4.0,
+            cooldown: 12.0,
+            cooldown_remaining: 0.0,
+            alpha: 0.35,
+        }
+    }
+}
+
+impl StealthAbility {
+    pub fn ready(&self) -> bool {
+        self.enabled && self.cooldown_remaining <= 0.0
+    }
+
+    /// Start the cooldown and return the cloak duration, None while cooling down
+    pub fn activate(&mut self) -> Option<f32> {
+        if !self.ready() { return None; }
+        self.cooldown_remaining = self.cooldown;
+        Some(self.duration)
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        self.cooldown_remaining = (self.cooldown_remaining - dt).max(0.0);
+    }
+
+    /// Position chemotaxis hunters may track, hidden while the player is cloaked
+    pub fn trackable_position(player_pos: Option<Vec2>, stealthed: bool) -> Option<Vec2> {
+        player_pos.filter(|_| !stealthed)
+    }
+}
+
+// ===== DAMAGE CAP =====
+/// Per-hit damage limit for enemies flagged with DamageCap, so bosses can't be one-shot
+#[derive(Resource, Clone)]
+pub struct DamageCapConfig {
+    pub enabled: bool,
+    pub max_damage: Option<i32>,            // Absolute ceiling per hit
+    pub max_health_fraction: Option<f32>,   // Ceiling as a share of the enemy's max health
+}
+
+impl Default for DamageCapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_damage: None,
+            max_health_fraction: Some(0.2),
+        }
+    }
+}
+
+impl DamageCapConfig {
+    pub fn cap(&self, damage: i32, max_health: i32) -> i32 {
+        if !self.enabled { return damage; }
+        let mut limit = i32::MAX;
+        if let Some(max_damage) = self.max_damage {
+            limit = limit.min(max_damage);
+        }
+        if let Some(fraction) = self.max_health_fraction {
+            limit = limit.min(((max_health as f32 * fraction) as i32).max(1));
+        }
+        damage.min(limit)
+    }
+}
+
+// ===== WAVE ENVIRONMENT CURVE =====
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnvironmentKey {
+    pub wave: u32,
+    pub base_ph: f32,
+    pub base_oxygen: f32,
+    pub turbulence: f32,
+}
+
+/// Environmental conditions keyed by wave number, later waves play out in harsher water.
+/// Values between keys are interpolated linearly and hold at the last key
+#[derive(Resource, Clone)]
+pub struct WaveEnvironmentCurve {
+    pub enabled: bool,
+    pub keys: Vec<EnvironmentKey>, // Sorted by wave
+}
+
+impl Default for WaveEnvironmentCurve {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            keys: vec![
+                EnvironmentKey { wave: 1, base_ph: 7.0, base_oxygen: 0.5, turbulence: 0.3 },
+                EnvironmentKey { wave: 10, base_ph: 6.4, base_oxygen: 0.4, turbulence: 0.45 },
+                EnvironmentKey { wave: 25, base_ph: 5.6, base_oxygen: 0.3, turbulence: 0.65 },
+            ],
+        }
+    }
+}
+
+impl WaveEnvironmentCurve {
+    pub fn sample(&self, wave: u32) -> Option<EnvironmentKey> {
+        let first = self.keys.first()?;
+        if wave <= first.wave { return Some(EnvironmentKey { wave, ..*first }); }
+
+        for pair in self.keys.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if wave <= b.wave {
+                let t = (wave - a.wave) as f32 / (b.wave - a.wave).max(1) as f32;
+                return Some(EnvironmentKey {
+                    wave,
+                    base_ph: a.base_ph.lerp(b.base_ph, t),
+                    base_oxygen: a.base_oxygen.lerp(b.base_oxygen, t),
+                    turbulence: a.turbulence.lerp(b.turbulence, t),
+                });
+            }
+        }
+
+        self.keys.last().map(|last| EnvironmentKey { wave, ..*last })
+    }
+
+    /// Push the sampled conditions for `wave` into the live environment
+    pub fn apply(&self, wave: u32, chemical: &mut ChemicalEnvironment, fluid: &mut FluidEnvironment) {
+        if !self.enabled { return; }
+        let Some(key) = self.sample(wave) else { return };
+        chemical.base_ph = key.base_ph;
+        chemical.base_oxygen = key.base_oxygen;
+        fluid.base_turbulence = key.turbulence;
+        fluid.turbulence_intensity = key.turbulence;
+    }
+}
+
+// ===== BOSS LOOT =====
+#[derive(Clone, Debug)]
+pub enum BossReward {
+    FreeEvolution(crate::components::EvolutionType), // Permanent evolution pickup, no ATP cost
+    AtpPayout(u32),                                   // Single large ATP drop
+}
+
+/// Guaranteed rewards dropped by bosses on death, on top of their normal loot
+#[derive(Resource, Clone)]
+pub struct BossLootConfig {
+    pub enabled: bool,
+    pub rewards: Vec<(crate::enemy_types::EnemyType, Vec<BossReward>)>,
+}
+
+impl Default for BossLootConfig {
+    fn default() -> Self {
+        use crate::components::EvolutionType;
+        use crate::enemy_types::EnemyType;
+
+        Self {
+            enabled: true,
+            rewards: vec![
+                (EnemyType::InfectedMacrophage, vec![
+                    BossReward::FreeEvolution(EvolutionType::SymbioticHunters {
+                        damage: 25, fire_rate: 0.8, homing_strength: 2.0, blast_radius: 50.0,
+                    }),
+                    BossReward::AtpPayout(60),
+                ]),
+            ],
+        }
+    }
+}
+
+impl BossLootConfig {
+    pub fn rewards_for(&self, enemy_type: &crate::enemy_types::EnemyType) -> &[BossReward] {
+        if !self.enabled { return &[]; }
+        self.rewards.iter()
+            .find(|(boss, _)| boss == enemy_type)
+            .map(|(_, rewards)| rewards.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+// ===== DROP HOMING =====
+/// Dropped ATP and power-ups drift toward the player after a delay, independent of the magnet
+#[derive(Resource, Clone)]
+pub struct DropHomingConfig {
+    pub enabled: bool,
+    pub delay: f32,         // Seconds before a drop starts homing
+    pub ramp_time: f32,     // Seconds to reach full strength once homing
+    pub strength: f32,      // Pixels per second at full strength
+}
+
+impl Default for DropHomingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            delay: 1.5,
+            ramp_time: 2.0,
+            strength: 60.0,
+        }
+    }
+}
+
+impl DropHomingConfig {
+    /// Displacement this frame for a drop of the given age
+    pub fn pull(&self, drop_pos: Vec2, player_pos: Vec2, age: f32, dt: f32) -> Vec2 {
+        if !self.enabled || age < self.delay { return Vec2::ZERO; }
+        let ramp = ((age - self.delay) / self.ramp_time.max(0.01)).min(1.0);
+        (player_pos - drop_pos).normalize_or_zero() * self.strength * ramp * dt
+    }
+}
+
+// ===== HAZARD WARNINGS =====
+/// Environmental danger at the player's position, in the order of the HUD indicator states
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HazardWarning {
+    None,
+    Acidic,
+    Alkaline,
+    LowOxygen,
+    Overheating,
+}
+
+impl HazardWarning {
+    pub const ALL: [HazardWarning; 5] = [
+        HazardWarning::None,
+        HazardWarning::Acidic,
+        HazardWarning::Alkaline,
+        HazardWarning::LowOxygen,
+        HazardWarning::Overheating,
+    ];
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            HazardWarning::None => "",
+            HazardWarning::Acidic => "Acidic water!",
+            HazardWarning::Alkaline => "Alkaline water!",
+            HazardWarning::LowOxygen => "Low oxygen!",
+            HazardWarning::Overheating => "Overheating!",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            HazardWarning::None => Color::NONE,
+            HazardWarning::Acidic => Color::srgb(1.0, 0.9, 0.2),
+            HazardWarning::Alkaline => Color::srgb(0.6, 0.5, 1.0),
+            HazardWarning::LowOxygen => Color::srgb(0.4, 0.8, 1.0),
+            HazardWarning::Overheating => Color::srgb(1.0, 0.4, 0.2),
+        }
+    }
+
+    /// Index into the status indicator built from `ALL`
+    pub fn state_index(&self) -> usize {
+        Self::ALL.iter().position(|warning| warning == self).unwrap_or(0)
+    }
+
+    /// Worst hazard for the sampled conditions, heat first since vents hurt the fastest
+    pub fn evaluate(ph: f32, oxygen: f32, heat_intensity: f32, sensitivity: &crate::components::ChemicalSensitivity, config: &HazardWarningConfig) -> Self {
+        if heat_intensity > config.overheat_intensity {
+            HazardWarning::Overheating
+        } else if ph < sensitivity.ph_tolerance_min {
+            HazardWarning::Acidic
+        } else if ph > sensitivity.ph_tolerance_max {
+            HazardWarning::Alkaline
+        } else if oxygen < sensitivity.oxygen_requirement {
+            HazardWarning::LowOxygen
+        } else {
+            HazardWarning::None
+        }
+    }
+}
+
+#[derive(Resource, Clone)]
+pub struct HazardWarningConfig {
+    pub enabled: bool,
+    pub overheat_intensity: f32, // Vent heat (0.0 to 1.0) where vents start to burn
+    pub transition: f32,         // Seconds to cross-fade between warnings
+}
+
+impl Default for HazardWarningConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            overheat_intensity: 0.7,
+            transition: 0.25,
+        }
+    }
+}
+
+// ===== ENEMY BERSERK =====
+/// Pack enemies that enrage when an ally dies close by, rewarding careful kill order
+#[derive(Resource, Clone)]
+pub struct BerserkConfig {
+    pub enabled: bool,
+    pub radius: f32,
+    pub duration: f32,
+    pub speed_multiplier: f32,
+    pub damage_multiplier: f32,
+    pub capable: Vec<crate::enemy_types::EnemyType>,
+}
+
+impl Default for BerserkConfig {
+    fn default() -> Self {
+        use crate::enemy_types::EnemyType;
+        Self {
+            enabled: false,
+            radius: 160.0,
+            duration: 4.0,
+            speed_multiplier: 1.5,
+            damage_multiplier: 1.5,
+            capable: vec![EnemyType::AggressiveBacteria, EnemyType::SwarmCell],
+        }
+    }
+}
+
+impl BerserkConfig {
+    /// Buff for an ally at `ally` when an enemy dies at `death`, None if out of range or not capable
+    pub fn buff_for(&self, enemy_type: &crate::enemy_types::EnemyType, ally: Vec2, death: Vec2) -> Option<crate::components::Berserk> {
+        if !self.enabled || !self.capable.contains(enemy_type) { return None; }
+        if ally.distance_squared(death) > self.radius * self.radius { return None; }
+
+        Some(crate::components::Berserk {
+            remaining: self.duration,
+            speed_multiplier: self.speed_multiplier,
+            damage_multiplier: self.damage_multiplier,
+        })
+    }
 }
 
-#[derive(Clone)]
-pub struct BioluminescentSource {
-    pub position: Vec2,
-    pub color: Color,
-    pub intensity: f32,
-    pub pulse_frequency: f32,
-    pub radius: f32,
-    pub organic_variation: f32,
+// ===== SPLIT DISPERSAL =====
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitDispersal {
+    Sideways,        // Offspring side by side, both drifting down the screen
+    AwayFromPlayer,  // Offspring fan out away from the player
+    Random,          // Offspring fly apart along a random axis
 }
 
-impl Default for BioluminescenceManager {
+/// How cell division launches its two offspring, read at the moment of the split
+#[derive(Resource, Clone)]
+pub struct SplitDispersalConfig {
+    pub pattern: SplitDispersal,
+    pub offset: f32,        // Distance from the parent each offspring appears at
+    pub fan_angle: f32,     // Radians either side of the away vector for AwayFromPlayer
+}
+
+impl Default for SplitDispersalConfig {
     fn default() -> Self {
         Self {
-            ambient_glow: 0.1,
-            pulse_sources: Vec::new(),
-            player_glow_intensity: 0.8,
-            environment_reactivity: 1.0,
+            pattern: SplitDispersal::Sideways,
+            offset: 30.0,
+            fan_angle: 0.5,
         }
     }
 }
 
-// ===== ECOSYSTEM STATE =====
-#[derive(Resource)]
-pub struct EcosystemState {
-    pub health: f32,
-    pub infection_level: f32,
-    pub symbiotic_activity: f32,
-    pub nutrient_density: f32,
-    pub ph_stability: f32,
-    pub oxygen_circulation: f32,
-    pub population_balance: BiomePopulation,
+impl SplitDispersalConfig {
+    /// Spawn offset and travel direction for each offspring. `random_angle` is only
+    /// used by the Random pattern, and AwayFromPlayer falls back to Sideways with no player
+    pub fn offspring(&self, parent: Vec2, player: Option<Vec2>, random_angle: f32) -> [(Vec2, Vec2); 2] {
+        let sideways = [
+            (Vec2::new(-self.offset, 0.0), Vec2::NEG_Y),
+            (Vec2::new(self.offset, 0.0), Vec2::NEG_Y),
+        ];
+
+        match self.pattern {
+            SplitDispersal::Sideways => sideways,
+            SplitDispersal::AwayFromPlayer => {
+                let Some(away) = player.and_then(|p| (parent - p).try_normalize()) else { return sideways };
+                let left = Vec2::from_angle(self.fan_angle).rotate(away);
+                let right = Vec2::from_angle(-self.fan_angle).rotate(away);
+                [(left * self.offset, left), (right * self.offset, right)]
+            }
+            SplitDispersal::Random => {
+                let axis = Vec2::from_angle(random_angle);
+                [(axis * self.offset, axis), (-axis * self.offset, -axis)]
+            }
+        }
+    }
 }
 
-#[derive(Clone)]
-pub struct BiomePopulation {
-    pub beneficial_microbes: u32,
-    pub neutral_organisms: u32,
-    pub pathogenic_threats: u32,
-    pub symbiotic_pairs: u32,
+// ===== EVOLUTION CHAMBER COST SCALING =====
+/// Each repeat purchase of a chamber upgrade costs growth_factor times the previous one
+#[derive(Resource, Clone)]
+pub struct CostConfig {
+    pub enabled: bool,
+    pub growth_factor: f32,
 }
 
-impl Default for EcosystemState {
+impl Default for CostConfig {
     fn default() -> Self {
         Self {
-            health: 1.0,
-            infection_level: 0.0,
-            symbiotic_activity: 0.5,
-            nutrient_density: 0.7,
-            ph_stability: 0.8,
-            oxygen_circulation: 0.9,
-            population_balance: BiomePopulation {
-                beneficial_microbes: 50,
-                neutral_organisms: 30,
-                pathogenic_threats: 20,
-                symbiotic_pairs: 10,
-            },
+            enabled: true,
+            growth_factor: 1.35,
+        }
+    }
+}
+
+impl CostConfig {
+    /// Price of the next purchase given how many times this upgrade was already bought
+    pub fn scaled_cost(&self, base: u32, times_purchased: u32) -> u32 {
+        if !self.enabled || times_purchased == 0 {
+            return base;
+        }
+        (base as f32 * self.growth_factor.max(1.0).powi(times_purchased as i32)).round() as u32
+    }
+}
+
+// ===== ATP COST HINTS =====
+/// Evolution chamber readout of how long until each option is affordable at the current ATP income
+#[derive(Resource, Clone)]
+pub struct AtpCostHintConfig {
+    pub enabled: bool,
+}
+
+impl Default for AtpCostHintConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl AtpCostHintConfig {
+    /// Seconds until `cost` is affordable, zero when it already is, None without any income to project from
+    pub fn time_to_afford(cost: u32, atp: u32, generation_rate: f32) -> Option<f32> {
+        if atp >= cost { return Some(0.0); }
+        if generation_rate <= 0.0 { return None; }
+        Some((cost - atp) as f32 / generation_rate)
+    }
+
+    pub fn label(cost: u32, atp: u32, generation_rate: f32) -> String {
+        match Self::time_to_afford(cost, atp, generation_rate) {
+            Some(seconds) if seconds <= 0.0 => "ready".to_string(),
+            Some(seconds) => format!("~{:.0}s", seconds.ceil()),
+            None => "no income".to_string(),
         }
     }
 }
@@ -412,6 +1722,95 @@ impl Default for TidalPoolPhysics {
     }
 }
 
+// ===== KING TIDE CONTROL DISTORTION =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistortionMode {
+    Invert,  // Input direction is reversed
+    Dampen,  // Input is scaled down heavily
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistortionPhase {
+    Idle,
+    Warning { timer: f32 },
+    Active { timer: f32 },
+    Spent, // Already fired this king tide
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistortionTransition {
+    WarningStarted,
+    Started,
+    Ended,
+}
+
+/// Transient king tide hazard where the current scrambles player input, always telegraphed first
+#[derive(Resource, Clone)]
+pub struct ControlDistortion {
+    pub enabled: bool,
+    pub mode: DistortionMode,
+    pub damping: f32,        // Input scale while dampened
+    pub warning_time: f32,
+    pub duration: f32,
+    pub phase: DistortionPhase,
+}
+
+impl Default for ControlDistortion {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            mode: DistortionMode::Invert,
+            damping: 0.3,
+            warning_time: 1.5,
+            duration: 4.0,
+            phase: DistortionPhase::Idle,
+        }
+    }
+}
+
+impl ControlDistortion {
+    pub fn is_active(&self) -> bool {
+        matches!(self.phase, DistortionPhase::Active { .. })
+    }
+
+    /// Fires once per king tide: warn, distort, then stay spent until the tide ends
+    pub fn update(&mut self, king_tide_active: bool, dt: f32) -> Option<DistortionTransition> {
+        if !king_tide_active || !self.enabled {
+            let was_active = self.is_active();
+            self.phase = DistortionPhase::Idle;
+            return was_active.then_some(DistortionTransition::Ended);
+        }
+
+        match &mut self.phase {
+            DistortionPhase::Idle => {
+                self.phase = DistortionPhase::Warning { timer: self.warning_time };
+                Some(DistortionTransition::WarningStarted)
+            }
+            DistortionPhase::Warning { timer } => {
+                *timer -= dt;
+                if *timer > 0.0 { return None; }
+                self.phase = DistortionPhase::Active { timer: self.duration };
+                Some(DistortionTransition::Started)
+            }
+            DistortionPhase::Active { timer } => {
+                *timer -= dt;
+                if *timer > 0.0 { return None; }
+                self.phase = DistortionPhase::Spent;
+                Some(DistortionTransition::Ended)
+            }
+            DistortionPhase::Spent => None,
+        }
+    }
+
+    pub fn apply(&self, movement: Vec2) -> Vec2 {
+        if !self.is_active() { return movement; }
+        match self.mode {
+            DistortionMode::Invert => -movement,
+            DistortionMode::Dampen => movement * self.damping,
+        }
+    }
+}
+
 // ===== TIDAL STATE =====
 use crate::components::TidePhase;
 
@@ -454,6 +1853,312 @@ impl Default for ScreenShakeResource {
     }
 }
 
+// ===== COMBO TRACKING =====
+#[derive(Resource, Clone)]
+pub struct ComboState {
+    pub count: u32,
+    pub timer: f32,
+    pub window: f32,            // Seconds allowed between kills before the combo drops
+    pub weapon_buff_threshold: u32,
+    pub best: u32,
+}
+
+impl Default for ComboState {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            timer: 0.0,
+            window: 2.5,
+            weapon_buff_threshold: 15,
+            best: 0,
+        }
+    }
+}
+
+impl ComboState {
+    pub fn register_kill(&mut self) {
+        self.count += 1;
+        self.timer = self.window;
+        self.best = self.best.max(self.count);
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        if self.count == 0 { return; }
+        self.timer -= dt;
+        if self.timer <= 0.0 {
+            self.count = 0;
+            self.timer = 0.0;
+        }
+    }
+
+    pub fn weapon_buff_active(&self) -> bool {
+        self.count >= self.weapon_buff_threshold
+    }
+}
+
+// ===== WEAPON SPREAD =====
+/// Seeded RNG for projectile spread so volleys are reproducible run to run
+#[derive(Resource)]
+pub struct WeaponSpreadRng {
+    pub seed: u64, // Kept so a run, or a replay of it, can restart the sequence
+    pub rng: StdRng,
+}
+
+impl Default for WeaponSpreadRng {
+    fn default() -> Self {
+        Self::from_seed(SPREAD_RNG_SEED)
+    }
+}
+
+impl WeaponSpreadRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self { seed, rng: StdRng::seed_from_u64(seed) }
+    }
+
+    /// Restart the spread sequence from `seed`
+    pub fn reseed(&mut self) {
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+}
+
+// ===== EMERGENCY SPORE AIMING =====
+/// Optional mode where the emergency spore slows time while the player places the blast
+#[derive(Resource, Clone)]
+pub struct SporeAiming {
+    pub enabled: bool,
+    pub time_scale: f32,         // Virtual time speed while aiming
+    pub max_aim_time: f32,       // Real seconds before the blast auto-releases
+    pub reticle_speed: f32,      // Real pixels per second
+    pub target: Option<Vec3>,
+    pub aim_elapsed: f32,
+}
+
+impl Default for SporeAiming {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            time_scale: 0.15,
+            max_aim_time: 2.0,
+            reticle_speed: 600.0,
+            target: None,
+            aim_elapsed: 0.0,
+        }
+    }
+}
+
+impl SporeAiming {
+    pub fn is_aiming(&self) -> bool {
+        self.target.is_some()
+    }
+
+    pub fn begin(&mut self, origin: Vec3, time: &mut Time<Virtual>) {
+        self.target = Some(origin);
+        self.aim_elapsed = 0.0;
+        time.set_relative_speed(self.time_scale);
+    }
+
+    /// Release the blast at the aimed position and restore normal time
+    pub fn confirm(&mut self, time: &mut Time<Virtual>) -> Option<Vec3> {
+        time.set_relative_speed(1.0);
+        self.aim_elapsed = 0.0;
+        self.target.take()
+    }
+}
+
+// ===== AI DIFFICULTY TIERS =====
+/// Later waves field veteran versions of the same enemy types
+#[derive(Resource, Clone)]
+pub struct AiTierConfig {
+    pub enabled: bool,
+    pub waves_per_tier: u32,
+    pub max_tier: u32,
+    pub step_per_tier: f32, // Added to the AI multiplier per tier
+}
+
+impl Default for AiTierConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            waves_per_tier: 5,
+            max_tier: 4,
+            step_per_tier: 0.15,
+        }
+    }
+}
+
+impl AiTierConfig {
+    pub fn tier_for_wave(&self, wave: u32) -> u32 {
+        if !self.enabled || self.waves_per_tier == 0 { return 0; }
+        (wave.saturating_sub(1) / self.waves_per_tier).min(self.max_tier)
+    }
+
+    pub fn multiplier(&self, tier: u32) -> f32 {
+        1.0 + tier as f32 * self.step_per_tier
+    }
+}
+
+// ===== BOSS INTRO =====
+/// Scripted entrance for mini-bosses before they start attacking
+#[derive(Resource, Clone)]
+pub struct BossIntroConfig {
+    pub enabled: bool,
+    pub duration: f32,
+    pub zoom: f32,          // Projection scale at the peak of the intro
+    pub camera_pull: f32,   // Share of the distance to the boss the camera drifts
+    pub roar_shake: f32,
+}
+
+impl Default for BossIntroConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration: 2.5,
+            zoom: 0.85,
+            camera_pull: 0.3,
+            roar_shake: 1.0,
+        }
+    }
+}
+
+// ===== DEATH LINGER =====
+/// Brief slow-motion zoom on the fatal hit before the game over screen
+#[derive(Resource, Clone)]
+pub struct DeathLinger {
+    pub enabled: bool,
+    pub duration: f32,      // Real seconds
+    pub time_scale: f32,    // Virtual time speed while lingering
+    pub zoom: f32,          // Projection scale reached at the end of the linger
+    pub focus: Vec3,
+    pub remaining: Option<f32>,
+}
+
+impl Default for DeathLinger {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration: 1.5,
+            time_scale: 0.2,
+            zoom: 0.75,
+            focus: Vec3::ZERO,
+            remaining: None,
+        }
+    }
+}
+
+impl DeathLinger {
+    pub fn is_lingering(&self) -> bool {
+        self.remaining.is_some()
+    }
+
+    pub fn begin(&mut self, focus: Vec3, time: &mut Time<Virtual>) {
+        self.focus = focus;
+        self.remaining = Some(self.duration);
+        time.set_relative_speed(self.time_scale);
+    }
+
+    /// 0 at the fatal hit, 1 as the linger ends
+    pub fn progress(&self) -> f32 {
+        match self.remaining {
+            Some(remaining) if self.duration > 0.0 => (1.0 - remaining / self.duration).clamp(0.0, 1.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Count down in real time, returns true on the frame the linger ends and normal speed is restored
+    pub fn tick(&mut self, real_dt: f32, time: &mut Time<Virtual>) -> bool {
+        let Some(remaining) = self.remaining.as_mut() else { return false };
+        *remaining -= real_dt;
+        if *remaining > 0.0 { return false; }
+        self.finish(time);
+        true
+    }
+
+    pub fn finish(&mut self, time: &mut Time<Virtual>) {
+        self.remaining = None;
+        time.set_relative_speed(1.0);
+    }
+}
+
+// ===== RUN MODIFIERS =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunModifier {
+    Frenzy,     // Enemies move twice as fast, ATP drops are doubled
+    IronCell,   // One life only, player projectiles deal extra damage
+}
+
+impl RunModifier {
+    pub const ALL: [RunModifier; 2] = [RunModifier::Frenzy, RunModifier::IronCell];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RunModifier::Frenzy => "Frenzy",
+            RunModifier::IronCell => "Iron Cell",
+        }
+    }
+
+    pub fn score_multiplier(&self) -> f32 {
+        match self {
+            RunModifier::Frenzy => 1.5,
+            RunModifier::IronCell => 2.0,
+        }
+    }
+}
+
+/// Challenge modifiers picked on the title screen, applied for the whole run
+#[derive(Resource, Clone, Default)]
+pub struct RunModifiers {
+    pub active: Vec<RunModifier>,
+}
+
+impl RunModifiers {
+    pub fn has(&self, modifier: RunModifier) -> bool {
+        self.active.contains(&modifier)
+    }
+
+    pub fn enemy_speed_multiplier(&self) -> f32 {
+        if self.has(RunModifier::Frenzy) { 2.0 } else { 1.0 }
+    }
+
+    pub fn atp_multiplier(&self) -> f32 {
+        if self.has(RunModifier::Frenzy) { 2.0 } else { 1.0 }
+    }
+
+    pub fn player_damage_multiplier(&self) -> f32 {
+        if self.has(RunModifier::IronCell) { 1.5 } else { 1.0 }
+    }
+
+    pub fn starting_lives(&self, default_lives: i32) -> i32 {
+        if self.has(RunModifier::IronCell) { 1 } else { default_lives }
+    }
+
+    pub fn score_multiplier(&self) -> f32 {
+        self.active.iter().map(RunModifier::score_multiplier).product()
+    }
+
+    pub fn scale_score(&self, points: u32) -> u32 {
+        (points as f32 * self.score_multiplier()).round() as u32
+    }
+
+    /// Step through none -> each single modifier -> all combined
+    pub fn cycle_preset(&mut self) {
+        let presets: Vec<Vec<RunModifier>> = std::iter::once(Vec::new())
+            .chain(RunModifier::ALL.iter().map(|m| vec![*m]))
+            .chain(std::iter::once(RunModifier::ALL.to_vec()))
+            .collect();
+
+        let current = presets.iter().position(|p| *p == self.active).unwrap_or(0);
+        self.active = presets[(current + 1) % presets.len()].clone();
+    }
+
+    pub fn label(&self) -> String {
+        if self.active.is_empty() {
+            return "None".to_string();
+        }
+        let names: Vec<&str> = self.active.iter().map(RunModifier::display_name).collect();
+        format!("{} (x{:.1} score)", names.join(" + "), self.score_multiplier())
+    }
+}
+
 // ===== HIGH SCORES =====
 #[derive(Resource, Clone, Default)]
 pub struct GameScore {
@@ -481,6 +2186,8 @@ pub struct GameScore {
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct HighScoreData {
+    #[serde(default)]
+    pub version: u32,
     pub scores: Vec<HighScoreEntry>,
     pub total_games_played: u32,
     pub total_play_time: f32,
@@ -507,6 +2214,7 @@ pub struct HighScoreEntry {
 impl Default for HighScoreData {
     fn default() -> Self {
         Self {
+            version: HighScoreData::CURRENT_VERSION,
             scores: vec![
                 HighScoreEntry {
                     score: 10000,
@@ -551,6 +2259,11 @@ impl Default for HighScoreData {
     }
 }
 
+impl VersionedSave for HighScoreData {
+    const CURRENT_VERSION: u32 = 1;
+    const LABEL: &'static str = "high scores";
+}
+
 // ===== SCALE MANAGEMENT =====
 #[derive(Resource)]
 pub struct ScaleManager {
@@ -992,17 +2705,39 @@ pub struct GameSettings {
     pub gameplay: GameplaySettings,
 }
 
-#[derive(Default, Clone)]
+/// Live graphics options, persisted on their own. Picking a `GraphicsQuality` preset fills in
+/// density, lighting and post processing; see graphics_quality.rs
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct GraphicsSettings {
+    #[serde(default)]
+    pub version: u32,
     pub fullscreen: bool,
     pub vsync: bool,
-    pub particle_density: f32,
+    pub particle_density: f32, // Share of MAX_PARTICLES effects may use, also scales trails and background detail
     pub lighting_quality: LightingQuality,
-    pub post_processing: bool,
+    pub post_processing: bool, // Dynamic enemy, projectile and explosion lights
     pub screen_shake_intensity: f32,
+    #[serde(default)]
+    pub quality: GraphicsQuality,
 }
 
-#[derive(Default, Clone)]
+impl Default for GraphicsSettings {
+    fn default() -> Self {
+        let quality = GraphicsQuality::default();
+        Self {
+            version: 0,
+            fullscreen: false,
+            vsync: true,
+            particle_density: quality.particle_density(),
+            lighting_quality: quality.lighting_quality(),
+            post_processing: quality.post_processing(),
+            screen_shake_intensity: 1.0,
+            quality,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LightingQuality {
     #[default]
     Medium,
@@ -1117,3 +2852,41 @@ impl StageProgressTracker {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(seed: u64) -> (Vec<Vec2>, Vec<Vec2>, Vec<Vec2>, Vec2) {
+        let chemistry = ChemicalEnvironment::from_seed(seed);
+        let currents = CurrentGenerator::from_seed(seed);
+        (
+            chemistry.ph_zones.iter().map(|zone| zone.position).collect(),
+            chemistry.oxygen_zones.iter().map(|zone| zone.position).collect(),
+            currents.thermal_vents.iter().map(|vent| vent.position).collect(),
+            currents.noise_offset,
+        )
+    }
+
+    #[test]
+    fn same_seed_rebuilds_the_same_pool() {
+        assert_eq!(layout(0xC0FFEE), layout(0xC0FFEE));
+        assert_ne!(layout(0xC0FFEE), layout(0xBEEF));
+    }
+
+    #[test]
+    fn pool_codes_round_trip_to_the_same_seed() {
+        let seed = EnvironmentSeed { seed: 0xC0FFEE, ..default() };
+        assert_eq!(EnvironmentSeed::from_pool_code(&seed.pool_code()).map(|shared| shared.seed), Some(0xC0FFEE));
+    }
+
+    #[test]
+    fn time_to_afford_is_the_atp_shortfall_over_income() {
+        assert_eq!(AtpCostHintConfig::time_to_afford(40, 10, 2.5), Some(12.0));
+        assert_eq!(AtpCostHintConfig::label(40, 10, 2.5), "~12s");
+
+        assert_eq!(AtpCostHintConfig::time_to_afford(40, 55, 2.5), Some(0.0));
+        assert_eq!(AtpCostHintConfig::label(40, 40, 2.5), "ready");
+        assert_eq!(AtpCostHintConfig::time_to_afford(40, 10, 0.0), None);
+    }
+}
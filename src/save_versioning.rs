@@ -0,0 +1,155 @@
+// src/save_versioning.rs - Versioned save files with migration and safe reset
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::fs;
+
+// ===== CONSTANTS =====
+/// Files written before versioning was introduced carry no `version` field
+pub const LEGACY_SAVE_VERSION: u32 = 0;
+
+// ===== VERSIONED SAVE TRAIT =====
+pub trait VersionedSave: Serialize + DeserializeOwned {
+    const CURRENT_VERSION: u32;
+    const LABEL: &'static str;
+
+    /// Upgrade raw JSON written by `from_version` one step towards the current format.
+    /// Returning false marks the file as incompatible, which triggers a backup and reset.
+    fn migrate_step(_value: &mut Value, from_version: u32) -> bool {
+        // Unversioned files share the v1 layout, they only lack the version tag
+        from_version == LEGACY_SAVE_VERSION
+    }
+}
+
+#[derive(Debug)]
+pub enum SaveLoadOutcome<T> {
+    Loaded(T),
+    Migrated { data: T, from_version: u32 },
+    Incompatible { found_version: u32, reason: String },
+}
+
+// ===== MIGRATION =====
+/// Parse and upgrade a save file's contents without touching the filesystem
+pub fn migrate_save_json<T: VersionedSave>(json: &str) -> SaveLoadOutcome<T> {
+    let mut value: Value = match serde_json::from_str(json) {
+        Ok(value) => value,
+        Err(e) => return SaveLoadOutcome::Incompatible { found_version: LEGACY_SAVE_VERSION, reason: e.to_string() },
+    };
+
+    let found_version = value.get("version")
+        .and_then(Value::as_u64)
+        .map(|v| v as u32)
+        .unwrap_or(LEGACY_SAVE_VERSION);
+
+    if found_version > T::CURRENT_VERSION {
+        return SaveLoadOutcome::Incompatible {
+            found_version,
+            reason: format!("written by a newer build (v{} > v{})", found_version, T::CURRENT_VERSION),
+        };
+    }
+
+    let mut version = found_version;
+    while version < T::CURRENT_VERSION {
+        if !T::migrate_step(&mut value, version) {
+            return SaveLoadOutcome::Incompatible { found_version, reason: format!("no migration from v{}", version) };
+        }
+        version += 1;
+    }
+
+    if let Some(object) = value.as_object_mut() {
+        object.insert("version".to_string(), Value::from(T::CURRENT_VERSION));
+    }
+
+    match serde_json::from_value::<T>(value) {
+        Ok(data) if found_version == T::CURRENT_VERSION => SaveLoadOutcome::Loaded(data),
+        Ok(data) => SaveLoadOutcome::Migrated { data, from_version: found_version },
+        Err(e) => SaveLoadOutcome::Incompatible { found_version, reason: e.to_string() },
+    }
+}
+
+// ===== FILE HELPERS =====
+/// Load a versioned save, migrating older files in place. Incompatible files are
+/// moved aside to `<path>.v<version>.bak` and `None` is returned so callers reset.
+pub fn load_versioned<T: VersionedSave>(path: &str) -> Option<T> {
+    let json = fs::read_to_string(path).ok()?;
+
+    match migrate_save_json::<T>(&json) {
+        SaveLoadOutcome::Loaded(data) => Some(data),
+        SaveLoadOutcome::Migrated { data, from_version } => {
+            println!("Migrated {} from v{} to v{}", T::LABEL, from_version, T::CURRENT_VERSION);
+            save_versioned(path, &data);
+            Some(data)
+        }
+        SaveLoadOutcome::Incompatible { found_version, reason } => {
+            let backup_path = format!("{}.v{}.bak", path, found_version);
+            match fs::rename(path, &backup_path) {
+                Ok(_) => eprintln!("Incompatible {} ({}), backed up to {} and reset", T::LABEL, reason, backup_path),
+                Err(e) => eprintln!("Incompatible {} ({}), backup to {} failed: {}", T::LABEL, reason, backup_path, e),
+            }
+            None
+        }
+    }
+}
+
+pub fn save_versioned<T: VersionedSave>(path: &str, data: &T) {
+    match serde_json::to_string_pretty(data) {
+        Ok(json) => {
+            if let Err(e) = fs::write(path, json) {
+                eprintln!("Failed to save {}: {}", T::LABEL, e);
+            }
+        }
+        Err(e) => eprintln!("Failed to serialize {}: {}", T::LABEL, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Profile {
+        #[serde(default)]
+        version: u32,
+        best_score: u32,
+        #[serde(default)]
+        nickname: String, // Added in v2
+    }
+
+    impl VersionedSave for Profile {
+        const CURRENT_VERSION: u32 = 2;
+        const LABEL: &'static str = "test profile";
+
+        fn migrate_step(value: &mut Value, from_version: u32) -> bool {
+            match from_version {
+                LEGACY_SAVE_VERSION => true,
+                1 => {
+                    value["nickname"] = Value::from("Cell");
+                    true
+                }
+                _ => false,
+            }
+        }
+    }
+
+    #[test]
+    fn older_files_migrate_and_keep_their_data() {
+        let outcome = migrate_save_json::<Profile>(r#"{"version":1,"best_score":4200}"#);
+        let SaveLoadOutcome::Migrated { data, from_version } = outcome else { panic!("expected a migration, got {:?}", outcome) };
+        assert_eq!(from_version, 1);
+        assert_eq!(data, Profile { version: 2, best_score: 4200, nickname: "Cell".to_string() });
+    }
+
+    #[test]
+    fn unversioned_files_walk_every_step() {
+        let SaveLoadOutcome::Migrated { data, from_version } = migrate_save_json::<Profile>(r#"{"best_score":10}"#) else { panic!() };
+        assert_eq!(from_version, LEGACY_SAVE_VERSION);
+        assert_eq!(data.nickname, "Cell");
+    }
+
+    #[test]
+    fn newer_or_broken_files_are_incompatible_instead_of_panicking() {
+        assert!(matches!(migrate_save_json::<Profile>(r#"{"version":9,"best_score":1}"#), SaveLoadOutcome::Incompatible { found_version: 9, .. }));
+        assert!(matches!(migrate_save_json::<Profile>("not json"), SaveLoadOutcome::Incompatible { .. }));
+        assert!(matches!(migrate_save_json::<Profile>(r#"{"version":1,"best_score":"lots"}"#), SaveLoadOutcome::Incompatible { found_version: 1, .. }));
+    }
+}
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 use crate::components::*;
 use crate::resources::*;
 use crate::card_system::*;
+use crate::target_dummy::TargetDummy;
 use crate::wave_systems::*;
 use crate::enemy_types::*;
 use crate::events::*;
@@ -92,12 +93,10 @@ pub fn calculate_stage_summary(
     } else { 1.0 };
     
     // Check objectives completion
-    let objectives = vec![
-        StageObjective::SeventyPercentEnemies,
+    let objectives = [StageObjective::SeventyPercentEnemies,
         StageObjective::HundredPercentEnemies,
         StageObjective::AllInfrastructureDestroyed,
-        StageObjective::Untouched,
-    ];
+        StageObjective::Untouched];
     
     let temp_summary = StageSummaryData {
         stage_number: stage_progress.current_stage,
@@ -157,13 +156,13 @@ pub fn stage_summary_trigger_system(
     mut game_score: ResMut<GameScore>,
     card_collection: Res<CardCollection>,
     wave_manager: Res<WaveManager>,
-    enemy_query: Query<&Enemy>,
+    enemy_query: Query<&Enemy, Without<TargetDummy>>,
     mut summary_data: ResMut<StageSummaryData>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     // Trigger summary when stage is complete (every 5 waves)
     if wave_manager.current_wave > 0 && 
-       wave_manager.current_wave % 5 == 0 && 
+       wave_manager.current_wave.is_multiple_of(5) && 
        !wave_manager.wave_active && 
        enemy_query.iter().count() == 0 &&
        !summary_data.show_summary {
@@ -213,7 +212,7 @@ pub fn setup_stage_summary_ui(
         )).with_children(|summary| {
             // Title
             summary.spawn((
-                Text::new(&format!("STAGE {:02} COMPLETE", summary_data.stage_number)),
+                Text::new(format!("STAGE {:02} COMPLETE", summary_data.stage_number)),
                 TextFont { font: font.clone(), font_size: 36.0, ..default() },
                 TextColor(Color::srgb(0.3, 1.0, 0.7)),
                 Node { margin: UiRect::bottom(Val::Px(20.0)), align_self: AlignSelf::Center, ..default() },
@@ -221,7 +220,7 @@ pub fn setup_stage_summary_ui(
             
             // Base Score
             summary.spawn((
-                Text::new(&format!("Base Score: {}", summary_data.base_score)),
+                Text::new(format!("Base Score: {}", summary_data.base_score)),
                 TextFont { font: font.clone(), font_size: 22.0, ..default() },
                 TextColor(Color::WHITE),
                 Node { margin: UiRect::bottom(Val::Px(10.0)), ..default() },
@@ -237,7 +236,7 @@ pub fn setup_stage_summary_ui(
             };
             
             summary.spawn((
-                Text::new(&format!("ATP Collected: {:.1}%{}", 
+                Text::new(format!("ATP Collected: {:.1}%{}", 
                     summary_data.atp_collected_percent * 100.0,
                     if summary_data.atp_collected_percent >= 1.0 { " (+5,000 bonus)" } else { "" }
                 )),
@@ -281,7 +280,7 @@ pub fn setup_stage_summary_ui(
                 let symbol = if completed { "✓" } else { "✗" };
                 
                 summary.spawn((
-                    Text::new(&format!("{} {}", symbol, objective.get_display_text())),
+                    Text::new(format!("{} {}", symbol, objective.get_display_text())),
                     TextFont { font: font.clone(), font_size: 16.0, ..default() },
                     TextColor(color),
                     Node { margin: UiRect::bottom(Val::Px(5.0)), ..default() },
@@ -298,7 +297,7 @@ pub fn setup_stage_summary_ui(
             };
             
             summary.spawn((
-                Text::new(&format!("Score Multiplier: {:.0}x", summary_data.score_multiplier)),
+                Text::new(format!("Score Multiplier: {:.0}x", summary_data.score_multiplier)),
                 TextFont { font: font.clone(), font_size: 20.0, ..default() },
                 TextColor(multiplier_color),
                 Node { margin: UiRect::vertical(Val::Px(15.0)), ..default() },
@@ -316,7 +315,7 @@ pub fn setup_stage_summary_ui(
             
             // Final Score (Highlighted)
             summary.spawn((
-                Text::new(&format!("FINAL SCORE: {}", summary_data.final_score)),
+                Text::new(format!("FINAL SCORE: {}", summary_data.final_score)),
                 TextFont { font: font.clone(), font_size: 32.0, ..default() },
                 TextColor(Color::srgb(1.0, 1.0, 0.3)),
                 Node { 
@@ -440,7 +439,7 @@ pub fn stage_tracking_system(
     mut stage_progress: ResMut<StageProgress>,
     mut game_score: ResMut<GameScore>,
     wave_manager: Res<WaveManager>,
-    enemy_query: Query<&Enemy>,
+    enemy_query: Query<&Enemy, Without<TargetDummy>>,
     mut enemy_death_events: EventReader<EnemyDeathEvent>,
     mut player_damage_events: EventReader<PlayerHit>,
 ) {
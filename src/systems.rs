@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use bevy::render::camera::ScalingMode;
+use bevy::ecs::system::SystemParam;
 use crate::components::*;
 use crate::resources::*;
 use crate::events::*;
@@ -8,17 +9,22 @@ use crate::enemy_types::*;
 use crate::physics::*;
 use crate::wave_systems::*;
 use crate::despawn::{SafeDespawn};
+use crate::weapon_mods::{PiercingMod, SplitOnHitMod};
+use crate::target_dummy::TargetDummy;
+use crate::balance_systems::DamageLog;
+use crate::enemy_systems::EnemyArchetypes;
 
 // ===== PERFORMANCE CONSTANTS =====
-const MAX_PARTICLES: usize = 200;
-const CLEANUP_PARTICLES_TO: usize = 150;
+const CLEANUP_TO_BUDGET_FRACTION: f32 = 0.75; // Cull down to this share of the graphics particle budget
 const CLEANUP_INTERVAL: f32 = 2.0;
 const MAX_AUDIO_ENTITIES: usize = 10;
-const OFFSCREEN_BOUNDS: f32 = 600.0;
-const COLLISION_GRID_SIZE: f32 = 64.0;
+const OFFSCREEN_BOUNDS_X: f32 = 720.0;
+const OFFSCREEN_BOUNDS_Y: f32 = 480.0;
 const SPAWN_RATE_MIN: f32 = 0.3;
 const SPAWN_RATE_DECAY: f32 = 0.02;
 const ENEMY_SHOOT_INTERVAL: f32 = 1.5;
+const ENEMY_PROJECTILE_SPEED: f32 = 300.0;
+const ENEMY_PROJECTILE_DAMAGE: i32 = 15;
 
 // ===== WAVE CONSTANTS =====
 const WAVE_1_DURATION: f32 = 20.0;
@@ -45,6 +51,16 @@ fn calculate_crit_hit(damage: i32, crit_stats: &CriticalHitStats, seed: f32) ->
     }
 }
 
+fn spawn_floating_text(commands: &mut Commands, position: Vec3, text: String, size: f32, color: Color, fonts: &GameFonts) {
+    commands.spawn((
+        Text2d::new(text),
+        TextFont { font: fonts.default_font.clone(), font_size: size, ..default() },
+        TextColor(color),
+        Transform::from_translation(position + Vec3::new(0.0, 25.0, 1.0)),
+        DamageText { timer: 1.5, velocity: Vec2::new(0.0, 80.0) },
+    ));
+}
+
 fn spawn_damage_text_fast(commands: &mut Commands, position: Vec3, damage: i32, is_crit: bool, fonts: &GameFonts) {
     let (color, size) = if is_crit { 
         (Color::srgb(1.0, 1.0, 0.3), 16.0) 
@@ -52,13 +68,14 @@ fn spawn_damage_text_fast(commands: &mut Commands, position: Vec3, damage: i32,
         (Color::WHITE, 12.0) 
     };
     
-    commands.spawn((
-        Text2d::new(format!("{}", damage)),
-        TextFont { font: fonts.default_font.clone(), font_size: size, ..default() },
-        TextColor(color),
-        Transform::from_translation(position + Vec3::new(0.0, 25.0, 1.0)),
-        DamageText { timer: 1.5, velocity: Vec2::new(0.0, 80.0) },
-    ));
+    spawn_floating_text(commands, position, format!("{}", damage), size, color, fonts);
+}
+
+fn spawn_score_popup(commands: &mut Commands, position: Vec3, points: u32, multiplier: f32, config: &ScorePopupConfig, fonts: &GameFonts) {
+    if !config.enabled { return; }
+    let (size, color) = config.style(multiplier);
+    // Sit above the damage number so the two don't overlap
+    spawn_floating_text(commands, position + Vec3::new(0.0, 18.0, 0.0), format!("+{}", points), size, color, fonts);
 }
 
 // ===== OPTIMIZED WAVE SYSTEM =====
@@ -85,7 +102,7 @@ fn get_enemy_config_fast(wave_timer: f32, enemies_spawned: u32) -> (EnemyAI, Ene
             }
         },
         _ => {
-            if enemies_spawned % 10 == 0 {
+            if enemies_spawned.is_multiple_of(10) {
                 (EnemyAI::MiniBoss { pattern: 0, timer: 0.0 }, EnemyType::InfectedMacrophage)
             } else {
                 (EnemyAI::FluidFlow { 
@@ -106,6 +123,7 @@ pub fn setup_camera(mut commands: Commands) {
             scaling_mode: ScalingMode::FixedVertical { viewport_height: 720.0 },
             ..OrthographicProjection::default_2d()
         }),
+        SpatialListener::new(crate::audio::SPATIAL_LISTENER_EAR_GAP), // Positional telegraph cues pan around the camera
     ));
 }
 
@@ -117,13 +135,15 @@ pub fn init_particle_pool(mut commands: Commands) {
 // ===== OPTIMIZED MOVEMENT SYSTEMS =====
 
 pub fn move_projectiles(
-    mut projectile_query: Query<(&mut Transform, &Projectile)>,
+    mut projectile_query: Query<(&mut Transform, &Projectile, Has<HitStop>)>,
     fluid_environment: Res<FluidEnvironment>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
     
-    projectile_query.par_iter_mut().for_each(|(mut transform, projectile)| {
+    projectile_query.par_iter_mut().for_each(|(mut transform, projectile, hit_stopped)| {
+        if hit_stopped { return; }
+        
         // Move projectile
         let velocity_dt = projectile.velocity * dt;
         transform.translation.x += velocity_dt.x;
@@ -166,13 +186,11 @@ pub fn cleanup_offscreen(
         Without<AutoMissile>
     )>,
 ) {
-    let bounds_sq = OFFSCREEN_BOUNDS * OFFSCREEN_BOUNDS;
-    
+    // Rectangular bounds leave room for enemies entering from the side edges
     for (entity, transform) in query.iter() {
         let pos = transform.translation;
-        let distance_sq = pos.x * pos.x + pos.y * pos.y;
         
-        if distance_sq > bounds_sq {
+        if pos.x.abs() > OFFSCREEN_BOUNDS_X || pos.y.abs() > OFFSCREEN_BOUNDS_Y {
             commands.entity(entity).safe_despawn_delayed(0.1);
         }
     }
@@ -216,7 +234,7 @@ pub fn spawn_enemies(
 
 pub fn enemy_shooting(
     mut commands: Commands,
-    enemy_query: Query<(&Transform, &Enemy)>,
+    enemy_query: Query<(&Transform, &Enemy, Option<&Berserk>), (Without<Frozen>, Without<TargetDummy>, Without<BossIntro>)>,
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
     assets: Option<Res<GameAssets>>,
     time: Res<Time>,
@@ -236,10 +254,9 @@ pub fn enemy_shooting(
     if enemies.is_empty() { return; }
     
     *enemy_index = (*enemy_index + 1) % enemies.len();
-    let (enemy_transform, enemy) = enemies[*enemy_index];
+    let (enemy_transform, enemy, berserk) = enemies[*enemy_index];
     
     let direction = (player_pos - enemy_transform.translation.truncate()).normalize_or_zero();
-    let angle = direction.y.atan2(direction.x) - std::f32::consts::FRAC_PI_2;
     
     let color = match enemy.enemy_type {
         EnemyType::ViralParticle => Color::srgb(0.9, 0.9, 1.0),
@@ -250,22 +267,59 @@ pub fn enemy_shooting(
         _ => Color::WHITE,
     };
     
-    commands.spawn((
-        Sprite { image: assets.projectile_texture.clone(), color, ..default() },
-        Transform::from_translation(enemy_transform.translation - Vec3::new(0.0, 20.0, 0.0))
-            .with_rotation(Quat::from_rotation_z(angle)),
-        Projectile {
-            velocity: direction * 300.0,
-            damage: 15,
-            friendly: false,
+    fire_pattern(
+        &mut commands,
+        &assets.projectile_texture,
+        enemy.enemy_type.bullet_pattern(),
+        enemy_transform.translation - Vec3::new(0.0, 20.0, 0.0),
+        direction,
+        time.elapsed_secs(),
+        HostileShot {
+            color,
+            speed: ENEMY_PROJECTILE_SPEED,
+            damage: berserk.map_or(ENEMY_PROJECTILE_DAMAGE, |rage| (ENEMY_PROJECTILE_DAMAGE as f32 * rage.damage_multiplier) as i32),
             organic_trail: enemy.chemical_signature.releases_toxins,
         },
-        Collider { radius: 4.0 },
-    ));
+    );
     
     *shoot_timer = ENEMY_SHOOT_INTERVAL;
 }
 
+/// Look and stats shared by every projectile in one hostile volley
+#[derive(Clone, Copy)]
+pub struct HostileShot {
+    pub color: Color,
+    pub speed: f32,
+    pub damage: i32,
+    pub organic_trail: bool,
+}
+
+/// Spawn one hostile volley, `phase` is the elapsed time used to turn spiral patterns
+pub fn fire_pattern(
+    commands: &mut Commands,
+    texture: &Handle<Image>,
+    pattern: BulletPattern,
+    origin: Vec3,
+    aim: Vec2,
+    phase: f32,
+    shot: HostileShot,
+) {
+    for velocity in pattern.velocities(aim, shot.speed, phase) {
+        let angle = velocity.y.atan2(velocity.x) - std::f32::consts::FRAC_PI_2;
+        commands.spawn((
+            Sprite { image: texture.clone(), color: shot.color, ..default() },
+            Transform::from_translation(origin).with_rotation(Quat::from_rotation_z(angle)),
+            Projectile {
+                velocity,
+                damage: shot.damage,
+                friendly: false,
+                organic_trail: shot.organic_trail,
+            },
+            Collider { radius: 4.0 },
+        ));
+    }
+}
+
 // ===== SPAWN ENEMY SYSTEM (simplified) =====
 
 pub fn old_spawn_enemy_system(
@@ -297,7 +351,7 @@ pub fn old_spawn_enemy_system(
             Enemy {
                 ai_type: event.ai_type.clone(),
                 health, speed,
-                enemy_type: event.enemy_type.clone(),
+                enemy_type: event.enemy_type,
                 colony_id: None,
                 chemical_signature: chemical_signature.clone(),
             },
@@ -330,18 +384,26 @@ pub fn spawn_enemy_system(
     mut commands: Commands,
     mut spawn_events: EventReader<SpawnEnemy>,
     wave_manager: ResMut<WaveManager>,
+    run_modifiers: Res<RunModifiers>,
+    collapse: Res<EcosystemCollapse>,
+    ai_tiers: Res<AiTierConfig>,
+    boss_intro: Res<BossIntroConfig>,
+    archetypes: Res<EnemyArchetypes>,
     assets: Option<Res<GameAssets>>,
 ) {
     let Some(assets) = assets else { return };
+    let ai_tier = ai_tiers.tier_for_wave(wave_manager.current_wave);
     
     for event in spawn_events.read() {
-        let (base_health, _damage, base_speed, base_color) = event.enemy_type.get_stats();
+        let (base_health, _damage, _, base_color) = event.enemy_type.get_stats();
+        let archetype = archetypes.get(event.enemy_type);
+        let base_speed = archetype.speed;
         let chemical_signature = event.enemy_type.get_chemical_signature();
         
         // Apply wave difficulty scaling
         let (health_mult, speed_mult) = wave_manager.calculate_difficulty_multipliers();
-        let final_health = (base_health as f32 * health_mult) as i32;
-        let final_speed = base_speed * speed_mult;
+        let final_health = (base_health as f32 * health_mult * collapse.enemy_health_multiplier()) as i32;
+        let final_speed = base_speed * speed_mult * run_modifiers.enemy_speed_multiplier();
         
         // Select appropriate texture
         let texture = match event.enemy_type {
@@ -364,15 +426,16 @@ pub fn spawn_enemy_system(
             },
             Transform::from_translation(event.position),
             Enemy {
-                ai_type: event.ai_type.clone(),
+                ai_type: archetype.apply_to(&event.ai_type).scaled_for_tier(ai_tiers.multiplier(ai_tier)),
                 health: final_health,
                 speed: final_speed,
-                enemy_type: event.enemy_type.clone(),
+                enemy_type: event.enemy_type,
                 colony_id: None,
                 chemical_signature,
             },
             Health(final_health),
-            Collider { radius: get_enemy_collision_radius(event.enemy_type.clone()) },
+            Collider { radius: get_enemy_collision_radius(event.enemy_type) },
+            AiTier(ai_tier),
         )).id();
 
         // Add special components based on enemy type
@@ -405,6 +468,26 @@ pub fn spawn_enemy_system(
         
         commands.entity(enemy_entity).insert(event.enemy_type.get_ecosystem_role());
         
+        if let Some(evasion) = event.enemy_type.evasive_maneuver() {
+            commands.entity(enemy_entity).insert(evasion);
+        }
+        
+        // Bosses take capped hits so their phases always play out
+        if event.ai_type.is_boss() {
+            commands.entity(enemy_entity).insert(DamageCap);
+            if boss_intro.enabled {
+                commands.entity(enemy_entity).insert(BossIntro::new(boss_intro.duration));
+            }
+        }
+        
+        // A collapsing pool corrupts everything that spawns into it
+        if collapse.active {
+            commands.entity(enemy_entity).insert(CorruptionEffect {
+                intensity: collapse.intensity.max(0.3),
+                color_shift_speed: 3.0,
+            });
+        }
+        
         // Add adaptive difficulty component for later waves
         if wave_manager.current_wave >= 10 {
             commands.entity(enemy_entity).insert(AdaptiveDifficulty {
@@ -419,55 +502,160 @@ pub fn spawn_enemy_system(
 
 // ===== MASSIVELY OPTIMIZED COLLISION SYSTEM =====
 
+/// Everything a kill touches. Every weapon that can finish an enemy goes through `kill`, so score,
+/// ATP drops, combo, shatter and ally reactions don't depend on what landed the last hit
+#[derive(SystemParam)]
+pub struct EnemyDeaths<'w, 's> {
+    pub commands: Commands<'w, 's>,
+    pub game_score: ResMut<'w, GameScore>,
+    pub combo: ResMut<'w, ComboState>,
+    pub run_modifiers: Res<'w, RunModifiers>,
+    pub score_popups: Res<'w, ScorePopupConfig>,
+    pub fonts: Res<'w, GameFonts>,
+    pub shake_events: EventWriter<'w, AddScreenShake>,
+    pub explosion_events: EventWriter<'w, SpawnExplosion>,
+    pub death_events: EventWriter<'w, EnemyDied>,
+    pub shatter_events: EventWriter<'w, FrozenShatter>,
+    pub achievement_events: EventWriter<'w, AchievementEvent>,
+    pub frozen_query: Query<'w, 's, (), With<Frozen>>,
+}
+
+impl EnemyDeaths<'_, '_> {
+    pub fn kill(&mut self, entity: Entity, position: Vec3, enemy: &Enemy) {
+        let enemy_type = &enemy.enemy_type;
+        let points = self.run_modifiers.scale_score(enemy_type.get_points());
+        self.game_score.current += points;
+        spawn_score_popup(&mut self.commands, position, points, self.game_score.score_multiplier, &self.score_popups, &self.fonts);
+        
+        let shake = match enemy_type {
+            EnemyType::InfectedMacrophage => 0.8,
+            EnemyType::ParasiticProtozoa => 0.4,
+            _ => 0.2,
+        };
+        self.shake_events.write(AddScreenShake { amount: shake });
+        self.explosion_events.write(SpawnExplosion { 
+            position, 
+            intensity: 1.0, 
+            enemy_type: Some(*enemy_type) 
+        });
+        self.death_events.write(EnemyDied { position, enemy_type: Some(*enemy_type) });
+        
+        if self.frozen_query.contains(entity) {
+            self.shatter_events.write(FrozenShatter { position, max_health: enemy.health });
+        }
+        
+        self.commands.entity(entity).safe_despawn();
+        self.game_score.enemies_defeated += 1;
+        self.combo.register_kill();
+        self.achievement_events.write(AchievementEvent::EnemyKilled(enemy_type.get_biological_description().to_string()));
+    }
+}
+
+/// Player damage landing on an enemy. Every weapon applies its hit through `deal`, so boss damage
+/// caps hold and the damage log credits the weapon that actually landed it
+#[derive(SystemParam)]
+pub struct EnemyDamage<'w> {
+    pub cap_config: Res<'w, DamageCapConfig>,
+    pub damage_log: ResMut<'w, DamageLog>,
+    pub time: Res<'w, Time>,
+}
+
+impl EnemyDamage<'_> {
+    /// Applies the hit, logs it against `weapon` and returns the damage actually dealt after the cap
+    pub fn deal(&mut self, health: &mut Health, damage: i32, enemy: &Enemy, is_capped: bool, weapon: &str) -> i32 {
+        self.deal_to(health, damage, enemy.enemy_type.display_name(), is_capped.then_some(enemy.health), weapon)
+    }
+
+    /// `deal` for targets without an `Enemy`, such as egg sacs. `capped_max_health` is the max health
+    /// the cap is measured against, or `None` for an uncapped target
+    pub fn deal_to(&mut self, health: &mut Health, damage: i32, target: &str, capped_max_health: Option<i32>, weapon: &str) -> i32 {
+        let damage = capped_max_health.map_or(damage, |max_health| self.cap_config.cap(damage, max_health));
+        health.0 -= damage;
+        self.damage_log.record_dealt(self.time.elapsed_secs(), weapon, target, damage);
+        damage
+    }
+}
+
+/// Damage log name for a projectile: secondary weapons by their marker, everything else is the primary evolution
+fn projectile_weapon_name((is_missile, is_auto_missile, is_wing_cannon): (bool, bool, bool), evolution_system: &EvolutionSystem) -> &'static str {
+    if is_missile {
+        "Homing Missile"
+    } else if is_auto_missile {
+        "Auto Missile"
+    } else if is_wing_cannon {
+        "Wing Cannon"
+    } else {
+        evolution_system.primary_evolution.get_display_name()
+    }
+}
+
 pub fn collision_system(
-    mut commands: Commands,
+    mut deaths: EnemyDeaths,
     mut player_hit_events: EventWriter<PlayerHit>,
-    mut explosion_events: EventWriter<SpawnExplosion>,
-    mut shake_events: EventWriter<AddScreenShake>,
     mut enemy_hit_events: EventWriter<EnemyHit>,
-    mut game_score: ResMut<GameScore>,
+    (mut split_events, weapon_kinds, barrier_query, knockback_config, status_resistance, mut enemy_damage, hit_stop, mut egg_events): (EventWriter<ProjectileSplit>, Query<(Has<MissileProjectile>, Has<AutoMissile>, Has<WingCannonProjectile>)>, Query<(&Transform, &ShieldBarrier)>, Res<KnockbackConfig>, Res<StatusResistanceConfig>, EnemyDamage, Res<HitStopConfig>, EventWriter<EggSacPopped>),
     time: Res<Time>,
-    fonts: Res<GameFonts>,
-    projectile_query: Query<(Entity, &Transform, &Collider, &Projectile), (Without<PendingDespawn>)>,
-    mut enemy_query: Query<(Entity, &Transform, &Collider, &mut Health, Option<&Enemy>), (Without<Projectile>, Without<Player>, Without<PendingDespawn>)>,
-    player_query: Query<(Entity, &Transform, &Collider, &Player, &CriticalHitStats), (With<Player>, Without<Enemy>, Without<PendingDespawn>)>,
-    mut achievement_events: EventWriter<AchievementEvent>,
+    projectile_query: Query<(Entity, &Transform, &Collider, &Projectile, Option<&PiercingMod>, Option<&SplitOnHitMod>, Option<&Knockback>), Without<PendingDespawn> >,
+    mut enemy_query: Query<(Entity, &mut Transform, &Collider, &mut Health, Option<&Enemy>, Has<TargetDummy>, Has<DamageCap>, Option<&EggSac>), (Without<Projectile>, Without<Player>, Without<ShieldBarrier>, Without<BossIntro>, Without<PendingDespawn>)>,
+    player_query: Query<(Entity, &Transform, &Collider, &Player, &CriticalHitStats, &EvolutionSystem), (With<Player>, Without<Enemy>, Without<PendingDespawn>)>,
 ) {
-    let Ok((_, player_transform, player_collider, player, crit_stats)) = player_query.single() else { return };
-    if player.invincible_timer > 0.0 { return; }
+    let Ok((_, player_transform, player_collider, player, crit_stats, evolution_system)) = player_query.single() else { return };
+
+    // Track entities to remove to avoid double-processing
+    let mut projectiles_to_remove = std::collections::HashSet::new();
+    let mut enemies_to_remove = std::collections::HashSet::new();
+
+    // Shield barriers soak enemy shots whether or not the player is currently invincible
+    for (proj_entity, proj_transform, proj_collider, projectile, _, _, _) in projectile_query.iter() {
+        if projectile.friendly { continue; }
+        let proj_pos = proj_transform.translation.truncate();
+        let absorbed = barrier_query.iter().any(|(barrier_transform, barrier)| {
+            barrier.blocks(barrier_transform.translation.truncate(), proj_pos, proj_collider.radius)
+        });
+        if absorbed {
+            deaths.explosion_events.write(SpawnExplosion {
+                position: proj_transform.translation,
+                intensity: 0.4,
+                enemy_type: None,
+            });
+            deaths.commands.entity(proj_entity).safe_despawn();
+            projectiles_to_remove.insert(proj_entity);
+        }
+    }
+
+    // Invincibility (hit recovery, wave-start grace) only shields the player, their shots keep landing
+    let player_invincible = player.invincible_timer > 0.0;
     
     let player_pos = player_transform.translation;
     let player_radius = player_collider.radius;
     let time_seed = time.elapsed_secs();
     
-    // Track entities to remove to avoid double-processing
-    let mut projectiles_to_remove = std::collections::HashSet::new();
-    let mut enemies_to_remove = std::collections::HashSet::new();
-    
     // Enemy projectiles vs player
-    for (proj_entity, proj_transform, proj_collider, projectile) in projectile_query.iter() {
+    for (proj_entity, proj_transform, proj_collider, projectile, _, _, _) in projectile_query.iter() {
+        if player_invincible { break; }
         if projectiles_to_remove.contains(&proj_entity) { continue; }
         if projectile.friendly { continue; }
         
         if check_collision_fast(player_pos, player_radius, proj_transform.translation, proj_collider.radius) {
             player_hit_events.write(PlayerHit { 
                 position: proj_transform.translation, 
-                damage: projectile.damage 
+                damage: projectile.damage,
+                source: "Enemy Projectile",
             });
-            shake_events.write(AddScreenShake { amount: 0.5 });
-            explosion_events.write(SpawnExplosion { 
+            deaths.shake_events.write(AddScreenShake { amount: 0.5 });
+            deaths.explosion_events.write(SpawnExplosion { 
                 position: proj_transform.translation, 
                 intensity: 0.8, 
                 enemy_type: None 
             });
             
-            commands.entity(proj_entity).safe_despawn();
+            deaths.commands.entity(proj_entity).safe_despawn();
             projectiles_to_remove.insert(proj_entity); // huh? if above?
         }
     }
     
     // Player projectiles vs enemies - ONE projectile per enemy per frame
-    for (proj_entity, proj_transform, proj_collider, projectile) in projectile_query.iter() {
+    for (proj_entity, proj_transform, proj_collider, projectile, piercing, split, knockback) in projectile_query.iter() {
         if projectiles_to_remove.contains(&proj_entity) { continue; }
         if !projectile.friendly { continue; }
         
@@ -477,9 +665,10 @@ pub fn collision_system(
         // Find closest enemy that this projectile can hit
         let mut closest_enemy: Option<(Entity, f32)> = None;
         
-        for (enemy_entity, enemy_transform, enemy_collider, enemy_health, enemy_opt) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform, enemy_collider, enemy_health, enemy_opt, _, _, egg_sac) in enemy_query.iter() {
             if enemies_to_remove.contains(&enemy_entity) { continue; }
-            if enemy_opt.is_none() { continue; }
+            if enemy_opt.is_none() && egg_sac.is_none() { continue; }
+            if piercing.is_some_and(|p| p.last_hit == Some(enemy_entity)) { continue; }
             
             if check_collision_fast(proj_pos, proj_radius, enemy_transform.translation, enemy_collider.radius) {
                 let distance_sq = proj_pos.distance_squared(enemy_transform.translation);
@@ -495,80 +684,122 @@ pub fn collision_system(
         }
         
         // Process hit with closest enemy
-        if let Some((enemy_entity, _)) = closest_enemy {
-            if let Ok((_, enemy_transform, _, mut enemy_health, enemy_opt)) = enemy_query.get_mut(enemy_entity) {
-                if let Some(enemy) = enemy_opt {
-                    let seed = proj_pos.x * 0.1 + time_seed;
-                    let (final_damage, is_crit) = calculate_crit_hit(projectile.damage, crit_stats, seed);
+        if let Some((enemy_entity, _)) = closest_enemy
+            && let Ok((_, mut enemy_transform, _, mut enemy_health, enemy_opt, is_dummy, is_capped, egg_sac)) = enemy_query.get_mut(enemy_entity)
+        {
+            let weapon = weapon_kinds.get(proj_entity).map_or("Projectile", |kind| projectile_weapon_name(kind, evolution_system));
+            // Egg sacs just soak damage and pay out ATP if popped before they hatch
+            if let Some(egg_sac) = egg_sac {
+                let damage = (projectile.damage as f32 * deaths.run_modifiers.player_damage_multiplier()) as i32;
+                enemy_damage.deal_to(&mut enemy_health, damage, "Egg Sac", None, weapon);
+                deaths.explosion_events.write(SpawnExplosion { position: proj_pos, intensity: 0.4, enemy_type: None });
+                deaths.commands.entity(proj_entity).safe_despawn();
+                projectiles_to_remove.insert(proj_entity);
                     
-                    enemy_health.0 -= final_damage;
-                    enemy_hit_events.write(EnemyHit { 
-                        entity: enemy_entity, 
-                        position: enemy_transform.translation 
-                    });
+                if enemy_health.0 <= 0 {
+                    egg_events.write(EggSacPopped { position: enemy_transform.translation, atp_reward: egg_sac.atp_reward });
+                    deaths.commands.entity(enemy_entity).try_insert(AlreadyDespawned).safe_despawn();
+                    enemies_to_remove.insert(enemy_entity);
+                }
+                continue;
+            }
+            if let Some(enemy) = enemy_opt {
+                let seed = proj_pos.x * 0.1 + time_seed;
+                let scaled_damage = (projectile.damage as f32 * deaths.run_modifiers.player_damage_multiplier()) as i32;
+                let (final_damage, is_crit) = calculate_crit_hit(scaled_damage, crit_stats, seed);
+                let final_damage = enemy_damage.deal(&mut enemy_health, final_damage, enemy, is_capped, weapon);
                     
-                    explosion_events.write(SpawnExplosion { 
-                        position: proj_pos, 
-                        intensity: 0.6, 
-                        enemy_type: None 
-                    });
+                // Shove the enemy along the shot's path, the practice dummy stays put
+                if !is_dummy {
+                    let strength = knockback.map_or(knockback_config.default_strength, |knockback| knockback.strength);
+                    let strength = status_resistance.scale(&enemy.enemy_type.get_ecosystem_role().role, strength);
+                    enemy_transform.translation += knockback_config.displacement(projectile.velocity, final_damage, strength).extend(0.0);
+                    if let Some(frames) = hit_stop.frames_for(final_damage) {
+                        deaths.commands.entity(enemy_entity).try_insert(HitStop { frames });
+                        if hit_stop.freeze_projectile {
+                            deaths.commands.entity(proj_entity).try_insert(HitStop { frames });
+                        }
+                    }
+                }
+                enemy_hit_events.write(EnemyHit { 
+                    entity: enemy_entity, 
+                    position: enemy_transform.translation 
+                });
                     
-                    spawn_damage_text_fast(&mut commands, enemy_transform.translation, final_damage, is_crit, &fonts);
+                deaths.explosion_events.write(SpawnExplosion { 
+                    position: proj_pos, 
+                    intensity: 0.6, 
+                    enemy_type: None 
+                });
                     
-                    // Remove projectile
-                    commands.entity(proj_entity).safe_despawn();
-                    projectiles_to_remove.insert(proj_entity);
+                spawn_damage_text_fast(&mut deaths.commands, enemy_transform.translation, final_damage, is_crit, &deaths.fonts);
                     
-                    // Check if enemy died
-                    if enemy_health.0 <= 0 {
-                        let enemy_type = &enemy.enemy_type;
-                        game_score.current += enemy_type.get_points();
-                        
-                        let shake = match enemy_type {
-                            EnemyType::InfectedMacrophage => 0.8,
-                            EnemyType::ParasiticProtozoa => 0.4,
-                            _ => 0.2,
-                        };
-                        shake_events.write(AddScreenShake { amount: shake });
-                        explosion_events.write(SpawnExplosion { 
-                            position: enemy_transform.translation, 
-                            intensity: 1.0, 
-                            enemy_type: Some(enemy_type.clone()) 
+                if let Some(split) = split {
+                    split_events.write(ProjectileSplit {
+                        position: proj_pos,
+                        velocity: projectile.velocity,
+                        damage: projectile.damage,
+                        fragments: split.fragments,
+                    });
+                }
+                    
+                // Piercing shots carry on through, otherwise remove projectile
+                match piercing {
+                    Some(piercing) if piercing.remaining > 0 => {
+                        deaths.commands.entity(proj_entity).try_insert(PiercingMod {
+                            remaining: piercing.remaining - 1,
+                            last_hit: Some(enemy_entity),
                         });
-                        
-                        commands.entity(enemy_entity).safe_despawn();
-                        enemies_to_remove.insert(enemy_entity);
-                        game_score.enemies_defeated += 1;
-                        achievement_events.write(AchievementEvent::EnemyKilled(enemy_type.get_biological_description().to_string()));
                     }
+                    _ => {
+                        deaths.commands.entity(proj_entity).safe_despawn();
+                    }
+                }
+                projectiles_to_remove.insert(proj_entity);
+                    
+                // Check if enemy died
+                if enemy_health.0 <= 0 {
+                    deaths.kill(enemy_entity, enemy_transform.translation, enemy);
+                    enemies_to_remove.insert(enemy_entity);
                 }
             }
         }
     }
     
     // Enemy vs player collision
-    for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health, enemy_opt) in enemy_query.iter_mut() {
+    for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health, enemy_opt, is_dummy, is_capped, _) in enemy_query.iter_mut() {
+        if player_invincible { break; }
         if enemies_to_remove.contains(&enemy_entity) { continue; }
-        if enemy_opt.is_none() { continue; }
+        if enemy_opt.is_none() || is_dummy { continue; }
         
         if check_collision_fast(player_pos, player_radius, enemy_transform.translation, enemy_collider.radius) {
             player_hit_events.write(PlayerHit { 
                 position: enemy_transform.translation, 
-                damage: 20 
+                damage: 20,
+                source: enemy_opt.map_or("Contact", |enemy| enemy.enemy_type.display_name()),
             });
-            shake_events.write(AddScreenShake { amount: 0.6 });
+            deaths.shake_events.write(AddScreenShake { amount: 0.6 });
             
-            // Damage enemy from collision
-            enemy_health.0 -= 30;
+            // Ramming takes the same capped, logged path as weapon hits
+            if let Some(enemy) = enemy_opt {
+                enemy_damage.deal(&mut enemy_health, 30, enemy, is_capped, "Collision");
+            }
             if enemy_health.0 <= 0 {
-                game_score.current += 50;
-                achievement_events.write(AchievementEvent::EnemyKilled("Collision Kill".to_string()));
-                explosion_events.write(SpawnExplosion { 
+                let points = deaths.run_modifiers.scale_score(50);
+                deaths.game_score.current += points;
+                spawn_score_popup(&mut deaths.commands, enemy_transform.translation, points, deaths.game_score.score_multiplier, &deaths.score_popups, &deaths.fonts);
+                deaths.combo.register_kill();
+                deaths.achievement_events.write(AchievementEvent::EnemyKilled("Collision Kill".to_string()));
+                deaths.explosion_events.write(SpawnExplosion { 
                     position: enemy_transform.translation, 
                     intensity: 1.0, 
                     enemy_type: None 
                 });
-                commands.entity(enemy_entity).safe_despawn();
+                deaths.death_events.write(EnemyDied { position: enemy_transform.translation, enemy_type: enemy_opt.map(|enemy| enemy.enemy_type) });
+                if let Some(enemy) = enemy_opt.filter(|_| deaths.frozen_query.contains(enemy_entity)) {
+                    deaths.shatter_events.write(FrozenShatter { position: enemy_transform.translation, max_health: enemy.health });
+                }
+                deaths.commands.entity(enemy_entity).safe_despawn();
             }
         }
     }
@@ -600,21 +831,78 @@ pub fn save_high_score_system(mut game_score: ResMut<GameScore>) {
     }
 }
 
+pub fn combo_decay_system(
+    mut combo: ResMut<ComboState>,
+    time: Res<Time>,
+) {
+    combo.tick(time.delta_secs());
+}
+
+/// Hand the fatal moment to the death linger, or go straight to game over when it's off
+fn begin_game_over(death_linger: &mut DeathLinger, virtual_time: &mut Time<Virtual>, next_state: &mut NextState<GameState>, position: Vec3) {
+    if death_linger.enabled {
+        death_linger.begin(position, virtual_time);
+    } else {
+        next_state.set(GameState::GameOver);
+    }
+}
+
 pub fn check_game_over(
     mut commands: Commands,
     player_query: Query<(Entity, &Health, &Transform, &Player), (With<Player>, Without<PendingDespawn>)>,
     mut next_state: ResMut<NextState<GameState>>,
     mut explosion_events: EventWriter<SpawnExplosion>,
+    mut death_linger: ResMut<DeathLinger>,
+    mut virtual_time: ResMut<Time<Virtual>>,
 ) {
-    if let Ok((entity, health, transform, player)) = player_query.single() {
-        if health.0 <= 0 && player.lives <= 0 {
-            explosion_events.write(SpawnExplosion {
-                position: transform.translation,
-                intensity: 2.5,
-                enemy_type: None,
-            });
-            commands.entity(entity).try_insert(AlreadyDespawned).safe_despawn();
-            next_state.set(GameState::GameOver);
+    if let Ok((entity, health, transform, player)) = player_query.single() && health.0 <= 0 && player.lives <= 0 {
+        explosion_events.write(SpawnExplosion {
+            position: transform.translation,
+            intensity: 2.5,
+            enemy_type: None,
+        });
+        commands.entity(entity).try_insert(AlreadyDespawned).safe_despawn();
+        begin_game_over(&mut death_linger, &mut virtual_time, &mut next_state, transform.translation);
+    }
+}
+
+/// Slow-motion zoom towards the fatal hit, then on to game over
+pub fn death_linger_system(
+    mut death_linger: ResMut<DeathLinger>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    if !death_linger.is_lingering() { return; }
+
+    let Ok((mut camera_transform, mut projection)) = camera_query.single_mut() else { return };
+    let finished = death_linger.tick(real_time.delta_secs(), &mut virtual_time);
+    let progress = if finished { 0.0 } else { death_linger.progress() };
+
+    // Screen shake resets the camera each frame, so the pan towards the kill is layered on top
+    let eased = progress * (2.0 - progress);
+    camera_transform.translation += (death_linger.focus.truncate() * eased).extend(0.0);
+    if let Projection::Orthographic(orthographic) = projection.as_mut() {
+        orthographic.scale = 1.0 + (death_linger.zoom - 1.0) * eased;
+    }
+
+    if finished {
+        next_state.set(GameState::GameOver);
+    }
+}
+
+/// Leaving play mid-linger (restart, quit) must restore normal speed and zoom
+pub fn cancel_death_linger(
+    mut death_linger: ResMut<DeathLinger>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut projection_query: Query<&mut Projection, With<Camera2d>>,
+) {
+    if !death_linger.is_lingering() { return; }
+    death_linger.finish(&mut virtual_time);
+    for mut projection in projection_query.iter_mut() {
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale = 1.0;
         }
     }
 }
@@ -638,12 +926,17 @@ pub fn handle_player_hit(
     mut player_query: Query<(Entity, &mut Health, &mut Player, &CellularUpgrades, Option<&CellWallReinforcement>), With<Player>>,
     mut explosion_events: EventWriter<SpawnExplosion>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut damage_log: ResMut<DamageLog>,
+    time: Res<Time>,
+    mut death_linger: ResMut<DeathLinger>,
+    mut virtual_time: ResMut<Time<Virtual>>,
 ) {
     for event in player_hit_events.read() {
         if let Ok((entity, mut health, mut player, upgrades, cell_wall)) = player_query.single_mut() {
             if cell_wall.is_some() || player.invincible_timer > 0.0 { continue; }
 
             health.0 -= event.damage;
+            damage_log.record_taken(time.elapsed_secs(), event.source, event.damage);
             player.invincible_timer = 1.0;
 
             explosion_events.write(SpawnExplosion { position: event.position, intensity: 0.8, enemy_type: None });
@@ -655,7 +948,7 @@ pub fn handle_player_hit(
                     player.invincible_timer = 3.0;
                 } else {
                     commands.entity(entity).try_insert(AlreadyDespawned).safe_despawn();
-                    next_state.set(GameState::GameOver);
+                    begin_game_over(&mut death_linger, &mut virtual_time, &mut next_state, event.position);
                 }
             }
         }
@@ -797,6 +1090,7 @@ pub fn performance_optimization_system(
     particle_query: Query<Entity, (With<Particle>, Without<PendingDespawn>)>,
     explosion_query: Query<Entity, (With<Explosion>, Without<PendingDespawn>)>,
     audio_query: Query<Entity, (With<AudioPlayer>, Without<PendingDespawn>)>,
+    graphics: Res<GraphicsSettings>,
     time: Res<Time>,
     mut cleanup_timer: Local<f32>,
 ) {
@@ -806,10 +1100,12 @@ pub fn performance_optimization_system(
         *cleanup_timer = 0.0;
         
         let total_entities = particle_query.iter().count() + explosion_query.iter().count();
+        let budget = graphics.particle_budget();
         
-        if total_entities > MAX_PARTICLES {
+        if total_entities > budget {
             let particles: Vec<Entity> = particle_query.iter().collect();
-            let remove_count = (total_entities - CLEANUP_PARTICLES_TO).min(particles.len());
+            let cleanup_to = (budget as f32 * CLEANUP_TO_BUDGET_FRACTION) as usize;
+            let remove_count = (total_entities - cleanup_to).min(particles.len());
             
             for &entity in particles.iter().take(remove_count) {
                 commands.entity(entity).try_insert(AlreadyDespawned).safe_despawn();
@@ -821,4 +1117,488 @@ pub fn performance_optimization_system(
             warn!("High audio entity count: {}", audio_count);
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance_systems::DamageDirection;
+    use std::time::Duration;
+
+    /// Headless app running only `collision_system`, with every resource and event it touches
+    fn collision_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<GameScore>()
+            .init_resource::<ComboState>()
+            .init_resource::<RunModifiers>()
+            .init_resource::<ScorePopupConfig>()
+            .init_resource::<KnockbackConfig>()
+            .init_resource::<StatusResistanceConfig>()
+            .init_resource::<DamageCapConfig>()
+            .init_resource::<DamageLog>()
+            .init_resource::<HitStopConfig>()
+            .insert_resource(GameFonts { default_font: Handle::default() })
+            .add_event::<PlayerHit>()
+            .add_event::<EnemyHit>()
+            .add_event::<ProjectileSplit>()
+            .add_event::<EggSacPopped>()
+            .add_event::<AddScreenShake>()
+            .add_event::<SpawnExplosion>()
+            .add_event::<EnemyDied>()
+            .add_event::<FrozenShatter>()
+            .add_event::<AchievementEvent>()
+            .add_systems(Update, collision_system);
+        app
+    }
+
+    fn spawn_player(app: &mut App, invincible_timer: f32) -> Entity {
+        app.world_mut().spawn((
+            Transform::default(),
+            Collider { radius: 16.0 },
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer, cell_membrane_thickness: 1.0 },
+            CriticalHitStats::default(),
+            EvolutionSystem::default(),
+        )).id()
+    }
+
+    fn spawn_enemy(app: &mut App, position: Vec3, health: i32) -> Entity {
+        app.world_mut().spawn((
+            Transform::from_translation(position),
+            Collider { radius: 16.0 },
+            Health(health),
+            Enemy { health, ..default() },
+        )).id()
+    }
+
+    fn spawn_shot(app: &mut App, position: Vec3, damage: i32, friendly: bool) -> Entity {
+        app.world_mut().spawn((
+            Transform::from_translation(position),
+            Collider { radius: 4.0 },
+            Projectile { velocity: Vec2::Y * 100.0, damage, friendly, organic_trail: false },
+        )).id()
+    }
+
+    fn player_hits(app: &App) -> usize {
+        app.world().resource::<Events<PlayerHit>>().len()
+    }
+
+    #[test]
+    fn grace_invincibility_blocks_enemy_hits_but_not_player_shots() {
+        let mut app = collision_app();
+        spawn_player(&mut app, 1.5);
+        let enemy = spawn_enemy(&mut app, Vec3::ZERO, 100);
+        spawn_shot(&mut app, Vec3::ZERO, 10, false);
+        spawn_shot(&mut app, Vec3::ZERO, 10, true);
+        app.update();
+
+        assert_eq!(player_hits(&app), 0);
+        assert!(app.world().get::<Health>(enemy).unwrap().0 < 100);
+    }
+
+    #[test]
+    fn without_invincibility_enemies_hit_the_player() {
+        let mut app = collision_app();
+        spawn_player(&mut app, 0.0);
+        spawn_enemy(&mut app, Vec3::ZERO, 100);
+        spawn_shot(&mut app, Vec3::ZERO, 10, false);
+        app.update();
+
+        assert_eq!(player_hits(&app), 2);
+    }
+
+    #[test]
+    fn ramming_a_boss_is_capped_and_logged_as_collision_damage() {
+        let mut app = collision_app();
+        spawn_player(&mut app, 0.0);
+        let boss = spawn_enemy(&mut app, Vec3::ZERO, 100);
+        app.world_mut().entity_mut(boss).insert(DamageCap);
+        app.update();
+
+        let capped = DamageCapConfig::default().cap(30, 100);
+        assert_eq!(app.world().get::<Health>(boss).unwrap().0, 100 - capped);
+        let entry = app.world().resource::<DamageLog>().entries.back().cloned().unwrap();
+        assert_eq!((entry.source.as_str(), entry.amount), ("Collision", capped));
+    }
+
+    fn spawn_egg_sac(app: &mut App, position: Vec3) -> Entity {
+        app.world_mut().spawn((
+            Transform::from_translation(position),
+            Collider { radius: 11.0 },
+            Health(10),
+            EggSac { hatch_timer: 1.0, hatch_duration: 1.0, offspring: 3, atp_reward: 15 },
+        )).id()
+    }
+
+    fn egg_app() -> App {
+        let mut app = collision_app();
+        app.init_resource::<GameAssets>()
+            .add_event::<SpawnEnemy>()
+            .add_systems(Update, (
+                crate::enemy_systems::egg_sac_hatch_system,
+                crate::currency_systems::egg_sac_reward_system,
+            ).after(collision_system));
+        spawn_player(&mut app, 0.0);
+        app
+    }
+
+    fn hatched_offspring(app: &mut App) -> usize {
+        let mut hatched = 0;
+        for _ in 0..8 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(250));
+            app.update();
+            hatched += app.world_mut().resource_mut::<Events<SpawnEnemy>>().drain().count();
+        }
+        hatched
+    }
+
+    #[test]
+    fn popping_an_egg_sac_before_it_hatches_grants_atp_instead() {
+        let mut app = egg_app();
+        spawn_egg_sac(&mut app, Vec3::new(200.0, 0.0, 0.0));
+        spawn_shot(&mut app, Vec3::new(200.0, 0.0, 0.0), 20, true);
+
+        assert_eq!(hatched_offspring(&mut app), 0);
+        let mut atp = app.world_mut().query::<&ATP>();
+        assert_eq!(atp.iter(app.world()).map(|atp| atp.amount).sum::<u32>(), 15);
+        let entry = app.world().resource::<DamageLog>().entries.front().cloned().unwrap();
+        assert_eq!(entry.target, "Egg Sac");
+    }
+
+    #[test]
+    fn untouched_egg_sac_hatches() {
+        let mut app = egg_app();
+        spawn_egg_sac(&mut app, Vec3::new(200.0, 0.0, 0.0));
+
+        assert_eq!(hatched_offspring(&mut app), 3);
+    }
+
+    fn shatter_app() -> App {
+        let mut app = collision_app();
+        app.init_resource::<GameAssets>()
+            .add_event::<SpawnParticles>()
+            .add_systems(Update, crate::enemy_systems::frozen_shatter_system.after(collision_system));
+        spawn_player(&mut app, 0.0);
+        app
+    }
+
+    /// Kills a 20 health enemy with one shot and returns the friendly shots left flying afterwards
+    fn shards_after_kill(app: &mut App, frozen: bool) -> Vec<i32> {
+        let enemy = spawn_enemy(app, Vec3::new(200.0, 0.0, 0.0), 20);
+        if frozen {
+            app.world_mut().entity_mut(enemy).insert(Frozen { timer: 2.0, original_color: Color::WHITE });
+        }
+        spawn_shot(app, Vec3::new(200.0, 0.0, 0.0), 50, true);
+        app.update();
+
+        assert!(app.world().get::<PendingDespawn>(enemy).is_some());
+        let mut shots = app.world_mut().query_filtered::<&Projectile, Without<PendingDespawn>>();
+        shots.iter(app.world()).filter(|shot| shot.friendly).map(|shot| shot.damage).collect()
+    }
+
+    #[test]
+    fn killing_a_frozen_enemy_shatters_it_into_ice_shards() {
+        let mut app = shatter_app();
+        let shards = shards_after_kill(&mut app, true);
+
+        assert!(!shards.is_empty());
+        assert!(shards.iter().all(|damage| *damage == crate::enemy_systems::shatter_shard_damage(20)));
+        assert_eq!(app.world().resource::<Events<FrozenShatter>>().len(), 1);
+    }
+
+    #[test]
+    fn killing_an_unfrozen_enemy_leaves_no_shards() {
+        let mut app = shatter_app();
+
+        assert!(shards_after_kill(&mut app, false).is_empty());
+        assert!(app.world().resource::<Events<FrozenShatter>>().is_empty());
+    }
+
+    #[test]
+    fn hits_dealt_and_taken_are_logged_against_who_landed_them() {
+        let mut app = collision_app();
+        app.init_resource::<DeathLinger>()
+            .init_resource::<Time<Virtual>>()
+            .init_resource::<NextState<GameState>>()
+            .add_systems(Update, handle_player_hit.after(collision_system));
+        let player = spawn_player(&mut app, 0.0);
+        app.world_mut().entity_mut(player).insert((Health(100), CellularUpgrades::default()));
+        spawn_enemy(&mut app, Vec3::new(200.0, 0.0, 0.0), 100);
+        spawn_shot(&mut app, Vec3::new(200.0, 0.0, 0.0), 10, true);
+        spawn_shot(&mut app, Vec3::ZERO, 10, false);
+        app.update();
+
+        let log = app.world().resource::<DamageLog>();
+        let dealt: Vec<_> = log.entries.iter().filter(|entry| entry.direction == DamageDirection::Dealt).collect();
+        let taken: Vec<_> = log.entries.iter().filter(|entry| entry.direction == DamageDirection::Taken).collect();
+        assert_eq!(dealt.len(), 1);
+        assert_eq!((dealt[0].target.as_str(), dealt[0].amount), (Enemy::default().enemy_type.display_name(), 10));
+        assert_eq!(taken.len(), 1);
+        assert_eq!(taken[0].target, "Player");
+        assert_eq!(taken[0].amount, 100 - app.world().get::<Health>(player).unwrap().0);
+    }
+
+    #[test]
+    fn damage_log_drops_the_oldest_hits_once_full() {
+        let mut log = DamageLog { capacity: 3, ..default() };
+        for amount in 1..=5 {
+            log.record_dealt(amount as f32, "Cytoplasmic Spray", "Viral Particle", amount);
+        }
+
+        let amounts: Vec<i32> = log.entries.iter().map(|entry| entry.amount).collect();
+        assert_eq!(amounts, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn barrier_absorbs_enemy_shots_until_it_expires() {
+        let mut app = collision_app();
+        app.add_systems(Update, crate::powerup_systems::shield_barrier_system.after(collision_system));
+        spawn_player(&mut app, 0.0);
+        let barrier = app.world_mut().spawn((
+            Transform::from_xyz(0.0, 100.0, 0.0),
+            Sprite::default(),
+            ShieldBarrier { timer: 1.0, half_extents: Vec2::new(60.0, 10.0) },
+        )).id();
+
+        let blocked = spawn_shot(&mut app, Vec3::new(20.0, 100.0, 0.0), 10, false);
+        app.update();
+        assert!(app.world().get::<PendingDespawn>(blocked).is_some());
+        assert!(app.world().get::<PendingDespawn>(barrier).is_none());
+
+        app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.1));
+        app.update();
+        assert!(app.world().get::<PendingDespawn>(barrier).is_some());
+
+        let passes = spawn_shot(&mut app, Vec3::new(20.0, 100.0, 0.0), 10, false);
+        app.update();
+        assert!(app.world().get::<PendingDespawn>(passes).is_none());
+        assert_eq!(player_hits(&app), 0);
+    }
+
+    fn volley(pattern: BulletPattern, aim: Vec2) -> Vec<Vec2> {
+        let mut world = World::new();
+        let mut queue = bevy::ecs::world::CommandQueue::default();
+        let shot = HostileShot { color: Color::WHITE, speed: 300.0, damage: 10, organic_trail: false };
+        fire_pattern(&mut Commands::new(&mut queue, &world), &Handle::default(), pattern, Vec3::new(0.0, 200.0, 0.0), aim, 0.0, shot);
+        queue.apply(&mut world);
+
+        let mut shots = world.query::<&Projectile>();
+        shots.iter(&world).inspect(|shot| assert!(!shot.friendly)).map(|shot| shot.velocity).collect()
+    }
+
+    #[test]
+    fn ring_spawns_evenly_spaced_shots_all_round() {
+        let velocities = volley(BulletPattern::Ring { count: 8 }, Vec2::NEG_Y);
+        assert_eq!(velocities.len(), 8);
+
+        let mut angles: Vec<f32> = velocities.iter().map(|velocity| velocity.to_angle()).collect();
+        angles.sort_by(f32::total_cmp);
+        let step = std::f32::consts::TAU / 8.0;
+        for pair in angles.windows(2) {
+            assert!((pair[1] - pair[0] - step).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn aimed_burst_heads_at_the_player_with_rising_speed() {
+        let to_player = Vec2::new(-150.0, -400.0);
+        let velocities = volley(BulletPattern::AimedBurst { count: 3, speed_step: 40.0 }, to_player);
+        assert_eq!(velocities.len(), 3);
+
+        for velocity in &velocities {
+            assert!(velocity.normalize().dot(to_player.normalize()) > 0.999);
+        }
+        let mut speeds: Vec<f32> = velocities.iter().map(|velocity| velocity.length()).collect();
+        speeds.sort_by(f32::total_cmp);
+        speeds.dedup();
+        assert_eq!(speeds.len(), 3);
+    }
+
+    /// Where a 100 health enemy ends up after one upward shot with the given knockback lands on it
+    fn position_after_hit(knockback: Option<f32>) -> Vec3 {
+        let mut app = collision_app();
+        spawn_player(&mut app, 0.0);
+        let enemy = spawn_enemy(&mut app, Vec3::new(200.0, 0.0, 0.0), 100);
+        let shot = spawn_shot(&mut app, Vec3::new(200.0, -4.0, 0.0), 20, true);
+        if let Some(strength) = knockback {
+            app.world_mut().entity_mut(shot).insert(Knockback { strength });
+        }
+        app.update();
+
+        assert!(app.world().get::<Health>(enemy).unwrap().0 < 100);
+        app.world().get::<Transform>(enemy).unwrap().translation
+    }
+
+    #[test]
+    fn knockback_shots_push_enemies_along_the_shot_and_zero_knockback_does_not() {
+        let pushed = position_after_hit(Some(1.0));
+        assert_eq!(pushed.x, 200.0);
+        assert!(pushed.y > 0.0);
+
+        assert_eq!(position_after_hit(Some(0.0)), Vec3::new(200.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn fatal_hit_lingers_in_slow_motion_before_game_over() {
+        use bevy::state::app::StatesPlugin;
+
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .insert_state(GameState::Playing)
+            .init_resource::<Time<Virtual>>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<DeathLinger>()
+            .add_event::<SpawnExplosion>()
+            .add_systems(Update, (check_game_over, death_linger_system).chain());
+        app.world_mut().spawn((Camera2d, Projection::Orthographic(OrthographicProjection::default_2d()), Transform::default()));
+        app.world_mut().spawn((
+            Player { speed: 400.0, roll_factor: 0.3, lives: 0, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+            Health(0),
+            Transform::from_xyz(80.0, -200.0, 0.0),
+        ));
+        let linger = app.world().resource::<DeathLinger>().clone();
+
+        app.update();
+        app.update();
+        assert!(app.world().resource::<DeathLinger>().is_lingering());
+        assert_eq!(app.world().resource::<Time<Virtual>>().relative_speed(), linger.time_scale);
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Playing);
+
+        // Real time's first update only sets its baseline
+        let mut real_time = app.world_mut().resource_mut::<Time<Real>>();
+        real_time.update_with_duration(std::time::Duration::ZERO);
+        real_time.update_with_duration(std::time::Duration::from_secs_f32(linger.duration + 0.1));
+        app.update();
+        app.update();
+        assert!(!app.world().resource::<DeathLinger>().is_lingering());
+        assert_eq!(app.world().resource::<Time<Virtual>>().relative_speed(), 1.0);
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::GameOver);
+    }
+
+    fn chemotaxis_sensitivity_on_wave(wave: u32) -> f32 {
+        let mut app = App::new();
+        app.insert_resource(WaveManager { current_wave: wave, ..default() })
+            .init_resource::<RunModifiers>()
+            .init_resource::<EcosystemCollapse>()
+            .init_resource::<AiTierConfig>()
+            .init_resource::<BossIntroConfig>()
+            .init_resource::<EnemyArchetypes>()
+            .init_resource::<GameAssets>()
+            .add_event::<SpawnEnemy>()
+            .add_systems(Update, spawn_enemy_system);
+        app.world_mut().send_event(SpawnEnemy {
+            position: Vec3::new(0.0, 300.0, 0.0),
+            ai_type: EnemyAI::Chemotaxis { target_chemical: ChemicalType::PlayerPheromones, sensitivity: 1.0, current_direction: Vec2::NEG_Y },
+            enemy_type: EnemyType::ViralParticle,
+        });
+        app.update();
+
+        let enemy = app.world_mut().query::<&Enemy>().single(app.world()).unwrap();
+        let EnemyAI::Chemotaxis { sensitivity, .. } = enemy.ai_type else { panic!("spawned with a different AI") };
+        sensitivity
+    }
+
+    #[test]
+    fn veteran_chemotaxis_cells_track_more_sensitively_than_early_ones() {
+        let config = AiTierConfig::default();
+        let late_wave = config.waves_per_tier * 3 + 1;
+        assert!(config.tier_for_wave(late_wave) > config.tier_for_wave(1));
+
+        assert!(chemotaxis_sensitivity_on_wave(late_wave) > chemotaxis_sensitivity_on_wave(1));
+    }
+
+    #[test]
+    fn boss_holds_fire_through_its_intro_and_attacks_once_it_ends() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<GameAssets>()
+            .insert_resource(GameFonts { default_font: Handle::default() })
+            .insert_resource(BossIntroConfig { duration: 1.0, ..default() })
+            .add_event::<AddScreenShake>()
+            .add_event::<TelegraphCue>()
+            .add_systems(Update, (crate::enemy_systems::boss_intro_system, enemy_shooting).chain());
+        spawn_player(&mut app, 0.0);
+        let boss = app.world_mut().spawn((
+            Enemy { ai_type: EnemyAI::MiniBoss { pattern: 0, timer: 0.0 }, enemy_type: EnemyType::InfectedMacrophage, ..default() },
+            Transform::from_xyz(0.0, 200.0, 0.0),
+            BossIntro::new(1.0),
+        )).id();
+        let hostile_shots = |app: &mut App| {
+            app.world_mut().query::<&Projectile>().iter(app.world()).filter(|shot| !shot.friendly).count()
+        };
+
+        for _ in 0..3 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(250));
+            app.update();
+            assert!(app.world().get::<BossIntro>(boss).is_some());
+            assert_eq!(hostile_shots(&mut app), 0);
+        }
+
+        for _ in 0..2 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(250));
+            app.update();
+        }
+        assert!(app.world().get::<BossIntro>(boss).is_none());
+        assert!(hostile_shots(&mut app) > 0);
+    }
+
+    #[test]
+    fn heavy_hits_halt_the_enemy_for_the_configured_frames_then_release_it() {
+        use crate::enemy_systems::{move_enemies, hit_stop_system, GridFormation, CoralAvoidanceConfig};
+
+        let mut app = collision_app();
+        app.insert_resource(HitStopConfig { heavy_hit_damage: 30, min_frames: 3, max_frames: 3, ..default() })
+            .init_resource::<FluidEnvironment>()
+            .init_resource::<ChemicalEnvironment>()
+            .init_resource::<GridFormation>()
+            .init_resource::<WaveManager>()
+            .init_resource::<WaveSpeedRamp>()
+            .init_resource::<EnemyArchetypes>()
+            .init_resource::<CoralAvoidanceConfig>()
+            .add_event::<BossPhaseChanged>()
+            .add_event::<TelegraphCue>()
+            .add_systems(Update, (move_enemies, hit_stop_system).chain().after(collision_system));
+        let player = spawn_player(&mut app, 0.0);
+        app.world_mut().get_mut::<CriticalHitStats>(player).unwrap().chance = 0.0;
+        let enemy = spawn_enemy(&mut app, Vec3::new(200.0, 0.0, 0.0), 100);
+        spawn_shot(&mut app, Vec3::new(200.0, 0.0, 0.0), 40, true);
+        let step = |app: &mut App| {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(16));
+            app.update();
+            app.world().get::<Transform>(enemy).unwrap().translation
+        };
+
+        // The hit frame may knock the enemy back, after that it holds still until the stop runs out
+        let struck_at = step(&mut app);
+        for _ in 1..3 {
+            assert_eq!(step(&mut app), struck_at);
+        }
+        assert!(app.world().get::<HitStop>(enemy).is_none());
+        assert!(step(&mut app).y < struck_at.y);
+    }
+
+    /// Font size and color of the +points popup from a kill at the given score multiplier
+    fn kill_popup_at(multiplier: f32) -> (f32, Srgba) {
+        let mut app = collision_app();
+        app.world_mut().resource_mut::<GameScore>().score_multiplier = multiplier;
+        spawn_player(&mut app, 0.0);
+        spawn_enemy(&mut app, Vec3::new(200.0, 0.0, 0.0), 5);
+        spawn_shot(&mut app, Vec3::new(200.0, 0.0, 0.0), 50, true);
+        app.update();
+
+        let mut popups = app.world_mut().query::<(&Text2d, &TextFont, &TextColor)>();
+        popups.iter(app.world())
+            .find(|(text, ..)| text.0.starts_with('+'))
+            .map(|(_, font, color)| (font.font_size, color.0.to_srgba()))
+            .expect("kill should raise a score popup")
+    }
+
+    #[test]
+    fn score_popups_grow_and_warm_with_the_multiplier() {
+        let (calm_size, calm_color) = kill_popup_at(1.0);
+        let (hot_size, hot_color) = kill_popup_at(4.0);
+
+        assert!(hot_size > calm_size);
+        assert!(hot_color.red >= calm_color.red && hot_color.blue < calm_color.blue);
+    }
+}
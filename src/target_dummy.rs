@@ -0,0 +1,148 @@
+// src/target_dummy.rs - Practice target dummy that measures real DPS
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::components::*;
+use crate::resources::*;
+use crate::enemy_types::*;
+use crate::input::*;
+use crate::despawn::*;
+
+// ===== CONSTANTS =====
+const DUMMY_HEALTH: i32 = 1_000_000;
+const DUMMY_DAMAGE_WINDOW: f32 = 5.0;    // Seconds of damage averaged into the readout
+const DUMMY_POSITION: Vec3 = Vec3::new(0.0, 180.0, 0.0);
+const DUMMY_RADIUS: f32 = 40.0;
+
+// ===== COMPONENTS =====
+/// Stationary enemy that never dies, it heals back to full every frame and logs what it lost
+#[derive(Component)]
+pub struct TargetDummy {
+    pub damage_window: f32,
+    pub samples: VecDeque<(f32, u32)>, // (time, damage taken that frame)
+    pub total_damage: u64,
+}
+
+impl TargetDummy {
+    pub fn new(damage_window: f32) -> Self {
+        Self {
+            damage_window,
+            samples: VecDeque::new(),
+            total_damage: 0,
+        }
+    }
+
+    pub fn record(&mut self, now: f32, damage: u32) {
+        if damage == 0 { return; }
+        self.samples.push_back((now, damage));
+        self.total_damage += damage as u64;
+    }
+
+    /// Damage per second over the trailing window, older samples are dropped
+    pub fn dps(&mut self, now: f32) -> f32 {
+        while self.samples.front().is_some_and(|(time, _)| now - time > self.damage_window) {
+            self.samples.pop_front();
+        }
+        let windowed: u32 = self.samples.iter().map(|(_, damage)| damage).sum();
+        windowed as f32 / self.damage_window
+    }
+}
+
+#[derive(Component)]
+pub struct DpsReadout;
+
+// ===== SYSTEMS =====
+/// Toggle the practice dummy and its readout (debug builds only)
+pub fn debug_spawn_target_dummy(
+    mut commands: Commands,
+    input_manager: Res<InputManager>,
+    dummy_query: Query<Entity, With<TargetDummy>>,
+    readout_query: Query<Entity, With<DpsReadout>>,
+    assets: Option<Res<GameAssets>>,
+    fonts: Res<GameFonts>,
+) {
+    if !input_manager.debug_enabled { return; }
+    if !input_manager.just_pressed(InputAction::DebugSpawnTargetDummy) { return; }
+
+    if !dummy_query.is_empty() {
+        for entity in dummy_query.iter().chain(readout_query.iter()) {
+            commands.entity(entity).safe_despawn();
+        }
+        return;
+    }
+
+    let Some(assets) = assets else { return };
+
+    commands.spawn((
+        Sprite {
+            image: assets.biofilm_colony_texture.clone(),
+            color: Color::srgb(0.8, 0.8, 0.8),
+            custom_size: Some(Vec2::splat(DUMMY_RADIUS * 2.0)),
+            ..default()
+        },
+        Transform::from_translation(DUMMY_POSITION),
+        Enemy {
+            ai_type: EnemyAI::Static,
+            health: DUMMY_HEALTH,
+            speed: 0.0,
+            enemy_type: EnemyType::BiofilmColony,
+            colony_id: None,
+            chemical_signature: EnemyType::BiofilmColony.get_chemical_signature(),
+        },
+        Collider { radius: DUMMY_RADIUS },
+        Health(DUMMY_HEALTH),
+        TargetDummy::new(DUMMY_DAMAGE_WINDOW),
+    ));
+
+    commands.spawn((
+        Text::new("DPS: 0"),
+        TextFont { font: fonts.default_font.clone(), font_size: 18.0, ..default() },
+        TextColor(Color::srgb(1.0, 0.9, 0.4)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(160.0),
+            right: Val::Px(20.0),
+            ..default()
+        },
+        DpsReadout,
+    ));
+}
+
+pub fn target_dummy_system(
+    mut commands: Commands,
+    mut dummy_query: Query<(&mut Health, &mut TargetDummy), Without<PendingDespawn>>,
+    mut readout_query: Query<(Entity, &mut Text), With<DpsReadout>>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+
+    // The dummy is cleared with the other enemies on reset, take the readout with it
+    if dummy_query.is_empty() {
+        for (entity, _) in readout_query.iter() {
+            commands.entity(entity).safe_despawn();
+        }
+        return;
+    }
+
+    for (mut health, mut dummy) in dummy_query.iter_mut() {
+        let damage = (DUMMY_HEALTH - health.0).max(0) as u32;
+        dummy.record(now, damage);
+        health.0 = DUMMY_HEALTH;
+
+        let dps = dummy.dps(now);
+        for (_, mut text) in readout_query.iter_mut() {
+            **text = format!("DPS: {:.0}  ({}s window)\nTotal: {}", dps, dummy.damage_window, dummy.total_damage);
+        }
+    }
+}
+
+// ===== PLUGIN =====
+pub struct TargetDummyPlugin;
+
+impl Plugin for TargetDummyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            debug_spawn_target_dummy,
+            target_dummy_system,
+        ).run_if(in_state(IsPaused::Running)));
+    }
+}
@@ -680,32 +680,30 @@ pub fn update_tidal_wave_effects(
         sprite.color.set_alpha(alpha);
         
         // Spawn wave particles at the edge
-        if let Some(assets) = &assets {
-            if (wave.wave_phase * 4.0) % (2.0 * std::f32::consts::PI) < 0.5 {
-                for i in 0..8 {
-                    let angle = (i as f32 / 8.0) * TAU;
-                    let edge_pos = transform.translation.truncate() + 
-                        Vec2::from_angle(angle) * wave.propagation_distance;
+        if let Some(assets) = &assets && (wave.wave_phase * 4.0) % (2.0 * std::f32::consts::PI) < 0.5 {
+            for i in 0..8 {
+                let angle = (i as f32 / 8.0) * TAU;
+                let edge_pos = transform.translation.truncate() + 
+                    Vec2::from_angle(angle) * wave.propagation_distance;
                     
-                    commands.spawn((
-                        Sprite {
-                            image: assets.particle_texture.clone(),
-                            color: Color::srgba(0.8, 0.9, 1.0, 0.6),
-                            custom_size: Some(Vec2::splat(4.0)),
-                            ..default()
-                        },
-                        Transform::from_translation(edge_pos.extend(0.3)),
-                        Particle {
-                            velocity: Vec2::from_angle(angle) * 50.0,
-                            lifetime: 0.0,
-                            max_lifetime: 2.0,
-                            size: 4.0,
-                            fade_rate: 1.0,
-                            bioluminescent: true,
-                            drift_pattern: DriftPattern::Floating,
-                        },
-                    ));
-                }
+                commands.spawn((
+                    Sprite {
+                        image: assets.particle_texture.clone(),
+                        color: Color::srgba(0.8, 0.9, 1.0, 0.6),
+                        custom_size: Some(Vec2::splat(4.0)),
+                        ..default()
+                    },
+                    Transform::from_translation(edge_pos.extend(0.3)),
+                    Particle {
+                        velocity: Vec2::from_angle(angle) * 50.0,
+                        lifetime: 0.0,
+                        max_lifetime: 2.0,
+                        size: 4.0,
+                        fade_rate: 1.0,
+                        bioluminescent: true,
+                        drift_pattern: DriftPattern::Floating,
+                    },
+                ));
             }
         }
     }
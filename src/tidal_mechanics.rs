@@ -14,6 +14,7 @@ pub fn advanced_tidal_system(
     mut chemical_environment: ResMut<ChemicalEnvironment>,
     mut tidal_events: EventWriter<TidalEvent>,
     mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    collapse: Res<EcosystemCollapse>,
     time: Res<Time>,
     mut last_king_tide: Local<f32>,
     mut current_tide_phase: Local<TidePhase>,
@@ -64,6 +65,7 @@ pub fn advanced_tidal_system(
         &mut current_generator,
         &mut chemical_environment,
         tide_strength,
+        collapse.turbulence_multiplier(),
         time.delta_secs(),
     );
     
@@ -80,7 +82,7 @@ pub fn advanced_tidal_system(
     }
 }
 
-fn trigger_king_tide(
+pub fn trigger_king_tide(
     tidal_physics: &mut TidalPoolPhysics,
     tidal_events: &mut EventWriter<TidalEvent>,
 ) {
@@ -101,6 +103,7 @@ fn apply_tidal_effects(
     mut current_generator: &mut CurrentGenerator,
     mut chemical_environment: &mut ChemicalEnvironment,
     tide_strength: f32,
+    collapse_turbulence: f32,
     delta_time: f32,
 ) {
     // 1. CURRENT FIELD UPDATES - Modify the flow field, not direct positions
@@ -125,10 +128,11 @@ fn apply_tidal_effects(
     }
     
     // 2. TURBULENCE - Safe to modify
-    fluid_environment.turbulence_intensity = 0.3 + tide_strength.abs() * 0.4;
+    fluid_environment.turbulence_intensity = fluid_environment.base_turbulence + tide_strength.abs() * 0.4;
     if tidal_physics.king_tide_active {
         fluid_environment.turbulence_intensity *= 1.5; // Reduced from 2.0
     }
+    fluid_environment.turbulence_intensity *= collapse_turbulence;
     
     // 3. CHEMICAL ZONES - Gentle movement instead of aggressive pushing
     for zone in &mut chemical_environment.ph_zones {
@@ -357,6 +361,25 @@ fn spawn_current_reversal_effect(commands: &mut Commands, assets: &Option<Res<Ga
     }
 }
 
+const KING_TIDE_INTERVAL: f32 = 90.0;
+
+/// Start a king tide every `KING_TIDE_INTERVAL` seconds of calm water. Runs in play on its own,
+/// the full `advanced_tidal_system` cycle is still switched off
+pub fn king_tide_schedule_system(
+    mut tidal_physics: ResMut<TidalPoolPhysics>,
+    mut tidal_state: ResMut<TidalState>,
+    mut tidal_events: EventWriter<TidalEvent>,
+    time: Res<Time>,
+) {
+    if tidal_physics.king_tide_active { return; }
+
+    tidal_state.last_king_tide += time.delta_secs();
+    if tidal_state.last_king_tide > KING_TIDE_INTERVAL {
+        tidal_state.last_king_tide = 0.0;
+        trigger_king_tide(&mut tidal_physics, &mut tidal_events);
+    }
+}
+
 // Update king tide state
 pub fn update_king_tide(
     mut tidal_physics: ResMut<TidalPoolPhysics>,
@@ -402,4 +425,129 @@ pub fn update_tidal_debris(
         // Debris slows down over time
         debris.velocity *= 0.995;
     }
-}
\ No newline at end of file
+}
+// ===== KING TIDE CONTROL DISTORTION =====
+#[derive(Component)]
+pub struct ControlDistortionWarning;
+
+pub fn control_distortion_system(
+    mut commands: Commands,
+    mut distortion: ResMut<ControlDistortion>,
+    tidal_physics: Res<TidalPoolPhysics>,
+    mut shake_events: EventWriter<AddScreenShake>,
+    mut warning_query: Query<(Entity, &mut Text, &mut TextColor), With<ControlDistortionWarning>>,
+    time: Res<Time>,
+) {
+    match distortion.update(tidal_physics.king_tide_active, time.delta_secs()) {
+        Some(DistortionTransition::WarningStarted) => {
+            let message = match distortion.mode {
+                DistortionMode::Invert => "CURRENT SURGE - CONTROLS REVERSING!",
+                DistortionMode::Dampen => "CURRENT SURGE - CONTROLS DRAGGING!",
+            };
+            commands.spawn((
+                Text::new(message),
+                TextFont { font_size: 26.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.8, 0.3)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(30.0),
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                TextLayout::new_with_justify(JustifyText::Center),
+                ControlDistortionWarning,
+            ));
+            shake_events.write(AddScreenShake { amount: 0.3 });
+        }
+        Some(DistortionTransition::Started) => {
+            shake_events.write(AddScreenShake { amount: 0.6 });
+        }
+        Some(DistortionTransition::Ended) | None => {}
+    }
+
+    match distortion.phase {
+        DistortionPhase::Warning { timer } => {
+            // Flash the warning faster as the distortion closes in
+            let flash = (timer * 12.0).sin() * 0.5 + 0.5;
+            for (_, _, mut color) in warning_query.iter_mut() {
+                color.0 = Color::srgba(1.0, 0.8, 0.3, 0.4 + flash * 0.6);
+            }
+        }
+        DistortionPhase::Active { timer } => {
+            for (_, mut text, mut color) in warning_query.iter_mut() {
+                **text = format!("CONTROLS DISTORTED {:.1}s", timer.max(0.0));
+                color.0 = Color::srgb(1.0, 0.4, 0.3);
+            }
+        }
+        DistortionPhase::Idle | DistortionPhase::Spent => {
+            for (entity, _, _) in warning_query.iter() {
+                commands.entity(entity).safe_despawn();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{InputAction, InputManager};
+    use crate::player::biological_movement_system;
+    use std::time::Duration;
+
+    fn king_tide_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<InputManager>()
+            .init_resource::<FluidEnvironment>()
+            .init_resource::<TidalPoolPhysics>()
+            .insert_resource(ControlDistortion { warning_time: 0.15, ..default() })
+            .add_event::<AddScreenShake>()
+            .add_systems(Update, (control_distortion_system, biological_movement_system).chain());
+        app.world_mut().resource_mut::<InputManager>().analog_values.insert(InputAction::MoveRight, 1.0);
+        let player = app.world_mut().spawn((
+            Transform::default(),
+            FluidDynamics { velocity: Vec2::ZERO, viscosity_resistance: 0.8, buoyancy: 0.0, current_influence: 0.0 },
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+        )).id();
+        (app, player)
+    }
+
+    /// Horizontal velocity one frame of holding right produces from a standstill
+    fn push_right(app: &mut App, player: Entity) -> f32 {
+        app.world_mut().get_mut::<FluidDynamics>(player).unwrap().velocity = Vec2::ZERO;
+        app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(100));
+        app.update();
+        app.world().get::<FluidDynamics>(player).unwrap().velocity.x
+    }
+
+    #[test]
+    fn king_tide_reverses_input_after_the_warning_and_releases_it_when_the_tide_ends() {
+        let (mut app, player) = king_tide_app();
+        assert!(push_right(&mut app, player) > 0.0);
+
+        app.world_mut().resource_mut::<TidalPoolPhysics>().king_tide_active = true;
+        assert!(push_right(&mut app, player) > 0.0, "the warning plays before controls flip");
+        assert!(push_right(&mut app, player) > 0.0);
+        assert!(push_right(&mut app, player) < 0.0);
+        assert!(app.world().resource::<ControlDistortion>().is_active());
+
+        app.world_mut().resource_mut::<TidalPoolPhysics>().king_tide_active = false;
+        assert!(push_right(&mut app, player) > 0.0);
+        assert!(!app.world().resource::<ControlDistortion>().is_active());
+    }
+
+    #[test]
+    fn dampen_mode_slows_input_without_flipping_it() {
+        let (mut app, player) = king_tide_app();
+        let free = push_right(&mut app, player);
+        app.world_mut().resource_mut::<ControlDistortion>().mode = DistortionMode::Dampen;
+        app.world_mut().resource_mut::<TidalPoolPhysics>().king_tide_active = true;
+        for _ in 0..3 {
+            push_right(&mut app, player);
+        }
+
+        let dampened = push_right(&mut app, player);
+        assert!(dampened > 0.0 && dampened < free * 0.5);
+    }
+}
@@ -0,0 +1,223 @@
+// src/time_attack.rs - Score attack against a countdown, kills and cleared waves buy more time
+use bevy::prelude::*;
+use crate::resources::*;
+use crate::achievements::*;
+use crate::despawn::*;
+
+// ===== CONSTANTS =====
+const TIME_ATTACK_STARTING_TIME: f32 = 90.0;
+const TIME_ATTACK_KILL_BONUS: f32 = 1.0;
+const TIME_ATTACK_WAVE_BONUS: f32 = 10.0;
+const TIME_ATTACK_MAX_TIME: f32 = 180.0;
+const TIME_ATTACK_WARNING_TIME: f32 = 10.0; // Clock turns red below this
+
+// ===== TIME ATTACK STATE =====
+/// Countdown for the time attack mode, the run ends when `remaining` hits zero
+#[derive(Resource, Clone)]
+pub struct TimeAttack {
+    pub enabled: bool,
+    pub remaining: f32,
+    pub starting_time: f32,
+    pub kill_bonus: f32,
+    pub wave_bonus: f32,
+    pub max_time: f32, // Bonuses can't bank more than this
+}
+
+impl Default for TimeAttack {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            remaining: TIME_ATTACK_STARTING_TIME,
+            starting_time: TIME_ATTACK_STARTING_TIME,
+            kill_bonus: TIME_ATTACK_KILL_BONUS,
+            wave_bonus: TIME_ATTACK_WAVE_BONUS,
+            max_time: TIME_ATTACK_MAX_TIME,
+        }
+    }
+}
+
+impl TimeAttack {
+    pub fn start(&mut self) {
+        self.remaining = self.starting_time;
+    }
+
+    pub fn add_time(&mut self, seconds: f32) {
+        self.remaining = (self.remaining + seconds).min(self.max_time);
+    }
+
+    pub fn register_kill(&mut self) {
+        self.add_time(self.kill_bonus);
+    }
+
+    pub fn register_wave_cleared(&mut self) {
+        self.add_time(self.wave_bonus);
+    }
+
+    /// Count down, returns true on the frame the clock runs out
+    pub fn tick(&mut self, dt: f32) -> bool {
+        if !self.enabled || self.remaining <= 0.0 { return false; }
+        self.remaining = (self.remaining - dt).max(0.0);
+        self.remaining <= 0.0
+    }
+
+    pub fn label(&self) -> String {
+        if self.enabled {
+            format!("Time Attack ({:.0}s)", self.starting_time)
+        } else {
+            "Standard".to_string()
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct TimeAttackClock;
+
+#[derive(Component)]
+pub struct TimeAttackText;
+
+// ===== SYSTEMS =====
+pub fn start_time_attack(mut time_attack: ResMut<TimeAttack>) {
+    time_attack.start();
+}
+
+pub fn time_attack_system(
+    mut time_attack: ResMut<TimeAttack>,
+    mut achievement_events: EventReader<AchievementEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+    time: Res<Time>,
+) {
+    if !time_attack.enabled {
+        achievement_events.clear();
+        return;
+    }
+
+    for event in achievement_events.read() {
+        match event {
+            AchievementEvent::EnemyKilled(_) => time_attack.register_kill(),
+            AchievementEvent::WaveCompleted { .. } => time_attack.register_wave_cleared(),
+            _ => {}
+        }
+    }
+
+    if time_attack.tick(time.delta_secs()) {
+        info!("Time attack clock ran out");
+        next_state.set(GameState::GameOver);
+    }
+}
+
+pub fn time_attack_hud_system(
+    mut commands: Commands,
+    time_attack: Res<TimeAttack>,
+    mut clock_query: Query<(&mut Text, &mut TextColor), With<TimeAttackClock>>,
+    fonts: Res<GameFonts>,
+) {
+    if !time_attack.enabled { return; }
+
+    let color = if time_attack.remaining <= TIME_ATTACK_WARNING_TIME {
+        Color::srgb(1.0, 0.3, 0.3)
+    } else {
+        Color::srgb(0.9, 0.95, 1.0)
+    };
+    let readout = format!("TIME {:.1}", time_attack.remaining);
+
+    if clock_query.is_empty() {
+        commands.spawn((
+            Text::new(readout),
+            TextFont { font: fonts.default_font.clone(), font_size: 28.0, ..default() },
+            TextColor(color),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(12.0),
+                left: Val::Percent(46.0),
+                ..default()
+            },
+            TimeAttackClock,
+        ));
+        return;
+    }
+
+    for (mut text, mut text_color) in clock_query.iter_mut() {
+        **text = readout.clone();
+        text_color.0 = color;
+    }
+}
+
+pub fn cleanup_time_attack_clock(
+    mut commands: Commands,
+    clock_query: Query<Entity, With<TimeAttackClock>>,
+) {
+    for entity in clock_query.iter() {
+        commands.entity(entity).safe_despawn();
+    }
+}
+
+pub fn update_time_attack_text(
+    time_attack: Res<TimeAttack>,
+    mut text_query: Query<&mut Text, With<TimeAttackText>>,
+) {
+    if !time_attack.is_changed() { return; }
+
+    for mut text in text_query.iter_mut() {
+        **text = format!("Mode: {}", time_attack.label());
+    }
+}
+
+// ===== PLUGIN =====
+pub struct TimeAttackPlugin;
+
+impl Plugin for TimeAttackPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<TimeAttack>()
+            .add_systems(OnExit(GameState::TitleScreen), start_time_attack)
+            .add_systems(OnExit(GameState::GameOver), start_time_attack)
+            .add_systems(OnExit(GameState::Playing), cleanup_time_attack_clock)
+            .add_systems(Update, update_time_attack_text.run_if(in_state(GameState::TitleScreen)))
+            .add_systems(Update, time_attack_hud_system.run_if(in_state(GameState::Playing)))
+            .add_systems(Update, time_attack_system.run_if(in_state(IsPaused::Running)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use std::time::Duration;
+
+    fn time_attack_app(remaining: f32) -> App {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<Time>()
+            .insert_resource(TimeAttack { enabled: true, remaining, ..default() })
+            .add_event::<AchievementEvent>()
+            .add_systems(Update, time_attack_system);
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Playing);
+        app.update();
+        app
+    }
+
+    #[test]
+    fn kills_buy_back_the_configured_bonus() {
+        let mut app = time_attack_app(30.0);
+        let kill_bonus = app.world().resource::<TimeAttack>().kill_bonus;
+
+        app.world_mut().send_event(AchievementEvent::EnemyKilled("Viral Particle".to_string()));
+        app.world_mut().send_event(AchievementEvent::EnemyKilled("Viral Particle".to_string()));
+        app.update();
+
+        assert_eq!(app.world().resource::<TimeAttack>().remaining, 30.0 + 2.0 * kill_bonus);
+    }
+
+    #[test]
+    fn running_out_of_time_ends_the_run() {
+        let mut app = time_attack_app(0.5);
+
+        app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.6));
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<TimeAttack>().remaining, 0.0);
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::GameOver);
+    }
+}
@@ -3,11 +3,16 @@ use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use crate::components::*;
 use crate::resources::*;
 use crate::wave_systems::*;
+use crate::target_dummy::TargetDummy;
 use crate::enemy_types::{Enemy};
 use crate::despawn::*;
+use crate::loadout_presets::*;
+use crate::balance_systems::BalanceAnalyzer;
+use crate::input::{InputManager, InputAction};
+use crate::biological_systems::{sample_ph, sample_oxygen, vent_heat_intensity};
+use cosmic_ui::{UIUpdateScheduler, UIUpdateCommand, StatusIndicator, StatusState, StatusAnimation};
 
 // ===== CONSTANTS =====
-const UI_FONT_SIZE_LARGE: f32 = 48.0;
 const UI_FONT_SIZE_MEDIUM: f32 = 24.0;
 const UI_FONT_SIZE_SMALL: f32 = 16.0;
 const UI_FONT_SIZE_TINY: f32 = 12.0;
@@ -194,6 +199,8 @@ pub fn enhanced_evolution_ui_with_limits(
     player_query: Query<(&Transform, &ATP, &UpgradeLimits), With<Player>>,
     existing_ui_query: Query<Entity, With<EvolutionUI>>,
     fonts: Res<GameFonts>,
+    loadouts: Res<LoadoutPresets>,
+    (cost_config, cost_hints, balance_analyzer): (Res<CostConfig>, Res<AtpCostHintConfig>, Option<Res<BalanceAnalyzer>>),
 ) {
     if let Ok((player_transform, atp, limits)) = player_query.single() {
         let near_chamber = chamber_query.iter().any(|chamber_transform| {
@@ -201,7 +208,7 @@ pub fn enhanced_evolution_ui_with_limits(
         });
 
         match (near_chamber, existing_ui_query.single()) {
-            (true, Err(_)) => spawn_evolution_ui(&mut commands, atp.amount, &fonts, limits),
+            (true, Err(_)) => spawn_evolution_ui(&mut commands, atp.amount, &fonts, limits, &loadouts, &cost_config, afford_rate(&cost_hints, balance_analyzer.as_deref())),
             (false, Ok(entity)) => { 
                 commands.entity(entity).safe_despawn();
             },
@@ -259,6 +266,8 @@ pub fn update_evolution_ui(
     player_query: Query<(&Transform, &ATP, &UpgradeLimits), With<Player>>,
     existing_ui_query: Query<Entity, With<EvolutionUI>>,
     fonts: Res<GameFonts>,
+    loadouts: Res<LoadoutPresets>,
+    (cost_config, cost_hints, balance_analyzer): (Res<CostConfig>, Res<AtpCostHintConfig>, Option<Res<BalanceAnalyzer>>),
 ) {
     if let Ok((player_transform, atp, limits)) = player_query.single() {
         let near_chamber = chamber_query.iter().any(|chamber_transform| {
@@ -268,7 +277,7 @@ pub fn update_evolution_ui(
         match (near_chamber, existing_ui_query.single()) {
             (true, Err(_)) => {
                 info!("within range, spawning ui");
-                spawn_evolution_ui(&mut commands, atp.amount, &fonts, limits)
+                spawn_evolution_ui(&mut commands, atp.amount, &fonts, limits, &loadouts, &cost_config, afford_rate(&cost_hints, balance_analyzer.as_deref()))
             },
             (false, Ok(entity)) => { 
                 info!("outside range, despawning ui");
@@ -285,19 +294,25 @@ pub fn update_evolution_ui(
 // Unlock items from drops or achievenets, or achieve via unlocks
 // Split values across two memory locations to make cheating more difficult
 
-fn spawn_evolution_ui(commands: &mut Commands, atp_amount: u32, fonts: &GameFonts, limits: &UpgradeLimits) {
+/// ATP income to project affordability from, None hides the hints
+fn afford_rate(cost_hints: &AtpCostHintConfig, balance_analyzer: Option<&BalanceAnalyzer>) -> Option<f32> {
+    if !cost_hints.enabled { return None; }
+    balance_analyzer.map(|analyzer| analyzer.atp_economy.generation_rate_per_second)
+}
+
+fn spawn_evolution_ui(commands: &mut Commands, atp_amount: u32, fonts: &GameFonts, limits: &UpgradeLimits, loadouts: &LoadoutPresets, cost_config: &CostConfig, afford_rate: Option<f32>) {
     let evolutions = [
-        ("1   Membrane Reinforcement (10 ATP)", 10, "Increases projectile damage by 15%", limits.damage_level, limits.damage_max),
-        ("2   Metabolic Enhancement (15 ATP)", 15, "+20% movement speed & fire rate", limits.metabolic_level, limits.metabolic_max),
-        ("3   Cellular Integrity (20 ATP)", 20, "+25 Maximum Health Points", limits.cellular_level, limits.cellular_max),
-        ("4   Enzyme Production (25 ATP)", 25, "Immunity to environmental toxins", limits.enzyme_level, limits.enzyme_max),
-        ("5   Bioluminescence (30 ATP)", 30, "Enhanced coordination abilities", limits.bioluminescence_level, limits.bioluminescence_max),
-        ("6   Emergency Spore (20 ATP)", 20, "+1 Emergency reproductive blast", 0, 3), // Special case for spores
-        ("7   Pseudopod Network (50 ATP)", 50, "Multi-directional tendril weapon", 0, 1),
-        ("8   Symbiotic Hunters (75 ATP)", 75, "Homing cooperative organisms", 0, 1),
-        ("9   Bioluminescent Beam (100 ATP)", 100, "Concentrated energy discharge", 0, 1),
-        ("0   Magnet Radius (25 ATP)", 25, "Increase ATP collection radius by 20px", limits.magnet_radius_level, limits.magnet_radius_max),
-        ("-   Magnet Strength (30 ATP)", 30, "Increase magnetic pull force by 30%", limits.magnet_strength_level, limits.magnet_strength_max),
+        ("1", "Membrane Reinforcement", 10, "Increases projectile damage by 15%", limits.damage_level, limits.damage_max),
+        ("2", "Metabolic Enhancement", 15, "+20% movement speed & fire rate", limits.metabolic_level, limits.metabolic_max),
+        ("3", "Cellular Integrity", 20, "+25 Maximum Health Points", limits.cellular_level, limits.cellular_max),
+        ("4", "Enzyme Production", 25, "Immunity to environmental toxins", limits.enzyme_level, limits.enzyme_max),
+        ("5", "Bioluminescence", 30, "Enhanced coordination abilities", limits.bioluminescence_level, limits.bioluminescence_max),
+        ("6", "Emergency Spore", 20, "+1 Emergency reproductive blast", 0, 3), // Special case for spores
+        ("7", "Pseudopod Network", 50, "Multi-directional tendril weapon", 0, 1),
+        ("8", "Symbiotic Hunters", 75, "Homing cooperative organisms", 0, 1),
+        ("9", "Bioluminescent Beam", 100, "Concentrated energy discharge", 0, 1),
+        ("0", "Magnet Radius", 25, "Increase ATP collection radius by 20px", limits.magnet_radius_level, limits.magnet_radius_max),
+        ("-", "Magnet Strength", 30, "Increase magnetic pull force by 30%", limits.magnet_strength_level, limits.magnet_strength_max),
     ];
 
     commands.spawn((
@@ -320,13 +335,24 @@ fn spawn_evolution_ui(commands: &mut Commands, atp_amount: u32, fonts: &GameFont
         ));
         
         parent.spawn((
-            Text::new(&format!("ATP Available: {}⚡", atp_amount)),
+            Text::new(format!("ATP Available: {}⚡", atp_amount)),
             TextFont { font: fonts.default_font.clone(), font_size: 16.0, ..default() },
             TextColor(Color::srgb(1.0, 1.0, 0.3)),
         ));
 
-        for (title, cost, effect, current_level, max_level) in evolutions {
-            let can_afford = atp_amount >= cost;
+        // Point at the loadout's next priority so a saved plan is easy to follow
+        if let Some(next) = loadouts.selected_preset().and_then(|preset| preset.next_upgrade(limits)) {
+            parent.spawn((
+                Text::new(format!("Loadout next: {}", next.display_name())),
+                TextFont { font: fonts.default_font.clone(), font_size: 13.0, ..default() },
+                TextColor(Color::srgb(0.6, 0.9, 1.0)),
+            ));
+        }
+
+        for (key, name, cost, effect, current_level, max_level) in evolutions {
+            let next_cost = cost_config.scaled_cost(cost, current_level);
+            let title = format!("{}   {} ({} ATP)", key, name, next_cost);
+            let can_afford = atp_amount >= next_cost;
             let can_upgrade = current_level < max_level;
             let color = if can_afford && can_upgrade { 
                 Color::srgb(0.9, 1.0, 0.9) 
@@ -342,8 +368,14 @@ fn spawn_evolution_ui(commands: &mut Commands, atp_amount: u32, fonts: &GameFont
                 String::new() 
             };
             
+            // Projected wait at the current ATP income, priced at the next (scaled) purchase
+            let afford_display = match afford_rate {
+                Some(rate) if can_upgrade => format!(" - {}", AtpCostHintConfig::label(next_cost, atp_amount, rate)),
+                _ => String::new(),
+            };
+            
             parent.spawn((
-                Text::new(&format!("{}{}", title, level_display)),
+                Text::new(format!("{}{}{}", title, level_display, afford_display)),
                 TextFont { font: fonts.default_font.clone(), font_size: 14.0, ..default() },
                 TextColor(color),
             ));
@@ -489,6 +521,103 @@ pub fn fps_text_update_system(
     }
 }
 
+// ===== HUD ELEMENT TOGGLES =====
+/// Push the player's HUD toggles to the widgets, on a settings change or when a widget is (re)spawned
+pub fn hud_visibility_system(
+    hud_visibility: Res<HudVisibility>,
+    mut scheduler: ResMut<UIUpdateScheduler>,
+    widget_query: Query<(Entity, Has<PerfHudText>, Has<WaveInfoText>, Has<EcosystemStatusText>), Or<(With<PerfHudText>, With<WaveInfoText>, With<EcosystemStatusText>, With<MultiplierText>)>>,
+    added_query: Query<(), Or<(Added<PerfHudText>, Added<WaveInfoText>, Added<EcosystemStatusText>, Added<MultiplierText>)>>,
+) {
+    if !hud_visibility.is_changed() && added_query.is_empty() { return; }
+
+    for (entity, is_fps, is_wave, is_ecosystem) in widget_query.iter() {
+        let element = match (is_fps, is_wave, is_ecosystem) {
+            (true, _, _) => HudElement::Fps,
+            (_, true, _) => HudElement::WaveCounter,
+            (_, _, true) => HudElement::EcosystemStatus,
+            _ => HudElement::Multiplier,
+        };
+        scheduler.queue_update(UIUpdateCommand::VisibilityUpdate {
+            entity,
+            visible: hud_visibility.is_visible(element),
+        });
+    }
+}
+
+// ===== INPUT DISPLAY =====
+const INPUT_DISPLAY_ROWS: [&[(InputAction, &str)]; 3] = [
+    &[(InputAction::MoveUp, "^")],
+    &[(InputAction::MoveLeft, "<"), (InputAction::MoveDown, "v"), (InputAction::MoveRight, ">")],
+    &[(InputAction::Shoot, "FIRE"), (InputAction::EmergencySpore, "SPORE"), (InputAction::ToggleBeamLock, "LOCK"), (InputAction::CycleEvolution, "SWAP")],
+];
+
+/// Spawn or remove the overlay as the setting changes, and light each key cap while its action is held
+pub fn input_display_system(
+    mut commands: Commands,
+    config: Res<InputDisplayConfig>,
+    input_manager: Res<InputManager>,
+    fonts: Res<GameFonts>,
+    overlay_query: Query<Entity, With<InputDisplayOverlay>>,
+    mut indicator_query: Query<(&InputDisplayIndicator, &mut BackgroundColor)>,
+) {
+    if !config.enabled {
+        for entity in overlay_query.iter() {
+            commands.entity(entity).safe_despawn();
+        }
+        return;
+    }
+
+    if overlay_query.is_empty() {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(UI_PADDING),
+                left: Val::Px(UI_PADDING),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            InputDisplayOverlay,
+        )).with_children(|parent| {
+            for row in INPUT_DISPLAY_ROWS {
+                parent.spawn(Node { flex_direction: FlexDirection::Row, ..default() }).with_children(|row_parent| {
+                    for (action, label) in row {
+                        row_parent.spawn((
+                            Node {
+                                min_width: Val::Px(28.0),
+                                padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                                margin: UiRect::all(Val::Px(2.0)),
+                                justify_content: JustifyContent::Center,
+                                border: UiRect::all(Val::Px(1.0)),
+                                ..default()
+                            },
+                            BackgroundColor(config.indicator_color(false)),
+                            BorderColor(COLOR_BORDER),
+                            InputDisplayIndicator(*action),
+                        )).with_children(|cap| {
+                            cap.spawn((
+                                Text::new(*label),
+                                TextFont { font: fonts.default_font.clone(), font_size: UI_FONT_SIZE_TINY, ..default() },
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                    }
+                });
+            }
+        });
+        return;
+    }
+
+    for (indicator, mut background) in indicator_query.iter_mut() {
+        background.0 = config.indicator_color(input_manager.pressed(indicator.0));
+    }
+}
+
+pub fn cleanup_input_display(mut commands: Commands, query: Query<Entity, With<InputDisplayOverlay>>) {
+    for entity in query.iter() { commands.entity(entity).safe_despawn(); }
+}
+
 // Wave information UI system
 pub fn setup_wave_ui(mut commands: Commands, fonts: Res<GameFonts>) {
     let font = fonts.default_font.clone();
@@ -533,20 +662,88 @@ pub fn setup_wave_ui(mut commands: Commands, fonts: Res<GameFonts>) {
     });
 }
 
+/// Centered hazard banner, driven through a cosmic_ui status indicator so warnings cross-fade
+pub fn setup_hazard_warning_ui(mut commands: Commands, fonts: Res<GameFonts>, config: Res<HazardWarningConfig>) {
+    let states = HazardWarning::ALL.iter().map(|warning| StatusState {
+        text: warning.message().to_string(),
+        color: warning.color(),
+        animation: match warning {
+            HazardWarning::None => StatusAnimation::Static,
+            _ => StatusAnimation::Pulse { frequency: 2.0 },
+        },
+    }).collect();
+    
+    let entity = commands.spawn((
+        Text::new(""),
+        TextFont { font: fonts.default_font.clone(), font_size: UI_FONT_SIZE_MEDIUM, ..default() },
+        TextColor(Color::NONE),
+        TextLayout::new_with_justify(JustifyText::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(20.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        HazardWarningText,
+    )).id();
+    commands.entity(entity).insert(StatusIndicator::new(entity, states).with_transition(config.transition));
+}
+
+/// Sample chemistry and vent heat at the player and switch the banner to the worst hazard
+pub fn hazard_warning_system(
+    config: Res<HazardWarningConfig>,
+    chemical_environment: Res<ChemicalEnvironment>,
+    current_generator: Res<CurrentGenerator>,
+    player_query: Query<(&Transform, &ChemicalSensitivity), With<Player>>,
+    mut indicator_query: Query<&mut StatusIndicator, With<HazardWarningText>>,
+) {
+    let warning = match player_query.single() {
+        Ok((transform, sensitivity)) if config.enabled => {
+            let position = transform.translation.truncate();
+            let heat = current_generator.thermal_vents.iter()
+                .filter(|vent| vent.active)
+                .map(|vent| vent_heat_intensity(vent, position))
+                .fold(0.0, f32::max);
+            HazardWarning::evaluate(
+                sample_ph(&chemical_environment, position),
+                sample_oxygen(&chemical_environment, position),
+                heat,
+                sensitivity,
+                &config,
+            )
+        }
+        _ => HazardWarning::None,
+    };
+    
+    for mut indicator in indicator_query.iter_mut() {
+        indicator.set_state(warning.state_index());
+    }
+}
+
 pub fn wave_ui_system(
     wave_manager: Res<WaveManager>,
-    enemy_query: Query<&Enemy>,
-    mut wave_text_query: Query<&mut Text, With<WaveInfoText>>,
+    enemy_query: Query<&Enemy, Without<TargetDummy>>,
+    mut wave_text_query: Query<(&mut Text, &mut TextColor), With<WaveInfoText>>,
     mut progress_bar_query: Query<&mut Node, With<WaveProgressBar>>,
+    boss_telegraph: Res<BossWaveTelegraphConfig>,
 ) {
+    // Warn a wave ahead when a boss is coming
+    let telegraph = boss_telegraph.styling(wave_manager.is_boss_wave(wave_manager.upcoming_wave()));
+
     // Update wave text
-    for mut text in wave_text_query.iter_mut() {
+    for (mut text, mut text_color) in wave_text_query.iter_mut() {
         let enemies_remaining = enemy_query.iter().count();
-        **text = if wave_manager.wave_active {
+        let mut readout = if wave_manager.wave_active {
             format!("Wave {} - Enemies: {}", wave_manager.current_wave, enemies_remaining)
         } else {
             format!("Preparing Wave {}...", wave_manager.current_wave)
         };
+        if let Some((_, label)) = telegraph {
+            readout = format!("{}\n{} (Wave {})", readout, label, wave_manager.upcoming_wave());
+        }
+        **text = readout;
+        text_color.0 = telegraph.map_or(Color::srgb(0.8, 1.0, 0.9), |(color, _)| color);
     }
 
     // Update progress bar
@@ -564,6 +761,83 @@ pub fn wave_ui_system(
     }
 }
 
+// Upcoming wave roster, rebuilt once per wave and only shown between waves
+pub fn wave_preview_system(
+    mut commands: Commands,
+    wave_manager: Res<WaveManager>,
+    preview_config: Res<WavePreviewConfig>,
+    panel_query: Query<Entity, With<WavePreviewPanel>>,
+    fonts: Res<GameFonts>,
+    mut previewed_wave: Local<Option<u32>>,
+) {
+    let showing = preview_config.enabled && !wave_manager.wave_active;
+    if showing && *previewed_wave == Some(wave_manager.current_wave) && !panel_query.is_empty() {
+        return;
+    }
+
+    for entity in panel_query.iter() {
+        commands.entity(entity).safe_despawn();
+    }
+    if !showing {
+        *previewed_wave = None;
+        return;
+    }
+    *previewed_wave = Some(wave_manager.current_wave);
+
+    let composition = wave_manager.upcoming_composition();
+    let font = fonts.default_font.clone();
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(UI_PADDING),
+            top: Val::Px(UI_PADDING + 84.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(3.0),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(COLOR_BACKGROUND),
+        BorderColor(COLOR_BORDER),
+        WavePreviewPanel,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(format!("Incoming: {} cells", composition.total())),
+            TextFont { font: font.clone(), font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.8, 1.0, 0.9)),
+        ));
+
+        for (enemy_type, count) in &composition.counts {
+            let (_, _, _, color) = enemy_type.get_stats();
+            parent.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(6.0),
+                ..default()
+            }).with_children(|row| {
+                row.spawn((
+                    Node { width: Val::Px(10.0), height: Val::Px(10.0), ..default() },
+                    BackgroundColor(color),
+                ));
+                row.spawn((
+                    Text::new(format!("{} x{}", enemy_type.display_name(), count)),
+                    TextFont { font: font.clone(), font_size: 12.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        }
+
+        if composition.random_count > 0 {
+            parent.spawn((
+                Text::new(format!("Mixed swarm x{}", composition.random_count)),
+                TextFont { font: font.clone(), font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        }
+    });
+}
+
 // Top-left upgrade indicators (similar to Sky Force Reloaded)
 pub fn setup_upgrade_indicators_ui(mut commands: Commands, fonts: Res<GameFonts>) {
     let font = fonts.default_font.clone();
@@ -704,4 +978,132 @@ pub fn update_upgrade_indicators(
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visibility_of(app: &App, entity: Entity) -> Option<bool> {
+        app.world().resource::<UIUpdateScheduler>().pending_updates.iter().rev()
+            .find_map(|update| match update {
+                UIUpdateCommand::VisibilityUpdate { entity: target, visible } if *target == entity => Some(*visible),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn hiding_the_fps_counter_leaves_the_other_widgets_visible() {
+        let mut app = App::new();
+        app.init_resource::<HudVisibility>()
+            .init_resource::<UIUpdateScheduler>()
+            .add_systems(Update, hud_visibility_system);
+
+        let fps = app.world_mut().spawn(PerfHudText).id();
+        let wave = app.world_mut().spawn(WaveInfoText).id();
+        let ecosystem = app.world_mut().spawn(EcosystemStatusText).id();
+        let multiplier = app.world_mut().spawn(MultiplierText).id();
+        app.update();
+        app.world_mut().resource_mut::<UIUpdateScheduler>().pending_updates.clear();
+
+        app.world_mut().resource_mut::<HudVisibility>().toggle(HudElement::Fps);
+        app.update();
+
+        assert_eq!(visibility_of(&app, fps), Some(false));
+        for widget in [wave, ecosystem, multiplier] {
+            assert_eq!(visibility_of(&app, widget), Some(true));
+        }
+    }
+
+    #[test]
+    fn holding_shoot_lights_the_fire_indicator_until_released() {
+        use crate::input::InputState;
+
+        let mut app = App::new();
+        app.insert_resource(InputDisplayConfig { enabled: true, ..default() })
+            .init_resource::<InputManager>()
+            .insert_resource(GameFonts { default_font: Handle::default() })
+            .add_systems(Update, input_display_system);
+        app.update();
+
+        let fire_color = |app: &mut App| {
+            app.world_mut().query::<(&InputDisplayIndicator, &BackgroundColor)>().iter(app.world())
+                .find(|(indicator, _)| indicator.0 == InputAction::Shoot)
+                .map(|(_, background)| background.0)
+                .expect("overlay should have a FIRE indicator")
+        };
+        let config = app.world().resource::<InputDisplayConfig>().clone();
+
+        app.world_mut().resource_mut::<InputManager>().current_states.insert(InputAction::Shoot, InputState::Pressed);
+        app.update();
+        assert_eq!(fire_color(&mut app), config.active_color);
+
+        app.world_mut().resource_mut::<InputManager>().current_states.insert(InputAction::Shoot, InputState::Released);
+        app.update();
+        assert_eq!(fire_color(&mut app), config.idle_color);
+    }
+
+    #[test]
+    fn wave_counter_warns_a_wave_ahead_of_a_boss() {
+        let mut app = App::new();
+        app.init_resource::<WaveManager>()
+            .init_resource::<BossWaveTelegraphConfig>()
+            .add_systems(Update, wave_ui_system);
+        let counter = app.world_mut().spawn((Text::default(), TextColor::default(), WaveInfoText)).id();
+        let wave_manager = app.world().resource::<WaveManager>();
+        let boss_wave = wave_manager.wave_patterns.iter()
+            .map(|pattern| pattern.wave_number)
+            .find(|wave| *wave > 1 && wave_manager.is_boss_wave(*wave))
+            .expect("scripted waves should include a boss");
+        let calm_wave = (1..boss_wave).find(|wave| !wave_manager.is_boss_wave(wave + 1)).unwrap();
+        let telegraph = app.world().resource::<BossWaveTelegraphConfig>().clone();
+        let show_wave = |app: &mut App, wave: u32| {
+            let mut wave_manager = app.world_mut().resource_mut::<WaveManager>();
+            wave_manager.current_wave = wave;
+            wave_manager.wave_active = true;
+            app.update();
+            (app.world().get::<Text>(counter).unwrap().0.clone(), app.world().get::<TextColor>(counter).unwrap().0)
+        };
+
+        let (text, color) = show_wave(&mut app, boss_wave - 1);
+        assert!(text.contains(&telegraph.label));
+        assert_eq!(color, telegraph.color);
+
+        let (text, color) = show_wave(&mut app, calm_wave);
+        assert!(!text.contains(&telegraph.label));
+        assert_ne!(color, telegraph.color);
+    }
+
+    #[test]
+    fn acidic_water_raises_the_hazard_banner_until_the_player_leaves() {
+        let acid_pool = Vec2::new(300.0, 0.0);
+        let mut chemical_environment = ChemicalEnvironment::default();
+        chemical_environment.deploy_zone(DeployedZoneKind::Acidic, acid_pool, 120.0, 60.0);
+
+        let mut app = App::new();
+        app.init_resource::<HazardWarningConfig>()
+            .insert_resource(chemical_environment)
+            .insert_resource(CurrentGenerator { thermal_vents: Vec::new(), ..default() })
+            .add_systems(Update, hazard_warning_system);
+        let player = app.world_mut().spawn((
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+            ChemicalSensitivity { ph_tolerance_min: 6.0, ph_tolerance_max: 8.0, oxygen_requirement: 0.2, damage_per_second_outside_range: 1 },
+            Transform::default(),
+        )).id();
+        let banner = app.world_mut().spawn(HazardWarningText).id();
+        let states = HazardWarning::ALL.iter().map(|warning| StatusState {
+            text: warning.message().to_string(),
+            color: warning.color(),
+            animation: StatusAnimation::Static,
+        }).collect();
+        app.world_mut().entity_mut(banner).insert(StatusIndicator::new(banner, states));
+        let warning_at = |app: &mut App, position: Vec2| {
+            app.world_mut().get_mut::<Transform>(player).unwrap().translation = position.extend(0.0);
+            app.update();
+            HazardWarning::ALL[app.world().get::<StatusIndicator>(banner).unwrap().target_state]
+        };
+
+        assert_eq!(warning_at(&mut app, -acid_pool), HazardWarning::None);
+        assert_eq!(warning_at(&mut app, acid_pool), HazardWarning::Acidic);
+        assert_eq!(warning_at(&mut app, -acid_pool), HazardWarning::None);
+    }
+}
@@ -4,6 +4,8 @@ use crate::components::*;
 use crate::resources::*;
 use crate::events::*;
 use crate::enemy_types::*;
+use crate::enemy_systems::GridFormation;
+use crate::target_dummy::TargetDummy;
 use crate::despawn::*;
 use rand::Rng;
 
@@ -11,9 +13,6 @@ use rand::Rng;
 const WAVE_BASE_HEALTH_MULTIPLIER: f32 = 1.15;
 const WAVE_BASE_SPEED_MULTIPLIER: f32 = 1.08;
 const TUTORIAL_WAVE_COUNT: u32 = 5;
-const MIXED_FORMATION_WAVE_COUNT: u32 = 10;
-const ENVIRONMENTAL_WAVE_COUNT: u32 = 15;
-const MINI_BOSS_WAVE_COUNT: u32 = 20;
 const ENDLESS_START_WAVE: u32 = 21;
 
 const ATP_BASE_REWARD: u32 = 50;
@@ -21,6 +20,32 @@ const ATP_WAVE_MULTIPLIER: f32 = 1.12;
 const POWERUP_BASE_CHANCE: f32 = 0.15;
 const POWERUP_WAVE_REDUCTION: f32 = 0.008;
 
+const WAVE_GRACE_INVULNERABILITY: f32 = 1.5;
+const WAVE_SPAWN_EXCLUSION_RADIUS: f32 = 180.0;
+
+const EDGE_SPAWN_TOP_Y: f32 = 380.0;
+const EDGE_SPAWN_BOTTOM_Y: f32 = -420.0;
+const EDGE_SPAWN_SIDE_X: f32 = 680.0;
+const EDGE_SPAWN_SPACING: f32 = 50.0;
+const SIDE_EDGE_SPAWN_CHANCE: f32 = 0.25;
+
+const GRID_WAVE_COLUMNS: u32 = 8;
+const GRID_WAVE_ROWS: u32 = 3;
+const GRID_WAVE_ENTRY_Y: f32 = 420.0;
+const GRID_WAVE_ENTRY_INTERVAL: f32 = 0.08;
+
+const THREAT_SPAWN_CAP: f32 = 30.0;
+const THREAT_RESUME_THRESHOLD: f32 = 18.0;
+const THREAT_SCREEN_HALF_EXTENTS: Vec2 = Vec2::new(640.0, 400.0);
+
+const PORTAL_WINDUP: f32 = 1.2;
+const PORTAL_MAX_Y: f32 = 300.0;      // Portals open inside the playfield so the telegraph is visible
+const PORTAL_SIZE: f32 = 56.0;
+
+const SPEED_RAMP_END_MULTIPLIER: f32 = 1.35;
+const SPEED_RAMP_DURATION: f32 = 45.0;
+const SPEED_RAMP_EXPONENT: f32 = 1.5;       // >1 eases in, keeping the opening of a wave calm
+
 // ===== WAVE CONFIGURATION =====
 #[derive(Resource, Clone)]
 pub struct WaveManager {
@@ -34,6 +59,231 @@ pub struct WaveManager {
     pub wave_patterns: Vec<WavePattern>,
 }
 
+/// Player protection applied whenever a new wave begins
+#[derive(Resource, Clone)]
+pub struct WaveGraceConfig {
+    pub invulnerability_duration: f32,
+    pub spawn_exclusion_radius: f32,
+}
+
+impl Default for WaveGraceConfig {
+    fn default() -> Self {
+        Self {
+            invulnerability_duration: WAVE_GRACE_INVULNERABILITY,
+            spawn_exclusion_radius: WAVE_SPAWN_EXCLUSION_RADIUS,
+        }
+    }
+}
+
+/// Spawns scheduled by the current wave, released by `wave_spawning_system`
+#[derive(Resource, Default)]
+pub struct WaveSpawnQueue {
+    pub pending: Vec<QueuedSpawn>,
+    pub wave_clock: f32, // Only advances while spawning isn't held back by the threat meter
+    pub budget: f32,     // Threat weight of everything the wave scheduled
+    pub spent: f32,      // Threat weight released so far
+}
+
+impl WaveSpawnQueue {
+    pub fn begin_wave(&mut self) {
+        self.pending.clear();
+        self.wave_clock = 0.0;
+        self.budget = 0.0;
+        self.spent = 0.0;
+    }
+
+    pub fn push(&mut self, spawn: QueuedSpawn) {
+        self.budget += enemy_threat_weight(&spawn.enemy_type);
+        self.pending.push(spawn);
+    }
+
+    pub fn record_release(&mut self, enemy_type: &EnemyType) {
+        self.spent += enemy_threat_weight(enemy_type);
+    }
+
+    /// Pending spawns per enemy type, in first-queued order
+    pub fn composition(&self) -> Vec<(EnemyType, u32)> {
+        let mut composition: Vec<(EnemyType, u32)> = Vec::new();
+        for queued in &self.pending {
+            match composition.iter_mut().find(|(enemy_type, _)| *enemy_type == queued.enemy_type) {
+                Some((_, count)) => *count += 1,
+                None => composition.push((queued.enemy_type, 1)),
+            }
+        }
+        composition
+    }
+
+    /// Debug readout of the wave's spawn budget and what's still queued
+    pub fn budget_label(&self) -> String {
+        let queued: Vec<String> = self.composition().iter()
+            .map(|(enemy_type, count)| format!("{}x {}", count, enemy_type.display_name()))
+            .collect();
+        format!(
+            "Spawn Budget: {:.1} / {:.1} spent\nQueued: {}",
+            self.spent,
+            self.budget,
+            if queued.is_empty() { "none".to_string() } else { queued.join(", ") },
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+/// Roster of enemy types shown during the break before a wave starts
+#[derive(Resource, Clone)]
+pub struct WavePreviewConfig {
+    pub enabled: bool,
+}
+
+impl Default for WavePreviewConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Optional tension ramp, enemies move faster the longer a wave drags on
+#[derive(Resource, Clone)]
+pub struct WaveSpeedRamp {
+    pub enabled: bool,
+    pub start_multiplier: f32,
+    pub end_multiplier: f32,
+    pub ramp_duration: f32, // Seconds into the wave at which `end_multiplier` is reached
+    pub exponent: f32,      // Curve shape, 1.0 is linear
+}
+
+impl Default for WaveSpeedRamp {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start_multiplier: 1.0,
+            end_multiplier: SPEED_RAMP_END_MULTIPLIER,
+            ramp_duration: SPEED_RAMP_DURATION,
+            exponent: SPEED_RAMP_EXPONENT,
+        }
+    }
+}
+
+impl WaveSpeedRamp {
+    /// Enemy speed multiplier `wave_elapsed` seconds after the current wave started
+    pub fn multiplier(&self, wave_elapsed: f32) -> f32 {
+        if !self.enabled || self.ramp_duration <= 0.0 {
+            return 1.0;
+        }
+        let progress = (wave_elapsed / self.ramp_duration).clamp(0.0, 1.0).powf(self.exponent);
+        self.start_multiplier + (self.end_multiplier - self.start_multiplier) * progress
+    }
+}
+
+/// What a wave will spawn, per enemy type in the order the wave introduces them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WaveComposition {
+    pub counts: Vec<(EnemyType, u32)>,
+    pub random_count: u32, // Endless waves roll these types as they spawn
+}
+
+impl WaveComposition {
+    pub fn add(&mut self, enemy_type: EnemyType, count: u32) {
+        if count == 0 { return; }
+        match self.counts.iter_mut().find(|(existing, _)| *existing == enemy_type) {
+            Some((_, total)) => *total += count,
+            None => self.counts.push((enemy_type, count)),
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.iter().map(|(_, count)| count).sum::<u32>() + self.random_count
+    }
+}
+
+/// Spawns flagged `via_portal` open a portal that winds up before releasing the enemy
+#[derive(Resource, Clone)]
+pub struct SpawnPortalConfig {
+    pub enabled: bool,
+    pub windup: f32,
+}
+
+impl Default for SpawnPortalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            windup: PORTAL_WINDUP,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct SpawnPortal {
+    pub enemy_type: EnemyType,
+    pub ai_type: EnemyAI,
+    pub timer: f32,
+    pub windup: f32,
+}
+
+impl SpawnPortal {
+    /// Advance the windup, returns true once the portal is fully open
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.timer += dt;
+        self.timer >= self.windup
+    }
+
+    pub fn progress(&self) -> f32 {
+        (self.timer / self.windup).clamp(0.0, 1.0)
+    }
+}
+
+/// On-screen threat with hysteresis: spawning stops at `spawn_cap` and resumes at `resume_threshold`
+#[derive(Resource, Clone)]
+pub struct ThreatMeter {
+    pub current: f32,
+    pub spawn_cap: f32,
+    pub resume_threshold: f32,
+    pub holding: bool,
+}
+
+impl Default for ThreatMeter {
+    fn default() -> Self {
+        Self {
+            current: 0.0,
+            spawn_cap: THREAT_SPAWN_CAP,
+            resume_threshold: THREAT_RESUME_THRESHOLD,
+            holding: false,
+        }
+    }
+}
+
+impl ThreatMeter {
+    pub fn update(&mut self, threat: f32) {
+        self.current = threat;
+        if self.holding && threat <= self.resume_threshold {
+            self.holding = false;
+        } else if !self.holding && threat >= self.spawn_cap {
+            self.holding = true;
+        }
+    }
+
+    pub fn spawning_allowed(&self) -> bool {
+        !self.holding
+    }
+
+    /// Count a just-released spawn so one frame can't push far past the cap
+    pub fn add_spawn(&mut self, enemy_type: &EnemyType) {
+        self.update(self.current + enemy_threat_weight(enemy_type));
+    }
+}
+
+pub fn enemy_threat_weight(enemy_type: &EnemyType) -> f32 {
+    match enemy_type {
+        EnemyType::Offspring | EnemyType::ViralParticle => 1.0,
+        EnemyType::SwarmCell | EnemyType::SuicidalSpore => 1.5,
+        EnemyType::AggressiveBacteria => 2.0,
+        EnemyType::ParasiticProtozoa | EnemyType::BiofilmColony => 3.0,
+        EnemyType::ReproductiveVesicle => 4.0,
+        EnemyType::InfectedMacrophage => 8.0,
+    }
+}
+
 #[derive(Clone)]
 pub struct WavePattern {
     pub wave_number: u32,
@@ -74,6 +324,32 @@ pub enum SpawnPosition {
     DiagonalApproach { angle: f32 },
     SpiralFormation { radius: f32, arms: u32 },
     RandomScattered { area: f32 },
+    FromEdge { edge: SpawnEdge },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpawnEdge {
+    Top,
+    Left,
+    Right,
+    Bottom,
+}
+
+/// Which screen edges endless waves may pull enemies in from
+/// Bottom is opt-in: the player starts at y -250, so a bottom entry lands behind the ship with no time to react
+#[derive(Resource, Clone)]
+pub struct SpawnEdgeConfig {
+    pub enabled_edges: Vec<SpawnEdge>,
+    pub side_spawn_chance: f32,
+}
+
+impl Default for SpawnEdgeConfig {
+    fn default() -> Self {
+        Self {
+            enabled_edges: vec![SpawnEdge::Top, SpawnEdge::Left, SpawnEdge::Right],
+            side_spawn_chance: SIDE_EDGE_SPAWN_CHANCE,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -248,6 +524,8 @@ impl WaveManager {
                     target_pos: Vec2::ZERO,
                     dive_speed: 250.0,
                     acquired_target: false,
+                    retarget_interval: 0.8,
+                    retarget_timer: 0.0,
                 }),
                 health_multiplier: 0.8,
                 speed_multiplier: 1.2,
@@ -559,6 +837,7 @@ impl WaveManager {
                 rotation: 0.0,
                 shoot_timer: 0.0,
                 detection_range: 400.0,
+                windup_timer: 0.0,
             }),
             _ => None,
         }
@@ -591,6 +870,21 @@ impl WaveManager {
         self.wave_patterns.iter().find(|p| p.wave_number == self.current_wave)
     }
 
+    /// True when `wave` spawns a boss: a scripted mini-boss pattern, or an endless boss wave
+    pub fn is_boss_wave(&self, wave: u32) -> bool {
+        if wave >= ENDLESS_START_WAVE {
+            return endless_boss_wave(wave - ENDLESS_START_WAVE);
+        }
+        self.wave_patterns.iter()
+            .find(|p| p.wave_number == wave)
+            .is_some_and(|p| matches!(p.wave_type, WaveType::MiniBoss { .. }))
+    }
+
+    /// The wave the HUD should warn about: the next one mid-wave, the one being prepared between waves
+    pub fn upcoming_wave(&self) -> u32 {
+        if self.wave_active { self.current_wave + 1 } else { self.current_wave }
+    }
+
     fn get_endless_wave_pattern(&self) -> Option<&WavePattern> {
         // Return a procedurally generated endless wave pattern
         // For now, return None to indicate endless mode needs special handling
@@ -610,6 +904,34 @@ impl WaveManager {
     pub fn calculate_powerup_spawn_rate(&self) -> f32 {
         (POWERUP_BASE_CHANCE - (self.current_wave as f32 * POWERUP_WAVE_REDUCTION)).max(0.05)
     }
+
+    /// Composition of `current_wave`, following the same branches `start_wave` takes to schedule it
+    pub fn upcoming_composition(&self) -> WaveComposition {
+        let mut composition = WaveComposition::default();
+
+        if let Some(pattern) = self.get_current_wave_pattern() {
+            for enemy_spawn in &pattern.enemy_spawns {
+                composition.add(enemy_spawn.enemy_type, enemy_spawn.spawn_count);
+            }
+        } else if self.current_wave >= ENDLESS_START_WAVE {
+            let wave_excess = self.current_wave - ENDLESS_START_WAVE;
+            composition.random_count = endless_swarm_size(wave_excess);
+            if wave_excess % 3 == 2 {
+                composition.add(EnemyType::ParasiticProtozoa, 1);
+            }
+            if wave_excess % 4 == 1 {
+                composition.add(EnemyType::AggressiveBacteria, GRID_WAVE_COLUMNS);
+                composition.add(EnemyType::SwarmCell, GRID_WAVE_COLUMNS * (GRID_WAVE_ROWS - 1));
+            }
+            if endless_boss_wave(wave_excess) {
+                composition.add(EnemyType::InfectedMacrophage, 1);
+            }
+        } else {
+            composition.add(fallback_enemy_type(self.current_wave), fallback_enemy_count(self.current_wave));
+        }
+
+        composition
+    }
 }
 
 impl Default for WaveManager {
@@ -661,7 +983,7 @@ impl SpawnPosition {
                 }
             }
             SpawnPosition::SpiralFormation { radius, arms } => {
-                let points_per_arm = (count + arms - 1) / arms;
+                let points_per_arm = count.div_ceil(*arms);
                 for arm in 0..*arms {
                     for point in 0..points_per_arm {
                         if arm * points_per_arm + point >= count { break; }
@@ -675,6 +997,12 @@ impl SpawnPosition {
                     }
                 }
             }
+            SpawnPosition::FromEdge { edge } => {
+                for i in 0..count {
+                    let lateral = (i as f32 - (count - 1) as f32 / 2.0) * EDGE_SPAWN_SPACING;
+                    positions.push(edge.entry_point(lateral).0);
+                }
+            }
             SpawnPosition::RandomScattered { area } => {
                 let mut rng = rand::rng();
                 let actual_area = area.min(1100.0); // Constrain to screen width
@@ -689,18 +1017,84 @@ impl SpawnPosition {
     }
 }
 
+impl SpawnEdge {
+    /// Direction of travel that carries an enemy from this edge into the playfield
+    pub fn inward_direction(&self) -> Vec2 {
+        match self {
+            SpawnEdge::Top => Vec2::NEG_Y,
+            SpawnEdge::Left => Vec2::X,
+            SpawnEdge::Right => Vec2::NEG_X,
+            SpawnEdge::Bottom => Vec2::Y,
+        }
+    }
+
+    /// Position just outside this edge, offset along it by `lateral`, plus the inward direction
+    pub fn entry_point(&self, lateral: f32) -> (Vec3, Vec2) {
+        let position = match self {
+            SpawnEdge::Top => Vec3::new(lateral.clamp(-580.0, 580.0), EDGE_SPAWN_TOP_Y, 0.0),
+            SpawnEdge::Bottom => Vec3::new(lateral.clamp(-580.0, 580.0), EDGE_SPAWN_BOTTOM_Y, 0.0),
+            // Side entries favour the upper half so they don't appear right beside the player
+            SpawnEdge::Left => Vec3::new(-EDGE_SPAWN_SIDE_X, (100.0 + lateral).clamp(-200.0, 300.0), 0.0),
+            SpawnEdge::Right => Vec3::new(EDGE_SPAWN_SIDE_X, (100.0 + lateral).clamp(-200.0, 300.0), 0.0),
+        };
+        (position, self.inward_direction())
+    }
+}
+
+/// Points directional AI patterns along the edge's inward direction
+pub fn orient_ai_for_edge(ai_type: EnemyAI, edge: SpawnEdge) -> EnemyAI {
+    let inward = edge.inward_direction();
+    match ai_type {
+        EnemyAI::Linear { .. } => EnemyAI::Linear { direction: inward },
+        EnemyAI::Chemotaxis { target_chemical, sensitivity, .. } => EnemyAI::Chemotaxis {
+            target_chemical,
+            sensitivity,
+            current_direction: inward,
+        },
+        EnemyAI::FluidFlow { flow_sensitivity, .. } => EnemyAI::FluidFlow { flow_sensitivity, base_direction: inward },
+        other => other,
+    }
+}
+
+/// Pushes a spawn position out to the exclusion radius if it would land on top of the player
+pub fn apply_spawn_exclusion(position: Vec3, player_pos: Option<Vec2>, radius: f32) -> Vec3 {
+    let Some(player_pos) = player_pos else { return position };
+
+    let offset = position.truncate() - player_pos;
+    if offset.length_squared() >= radius * radius {
+        return position;
+    }
+
+    // Spawns directly on the player get pushed upward, toward the normal spawn line
+    let direction = if offset.length_squared() > 0.001 { offset.normalize() } else { Vec2::Y };
+    let pushed = player_pos + direction * radius;
+    Vec3::new(pushed.x, pushed.y, position.z)
+}
+
+/// A wave is over once nothing it scheduled is still alive, queued or winding up in a portal
+pub fn wave_cleared(living_enemies: usize, spawn_queue: &WaveSpawnQueue, portals_pending: bool) -> bool {
+    living_enemies == 0 && spawn_queue.is_empty() && !portals_pending
+}
+
 // ===== WAVE EXECUTION SYSTEMS =====
 pub fn wave_progression_system(
     mut wave_manager: ResMut<WaveManager>,
     mut enemy_spawner: ResMut<EnemySpawner>,
-    mut spawn_events: EventWriter<SpawnEnemy>,
-    enemy_query: Query<&Enemy>,
+    mut spawn_queue: ResMut<WaveSpawnQueue>,
+    enemy_query: Query<&Enemy, Without<TargetDummy>>,
+    portal_query: Query<(), With<SpawnPortal>>,
+    mut player_query: Query<(&mut Player, &Transform)>,
+    grace_config: Res<WaveGraceConfig>,
+    edge_config: Res<SpawnEdgeConfig>,
+    grid_formation: Res<GridFormation>,
+    environment_curve: Res<WaveEnvironmentCurve>,
+    mut chemical_environment: ResMut<ChemicalEnvironment>,
+    mut fluid_environment: ResMut<FluidEnvironment>,
     time: Res<Time>,
 ) {
     // Check if current wave is complete
     if wave_manager.wave_active {
-        let living_enemies = enemy_query.iter().count();
-        if living_enemies == 0 && wave_manager.enemies_remaining == 0 {
+        if wave_cleared(enemy_query.iter().count(), &spawn_queue, !portal_query.is_empty()) {
             complete_current_wave(&mut wave_manager, time.elapsed_secs());
         }
         return;
@@ -708,39 +1102,129 @@ pub fn wave_progression_system(
 
     // Start next wave if ready
     if should_start_next_wave(&wave_manager, &enemy_spawner, time.elapsed_secs()) {
-        start_wave(&mut wave_manager, &mut spawn_events, time.elapsed_secs());
+        // Grace period so freshly spawned enemies can't catch the player off guard
+        let mut player_pos = None;
+        if let Ok((mut player, transform)) = player_query.single_mut() {
+            player.invincible_timer = player.invincible_timer.max(grace_config.invulnerability_duration);
+            player_pos = Some(transform.translation.truncate());
+        }
+
+        start_wave(&mut wave_manager, &mut spawn_queue, time.elapsed_secs(), player_pos, &grace_config, &edge_config, &grid_formation);
+        environment_curve.apply(wave_manager.current_wave, &mut chemical_environment, &mut fluid_environment);
     }
 }
 
+pub fn threat_meter_system(
+    mut threat_meter: ResMut<ThreatMeter>,
+    enemy_query: Query<(&Transform, &Enemy), (Without<PendingDespawn>, Without<TargetDummy>)>,
+) {
+    let threat = enemy_query.iter()
+        .filter(|(transform, _)| {
+            let pos = transform.translation.truncate().abs();
+            pos.x <= THREAT_SCREEN_HALF_EXTENTS.x && pos.y <= THREAT_SCREEN_HALF_EXTENTS.y
+        })
+        .map(|(_, enemy)| enemy_threat_weight(&enemy.enemy_type))
+        .sum();
+
+    threat_meter.update(threat);
+}
+
 pub fn wave_spawning_system(
+    mut commands: Commands,
     wave_manager: Res<WaveManager>,
     mut spawn_events: EventWriter<SpawnEnemy>,
-    mut local_timer: Local<f32>,
-    mut spawn_queue: Local<Vec<QueuedSpawn>>,
+    mut spawn_queue: ResMut<WaveSpawnQueue>,
+    mut threat_meter: ResMut<ThreatMeter>,
+    portal_config: Res<SpawnPortalConfig>,
+    assets: Option<Res<GameAssets>>,
     time: Res<Time>,
 ) {
     if !wave_manager.wave_active {
         return;
     }
 
-    *local_timer += time.delta_secs();
+    // Hold the wave's cadence while the screen is crowded
+    if !threat_meter.spawning_allowed() {
+        return;
+    }
+
+    spawn_queue.wave_clock += time.delta_secs();
+    let wave_clock = spawn_queue.wave_clock;
 
     // Process spawn queue
-    spawn_queue.retain(|queued| {
-        if *local_timer >= queued.spawn_time {
-            spawn_events.write(SpawnEnemy {
-                position: queued.position,
-                ai_type: queued.ai_type.clone(),
-                enemy_type: queued.enemy_type,
-            });
+    let mut released = Vec::new();
+    spawn_queue.pending.retain(|queued| {
+        if wave_clock >= queued.spawn_time && threat_meter.spawning_allowed() {
+            match &assets {
+                Some(assets) if queued.via_portal && portal_config.enabled => {
+                    spawn_portal(&mut commands, assets, queued, portal_config.windup);
+                }
+                _ => {
+                    spawn_events.write(SpawnEnemy {
+                        position: queued.position,
+                        ai_type: queued.ai_type.clone(),
+                        enemy_type: queued.enemy_type,
+                    });
+                }
+            }
+            threat_meter.add_spawn(&queued.enemy_type);
+            released.push(queued.enemy_type);
             false // Remove from queue
         } else {
             true // Keep in queue
         }
     });
+    for enemy_type in &released {
+        spawn_queue.record_release(enemy_type);
+    }
 }
 
 
+fn spawn_portal(commands: &mut Commands, assets: &GameAssets, queued: &QueuedSpawn, windup: f32) {
+    let position = Vec3::new(queued.position.x, queued.position.y.min(PORTAL_MAX_Y), 0.1);
+
+    commands.spawn((
+        Sprite {
+            image: assets.barrier_texture.clone(),
+            color: Color::srgba(0.8, 0.3, 1.0, 0.0),
+            custom_size: Some(Vec2::splat(PORTAL_SIZE)),
+            ..default()
+        },
+        Transform::from_translation(position).with_scale(Vec3::splat(0.1)),
+        SpawnPortal {
+            enemy_type: queued.enemy_type,
+            ai_type: queued.ai_type.clone(),
+            timer: 0.0,
+            windup,
+        },
+    ));
+}
+
+/// Iris the portal open over its windup, then release the enemy and close
+pub fn spawn_portal_system(
+    mut commands: Commands,
+    mut portal_query: Query<(Entity, &mut Transform, &mut Sprite, &mut SpawnPortal), Without<PendingDespawn>>,
+    mut spawn_events: EventWriter<SpawnEnemy>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut sprite, mut portal) in portal_query.iter_mut() {
+        if portal.tick(time.delta_secs()) {
+            spawn_events.write(SpawnEnemy {
+                position: transform.translation.with_z(0.0),
+                ai_type: portal.ai_type.clone(),
+                enemy_type: portal.enemy_type,
+            });
+            commands.entity(entity).safe_despawn();
+            continue;
+        }
+
+        let progress = portal.progress();
+        transform.scale = Vec3::splat(0.1 + progress * 0.9);
+        transform.rotate_z(time.delta_secs() * (2.0 + progress * 6.0));
+        sprite.color.set_alpha(0.3 + progress * 0.6);
+    }
+}
+
 pub fn environmental_hazard_system(
     mut commands: Commands,
     wave_manager: Res<WaveManager>,
@@ -790,18 +1274,17 @@ pub fn environmental_hazard_system(
 // ===== HELPER STRUCTURES =====
 #[derive(Clone)]
 pub struct QueuedSpawn {
-    position: Vec3,
-    ai_type: EnemyAI,
-    enemy_type: EnemyType,
-    spawn_time: f32,
+    pub position: Vec3,
+    pub ai_type: EnemyAI,
+    pub enemy_type: EnemyType,
+    pub spawn_time: f32, // Seconds of wave clock before release
+    pub via_portal: bool, // Telegraph the spawn with a SpawnPortal instead of appearing instantly
 }
 
 pub struct ActiveHazard {
     hazard_type: HazardType,
-    position: Vec3,
     intensity: f32,
     remaining_time: f32,
-    affected_area: f32,
     entity: Option<Entity>,
 }
 
@@ -817,6 +1300,19 @@ pub fn complete_current_wave(wave_manager: &mut WaveManager, current_time: f32)
     }
 }
 
+/// Debug skip: drop whatever the current wave still has queued and advance without rewards.
+/// Between waves it fast-forwards the break instead so the next wave starts right away.
+pub fn skip_current_wave(wave_manager: &mut WaveManager, spawn_queue: &mut WaveSpawnQueue, current_time: f32) {
+    spawn_queue.begin_wave();
+    wave_manager.enemies_remaining = 0;
+
+    if wave_manager.wave_active {
+        complete_current_wave(wave_manager, current_time);
+    } else {
+        wave_manager.wave_complete_time = f32::MIN;
+    }
+}
+
 fn should_start_next_wave(wave_manager: &WaveManager, _enemy_spawner: &EnemySpawner, current_time: f32) -> bool {
     let time_since_complete = current_time - wave_manager.wave_complete_time;
     let min_delay = if wave_manager.current_wave <= TUTORIAL_WAVE_COUNT { 3.0 } else { 2.0 };
@@ -826,10 +1322,15 @@ fn should_start_next_wave(wave_manager: &WaveManager, _enemy_spawner: &EnemySpaw
 
 fn start_wave(
     wave_manager: &mut WaveManager,
-    spawn_events: &mut EventWriter<SpawnEnemy>,
+    spawn_queue: &mut WaveSpawnQueue,
     current_time: f32,
+    player_pos: Option<Vec2>,
+    grace_config: &WaveGraceConfig,
+    edge_config: &SpawnEdgeConfig,
+    grid_formation: &GridFormation,
 ) {
     println!("Starting wave {}", wave_manager.current_wave);
+    spawn_queue.begin_wave();
     wave_manager.wave_active = true;
     wave_manager.wave_start_time = current_time;
     wave_manager.enemies_remaining = 0;
@@ -837,50 +1338,73 @@ fn start_wave(
     let mut wave_manager_clone = wave_manager.clone();
 
     if let Some(pattern) = wave_manager.get_current_wave_pattern() {
-        schedule_wave_spawns(pattern, spawn_events, current_time, &mut wave_manager_clone);
+        schedule_wave_spawns(pattern, spawn_queue, current_time, &mut wave_manager_clone, player_pos, grace_config);
     } else if wave_manager.current_wave >= ENDLESS_START_WAVE {
-        generate_endless_wave(wave_manager, spawn_events, current_time);
+        generate_endless_wave(wave_manager, spawn_queue, current_time, player_pos, grace_config, edge_config, grid_formation);
     } else {
         // Fallback for missing wave patterns
         println!("No pattern found for wave {}, using fallback", wave_manager.current_wave);
-        generate_fallback_wave(wave_manager, spawn_events, current_time);
+        generate_fallback_wave(wave_manager, spawn_queue, current_time, player_pos, grace_config);
     }
 }
 
 fn generate_fallback_wave(
     wave_manager: &mut WaveManager,
-    spawn_events: &mut EventWriter<SpawnEnemy>,
+    spawn_queue: &mut WaveSpawnQueue,
     _current_time: f32,
+    player_pos: Option<Vec2>,
+    grace_config: &WaveGraceConfig,
 ) {
-    let enemy_count = 3 + wave_manager.current_wave;
-    let enemy_type = match wave_manager.current_wave % 4 {
-        0 => EnemyType::ViralParticle,
-        1 => EnemyType::AggressiveBacteria,
-        2 => EnemyType::ParasiticProtozoa,
-        _ => EnemyType::SwarmCell,
-    };
+    let enemy_count = fallback_enemy_count(wave_manager.current_wave);
+    let enemy_type = fallback_enemy_type(wave_manager.current_wave);
 
     for i in 0..enemy_count {
         let x_offset = (i as f32 - (enemy_count - 1) as f32 / 2.0) * 60.0;
-        spawn_events.write(SpawnEnemy {
-            position: Vec3::new(x_offset, 400.0, 0.0),
+        spawn_queue.push(QueuedSpawn {
+            position: apply_spawn_exclusion(Vec3::new(x_offset, 400.0, 0.0), player_pos, grace_config.spawn_exclusion_radius),
             ai_type: get_default_ai_for_enemy(enemy_type),
             enemy_type,
+            spawn_time: 0.0,
+            via_portal: false,
         });
         wave_manager.enemies_remaining += 1;
     }
 }
 
+fn fallback_enemy_count(wave: u32) -> u32 {
+    3 + wave
+}
+
+fn fallback_enemy_type(wave: u32) -> EnemyType {
+    match wave % 4 {
+        0 => EnemyType::ViralParticle,
+        1 => EnemyType::AggressiveBacteria,
+        2 => EnemyType::ParasiticProtozoa,
+        _ => EnemyType::SwarmCell,
+    }
+}
+
+fn endless_swarm_size(wave_excess: u32) -> u32 {
+    8 + wave_excess * 2
+}
+
+fn endless_boss_wave(wave_excess: u32) -> bool {
+    wave_excess.is_multiple_of(5)
+}
+
 fn schedule_wave_spawns(
     pattern: &WavePattern,
-    spawn_events: &mut EventWriter<SpawnEnemy>,
+    spawn_queue: &mut WaveSpawnQueue,
     start_time: f32,
     wave_manager: &mut WaveManager,
+    player_pos: Option<Vec2>,
+    grace_config: &WaveGraceConfig,
 ) {
     let (health_mult, speed_mult) = wave_manager.calculate_difficulty_multipliers();
     
     for enemy_spawn in &pattern.enemy_spawns {
-        let positions = enemy_spawn.spawn_positions[0].get_world_positions(enemy_spawn.spawn_count);
+        let spawn_position = &enemy_spawn.spawn_positions[0];
+        let positions = spawn_position.get_world_positions(enemy_spawn.spawn_count);
         
         for (i, position) in positions.into_iter().enumerate() {
             let spawn_delay = enemy_spawn.spawn_delay + 
@@ -889,12 +1413,19 @@ fn schedule_wave_spawns(
 
             let enemy_spawn_clone = enemy_spawn.clone();
             let enemy_type = enemy_spawn_clone.enemy_type;
-            spawn_events.write(SpawnEnemy {
-                position,
-                ai_type: enemy_spawn.ai_override.clone().unwrap_or_else(|| {
-                    get_default_ai_for_enemy(enemy_spawn_clone.enemy_type)
-                }),
+            let mut ai_type = enemy_spawn.ai_override.clone().unwrap_or_else(|| {
+                get_default_ai_for_enemy(enemy_spawn_clone.enemy_type)
+            });
+            if let SpawnPosition::FromEdge { edge } = spawn_position {
+                ai_type = orient_ai_for_edge(ai_type, *edge);
+            }
+
+            spawn_queue.push(QueuedSpawn {
+                position: apply_spawn_exclusion(position, player_pos, grace_config.spawn_exclusion_radius),
+                ai_type,
                 enemy_type,
+                spawn_time: spawn_delay,
+                via_portal: matches!(spawn_position, SpawnPosition::RandomScattered { .. }),
             });
             
             wave_manager.enemies_remaining += 1;
@@ -904,12 +1435,16 @@ fn schedule_wave_spawns(
 
 fn generate_endless_wave(
     wave_manager: &mut WaveManager,
-    spawn_events: &mut EventWriter<SpawnEnemy>,
+    spawn_queue: &mut WaveSpawnQueue,
     current_time: f32,
+    player_pos: Option<Vec2>,
+    grace_config: &WaveGraceConfig,
+    edge_config: &SpawnEdgeConfig,
+    grid_formation: &GridFormation,
 ) {
     // Endless mode generation
     let wave_excess = wave_manager.current_wave - ENDLESS_START_WAVE;
-    let enemy_count = 8 + wave_excess * 2;
+    let enemy_count = endless_swarm_size(wave_excess);
     let enemy_types = [
         EnemyType::ViralParticle,
         EnemyType::AggressiveBacteria,
@@ -922,24 +1457,72 @@ fn generate_endless_wave(
     let mut rng = rand::rng();
     for i in 0..enemy_count {
         let enemy_type = enemy_types[rng.random_range(0..enemy_types.len())];
-        let spawn_pos = SpawnPosition::RandomScattered { area: 600.0 };
-        let positions = spawn_pos.get_world_positions(1);
-        
-        spawn_events.write(SpawnEnemy {
-            position: positions[0],
-            ai_type: get_default_ai_for_enemy(enemy_type),
+        let mut ai_type = get_default_ai_for_enemy(enemy_type);
+
+        // Some of the swarm flanks in from the other enabled edges
+        let side_edges: Vec<SpawnEdge> = edge_config.enabled_edges.iter()
+            .copied()
+            .filter(|edge| *edge != SpawnEdge::Top)
+            .collect();
+        let from_side = !side_edges.is_empty() && rng.random::<f32>() < edge_config.side_spawn_chance;
+        let position = if from_side {
+            let edge = side_edges[rng.random_range(0..side_edges.len())];
+            ai_type = orient_ai_for_edge(ai_type, edge);
+            edge.entry_point(rng.random_range(-150.0..150.0)).0
+        } else {
+            SpawnPosition::RandomScattered { area: 600.0 }.get_world_positions(1)[0]
+        };
+
+        spawn_queue.push(QueuedSpawn {
+            position: apply_spawn_exclusion(position, player_pos, grace_config.spawn_exclusion_radius),
+            ai_type,
             enemy_type,
+            spawn_time: 0.0,
+            via_portal: !from_side, // Side entries fly in, everything else tears through a portal
         });
         
         wave_manager.enemies_remaining += 1;
     }
 
-    // Spawn mini-boss every 5 waves in endless mode
-    if wave_excess % 5 == 0 {
-        spawn_events.write(SpawnEnemy {
-            position: Vec3::new(0.0, 400.0, 0.0),
-            ai_type: EnemyAI::MiniBoss { pattern: 0, timer: 0.0 },
+    // Support healer every 3 waves keeps the swarm topped up until it's dealt with
+    if wave_excess % 3 == 2 {
+        spawn_queue.push(QueuedSpawn {
+            position: apply_spawn_exclusion(Vec3::new(0.0, 380.0, 0.0), player_pos, grace_config.spawn_exclusion_radius),
+            ai_type: EnemyAI::Healer { heal_rate: 6.0, aura_radius: 140.0 },
+            enemy_type: EnemyType::ParasiticProtozoa,
+            spawn_time: 0.0,
+            via_portal: true,
+        });
+        wave_manager.enemies_remaining += 1;
+    }
+
+    // Arcade grid formation every 4 waves, flying in row by row
+    if wave_excess % 4 == 1 {
+        // Enter above the slots the live, swaying grid will hold
+        for row in 0..GRID_WAVE_ROWS {
+            for column in 0..GRID_WAVE_COLUMNS {
+                let home_slot = UVec2::new(column, row);
+                let slot_x = grid_formation.slot_position(home_slot).x;
+                spawn_queue.push(QueuedSpawn {
+                    position: Vec3::new(slot_x, GRID_WAVE_ENTRY_Y, 0.0),
+                    ai_type: EnemyAI::GridMember { home_slot, state: GridMemberState::Assembling },
+                    enemy_type: if row == 0 { EnemyType::AggressiveBacteria } else { EnemyType::SwarmCell },
+                    spawn_time: (row * GRID_WAVE_COLUMNS + column) as f32 * GRID_WAVE_ENTRY_INTERVAL,
+                    via_portal: false,
+                });
+                wave_manager.enemies_remaining += 1;
+            }
+        }
+    }
+
+    // Spawn mini-boss every 5 waves in endless mode, every other one is a full multi-phase boss
+    if endless_boss_wave(wave_excess) {
+        spawn_queue.push(QueuedSpawn {
+            position: apply_spawn_exclusion(Vec3::new(0.0, 400.0, 0.0), player_pos, grace_config.spawn_exclusion_radius),
+            ai_type: if wave_excess.is_multiple_of(10) { EnemyAI::boss() } else { EnemyAI::MiniBoss { pattern: 0, timer: 0.0 } },
             enemy_type: EnemyType::InfectedMacrophage,
+            spawn_time: 0.0,
+            via_portal: true,
         });
         wave_manager.enemies_remaining += 1;
     }
@@ -958,17 +1541,21 @@ fn get_default_ai_for_enemy(enemy_type: EnemyType) -> EnemyAI {
             target_pos: Vec2::ZERO,
             dive_speed: 200.0,
             acquired_target: false,
+            retarget_interval: 1.0,
+            retarget_timer: 0.0,
         },
         EnemyType::BiofilmColony => EnemyAI::Turret {
             rotation: 0.0,
             shoot_timer: 0.0,
             detection_range: 250.0,
+            windup_timer: 0.0,
         },
-        EnemyType::SwarmCell => EnemyAI::Formation {
-            formation_id: 0,
-            position_in_formation: Vec2::ZERO,
-            leader_offset: Vec2::ZERO,
-            formation_timer: 0.0,
+        EnemyType::SwarmCell => EnemyAI::Flocking {
+            separation: 1.6,
+            alignment: 1.0,
+            cohesion: 0.8,
+            neighbor_radius: 90.0,
+            heading: Vec2::new(0.0, -1.0),
         },
         EnemyType::ReproductiveVesicle => EnemyAI::Spawner {
             spawn_timer: 3.0,
@@ -1013,10 +1600,8 @@ fn spawn_environmental_hazard(
 
             active_hazards.push(ActiveHazard {
                 hazard_type: hazard.hazard_type,
-                position: Vec3::ZERO,
                 intensity: hazard.intensity,
                 remaining_time: hazard.duration,
-                affected_area: hazard.affected_area,
                 entity: Some(entity),
             });
         }
@@ -1043,17 +1628,15 @@ fn update_hazard_effects(
     // Update hazard visual effects based on remaining time
     let fade_factor = (hazard.remaining_time / 45.0).clamp(0.3, 1.0);
     
-    if let Some(entity) = hazard.entity {
-        if let Ok(mut sprite) = hazard_sprites.get_mut(entity) {
-            let mut base_color = get_hazard_color(hazard.hazard_type);
-            base_color.set_alpha(base_color.alpha() * fade_factor);
-            sprite.color = base_color;
+    if let Some(entity) = hazard.entity && let Ok(mut sprite) = hazard_sprites.get_mut(entity) {
+        let mut base_color = get_hazard_color(hazard.hazard_type);
+        base_color.set_alpha(base_color.alpha() * fade_factor);
+        sprite.color = base_color;
             
-            // Add pulsing effect for more dangerous hazards
-            if hazard.intensity > 1.0 {
-                let pulse = (hazard.remaining_time * 4.0).sin() * 0.2 + 0.8;
-                sprite.color = multiply_color(sprite.color, pulse);
-            }
+        // Add pulsing effect for more dangerous hazards
+        if hazard.intensity > 1.0 {
+            let pulse = (hazard.remaining_time * 4.0).sin() * 0.2 + 0.8;
+            sprite.color = multiply_color(sprite.color, pulse);
         }
     }
 }
@@ -1079,4 +1662,171 @@ fn hazard_to_zone_type(hazard_type: HazardType) -> ZoneType {
         HazardType::ChemicalSpill => ZoneType::Toxic,
         HazardType::KingTide => ZoneType::Current,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Headless app running only `wave_progression_system`
+    fn wave_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<WaveManager>()
+            .init_resource::<EnemySpawner>()
+            .init_resource::<WaveSpawnQueue>()
+            .init_resource::<WaveGraceConfig>()
+            .init_resource::<SpawnEdgeConfig>()
+            .init_resource::<GridFormation>()
+            .init_resource::<WaveEnvironmentCurve>()
+            .init_resource::<ChemicalEnvironment>()
+            .init_resource::<FluidEnvironment>()
+            .add_systems(Update, wave_progression_system);
+        app
+    }
+
+    fn activate_wave(app: &mut App, spawned: u32) {
+        let mut wave_manager = app.world_mut().resource_mut::<WaveManager>();
+        wave_manager.wave_active = true;
+        wave_manager.enemies_remaining = spawned;
+    }
+
+    #[test]
+    fn wave_with_every_enemy_dead_and_nothing_queued_completes() {
+        let mut app = wave_app();
+        activate_wave(&mut app, 6);
+        let wave = app.world().resource::<WaveManager>().current_wave;
+        app.update();
+
+        let wave_manager = app.world().resource::<WaveManager>();
+        assert!(!wave_manager.wave_active);
+        assert_eq!(wave_manager.current_wave, wave + 1);
+    }
+
+    #[test]
+    fn wave_stays_active_while_enemies_live_or_spawns_are_queued() {
+        let mut app = wave_app();
+        activate_wave(&mut app, 6);
+        let enemy = app.world_mut().spawn(Enemy::default()).id();
+        app.update();
+        assert!(app.world().resource::<WaveManager>().wave_active);
+
+        app.world_mut().despawn(enemy);
+        app.world_mut().resource_mut::<WaveSpawnQueue>().push(QueuedSpawn {
+            enemy_type: EnemyType::ViralParticle,
+            ai_type: EnemyAI::Linear { direction: Vec2::NEG_Y },
+            position: Vec3::new(0.0, 400.0, 0.0),
+            spawn_time: 5.0,
+            via_portal: false,
+        });
+        app.update();
+        assert!(app.world().resource::<WaveManager>().wave_active);
+    }
+
+    #[test]
+    fn endless_grid_wave_enters_above_the_live_formation_slots() {
+        let mut wave_manager = WaveManager { current_wave: ENDLESS_START_WAVE + 1, ..default() };
+        let mut spawn_queue = WaveSpawnQueue::default();
+        let formation = GridFormation { origin: Vec2::new(120.0, 280.0), ..default() };
+        start_wave(&mut wave_manager, &mut spawn_queue, 0.0, None, &WaveGraceConfig::default(), &SpawnEdgeConfig::default(), &formation);
+
+        let grid_spawns: Vec<&QueuedSpawn> = spawn_queue.pending.iter()
+            .filter(|spawn| matches!(spawn.ai_type, EnemyAI::GridMember { .. }))
+            .collect();
+        assert_eq!(grid_spawns.len(), (GRID_WAVE_COLUMNS * GRID_WAVE_ROWS) as usize);
+        for spawn in grid_spawns {
+            let EnemyAI::GridMember { home_slot, .. } = spawn.ai_type else { unreachable!() };
+            assert_eq!(spawn.position.x, formation.slot_position(home_slot).x);
+        }
+    }
+
+    #[test]
+    fn left_edge_spawns_start_off_screen_and_head_right() {
+        let (position, inward) = SpawnEdge::Left.entry_point(0.0);
+        assert!(position.x <= -EDGE_SPAWN_SIDE_X);
+        assert_eq!(inward, Vec2::X);
+
+        let ai_type = orient_ai_for_edge(EnemyAI::Linear { direction: Vec2::NEG_Y }, SpawnEdge::Left);
+        assert!(matches!(ai_type, EnemyAI::Linear { direction } if direction == Vec2::X));
+    }
+
+    #[test]
+    fn default_edges_leave_the_bottom_opt_in() {
+        let config = SpawnEdgeConfig::default();
+        assert!(!config.enabled_edges.contains(&SpawnEdge::Bottom));
+        assert!(config.enabled_edges.contains(&SpawnEdge::Left) && config.enabled_edges.contains(&SpawnEdge::Right));
+    }
+
+    #[test]
+    fn portal_releases_its_enemy_only_once_fully_open() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<SpawnEnemy>()
+            .add_systems(Update, spawn_portal_system);
+        app.world_mut().spawn((
+            Sprite::default(),
+            Transform::from_xyz(50.0, 200.0, 0.1),
+            SpawnPortal { enemy_type: EnemyType::AggressiveBacteria, ai_type: EnemyAI::Linear { direction: Vec2::NEG_Y }, timer: 0.0, windup: 1.0 },
+        ));
+
+        let mut released = Vec::new();
+        for _ in 0..6 {
+            app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(250));
+            app.update();
+            released.push(app.world_mut().resource_mut::<Events<SpawnEnemy>>().drain().collect::<Vec<_>>());
+        }
+
+        // Quarter-second steps open a one second portal on the fourth tick
+        assert!(released[..3].iter().all(Vec::is_empty));
+        assert_eq!(released[3].len(), 1);
+        assert_eq!(released[3][0].enemy_type, EnemyType::AggressiveBacteria);
+        assert_eq!(released[3][0].position, Vec3::new(50.0, 200.0, 0.0));
+        assert!(released[4..].iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn preview_lists_exactly_what_each_scripted_wave_queues() {
+        for wave in 1..ENDLESS_START_WAVE {
+            let mut wave_manager = WaveManager { current_wave: wave, ..default() };
+            let preview = wave_manager.upcoming_composition();
+            let mut spawn_queue = WaveSpawnQueue::default();
+            start_wave(&mut wave_manager, &mut spawn_queue, 0.0, None, &WaveGraceConfig::default(), &SpawnEdgeConfig::default(), &GridFormation::default());
+
+            let queued = spawn_queue.composition();
+            assert_eq!(preview.random_count, 0, "wave {wave}");
+            assert_eq!(preview.counts.len(), queued.len(), "wave {wave}");
+            for entry in &preview.counts {
+                assert!(queued.contains(entry), "wave {wave} previews {entry:?} but queues {queued:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn starting_later_waves_follows_the_environment_curve() {
+        let mut app = wave_app();
+        app.insert_resource(WaveEnvironmentCurve {
+            enabled: true,
+            keys: vec![
+                EnvironmentKey { wave: 1, base_ph: 7.0, base_oxygen: 0.5, turbulence: 0.2 },
+                EnvironmentKey { wave: 11, base_ph: 6.0, base_oxygen: 0.3, turbulence: 0.7 },
+            ],
+        });
+        let start_wave_number = |app: &mut App, wave: u32| {
+            let now = app.world().resource::<Time>().elapsed_secs();
+            let mut wave_manager = app.world_mut().resource_mut::<WaveManager>();
+            wave_manager.current_wave = wave;
+            wave_manager.wave_active = false;
+            wave_manager.wave_complete_time = now;
+            app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_secs(5));
+            app.update();
+            assert!(app.world().resource::<WaveManager>().wave_active);
+            (app.world().resource::<ChemicalEnvironment>().base_ph, app.world().resource::<FluidEnvironment>().turbulence_intensity)
+        };
+
+        let (ph, turbulence) = start_wave_number(&mut app, 6);
+        assert!((ph - 6.5).abs() < 1e-4 && (turbulence - 0.45).abs() < 1e-4);
+
+        let (ph, turbulence) = start_wave_number(&mut app, 30);
+        assert!((ph - 6.0).abs() < 1e-4 && (turbulence - 0.7).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,221 @@
+// src/weapon_mods.rs - Socketable weapon mods applied to player projectiles
+use bevy::prelude::*;
+use bevy::ecs::system::EntityCommands;
+use crate::components::*;
+use crate::resources::*;
+use crate::events::*;
+use crate::enemy_types::*;
+use crate::input::*;
+
+// ===== CONSTANTS =====
+const MOD_SLOT_COUNT: usize = 2;
+const MOD_SOCKET_COST: u32 = 40;
+const MOD_CHAMBER_RANGE: f32 = 80.0;
+const PIERCE_MOD_HITS: u32 = 2;
+const HOMING_MOD_TURN_RATE: f32 = 4.0;
+const HOMING_MOD_RANGE: f32 = 350.0;
+const SPLIT_MOD_FRAGMENTS: u32 = 3;
+const SPLIT_FRAGMENT_ANGLE: f32 = 0.5; // Radians between fragments
+
+// ===== MOD TYPES =====
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeaponMod {
+    Pierce,
+    Homing,
+    SplitOnHit,
+}
+
+impl WeaponMod {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WeaponMod::Pierce => "+Pierce",
+            WeaponMod::Homing => "+Homing",
+            WeaponMod::SplitOnHit => "+Split",
+        }
+    }
+}
+
+/// Mods socketed into the player's cannon, applied to every main cannon shot
+#[derive(Resource, Clone)]
+pub struct EquippedMods {
+    pub slots: Vec<WeaponMod>,
+    pub max_slots: usize,
+}
+
+impl Default for EquippedMods {
+    fn default() -> Self {
+        Self {
+            slots: Vec::new(),
+            max_slots: MOD_SLOT_COUNT,
+        }
+    }
+}
+
+impl EquippedMods {
+    pub fn has(&self, weapon_mod: WeaponMod) -> bool {
+        self.slots.contains(&weapon_mod)
+    }
+
+    /// Returns false when the mod is already socketed or every slot is taken
+    pub fn equip(&mut self, weapon_mod: WeaponMod) -> bool {
+        if self.has(weapon_mod) || self.slots.len() >= self.max_slots {
+            return false;
+        }
+        self.slots.push(weapon_mod);
+        true
+    }
+
+    pub fn unequip(&mut self, weapon_mod: WeaponMod) {
+        self.slots.retain(|m| *m != weapon_mod);
+    }
+}
+
+// ===== PROJECTILE COMPONENTS =====
+#[derive(Component)]
+pub struct PiercingMod {
+    pub remaining: u32,
+    pub last_hit: Option<Entity>, // Skip the enemy we're still passing through
+}
+
+#[derive(Component)]
+pub struct HomingMod {
+    pub turn_rate: f32,
+    pub range: f32,
+}
+
+#[derive(Component)]
+pub struct SplitOnHitMod {
+    pub fragments: u32,
+}
+
+/// Insert a component for each equipped mod onto a freshly spawned projectile
+pub fn apply_weapon_mods(projectile: &mut EntityCommands, mods: &EquippedMods) {
+    for weapon_mod in &mods.slots {
+        match weapon_mod {
+            WeaponMod::Pierce => { projectile.insert(PiercingMod { remaining: PIERCE_MOD_HITS, last_hit: None }); }
+            WeaponMod::Homing => { projectile.insert(HomingMod { turn_rate: HOMING_MOD_TURN_RATE, range: HOMING_MOD_RANGE }); }
+            WeaponMod::SplitOnHit => { projectile.insert(SplitOnHitMod { fragments: SPLIT_MOD_FRAGMENTS }); }
+        }
+    }
+}
+
+// ===== SYSTEMS =====
+/// Socket or unsocket mods while parked at an evolution chamber
+pub fn weapon_mod_socket_system(
+    input_manager: Res<InputManager>,
+    mut equipped_mods: ResMut<EquippedMods>,
+    chamber_query: Query<&Transform, With<EvolutionChamber>>,
+    mut player_query: Query<(&Transform, &mut ATP), With<Player>>,
+) {
+    let Ok((player_transform, mut atp)) = player_query.single_mut() else { return };
+
+    let at_chamber = chamber_query.iter()
+        .any(|chamber| chamber.translation.distance(player_transform.translation) < MOD_CHAMBER_RANGE);
+    if !at_chamber { return; }
+
+    for (action, weapon_mod) in [
+        (InputAction::SocketPierceMod, WeaponMod::Pierce),
+        (InputAction::SocketHomingMod, WeaponMod::Homing),
+        (InputAction::SocketSplitMod, WeaponMod::SplitOnHit),
+    ] {
+        if !input_manager.just_pressed(action) { continue; }
+
+        if equipped_mods.has(weapon_mod) {
+            equipped_mods.unequip(weapon_mod);
+            info!("Unsocketed {}", weapon_mod.display_name());
+        } else if atp.amount >= MOD_SOCKET_COST && equipped_mods.equip(weapon_mod) {
+            atp.amount -= MOD_SOCKET_COST;
+            info!("Socketed {}", weapon_mod.display_name());
+        }
+    }
+}
+
+pub fn homing_mod_system(
+    mut projectile_query: Query<(&mut Transform, &mut Projectile, &HomingMod), Without<PendingDespawn>>,
+    enemy_query: Query<&Transform, (With<Enemy>, Without<Projectile>)>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut projectile, homing) in projectile_query.iter_mut() {
+        let position = transform.translation.truncate();
+        let target = enemy_query.iter()
+            .map(|enemy| enemy.translation.truncate())
+            .filter(|enemy_pos| enemy_pos.distance(position) <= homing.range)
+            .min_by(|a, b| a.distance_squared(position).total_cmp(&b.distance_squared(position)));
+
+        let Some(target) = target else { continue };
+
+        let speed = projectile.velocity.length();
+        let current = projectile.velocity.normalize_or_zero();
+        let desired = (target - position).normalize_or_zero();
+        let new_direction = current.lerp(desired, (homing.turn_rate * time.delta_secs()).min(1.0)).normalize_or_zero();
+
+        projectile.velocity = new_direction * speed;
+        transform.rotation = Quat::from_rotation_z(new_direction.y.atan2(new_direction.x) - std::f32::consts::FRAC_PI_2);
+    }
+}
+
+/// Fan split fragments out around the parent shot's heading, fragments carry no mods
+pub fn projectile_split_system(
+    mut commands: Commands,
+    mut split_events: EventReader<ProjectileSplit>,
+    assets: Option<Res<GameAssets>>,
+) {
+    let Some(assets) = assets else { return };
+
+    for event in split_events.read() {
+        let speed = event.velocity.length();
+        let heading = event.velocity.normalize_or(Vec2::Y);
+        let fragment_damage = (event.damage / 2).max(1);
+
+        for i in 0..event.fragments {
+            let angle = (i as f32 - (event.fragments - 1) as f32 / 2.0) * SPLIT_FRAGMENT_ANGLE;
+            let direction = Vec2::from_angle(angle).rotate(heading);
+
+            commands.spawn((
+                Sprite {
+                    image: assets.projectile_texture.clone(),
+                    color: Color::srgb(0.6, 1.0, 0.9),
+                    custom_size: Some(Vec2::splat(5.0)),
+                    ..default()
+                },
+                Transform::from_translation(event.position + (direction * 12.0).extend(0.0))
+                    .with_rotation(Quat::from_rotation_z(direction.y.atan2(direction.x) - std::f32::consts::FRAC_PI_2)),
+                Projectile {
+                    velocity: direction * speed,
+                    damage: fragment_damage,
+                    friendly: true,
+                    organic_trail: false,
+                },
+                Collider { radius: 2.5 },
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fire_shot(world: &mut World, mods: &EquippedMods) -> Entity {
+        let mut commands = world.commands();
+        let mut projectile = commands.spawn(Projectile { velocity: Vec2::Y * 850.0, damage: 10, friendly: true, organic_trail: true });
+        apply_weapon_mods(&mut projectile, mods);
+        let entity = projectile.id();
+        world.flush();
+        entity
+    }
+
+    #[test]
+    fn pierce_mod_adds_piercing_until_it_is_unsocketed() {
+        let mut world = World::new();
+        let mut mods = EquippedMods::default();
+        assert!(mods.equip(WeaponMod::Pierce));
+
+        let pierced = fire_shot(&mut world, &mods);
+        assert_eq!(world.get::<PiercingMod>(pierced).unwrap().remaining, PIERCE_MOD_HITS);
+
+        mods.unequip(WeaponMod::Pierce);
+        let plain = fire_shot(&mut world, &mods);
+        assert!(world.get::<PiercingMod>(plain).is_none());
+    }
+}
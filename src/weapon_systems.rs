@@ -6,6 +6,10 @@ use crate::enemy_types::*;
 use crate::input::*;
 use crate::constants::*;
 use crate::despawn::*;
+use crate::weapon_mods::*;
+use crate::systems::{EnemyDamage, EnemyDeaths};
+use std::collections::HashMap;
+use rand::Rng;
 
 // New components for biological weapons
 #[derive(Component)]
@@ -89,7 +93,7 @@ pub fn unified_weapon_update_system(
             Entity, 
             &mut LaserBeam, 
             &mut Sprite, 
-            &Transform, 
+            &mut Transform, 
             Option<&BioluminescentParticle>
         ), (With<LaserBeam>, Without<MissileProjectile>, Without<SporeWave>, Without<ToxinCloudEffect>)>,
         
@@ -114,11 +118,13 @@ pub fn unified_weapon_update_system(
     )>,
     
     // Separate enemy queries to avoid conflicts
-    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<MissileProjectile>, Without<SporeWave>, Without<LaserBeam>, Without<ToxinCloudEffect>)>,
-    mut enemy_health_query: Query<(Entity, &Transform, &Collider, &mut Health), (With<Enemy>, Without<ToxinCloudEffect>, Without<LaserBeam>, Without<SporeWave>, Without<MissileProjectile>)>,
+    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<MissileProjectile>, Without<SporeWave>, Without<LaserBeam>, Without<ToxinCloudEffect>, Without<BossIntro>)>,
+    mut enemy_health_query: Query<(Entity, &Transform, &Collider, &mut Health, &Enemy, Has<DamageCap>), (Without<ToxinCloudEffect>, Without<LaserBeam>, Without<SporeWave>, Without<MissileProjectile>, Without<BossIntro>)>,
     
     // Events and resources
-    mut explosion_events: EventWriter<SpawnExplosion>,
+    mut deaths: EnemyDeaths,
+    mut enemy_damage: EnemyDamage,
+    mut beam_damage_carry: Local<HashMap<Entity, f32>>,
     assets: Option<Res<GameAssets>>,
     time: Res<Time>,
 ) {
@@ -165,9 +171,10 @@ pub fn unified_weapon_update_system(
     }
 
     // 2. UPDATE LASER BEAMS
+    beam_damage_carry.retain(|entity, _| enemy_health_query.contains(*entity));
     if let Some(assets) = &assets {
         let mut lasers = weapon_queries.p1();
-        for (entity, mut laser, mut sprite, transform, bio_particle) in lasers.iter_mut() {
+        for (entity, mut laser, mut sprite, mut transform, bio_particle) in lasers.iter_mut() {
             laser.timer += time.delta_secs();
             
             if laser.timer >= laser.max_duration {
@@ -175,6 +182,42 @@ pub fn unified_weapon_update_system(
                     .safe_despawn();
                 continue;
             }
+
+            // Sweep locked beams toward their target, keeping the last heading if it died
+            if let Some(target) = laser.locked_target {
+                if let Ok((_, target_transform)) = enemy_query.get(target) {
+                    let direction = beam_direction(laser.origin.truncate(), Some(target_transform.translation.truncate()));
+                    *transform = beam_transform(laser.origin, direction, laser.length);
+                } else {
+                    laser.locked_target = None;
+                }
+            }
+
+            // Multi-hit along the (possibly rotated) beam segment. Fractions carry over per target
+            // so damage per second doesn't depend on the frame rate
+            let beam_axis = (transform.rotation * Vec3::Y).truncate();
+            let frame_damage = laser.damage_per_second as f32 * time.delta_secs();
+            for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health, enemy, is_capped) in enemy_health_query.iter_mut() {
+                if enemy_health.0 <= 0 { continue; }
+                if beam_hits_circle(
+                    laser.origin.truncate(),
+                    beam_axis,
+                    laser.length,
+                    laser.width / 2.0,
+                    enemy_transform.translation.truncate(),
+                    enemy_collider.radius,
+                ) {
+                    let carry = beam_damage_carry.entry(enemy_entity).or_insert(0.0);
+                    *carry += frame_damage;
+                    let tick_damage = carry.floor();
+                    *carry -= tick_damage;
+                    enemy_damage.deal(&mut enemy_health, tick_damage as i32, enemy, is_capped, "Bioluminescent Beam");
+                    
+                    if enemy_health.0 <= 0 {
+                        deaths.kill(enemy_entity, enemy_transform.translation, enemy);
+                    }
+                }
+            }
             
             // Enhanced fade with bioluminescent pulsing
             let base_alpha = 1.0 - (laser.timer / laser.max_duration);
@@ -187,6 +230,7 @@ pub fn unified_weapon_update_system(
                 if (time.elapsed_secs() * 20.0) % 1.0 < 0.1 {
                     for i in 0..5 {
                         let y_offset = (i as f32 - 2.0) * laser.length / 5.0;
+                        let particle_offset = transform.rotation * Vec3::new(0.0, y_offset, 0.1);
                         commands.spawn((
                             Sprite {
                                 image: assets.particle_texture.clone(),
@@ -194,7 +238,7 @@ pub fn unified_weapon_update_system(
                                 custom_size: Some(Vec2::splat(3.0)),
                                 ..default()
                             },
-                            Transform::from_translation(transform.translation + Vec3::new(0.0, y_offset, 0.1)),
+                            Transform::from_translation(transform.translation + particle_offset),
                             Particle {
                                 velocity: Vec2::new(
                                     (time.elapsed_secs() * 234.56).sin() * 20.0,
@@ -242,10 +286,11 @@ pub fn unified_weapon_update_system(
             sprite.color = Color::srgba(1.0 - color_shift, 0.8, 0.3 + color_shift, alpha);
             
             // Damage enemies within radius
-            for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health) in enemy_health_query.iter_mut() {
+            for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health, enemy, is_capped) in enemy_health_query.iter_mut() {
+                if enemy_health.0 <= 0 { continue; }
                 let distance = spore_transform.translation.distance(enemy_transform.translation);
                 if distance <= spore.current_radius {
-                    enemy_health.0 -= spore.damage;
+                    enemy_damage.deal(&mut enemy_health, spore.damage, enemy, is_capped, "Emergency Spore");
                     
                     // Spawn organic destruction particles
                     if let Some(assets) = &assets {
@@ -275,13 +320,7 @@ pub fn unified_weapon_update_system(
                     }
                     
                     if enemy_health.0 <= 0 {
-                        explosion_events.write(SpawnExplosion {
-                            position: enemy_transform.translation,
-                            intensity: 1.8,
-                            enemy_type: None,
-                        });
-                        commands.entity(enemy_entity)
-                    .safe_despawn();
+                        deaths.kill(enemy_entity, enemy_transform.translation, enemy);
                     }
                 }
             }
@@ -309,20 +348,15 @@ pub fn unified_weapon_update_system(
             sprite.color.set_alpha(cloud.intensity * 0.6);
             
             // Damage enemies in cloud
-            for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health) in enemy_health_query.iter_mut() {
+            for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health, enemy, is_capped) in enemy_health_query.iter_mut() {
+                if enemy_health.0 <= 0 { continue; }
                 let distance = cloud_transform.translation.distance(enemy_transform.translation);
                 if distance <= cloud.radius {
                     let damage = (cloud.damage_per_second as f32 * time.delta_secs()) as i32;
-                    enemy_health.0 -= damage;
+                    enemy_damage.deal(&mut enemy_health, damage, enemy, is_capped, "Toxin Cloud");
                     
                     if enemy_health.0 <= 0 {
-                        explosion_events.write(SpawnExplosion {
-                            position: enemy_transform.translation,
-                            intensity: 0.8,
-                            enemy_type: None,
-                        });
-                        commands.entity(enemy_entity)
-                    .safe_despawn();
+                        deaths.kill(enemy_entity, enemy_transform.translation, enemy);
                     }
                 }
             }
@@ -342,51 +376,46 @@ pub fn unified_weapon_update_system(
             }
             
             // Apply damage to target
-            if let Some(target_entity) = arc.target_entity {
-                if let Ok((_, target_transform, _, mut target_health)) = enemy_health_query.get_mut(target_entity) {
-                    target_health.0 -= arc.damage;
+            if let Some(target_entity) = arc.target_entity
+                && let Ok((_, target_transform, _, mut target_health, target_enemy, is_capped)) = enemy_health_query.get_mut(target_entity)
+            {
+                if target_health.0 <= 0 { continue; }
+                enemy_damage.deal(&mut target_health, arc.damage, target_enemy, is_capped, "Electric Discharge");
                     
-                    // Spawn arc visual effect
-                    if let Some(assets) = &assets {
-                        let segments = 8;
-                        for i in 0..segments {
-                            let t = i as f32 / segments as f32;
-                            let pos = arc.start_pos.lerp(arc.end_pos, t);
-                            let jitter = Vec2::new(
-                                (time.elapsed_secs() * 20.0 + i as f32).sin() * 5.0,
-                                (time.elapsed_secs() * 25.0 + i as f32).cos() * 5.0,
-                            );
+                // Spawn arc visual effect
+                if let Some(assets) = &assets {
+                    let segments = 8;
+                    for i in 0..segments {
+                        let t = i as f32 / segments as f32;
+                        let pos = arc.start_pos.lerp(arc.end_pos, t);
+                        let jitter = Vec2::new(
+                            (time.elapsed_secs() * 20.0 + i as f32).sin() * 5.0,
+                            (time.elapsed_secs() * 25.0 + i as f32).cos() * 5.0,
+                        );
                             
-                            commands.spawn((
-                                Sprite {
-                                    image: assets.particle_texture.clone(),
-                                    color: Color::srgb(0.8, 0.9, 1.0),
-                                    custom_size: Some(Vec2::splat(3.0)),
-                                    ..default()
-                                },
-                                Transform::from_translation((pos + jitter).extend(0.0)),
-                                Particle {
-                                    velocity: Vec2::ZERO,
-                                    lifetime: 0.0,
-                                    max_lifetime: 0.1,
-                                    size: 3.0,
-                                    fade_rate: 10.0,
-                                    bioluminescent: true,
-                                    drift_pattern: DriftPattern::Pulsing,
-                                },
-                            ));
-                        }
+                        commands.spawn((
+                            Sprite {
+                                image: assets.particle_texture.clone(),
+                                color: Color::srgb(0.8, 0.9, 1.0),
+                                custom_size: Some(Vec2::splat(3.0)),
+                                ..default()
+                            },
+                            Transform::from_translation((pos + jitter).extend(0.0)),
+                            Particle {
+                                velocity: Vec2::ZERO,
+                                lifetime: 0.0,
+                                max_lifetime: 0.1,
+                                size: 3.0,
+                                fade_rate: 10.0,
+                                bioluminescent: true,
+                                drift_pattern: DriftPattern::Pulsing,
+                            },
+                        ));
                     }
+                }
                     
-                    if target_health.0 <= 0 {
-                        explosion_events.write(SpawnExplosion {
-                            position: target_transform.translation,
-                            intensity: 1.2,
-                            enemy_type: None,
-                        });
-                        commands.entity(target_entity)
-                    .safe_despawn();
-                    }
+                if target_health.0 <= 0 {
+                    deaths.kill(target_entity, target_transform.translation, target_enemy);
                 }
             }
         }
@@ -394,9 +423,120 @@ pub fn unified_weapon_update_system(
 }
 
 
+// ===== BEAM TARGET LOCK =====
+/// Heading from the beam origin toward the lock target, straight up when unlocked
+pub fn beam_direction(origin: Vec2, target: Option<Vec2>) -> Vec2 {
+    target
+        .map(|target| (target - origin).normalize_or(Vec2::Y))
+        .unwrap_or(Vec2::Y)
+}
+
+/// Beam sprites are centred on their segment, rotated so local +Y follows `direction`
+pub fn beam_transform(origin: Vec3, direction: Vec2, length: f32) -> Transform {
+    let angle = direction.y.atan2(direction.x) - std::f32::consts::FRAC_PI_2;
+    Transform::from_translation(origin + (direction * length * 0.5).extend(0.0))
+        .with_rotation(Quat::from_rotation_z(angle))
+}
+
+/// Segment-vs-circle test for the beam's multi-hit collision
+pub fn beam_hits_circle(origin: Vec2, direction: Vec2, length: f32, half_width: f32, center: Vec2, radius: f32) -> bool {
+    let along = (center - origin).dot(direction).clamp(0.0, length);
+    let closest = origin + direction * along;
+    closest.distance_squared(center) <= (half_width + radius).powi(2)
+}
+
+pub fn beam_lock_system(
+    mut commands: Commands,
+    mut input_manager: ResMut<InputManager>,
+    player_query: Query<(Entity, &Transform, Option<&BeamLock>), With<Player>>,
+    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<Player>)>,
+) {
+    let Ok((player_entity, player_transform, beam_lock)) = player_query.single() else { return };
+
+    let nearest = || {
+        enemy_query
+            .iter()
+            .min_by(|(_, a), (_, b)| {
+                a.translation.distance_squared(player_transform.translation)
+                    .total_cmp(&b.translation.distance_squared(player_transform.translation))
+            })
+            .map(|(entity, _)| entity)
+    };
+
+    if input_manager.consume_press(InputAction::ToggleBeamLock) {
+        if beam_lock.is_some() {
+            commands.entity(player_entity).try_remove::<BeamLock>();
+        } else {
+            commands.entity(player_entity).try_insert(BeamLock { target: nearest() });
+        }
+        return;
+    }
+
+    // Re-acquire when the locked enemy is destroyed
+    if let Some(lock) = beam_lock {
+        let target_alive = lock.target.is_some_and(|target| enemy_query.contains(target));
+        if !target_alive {
+            let next_target = nearest();
+            if next_target != lock.target {
+                commands.entity(player_entity).try_insert(BeamLock { target: next_target });
+            }
+        }
+    }
+}
+
+// ===== EVOLUTION SWITCHING =====
+/// Evolutions the player has permanently acquired this run, in the order they were gained
+#[derive(Resource, Default)]
+pub struct OwnedEvolutions {
+    pub owned: Vec<EvolutionType>,
+}
+
+impl OwnedEvolutions {
+    /// Track an evolution, one entry per kind so stat tweaks don't duplicate it
+    pub fn add(&mut self, evolution: &EvolutionType) {
+        let kind = std::mem::discriminant(evolution);
+        match self.owned.iter_mut().find(|owned| std::mem::discriminant(*owned) == kind) {
+            Some(owned) => *owned = evolution.clone(),
+            None => self.owned.push(evolution.clone()),
+        }
+    }
+
+    /// The owned evolution after `current`, wrapping around
+    pub fn next_after(&self, current: &EvolutionType) -> Option<EvolutionType> {
+        if self.owned.len() < 2 { return None; }
+        let kind = std::mem::discriminant(current);
+        let index = self.owned.iter().position(|owned| std::mem::discriminant(owned) == kind)?;
+        Some(self.owned[(index + 1) % self.owned.len()].clone())
+    }
+}
+
+/// Remember every evolution the player settles on; temporary swaps are borrowed, not owned
+pub fn track_owned_evolutions(
+    mut owned: ResMut<OwnedEvolutions>,
+    player_query: Query<&EvolutionSystem, (With<Player>, Changed<EvolutionSystem>, Without<TemporaryEvolutionSwap>)>,
+) {
+    for evolution_system in player_query.iter() {
+        owned.add(&evolution_system.primary_evolution);
+    }
+}
+
+pub fn cycle_evolution_system(
+    mut input_manager: ResMut<InputManager>,
+    owned: Res<OwnedEvolutions>,
+    mut player_query: Query<&mut EvolutionSystem, (With<Player>, Without<TemporaryEvolutionSwap>)>,
+) {
+    if !input_manager.consume_press(InputAction::CycleEvolution) { return; }
+    let Ok(mut evolution_system) = player_query.single_mut() else { return };
+
+    if let Some(next) = owned.next_after(&evolution_system.primary_evolution) {
+        info!("Switched evolution to {}", next.get_display_name());
+        evolution_system.primary_evolution = next;
+    }
+}
+
 // Helper function to find nearest enemy
 fn find_nearest_enemy(
-    enemy_query: &Query<(Entity, &Transform), (With<Enemy>, Without<MissileProjectile>, Without<SporeWave>, Without<LaserBeam>, Without<ToxinCloudEffect>)>,
+    enemy_query: &Query<(Entity, &Transform), (With<Enemy>, Without<MissileProjectile>, Without<SporeWave>, Without<LaserBeam>, Without<ToxinCloudEffect>, Without<BossIntro>)>,
     player_pos: Vec3,
 ) -> Option<Entity> {
     enemy_query
@@ -413,7 +553,7 @@ fn find_nearest_enemy(
 
 pub fn enhanced_shooting_system(
     mut commands: Commands,
-    input_manager: Res<InputManager>,
+    mut input_manager: ResMut<InputManager>,
     mut player_query: Query<(
         &Transform, 
         &mut EvolutionSystem, 
@@ -421,17 +561,24 @@ pub fn enhanced_shooting_system(
         Option<&mut WingCannon>,
         Option<&mut MissileSystem>
     ), With<Player>>,
-    enemy_query: Query<(Entity, &Transform, &Enemy), (Without<AutoMissile>, Without<Player>)>,
+    enemy_query: Query<(Entity, &Transform, &Enemy), (Without<AutoMissile>, Without<Player>, Without<BossIntro>)>,
     assets: Option<Res<GameAssets>>,
+    combo: Res<ComboState>,
+    equipped_mods: Res<EquippedMods>,
+    mut spread_rng: ResMut<WeaponSpreadRng>,
+    spore_aiming: Res<SporeAiming>,
     time: Res<Time>,
     mut main_cannon_timer: Local<f32>,
+    mut cannon_heat: Local<f32>,
 ) {
     let Some(assets) = assets else { return };
     
     *main_cannon_timer -= time.delta_secs();
+    *cannon_heat = (*cannon_heat - HEAT_COOLDOWN_RATE * time.delta_secs()).max(0.0);
     
     if let Ok((player_transform, mut evolution_system, upgrades, wing_cannon, missile_system)) = player_query.single_mut() {
         let shooting = input_manager.pressed(InputAction::Shoot);
+        let accuracy = effective_accuracy(upgrades.accuracy, *cannon_heat);
         
         // ===== MAIN CANNON (Enhanced) =====
         if shooting && *main_cannon_timer <= 0.0 {
@@ -440,11 +587,16 @@ pub fn enhanced_shooting_system(
                 &assets,
                 player_transform,
                 &evolution_system,
-                upgrades
+                upgrades,
+                combo.weapon_buff_active(),
+                &equipped_mods,
+                accuracy,
+                &mut spread_rng.rng,
             );
             
             let base_fire_rate = evolution_system.primary_evolution.get_fire_rate();
             *main_cannon_timer = base_fire_rate / upgrades.metabolic_rate;
+            *cannon_heat = (*cannon_heat + HEAT_PER_VOLLEY).min(1.0);
         }
 
         // ===== WING CANNONS =====
@@ -457,7 +609,9 @@ pub fn enhanced_shooting_system(
                     &assets,
                     player_transform,
                     &wing_cannon,
-                    upgrades
+                    upgrades,
+                    accuracy,
+                    &mut spread_rng.rng,
                 );
                 wing_cannon.fire_timer = wing_cannon.fire_rate / upgrades.metabolic_rate;
             }
@@ -488,7 +642,8 @@ pub fn enhanced_shooting_system(
         }
 
         // ===== EMERGENCY SPORE =====
-        if input_manager.just_pressed(InputAction::EmergencySpore) && evolution_system.emergency_spores > 0 {
+        // Aimed spores are handled by spore_aiming_system
+        if !spore_aiming.enabled && evolution_system.emergency_spores > 0 && input_manager.consume_press(InputAction::EmergencySpore) {
             spawn_emergency_spore(&mut commands, &assets, player_transform.translation);
             evolution_system.emergency_spores -= 1;
         }
@@ -501,42 +656,47 @@ fn spawn_enhanced_main_cannon_projectiles(
     player_transform: &Transform,
     evolution_system: &EvolutionSystem,
     upgrades: &CellularUpgrades,
+    combo_buff: bool,
+    mods: &EquippedMods,
+    accuracy: f32,
+    rng: &mut impl Rng,
 ) {
     let damage_level = upgrades.damage_amplification;
     let base_damage = evolution_system.primary_evolution.get_base_damage();
     let final_damage = (base_damage as f32 * damage_level) as i32;
     
-    // Determine number of projectiles based on damage level
-    let projectile_count = match damage_level as u32 {
-        1..=1 => 1,
-        2..=2 => 2, // Add second projectile
-        3..=3 => 3, // Add third projectile
-        4..=4 => 4, // Four projectiles
-        _ => 5,     // Maximum five projectiles
-    } as f32;
+    let projectile_count = main_cannon_projectile_count(damage_level, combo_buff) as f32;
     
     let base_size = 8.0 + (damage_level - 1.0) * 2.0; // Size increases with level
     
+    // High combos fan the shots out wider and tint them gold so the buff reads at a glance
+    let (spread, color) = if combo_buff {
+        (COMBO_BUFF_SPREAD, Color::srgb(1.0, 0.85, 0.3))
+    } else {
+        (MAIN_CANNON_SPREAD, Color::srgb(0.4, 0.9, 0.7))
+    };
+    
     // Spawn multiple projectiles in a tight spread
     for i in 0..(projectile_count as u32) {
         let offset_x = if projectile_count == 1.0 {
             0.0
         } else {
-            (i as f32 - (projectile_count - 1.0) / 2.0) * 12.0
+            (i as f32 - (projectile_count - 1.0) / 2.0) * spread
         };
         
-        commands.spawn((
+        let velocity = apply_accuracy_spread(Vec2::new(0.0, 850.0), accuracy, rng);
+        let mut projectile = commands.spawn((
             Sprite {
                 image: assets.projectile_texture.clone(),
-                color: Color::srgb(0.4, 0.9, 0.7),
+                color,
                 custom_size: Some(Vec2::splat(base_size)),
                 ..default()
             },
             Transform::from_translation(
                 player_transform.translation + Vec3::new(offset_x, 30.0, 0.0)
-            ),
+            ).with_rotation(shot_rotation(velocity)),
             Projectile {
-                velocity: Vec2::new(0.0, 850.0),
+                velocity,
                 damage: final_damage,
                 friendly: true,
                 organic_trail: true,
@@ -552,7 +712,39 @@ fn spawn_enhanced_main_cannon_projectiles(
                 },
             },
         ));
+        apply_weapon_mods(&mut projectile, mods);
+    }
+}
+
+/// Main cannon volley size: one per damage level (max five), plus one while the combo buff is up
+pub fn main_cannon_projectile_count(damage_level: f32, combo_buff: bool) -> u32 {
+    let base = match damage_level as u32 {
+        1..=1 => 1,
+        2..=2 => 2, // Add second projectile
+        3..=3 => 3, // Add third projectile
+        4..=4 => 4, // Four projectiles
+        _ => 5,     // Maximum five projectiles
+    };
+    if combo_buff { base + 1 } else { base }
+}
+
+/// Accuracy after heat, sustained fire loses up to HEAT_ACCURACY_PENALTY of the base stat
+pub fn effective_accuracy(accuracy: f32, heat: f32) -> f32 {
+    (accuracy * (1.0 - heat.clamp(0.0, 1.0) * HEAT_ACCURACY_PENALTY)).clamp(0.0, 1.0)
+}
+
+/// Rotate a shot by a random angle that widens as accuracy drops, perfect accuracy leaves it untouched
+pub fn apply_accuracy_spread(velocity: Vec2, accuracy: f32, rng: &mut impl Rng) -> Vec2 {
+    let max_angle = MAX_SPREAD_ANGLE * (1.0 - accuracy.clamp(0.0, 1.0));
+    if max_angle <= 0.0 {
+        return velocity;
     }
+    Vec2::from_angle(rng.random_range(-max_angle..=max_angle)).rotate(velocity)
+}
+
+/// Rotation that turns a projectile sprite's local +Y onto its flight direction
+pub fn shot_rotation(velocity: Vec2) -> Quat {
+    Quat::from_rotation_z(velocity.y.atan2(velocity.x) - std::f32::consts::FRAC_PI_2)
 }
 
 fn spawn_wing_cannon_projectiles(
@@ -561,6 +753,8 @@ fn spawn_wing_cannon_projectiles(
     player_transform: &Transform,
     wing_cannon: &WingCannon,
     upgrades: &CellularUpgrades,
+    accuracy: f32,
+    rng: &mut impl Rng,
 ) {
     let enhanced_damage = (wing_cannon.damage as f32 * upgrades.damage_amplification) as i32;
     
@@ -574,6 +768,7 @@ fn spawn_wing_cannon_projectiles(
     };
     
     // Spawn left wing projectile
+    let velocity = apply_accuracy_spread(Vec2::new(0.0, 750.0), accuracy, rng);
     commands.spawn((
         Sprite {
             image: assets.projectile_texture.clone(),
@@ -583,9 +778,9 @@ fn spawn_wing_cannon_projectiles(
         },
         Transform::from_translation(
             player_transform.translation + Vec3::new(-WING_CANNON_OFFSET, WING_CANNON_Y_OFFSET, 0.0)
-        ),
+        ).with_rotation(shot_rotation(velocity)),
         Projectile {
-            velocity: Vec2::new(0.0, 750.0),
+            velocity,
             damage: enhanced_damage,
             friendly: true,
             organic_trail: false,
@@ -599,6 +794,7 @@ fn spawn_wing_cannon_projectiles(
     ));
     
     // Spawn right wing projectile
+    let velocity = apply_accuracy_spread(Vec2::new(0.0, 750.0), accuracy, rng);
     commands.spawn((
         Sprite {
             image: assets.projectile_texture.clone(),
@@ -608,9 +804,9 @@ fn spawn_wing_cannon_projectiles(
         },
         Transform::from_translation(
             player_transform.translation + Vec3::new(WING_CANNON_OFFSET, WING_CANNON_Y_OFFSET, 0.0)
-        ),
+        ).with_rotation(shot_rotation(velocity)),
         Projectile {
-            velocity: Vec2::new(0.0, 750.0),
+            velocity,
             damage: enhanced_damage,
             friendly: true,
             organic_trail: false,
@@ -679,7 +875,7 @@ fn spawn_auto_missiles(
 }
 
 fn find_strongest_enemy_in_range(
-    enemy_query: &Query<(Entity, &Transform, &Enemy), (Without<AutoMissile>, Without<Player>)>,
+    enemy_query: &Query<(Entity, &Transform, &Enemy), (Without<AutoMissile>, Without<Player>, Without<BossIntro>)>,
     player_pos: Vec3,
     range: f32,
 ) -> Option<Entity> {
@@ -719,48 +915,44 @@ fn find_strongest_enemy_in_range(
 
 pub fn wing_cannon_collision_system(
     mut commands: Commands,
-    mut projectile_query: Query<(Entity, &Transform, &Collider, &mut Projectile, &mut WingCannonProjectile),(Without<PendingDespawn>)>,
-    mut enemy_query: Query<(Entity, &Transform, &Collider, &mut Health), (With<Enemy>, Without<WingCannonProjectile>, Without<PendingDespawn>)>,
-    mut explosion_events: EventWriter<SpawnExplosion>,
+    mut projectile_query: Query<(Entity, &Transform, &Collider, &mut Projectile, &mut WingCannonProjectile),Without<PendingDespawn> >,
+    mut enemy_query: Query<(Entity, &Transform, &Collider, &mut Health, &Enemy, Has<DamageCap>), (Without<WingCannonProjectile>, Without<BossIntro>, Without<PendingDespawn>)>,
+    mut deaths: EnemyDeaths,
+    mut enemy_damage: EnemyDamage,
 ) {
     for (proj_entity, proj_transform, proj_collider, mut projectile, mut wing_cannon) in projectile_query.iter_mut() {
-        for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health) in enemy_query.iter_mut() {
+        for (enemy_entity, enemy_transform, enemy_collider, mut enemy_health, enemy, is_capped) in enemy_query.iter_mut() {
+            if enemy_health.0 <= 0 { continue; }
             let distance = proj_transform.translation.distance(enemy_transform.translation);
             
             if distance < proj_collider.radius + enemy_collider.radius {
                 // Apply damage with falloff
                 let actual_damage = (projectile.damage as f32 * wing_cannon.damage_falloff.powi(wing_cannon.pierce_count as i32)) as i32;
-                enemy_health.0 -= actual_damage;
+                enemy_damage.deal(&mut enemy_health, actual_damage, enemy, is_capped, "Wing Cannon");
                 
                 // Spawn hit effect
-                explosion_events.write(SpawnExplosion {
+                deaths.explosion_events.write(SpawnExplosion {
                     position: enemy_transform.translation,
                     intensity: 0.6,
                     enemy_type: None,
                 });
                 
+                // Check if enemy died
+                if enemy_health.0 <= 0 {
+                    deaths.kill(enemy_entity, enemy_transform.translation, enemy);
+                }
+                
                 wing_cannon.pierce_count += 1;
                 
                 // Check if projectile should be destroyed
                 if wing_cannon.pierce_count >= wing_cannon.max_pierce {
                     commands.entity(proj_entity)
                         .safe_despawn();
-                    break;
                 } else {
                     // Reduce damage for next hit
                     projectile.damage = actual_damage;
                 }
                 
-                // Check if enemy died
-                if enemy_health.0 <= 0 {
-                    explosion_events.write(SpawnExplosion {
-                        position: enemy_transform.translation,
-                        intensity: 1.0,
-                        enemy_type: None,
-                    });
-                    commands.entity(enemy_entity).try_insert(PendingDespawn { delay: 0.1 });
-                }
-                
                 break; // Only hit one enemy per frame per projectile
             }
         }
@@ -769,7 +961,7 @@ pub fn wing_cannon_collision_system(
 
 pub fn auto_missile_system(
     mut missile_query: Query<(Entity, &mut Transform, &mut Projectile, &mut AutoMissile)>,
-    enemy_query: Query<(Entity, &Transform, &Enemy), (Without<AutoMissile>, Without<Player>)>,
+    enemy_query: Query<(Entity, &Transform, &Enemy), (Without<AutoMissile>, Without<Player>, Without<BossIntro>)>,
     time: Res<Time>,
 ) {
     for (missile_entity, mut missile_transform, mut projectile, mut auto_missile) in missile_query.iter_mut() {
@@ -798,351 +990,108 @@ pub fn auto_missile_system(
         }
         
         // Homing behavior
-        if let Some(target_entity) = auto_missile.target {
-            if let Ok((_, target_transform, _)) = enemy_query.get(target_entity) {
+        if let Some(target_entity) = auto_missile.target && let Ok((_, target_transform, _)) = enemy_query.get(target_entity) {
 
-                let target_pos = target_transform.translation;
-                let missile_pos = missile_transform.translation;
+            let target_pos = target_transform.translation;
+            let missile_pos = missile_transform.translation;
                                 
-                let direction_to_target = (target_transform.translation - missile_transform.translation).normalize_or_zero();
-                let current_direction = projectile.velocity.normalize_or_zero();
+            let direction_to_target = (target_transform.translation - missile_transform.translation).normalize_or_zero();
+            let current_direction = projectile.velocity.normalize_or_zero();
                 
-                // Smooth homing
-                let homing_rate = auto_missile.homing_strength * 4.0 * time.delta_secs(); // add 4x multiplier
-                let new_direction = (current_direction + direction_to_target.truncate() * homing_rate).normalize_or_zero();
-
-                // Limit velocity to prevent overshooting
-                let distance_to_target = missile_pos.distance(target_pos);
-                let speed_multiplier = if distance_to_target < 100.0 {
-                    // Slow down when close to prevent overshooting
-                    (distance_to_target / 100.0).max(0.3)
-                } else {
-                    1.0
-                };
+            // Smooth homing
+            let homing_rate = auto_missile.homing_strength * 4.0 * time.delta_secs(); // add 4x multiplier
+            let new_direction = (current_direction + direction_to_target.truncate() * homing_rate).normalize_or_zero();
+
+            // Limit velocity to prevent overshooting
+            let distance_to_target = missile_pos.distance(target_pos);
+            let speed_multiplier = if distance_to_target < 100.0 {
+                // Slow down when close to prevent overshooting
+                (distance_to_target / 100.0).max(0.3)
+            } else {
+                1.0
+            };
 
-                projectile.velocity = new_direction * auto_missile.speed * speed_multiplier;
+            projectile.velocity = new_direction * auto_missile.speed * speed_multiplier;
                 
-                // Update rotation
-                let angle = new_direction.y.atan2(new_direction.x) - std::f32::consts::FRAC_PI_2;
-                missile_transform.rotation = Quat::from_rotation_z(angle);
-            }
+            // Update rotation
+            let angle = new_direction.y.atan2(new_direction.x) - std::f32::consts::FRAC_PI_2;
+            missile_transform.rotation = Quat::from_rotation_z(angle);
         }
     }
 }
 
-// Spawn cytoplasmic spray projectiles
-fn spawn_cytoplasmic_projectile(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    player_transform: &Transform,
-    base_damage: i32,
-    adaptations: &CellularAdaptations,
-) {
-    let damage = (base_damage as f32 * adaptations.membrane_permeability) as i32;
-    
-    let mut entity_commands = commands.spawn((
-        Sprite {
-            image: assets.projectile_texture.clone(),
-            color: Color::srgb(0.4, 0.9, 0.7), // Organic cyan-green
-            ..default()
-        },
-        Transform::from_translation(player_transform.translation + Vec3::new(0.0, 30.0, 0.0)),
-        Projectile {
-            velocity: Vec2::new(0.0, 850.0),
-            damage,
-            friendly: true,
-            organic_trail: true,
-        },
-        Collider { radius: 5.0 },
-    ));
-    
-    // Add special properties based on adaptations
-    if adaptations.extremophile_traits {
-        entity_commands.insert(ArmorPiercing {
-            pierce_count: 0,
-            max_pierce: 2,
-            enzyme_based: true,
-        });
-    }
-    
-    if adaptations.biofilm_formation {
-        entity_commands.insert(ExplosiveProjectile {
-            blast_radius: 30.0,
-            blast_damage: damage / 3,
-            organic_explosion: true,
-        });
-    }
-}
+#[derive(Component)]
+pub struct SporeAimReticle;
 
-// Spawn pseudopod network (spread shot)
-fn spawn_pseudopod_network(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    player_transform: &Transform,
-    base_damage: i32,
-    tendril_count: u32,
-    spread_angle: f32,
-    adaptations: &CellularAdaptations,
+/// Aimed emergency spore: first press slows time and shows a reticle, a second press (or Shoot, or the timeout) releases it
+pub fn spore_aiming_system(
+    mut commands: Commands,
+    mut input_manager: ResMut<InputManager>,
+    mut player_query: Query<(&Transform, &mut EvolutionSystem), With<Player>>,
+    mut reticle_query: Query<(Entity, &mut Transform), (With<SporeAimReticle>, Without<Player>)>,
+    mut spore_aiming: ResMut<SporeAiming>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+    assets: Option<Res<GameAssets>>,
 ) {
-    let damage = (base_damage as f32 * adaptations.membrane_permeability) as i32;
-    let angle_step = spread_angle / (tendril_count - 1) as f32;
-    let start_angle = -spread_angle / 2.0;
-    
-    for i in 0..tendril_count {
-        let angle = start_angle + angle_step * i as f32;
-        let direction = Vec2::new(angle.sin(), angle.cos());
-        
-        commands.spawn((
-            Sprite {
-                image: assets.projectile_texture.clone(),
-                color: Color::srgb(0.8, 0.6, 1.0), // Purple pseudopod
-                custom_size: Some(Vec2::splat(4.0)),
-                ..default()
-            },
-            Transform::from_translation(player_transform.translation + Vec3::new(0.0, 30.0, 0.0))
-                .with_rotation(Quat::from_rotation_z(angle)),
-            Projectile {
-                velocity: direction * 720.0,
-                damage,
-                friendly: true,
-                organic_trail: true,
-            },
-            Collider { radius: 4.0 },
-            BioluminescentParticle {
-                base_color: Color::srgb(0.8, 0.6, 1.0),
-                pulse_frequency: 4.0,
-                pulse_intensity: 0.5,
-                organic_motion: OrganicMotion {
-                    undulation_speed: 2.0,
-                    response_to_current: 0.3,
-                },
-            },
-        ));
-    }
-}
+    if !spore_aiming.enabled { return; }
+    let Some(assets) = assets else { return };
+    let Ok((player_transform, mut evolution_system)) = player_query.single_mut() else { return };
 
-// Spawn bioluminescent beam
-fn spawn_bioluminescent_beam(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    player_transform: &Transform,
-    base_damage: i32,
-    duration: f32,
-    width: f32,
-    adaptations: &CellularAdaptations,
-) {
-    let damage = (base_damage as f32 * adaptations.membrane_permeability) as i32;
-    
-    commands.spawn((
-        Sprite {
-            image: assets.projectile_texture.clone(),
-            color: Color::srgb(0.3, 1.0, 0.8), // Bright bioluminescent
-            custom_size: Some(Vec2::new(width, 900.0)),
-            ..default()
-        },
-        Transform::from_translation(player_transform.translation + Vec3::new(0.0, 450.0, 0.0)),
-        LaserBeam {
-            timer: 0.0,
-            max_duration: duration * adaptations.metabolic_efficiency,
-            damage_per_second: damage * 12,
-            width,
-            length: 900.0,
-            bioluminescent: true,
-        },
-        Collider { radius: width / 2.0 },
-        BioluminescentParticle {
-            base_color: Color::srgb(0.3, 1.0, 0.8),
-            pulse_frequency: 8.0,
-            pulse_intensity: 0.8,
-            organic_motion: OrganicMotion {
-                undulation_speed: 0.5,
-                response_to_current: 0.0,
-            },
-        },
-    ));
-}
+    let Some(mut target) = spore_aiming.target else {
+        if evolution_system.emergency_spores > 0 && input_manager.consume_press(InputAction::EmergencySpore) {
+            spore_aiming.begin(player_transform.translation, &mut virtual_time);
+            commands.spawn((
+                Sprite {
+                    image: assets.explosion_texture.clone(),
+                    color: Color::srgba(1.0, 0.8, 0.3, 0.5),
+                    custom_size: Some(Vec2::splat(96.0)),
+                    ..default()
+                },
+                Transform::from_translation(player_transform.translation + Vec3::Z),
+                SporeAimReticle,
+            ));
+        }
+        return;
+    };
 
-// Spawn symbiotic hunter (homing missile)
-fn spawn_symbiotic_hunter(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    player_transform: &Transform,
-    base_damage: i32,
-    target: Option<Entity>,
-    homing_strength: f32,
-    blast_radius: f32,
-    adaptations: &CellularAdaptations,
-) {
-    let damage = (base_damage as f32 * adaptations.membrane_permeability) as i32;
-    
-    commands.spawn((
-        Sprite {
-            image: assets.projectile_texture.clone(),
-            color: Color::srgb(1.0, 0.7, 0.3), // Warm symbiotic orange
-            custom_size: Some(Vec2::splat(8.0)),
-            ..default()
-        },
-        Transform::from_translation(player_transform.translation + Vec3::new(0.0, 30.0, 0.0)),
-        Projectile {
-            velocity: Vec2::new(0.0, 450.0),
-            damage,
-            friendly: true,
-            organic_trail: true,
-        },
-        MissileProjectile {
-            target,
-            homing_strength: homing_strength * adaptations.chemoreceptor_sensitivity,
-            blast_radius,
-            seek_timer: 0.0,
-            symbiotic: true,
-        },
-        ExplosiveProjectile {
-            blast_radius,
-            blast_damage: damage,
-            organic_explosion: true,
-        },
-        Collider { radius: 6.0 },
-        BioluminescentParticle {
-            base_color: Color::srgb(1.0, 0.7, 0.3),
-            pulse_frequency: 3.0,
-            pulse_intensity: 0.6,
-            organic_motion: OrganicMotion {
-                undulation_speed: 1.5,
-                response_to_current: 0.4,
-            },
-        },
-    ));
-}
+    // Reticle moves in real time so aiming stays responsive while the world crawls
+    let dt = real_time.delta_secs();
+    spore_aiming.aim_elapsed += dt;
+    target += (input_manager.movement_vector() * spore_aiming.reticle_speed * dt).extend(0.0);
+    target.x = target.x.clamp(-600.0, 600.0);
+    target.y = target.y.clamp(-350.0, 350.0);
+    spore_aiming.target = Some(target);
 
-// Spawn enzyme burst
-fn spawn_enzyme_burst(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    player_transform: &Transform,
-    base_damage: i32,
-    acid_damage: f32,
-    adaptations: &CellularAdaptations,
-) {
-    let damage = (base_damage as f32 * adaptations.membrane_permeability) as i32;
-    
-    // Spawn multiple enzyme projectiles in a burst
-    for i in 0..5 {
-        let angle = (i as f32 - 2.0) * 0.2;
-        let direction = Vec2::new(angle.sin(), angle.cos());
-        
-        commands.spawn((
-            Sprite {
-                image: assets.projectile_texture.clone(),
-                color: Color::srgb(0.9, 1.0, 0.4), // Acidic yellow-green
-                custom_size: Some(Vec2::splat(6.0)),
-                ..default()
-            },
-            Transform::from_translation(player_transform.translation + Vec3::new(0.0, 30.0, 0.0)),
-            Projectile {
-                velocity: direction * 800.0,
-                damage,
-                friendly: true,
-                organic_trail: true,
-            },
-            Collider { radius: 5.0 },
-            ArmorPiercing {
-                pierce_count: 0,
-                max_pierce: 3,
-                enzyme_based: true,
-            },
-        ));
+    for (_, mut reticle_transform) in reticle_query.iter_mut() {
+        reticle_transform.translation = target + Vec3::Z;
     }
-}
 
-// Spawn toxin cloud
-fn spawn_toxin_cloud(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    player_transform: &Transform,
-    damage_per_second: i32,
-    cloud_radius: f32,
-    duration: f32,
-    adaptations: &CellularAdaptations,
-) {
-    let enhanced_damage = (damage_per_second as f32 * adaptations.membrane_permeability) as i32;
-    
-    commands.spawn((
-        Sprite {
-            image: assets.explosion_texture.clone(),
-            color: Color::srgba(0.7, 0.9, 0.3, 0.6), // Translucent toxic green
-            custom_size: Some(Vec2::splat(cloud_radius * 2.0)),
-            ..default()
-        },
-        Transform::from_translation(player_transform.translation + Vec3::new(0.0, 50.0, 0.0)),
-        ToxinCloudEffect {
-            timer: 0.0,
-            max_duration: duration,
-            damage_per_second: enhanced_damage,
-            radius: cloud_radius,
-            intensity: 1.0,
-        },
-        Collider { radius: cloud_radius },
-    ));
-}
+    let confirmed = input_manager.consume_press(InputAction::EmergencySpore)
+        || input_manager.just_pressed(InputAction::Shoot)
+        || spore_aiming.aim_elapsed >= spore_aiming.max_aim_time;
 
-// Spawn electric discharge
-fn spawn_electric_discharge(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    player_transform: &Transform,
-    enemy_query: &Query<(Entity, &Transform), (With<Enemy>, Without<MissileProjectile>, Without<SporeWave>, Without<LaserBeam>, Without<ToxinCloudEffect>)>,
-    base_damage: i32,
-    chain_count: u32,
-    range: f32,
-    adaptations: &CellularAdaptations,
-) {
-    let damage = (base_damage as f32 * adaptations.membrane_permeability) as i32;
-    
-    // Find nearby enemies for electric chain
-    let mut targets = Vec::new();
-    for (enemy_entity, enemy_transform) in enemy_query.iter() {
-        let distance = player_transform.translation.distance(enemy_transform.translation);
-        if distance <= range && targets.len() < chain_count as usize {
-            targets.push((enemy_entity, enemy_transform.translation));
+    if confirmed {
+        if let Some(position) = spore_aiming.confirm(&mut virtual_time) {
+            spawn_emergency_spore(&mut commands, &assets, position);
+            evolution_system.emergency_spores = evolution_system.emergency_spores.saturating_sub(1);
+        }
+        for (entity, _) in reticle_query.iter() {
+            commands.entity(entity).safe_despawn();
         }
-    }
-    
-    // Create electric discharge effects
-    for (i, (target_entity, target_pos)) in targets.iter().enumerate() {
-        commands.spawn((
-            ElectricArc {
-                start_pos: player_transform.translation.truncate(),
-                end_pos: target_pos.truncate(),
-                damage,
-                chain_index: i as u32,
-                timer: 0.0,
-                max_duration: 0.3,
-                target_entity: Some(*target_entity),
-            },
-        ));
     }
 }
 
-// Spawn clone projectile for binary fission
-fn spawn_clone_projectile(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    player_transform: &Transform,
-    evolution: &EvolutionType,
-    adaptations: &CellularAdaptations,
+/// Leaving play mid-aim must not strand the game in slow motion
+pub fn cancel_spore_aiming(
+    mut commands: Commands,
+    mut spore_aiming: ResMut<SporeAiming>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    reticle_query: Query<Entity, With<SporeAimReticle>>,
 ) {
-    // Create a slightly offset clone projectile
-    let offset = Vec2::new(15.0, 0.0);
-    let clone_transform = Transform::from_translation(player_transform.translation + offset.extend(0.0));
-    
-    match evolution {
-        EvolutionType::CytoplasmicSpray { damage, .. } => {
-            spawn_cytoplasmic_projectile(commands, assets, &clone_transform, *damage, adaptations);
-        }
-        _ => {
-            // Default clone projectile for other weapon types
-            spawn_cytoplasmic_projectile(commands, assets, &clone_transform, 8, adaptations);
-        }
+    spore_aiming.confirm(&mut virtual_time);
+    for entity in reticle_query.iter() {
+        commands.entity(entity).safe_despawn();
     }
 }
 
@@ -1174,3 +1123,126 @@ fn spawn_emergency_spore(commands: &mut Commands, assets: &GameAssets, position:
     ));
 }
 
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn volley(spread_rng: &mut WeaponSpreadRng) -> Vec<Vec2> {
+        (0..8).map(|_| apply_accuracy_spread(Vec2::new(0.0, 850.0), 0.3, &mut spread_rng.rng)).collect()
+    }
+
+    #[test]
+    fn reseeding_replays_the_same_spread() {
+        let mut spread_rng = WeaponSpreadRng::from_seed(42);
+        let first_run = volley(&mut spread_rng);
+        assert_ne!(volley(&mut spread_rng), first_run);
+
+        spread_rng.reseed();
+        assert_eq!(volley(&mut spread_rng), first_run);
+        assert_eq!(volley(&mut WeaponSpreadRng::from_seed(42)), first_run);
+    }
+
+    #[test]
+    fn spread_shots_face_their_flight_direction() {
+        for velocity in volley(&mut WeaponSpreadRng::default()) {
+            let facing = (shot_rotation(velocity) * Vec3::Y).truncate();
+            assert!(facing.abs_diff_eq(velocity.normalize(), 1e-5));
+        }
+    }
+
+    #[test]
+    fn combo_buff_adds_a_shot_above_the_threshold_and_drops_it_when_the_combo_ends() {
+        let mut combo = ComboState { weapon_buff_threshold: 3, ..default() };
+        for _ in 0..2 { combo.register_kill(); }
+        assert_eq!(main_cannon_projectile_count(2.0, combo.weapon_buff_active()), 2);
+
+        combo.register_kill();
+        assert_eq!(main_cannon_projectile_count(2.0, combo.weapon_buff_active()), 3);
+
+        // The combo lapses once the kill window runs out
+        combo.tick(combo.window + 0.1);
+        assert_eq!(main_cannon_projectile_count(2.0, combo.weapon_buff_active()), 2);
+    }
+
+    #[test]
+    fn locked_beams_point_at_their_target() {
+        let origin = Vec3::new(0.0, -250.0, 0.0);
+        let target = Vec2::new(300.0, 150.0);
+        let transform = beam_transform(origin, beam_direction(origin.truncate(), Some(target)), 600.0);
+
+        let heading = (transform.rotation * Vec3::Y).truncate();
+        let to_target = (target - origin.truncate()).normalize();
+        assert!(heading.dot(to_target) > 0.999, "beam heads {heading:?}, target is along {to_target:?}");
+        assert!(beam_hits_circle(origin.truncate(), heading, 600.0, 4.0, target, 10.0));
+    }
+
+    #[test]
+    fn unlocked_beams_fire_straight_up() {
+        let transform = beam_transform(Vec3::ZERO, beam_direction(Vec2::ZERO, None), 600.0);
+        assert!((transform.rotation * Vec3::Y).truncate().abs_diff_eq(Vec2::Y, 1e-5));
+    }
+
+    fn press(app: &mut App, action: InputAction) {
+        app.world_mut().resource_mut::<InputManager>().current_states.insert(action, InputState::JustPressed);
+        app.update();
+        app.world_mut().resource_mut::<InputManager>().current_states.insert(action, InputState::Released);
+    }
+
+    #[test]
+    fn aimed_spore_slows_time_until_the_blast_is_confirmed() {
+        let mut app = App::new();
+        app.init_resource::<Time<Virtual>>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<GameAssets>()
+            .init_resource::<InputManager>()
+            .insert_resource(SporeAiming { enabled: true, ..default() })
+            .add_systems(Update, spore_aiming_system);
+        let player = app.world_mut().spawn((
+            Transform::from_xyz(0.0, -250.0, 0.0),
+            EvolutionSystem::default(),
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+        )).id();
+        let spores = app.world().get::<EvolutionSystem>(player).unwrap().emergency_spores;
+        let blasts = |app: &mut App| app.world_mut().query::<&SporeWave>().iter(app.world()).count();
+
+        press(&mut app, InputAction::EmergencySpore);
+        app.update();
+        let time_scale = SporeAiming::default().time_scale;
+        assert_eq!(app.world().resource::<Time<Virtual>>().relative_speed(), time_scale);
+        assert!(app.world().resource::<SporeAiming>().is_aiming());
+        assert_eq!(blasts(&mut app), 0);
+
+        press(&mut app, InputAction::EmergencySpore);
+        assert_eq!(app.world().resource::<Time<Virtual>>().relative_speed(), 1.0);
+        assert_eq!(blasts(&mut app), 1);
+        assert_eq!(app.world().get::<EvolutionSystem>(player).unwrap().emergency_spores, spores - 1);
+    }
+
+    #[test]
+    fn cycling_with_two_owned_evolutions_switches_and_switches_back() {
+        let mut app = App::new();
+        app.init_resource::<InputManager>()
+            .init_resource::<OwnedEvolutions>()
+            .add_systems(Update, (track_owned_evolutions, cycle_evolution_system).chain());
+        let player = app.world_mut().spawn((
+            EvolutionSystem::default(),
+            Player { speed: 400.0, roll_factor: 0.3, lives: 3, invincible_timer: 0.0, cell_membrane_thickness: 1.0 },
+        )).id();
+        let is_spray = |app: &App| matches!(app.world().get::<EvolutionSystem>(player).unwrap().primary_evolution, EvolutionType::CytoplasmicSpray { .. });
+        let is_network = |app: &App| matches!(app.world().get::<EvolutionSystem>(player).unwrap().primary_evolution, EvolutionType::PseudopodNetwork { .. });
+
+        app.update();
+        assert!(is_spray(&app));
+        app.world_mut().get_mut::<EvolutionSystem>(player).unwrap().primary_evolution =
+            EvolutionType::PseudopodNetwork { damage: 8, fire_rate: 0.15, tendril_count: 5, spread_angle: 0.8 };
+        app.update();
+        assert_eq!(app.world().resource::<OwnedEvolutions>().owned.len(), 2);
+
+        press(&mut app, InputAction::CycleEvolution);
+        assert!(is_spray(&app));
+        press(&mut app, InputAction::CycleEvolution);
+        assert!(is_network(&app));
+    }
+}